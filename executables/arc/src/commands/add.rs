@@ -0,0 +1,57 @@
+use semver::VersionReq;
+
+use crate::managers::dependencies::{read_lockfile, resolve_dependencies, write_lockfile};
+use crate::managers::logging::{log_error, log_info};
+use crate::managers::manifest::{edit_dependency, is_valid_package_name, open_project};
+use crate::managers::registry::{available_versions, registry_path};
+use crate::models::command_args::AddCommandArgs;
+
+/// Add a dependency to the manifest, the manifest is not changed unless the dependencies could be resolved
+pub fn add_dependency(args: AddCommandArgs) {
+    let project = open_project(&args.project_path);
+    if project.is_none() {
+        return;
+    }
+    let (manifest_path, project_root, manifest) = project.unwrap();
+
+    if !is_valid_package_name(args.name.as_str()) || args.name == manifest.package.name {
+        log_error(format!("Invalid dependency name \"{}\"", args.name).as_str());
+        return;
+    }
+
+    let registry = registry_path(&manifest, &project_root);
+    if registry.is_none() {
+        log_error("Couldn't find the package registry, please set \"ARC_REGISTRY\" or \"ARC_HOME\"");
+        return;
+    }
+    let registry = registry.unwrap();
+
+    let requirement = match args.requirement {
+        Some(requirement) => requirement,
+        None => match available_versions(&registry, args.name.as_str()).first() {
+            Some(latest) => format!("^{}", latest),
+            None => {
+                log_error(format!("Package \"{}\" is not found in registry \"{}\"", args.name, registry.display()).as_str());
+                return;
+            }
+        },
+    };
+    if VersionReq::parse(requirement.as_str()).is_err() {
+        log_error(format!("Invalid version requirement \"{}\"", requirement).as_str());
+        return;
+    }
+
+    let mut updated_manifest = manifest.clone();
+    updated_manifest.dependencies.insert(args.name.clone(), requirement.clone());
+    let lockfile = resolve_dependencies(&updated_manifest, &registry, read_lockfile(&project_root).as_ref());
+    if lockfile.is_none() {
+        log_error(format!("Failed to add \"{}\"", args.name).as_str());
+        return;
+    }
+
+    if !edit_dependency(&manifest_path, args.name.as_str(), Some(requirement.as_str())) || !write_lockfile(&project_root, &lockfile.unwrap()) {
+        return;
+    }
+
+    log_info(format!("Added \"{}\" ({}) to dependencies", args.name, requirement).as_str());
+}
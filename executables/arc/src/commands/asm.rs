@@ -0,0 +1,30 @@
+use std::fs;
+
+use carbon_lang_compiler::assembler::encoder::assemble_package;
+
+use crate::managers::diagnostics::report_issues;
+use crate::{
+    managers::logging::{log_error, log_info},
+    models::command_args::AsmCommandArgs,
+};
+
+pub fn assemble(args: AsmCommandArgs) {
+    let source = fs::read_to_string(&args.input_path);
+    if source.is_err() {
+        log_error(format!("Couldn't open file \"{}\"", args.input_path.display()).as_str());
+        return;
+    }
+
+    let package = assemble_package(source.unwrap().as_str());
+    if package.is_err() {
+        report_issues(&package.unwrap_err().issues, Some(&args.input_path));
+        return;
+    }
+
+    if fs::write(&args.output_path, package.unwrap()).is_err() {
+        log_error(format!("Couldn't write package to \"{}\"", args.output_path.display()).as_str());
+        return;
+    }
+
+    log_info(format!("Package is written to \"{}\"", args.output_path.display()).as_str());
+}
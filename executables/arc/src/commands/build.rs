@@ -0,0 +1,96 @@
+use std::fs;
+use std::path::PathBuf;
+
+use chrono::Local;
+
+use carbon_lang_compiler::package_reader::layout_reader::read_package_layout;
+
+use crate::managers::compilation::{build_linked_package, build_sources_with_objects};
+use crate::managers::dependencies::{build_dependencies, prepare_dependencies};
+use crate::managers::logging::{log_error, log_info};
+use crate::managers::manifest::open_project;
+use crate::managers::source_files::collect_source_files;
+use crate::models::command_args::BuildCommandArgs;
+use crate::models::manifest::PackageType;
+
+pub const PACKAGE_FILE_EXTENSION: &str = "cbp";
+/// Where dependencies are built inside the build directory
+pub const DEPENDENCY_DIRECTORY_NAME: &str = "dependencies";
+
+/// Compile the project described by the nearest manifest
+pub fn build_project(args: BuildCommandArgs) {
+    let time_start = Local::now();
+
+    let project = open_project(&args.project_path);
+    if project.is_none() {
+        log_error("Build aborted!");
+        return;
+    }
+    let (_, project_root, manifest) = project.unwrap();
+
+    let source_directories: Vec<PathBuf> = manifest.build.source_dirs.iter().map(|d| project_root.join(d)).collect();
+    let mut source_files = vec![];
+    for path in &source_directories {
+        if !path.is_dir() {
+            log_error(format!("Source directory \"{}\" is not found", path.display()).as_str());
+            return;
+        }
+        source_files.extend(collect_source_files(path));
+    }
+    if source_files.is_empty() {
+        log_error("No source file found");
+        return;
+    }
+
+    let dependency_directories = prepare_dependencies(&manifest, &project_root);
+    if dependency_directories.is_none() {
+        log_error("Failed to prepare dependencies, build aborted!");
+        return;
+    }
+    let dependency_directories = dependency_directories.unwrap();
+
+    // Objects and caches of unchanged source files are reused by the next build
+    let output_dir = project_root.join(&manifest.build.output_dir);
+
+    let mut descriptor = manifest.package_descriptor();
+    // Libraries keep calls of their dependencies external, they are linked into the executable using them
+    let package = if dependency_directories.is_empty() || manifest.package.package_type == PackageType::Library {
+        build_sources_with_objects(source_files, &project_root, manifest.package.entry.clone(), &descriptor.metadata, &output_dir, false)
+    } else {
+        // Every dependency is built on its own, so only the functions it exports could be called
+        let dependencies = build_dependencies(&dependency_directories, &descriptor.metadata, &output_dir.join(DEPENDENCY_DIRECTORY_NAME));
+        if dependencies.is_none() {
+            log_error("Failed to build dependencies, build aborted!");
+            return;
+        }
+        build_linked_package(source_files, &project_root, manifest.package.entry.clone(), &descriptor.metadata, &output_dir, dependencies.unwrap())
+    };
+    if package.is_none() {
+        log_error("Build aborted!");
+        return;
+    }
+    let package = package.unwrap();
+    descriptor.entry_offset = read_package_layout(&package).ok().and_then(|l| l.entry_point);
+
+    let output_path = output_dir.join(format!("{}.{}", descriptor.name, PACKAGE_FILE_EXTENSION));
+    if fs::create_dir_all(&output_dir).and_then(|_| fs::write(&output_path, &package)).is_err() {
+        log_error(format!("Couldn't write package \"{}\"", output_path.display()).as_str());
+        return;
+    }
+
+    let kind = match descriptor.entry_offset {
+        Some(offset) => format!("entry at 0x{:X}", offset),
+        None => "library".to_string(),
+    };
+    let time_spanned = Local::now() - time_start;
+    log_info(format!(
+        "Built {} v{} ({} bytes, {}, compiler {}) to \"{}\" in {}s",
+        descriptor.name,
+        manifest.package.version,
+        package.len(),
+        kind,
+        descriptor.compiler_version,
+        output_path.display(),
+        time_spanned.num_milliseconds() as f64 / 1000_f64
+    ).as_str());
+}
@@ -0,0 +1,30 @@
+use std::process;
+
+use chrono::Local;
+
+use crate::{
+    managers::logging::{log_error, log_info},
+    models::command_args::CheckCommandArgs,
+};
+use crate::managers::compilation::check_sources;
+use crate::managers::source_files::find_source_files;
+
+/// Run lexical analysis, token parsing and availability checks without generating a package, all issues of every file are reported
+pub fn check_project(args: CheckCommandArgs) {
+    let time_start = Local::now();
+
+    let discovery = find_source_files(&args.input_path);
+    if discovery.is_none() {
+        log_error("Check aborted!");
+        process::exit(1);
+    }
+    let (source_files, project_root) = discovery.unwrap();
+
+    if !check_sources(source_files, &project_root, args.entry_function.clone()) {
+        log_error("Check failed");
+        process::exit(1);
+    }
+
+    let time_spanned = Local::now() - time_start;
+    log_info(format!("No problem found in {}s", time_spanned.num_milliseconds() as f64 / 1000_f64).as_str());
+}
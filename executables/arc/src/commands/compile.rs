@@ -1,132 +1,70 @@
-use std::{fs, io::Write};
+use std::fs;
 
 use chrono::Local;
-use console::style;
 
-use carbon_lang_compiler::{
-    package_generator::{command_builder::function_block::build_function_command, utils::align_array_width},
-    shared::package_generation::{
-        package_descriptor::PackageMetadata, relocation_reference::{RelocatableCommandList, RelocationReferenceType},
-    },
-};
+use carbon_lang_compiler::shared::package_generation::implementations::package_descriptor::LIBRARY_PACKAGE_TYPE;
+use carbon_lang_compiler::shared::package_generation::package_descriptor::PackageMetadata;
 
 use crate::{
-    managers::logging::{log_error, log_info, log_trace},
+    managers::logging::{log_error, log_info},
     models::command_args::CompileCommandArgs,
 };
-use crate::managers::compilation::{parse_tokens, token_conversion};
+use crate::managers::compilation::{build_object, build_sources, build_sources_with_objects, serialize_object};
+use crate::managers::source_files::find_source_files;
+use crate::managers::watcher::watch_sources;
 
 pub fn compile_package(args: CompileCommandArgs) {
-    // Calculate procedure time
-    let time_start = Local::now();
-
-    // Find out whether we are going to compile a directory or files
-    if args.input_path.is_dir() {
-        // TODO: Add directory builder
-    } else {
-        let file_content = fs::read_to_string(&args.input_path);
-        if file_content.is_ok() {
-            let tokens_result = token_conversion(file_content.unwrap().as_str());
-            if tokens_result.is_none() {
-                log_error("Failed to pass lexical analysis, compilation aborted");
-                return;
-            } else {
-                log_info("Lexical analysis passed");
-            }
-
-            let tokens = tokens_result.unwrap();
-
-            log_trace(format!("Found {} tokens", tokens.0.len()).as_str());
-
-            let decorated_tokens = tokens.0;
-            let string_pool = tokens.1;
-
-            let tree_result = parse_tokens(decorated_tokens, Some(args.entry_function));
-            if tree_result.is_none() {
-                log_error("Failed to pass token parsing, compilation aborted!");
-                return;
-            } else {
-                log_info("Token parsing passed");
-            }
-
-            let metadata = PackageMetadata {
-                data_slot_alignment: 2,
-                data_alignment: 8,
-                package_type: 0,
-                domain_layer_count_alignment: 2,
-                address_alignment: 8,
-                global_command_offset: 5,
-            };
-
-            if tree_result.is_some() {
-                let tree = tree_result.unwrap();
-
-                let mut output = RelocatableCommandList::new();
-                output.string_pool = string_pool;
-                output.function_table = tree.export_function_table();
-                // Place metadata
-                let serialized_metadata = metadata.serialize();
-                output.append_commands(serialized_metadata);
-                let prefix_len = output.commands.len();
-
-                // Reserve space for entry point if it is an executable
-                if metadata.package_type == 0 {
-                    output.append_commands(align_array_width(&vec![0x00], metadata.address_alignment));
-                }
-
-                // Place string pool
-                let string_pool_command = output.generate_string_pool(metadata.data_slot_alignment);
-                output.append_commands(string_pool_command);
+    if args.watch {
+        watch_sources(&args.input_path, || compile_once(&args));
+        return;
+    }
 
-                // Generate function commands
-                let mut func_commands_staging = RelocatableCommandList::new();
-                for func in &tree.functions {
-                    // Set function entry point address in command section
-                    let table_target = output.function_table.iter_mut().find(|f| f.name == func.declarator.identifier).unwrap();
-                    table_target.relocated_entry_address = func_commands_staging.commands.len();
+    compile_once(&args);
+}
 
-                    func_commands_staging.combine(build_function_command(func, &metadata));
-                }
+/// Compile the input once, returns whether the package is written
+fn compile_once(args: &CompileCommandArgs) -> bool {
+    // Calculate procedure time
+    let time_start = Local::now();
 
-                // Place function table
-                let function_table_command = output.generate_function_table(metadata.address_alignment);
-                output.append_commands(function_table_command);
+    let discovery = find_source_files(&args.input_path);
+    if discovery.is_none() {
+        log_error("Compilation aborted!");
+        return false;
+    }
+    let (source_files, project_root) = discovery.unwrap();
 
-                output.combine(func_commands_staging);
+    let mut metadata = PackageMetadata::default();
+    if args.library {
+        metadata.package_type = LIBRARY_PACKAGE_TYPE;
+    }
 
-                output.calculate_ref_to_target();
-                output.apply_relocation(metadata.address_alignment);
+    let output = if args.object {
+        build_object(source_files, &project_root, args.entry_function.clone(), &metadata).and_then(|o| serialize_object(&o, None))
+    } else if let Some(build_dir) = &args.build_dir {
+        build_sources_with_objects(source_files, &project_root, args.entry_function.clone(), &metadata, build_dir, false)
+    } else {
+        build_sources(source_files, &project_root, args.entry_function.clone(), &metadata, args.debug)
+    };
+    if output.is_none() {
+        log_error("Compilation aborted!");
+        return false;
+    }
 
-                // Place entry_point
-                let entry_function = output.descriptors.references.iter()
-                                           .find(|&p| p.ref_type == RelocationReferenceType::FunctionEntrance(tree.entry_point.clone()))
-                                           .unwrap();
-                let addr_u8_vec = align_array_width(entry_function.command_array_position.to_be_bytes().to_vec().as_ref(), metadata.address_alignment);
-                output.commands.splice(prefix_len..(prefix_len + metadata.address_alignment as usize), addr_u8_vec);
+    // A watching compiler outlives a missing output directory, so writing failures are only reported
+    if let Err(e) = fs::write(&args.output_path, output.unwrap()) {
+        log_error(format!("Couldn't write \"{}\": {}", args.output_path.display(), e).as_str());
+        return false;
+    }
 
-                let mut output_file = fs::File::create(&args.output_path).unwrap();
-                output_file.write_all(&output.commands.as_slice()).unwrap();
+    let time_spanned = Local::now() - time_start;
+    log_info(
+        format!(
+            "Compilation finished in {}s",
+            (time_spanned.num_milliseconds() as f64 / 1000 as f64)
+        )
+            .as_str(),
+    );
 
-                let time_spanned = Local::now() - time_start;
-                log_info(
-                    format!(
-                        "Compilation finished in {}s",
-                        (time_spanned.num_milliseconds() as f64 / 1000 as f64)
-                    )
-                        .as_str(),
-                );
-            } else {
-                log_error("Errors occurred during code generation");
-            }
-        } else {
-            log_error(
-                format!(
-                    "{}: couldn't open file \"{}\"",
-                    style("Error").red(),
-                    style(args.input_path.as_path().to_str().unwrap()).green()
-                )
-                    .as_str(),
-            );
-        }
-    }
+    return true;
 }
@@ -0,0 +1,156 @@
+use std::fs;
+use std::io::{stdin, BufRead};
+
+use carbon_lang_vm::models::virtual_machine::VirtualMachine;
+
+use crate::{
+    managers::logging::{log_error, log_info, log_warn},
+    models::command_args::DebugCommandArgs,
+};
+use crate::managers::debugger::{backtrace, describe_position, domain_nesting, frame_locals, operand_stack, resolve_breakpoint, resume, start};
+use crate::managers::execution::parse_arguments;
+use crate::models::debug_session::{Breakpoint, DebugSession, DebugStop, StepMode};
+use crate::STDOUT;
+
+const PROMPT: &str = "(debug) ";
+
+const HELP: &str = r#"Commands:
+  run, r                  Start the entry function, again if it is already running
+  continue, c             Run until a breakpoint or the end
+  step, s                 Run until the next source line, entering called functions
+  next, n                 Run until the next source line of this or a calling function
+  finish, f               Run until the current function returns
+  stepi, si               Execute a single command
+  break, b <target>       Stop before a function, "file:line" or a line of any file
+  delete, d <index>       Remove a breakpoint
+  breakpoints, bl         List breakpoints
+  where, w                Show the current position and command
+  backtrace, bt           List frames from the innermost one
+  locals, l [frame]       Show local slots of a frame, the innermost one by default
+  stack                   Show the operand stack from the top
+  domains                 Show domains containing the current command
+  help, h                 Show this message
+  quit, q                 Leave the debugger
+Without a debug section, stepping goes command by command and only functions could be breakpoints."#;
+
+/// Read debugger commands from STDIN until it ends or "quit" is typed
+pub fn debug_package(args: DebugCommandArgs) {
+    let package = fs::read(&args.package_path);
+    if package.is_err() {
+        log_error(format!("Couldn't open package \"{}\"", args.package_path.display()).as_str());
+        return;
+    }
+
+    let vm = VirtualMachine::from_bytes(package.unwrap().as_slice());
+    if vm.is_err() {
+        log_error(format!("Failed to load package: {}", vm.unwrap_err()).as_str());
+        return;
+    }
+    let vm = vm.unwrap();
+    if vm.layout.debug_info.is_none() {
+        log_warn("The package has no debug section, compile it with \"--debug\" to debug it by source lines");
+    }
+
+    let mut session = DebugSession::new(vm, parse_arguments(&args.arguments), args.max_steps);
+    log_info("Type \"help\" for help, \"run\" to start the entry function");
+
+    let mut lines = stdin().lock().lines();
+    loop {
+        STDOUT.write_str(PROMPT).unwrap();
+        let line = lines.next();
+        if line.is_none() || line.as_ref().unwrap().is_err() {
+            STDOUT.write_line("").unwrap();
+            return;
+        }
+        let line = line.unwrap().unwrap();
+
+        let words: Vec<&str> = line.split_whitespace().collect();
+        if words.is_empty() {
+            continue;
+        }
+
+        match (words[0], &words[1..]) {
+            ("quit" | "q" | "exit", _) => return,
+            ("help" | "h", _) => write_lines(&[HELP.to_string()]),
+            ("run" | "r", _) => {
+                let stop = start(&mut session);
+                report_stop(&session, stop);
+            }
+            ("continue" | "c", _) => step(&mut session, StepMode::Continue),
+            ("step" | "s", _) => step(&mut session, StepMode::Into),
+            ("next" | "n", _) => step(&mut session, StepMode::Over),
+            ("finish" | "f", _) => step(&mut session, StepMode::Out),
+            ("stepi" | "si", _) => step(&mut session, StepMode::Instruction),
+            ("break" | "b", [target]) => match resolve_breakpoint(&session.vm.layout, target) {
+                Ok(addresses) => {
+                    session.breakpoints.push(Breakpoint { target: target.to_string(), addresses });
+                    log_info(format!("Breakpoint {}: {}", session.breakpoints.len() - 1, session.breakpoints.last().unwrap()).as_str());
+                }
+                Err(message) => log_error(message.as_str()),
+            },
+            ("delete" | "d", [index]) => match index.parse::<usize>() {
+                Ok(index) if index < session.breakpoints.len() => {
+                    let removed = session.breakpoints.remove(index);
+                    log_info(format!("Breakpoint at {} is removed", removed.target).as_str());
+                }
+                _ => log_error(format!("Breakpoint {} doesn't exist", index).as_str()),
+            },
+            ("breakpoints" | "bl", _) => {
+                let breakpoints: Vec<String> = session.breakpoints.iter().enumerate().map(|(i, b)| format!("{}: {}", i, b)).collect();
+                write_lines(&breakpoints);
+            }
+            ("where" | "w", _) if session.is_running() => write_lines(&describe_position(&session.vm)),
+            ("backtrace" | "bt", _) if session.is_running() => write_lines(&backtrace(&session.vm)),
+            ("locals" | "l", [] | [_]) if session.is_running() => {
+                let frame = words.get(1).map_or(Ok(0), |w| w.parse::<usize>());
+                match frame.map_err(|_| "Frame should be a number".to_string()).and_then(|f| frame_locals(&session.vm, f)) {
+                    Ok(locals) => write_lines(&locals),
+                    Err(message) => log_error(message.as_str()),
+                }
+            }
+            ("stack", _) if session.is_running() => write_lines(&operand_stack(&session.vm)),
+            ("domains", _) if session.is_running() => match domain_nesting(&session.vm) {
+                Ok(domains) => write_lines(&domains),
+                Err(message) => log_error(message.as_str()),
+            },
+            ("where" | "w" | "backtrace" | "bt" | "locals" | "l" | "stack" | "domains", _) => log_error("The program is not running"),
+            ("break" | "b" | "delete" | "d", _) => log_error(format!("\"{}\" takes a single argument, type \"help\" for help", words[0]).as_str()),
+            _ => log_error(format!("Unknown command \"{}\", type \"help\" for help", line.trim()).as_str()),
+        }
+    }
+}
+
+fn step(session: &mut DebugSession, mode: StepMode) {
+    if !session.is_running() {
+        log_error("The program is not running, type \"run\" to start it");
+        return;
+    }
+
+    let stop = resume(session, mode);
+    report_stop(session, stop);
+}
+
+fn report_stop(session: &DebugSession, stop: DebugStop) {
+    match stop {
+        DebugStop::Breakpoint(index) => {
+            log_info(format!("Stopped at breakpoint {} ({})", index, session.breakpoints[index].target).as_str());
+            write_lines(&describe_position(&session.vm));
+        }
+        DebugStop::Stepped => write_lines(&describe_position(&session.vm)),
+        DebugStop::Finished(Some(value)) => log_info(format!("Entry function returned {}", value).as_str()),
+        DebugStop::Finished(None) => log_info("Entry function returned without value"),
+        DebugStop::Failed(message) => {
+            log_error(message.as_str());
+            // Runtime errors leave the machine where the failed command is, so it could still be inspected
+            if session.is_running() {
+                write_lines(&describe_position(&session.vm));
+            }
+        }
+    }
+}
+
+fn write_lines(lines: &[String]) {
+    for line in lines {
+        STDOUT.write_line(line.as_str()).unwrap();
+    }
+}
@@ -0,0 +1,35 @@
+use std::fs;
+
+use carbon_lang_compiler::package_reader::disassembler::disassemble_package;
+
+use crate::managers::diagnostics::report_issues;
+use crate::{
+    managers::logging::{log_error, log_info},
+    models::command_args::DisasmCommandArgs,
+    STDOUT,
+};
+
+pub fn disassemble(args: DisasmCommandArgs) {
+    let package = fs::read(&args.package_path);
+    if package.is_err() {
+        log_error(format!("Couldn't open package \"{}\"", args.package_path.display()).as_str());
+        return;
+    }
+
+    let listing = disassemble_package(package.unwrap().as_slice());
+    if listing.is_err() {
+        report_issues(&listing.unwrap_err().issues, Some(&args.package_path));
+        return;
+    }
+
+    match args.output_path {
+        Some(path) => {
+            if fs::write(&path, listing.unwrap()).is_err() {
+                log_error(format!("Couldn't write listing to \"{}\"", path.display()).as_str());
+                return;
+            }
+            log_info(format!("Listing is written to \"{}\"", path.display()).as_str());
+        }
+        None => STDOUT.write_str(listing.unwrap().as_str()).unwrap(),
+    }
+}
@@ -0,0 +1,58 @@
+use std::fs;
+use std::path::Path;
+
+use carbon_lang_compiler::documentation::renderer::{page_name, render_file, render_index};
+use carbon_lang_compiler::shared::documentation::file_documentation::DocumentationFormat;
+
+use crate::{
+    managers::logging::{log_error, log_info},
+    models::command_args::DocCommandArgs,
+};
+use crate::managers::documentation::document_units;
+use crate::managers::source_files::{find_source_files, load_separate_sources};
+
+/// Write a page for every source file and the files they link to, with an index page linking all of them
+pub fn generate_documentation(args: DocCommandArgs) {
+    let format = match args.format.as_str() {
+        "html" => DocumentationFormat::Html,
+        _ => DocumentationFormat::Markdown,
+    };
+
+    let discovery = find_source_files(&args.input_path);
+    if discovery.is_none() {
+        log_error("Documentation aborted!");
+        return;
+    }
+    let (source_files, project_root) = discovery.unwrap();
+
+    // Files are documented separately, so the entry function doesn't matter
+    let units = load_separate_sources(source_files, &project_root, "main".to_string(), None);
+    if units.is_none() {
+        log_error("Failed to load source files, documentation aborted!");
+        return;
+    }
+    let files = document_units(&units.unwrap(), &project_root);
+
+    if let Err(e) = fs::create_dir_all(&args.output_dir) {
+        log_error(format!("Couldn't create directory \"{}\": {}", args.output_dir.display(), e).as_str());
+        return;
+    }
+
+    // The root of a file in the current directory is empty
+    let root = if project_root.as_os_str().is_empty() { Path::new(".") } else { project_root.as_path() };
+    let title = fs::canonicalize(root).ok()
+                                      .and_then(|p| p.file_name().map(|n| n.to_string_lossy().to_string()))
+                                      .unwrap_or("Documentation".to_string());
+    let mut pages = vec![(page_name("index", format), render_index(title.as_str(), &files, format))];
+    pages.extend(files.iter().map(|file| (page_name(&file.file_path, format), render_file(file, &files, format))));
+
+    for (name, content) in &pages {
+        let path = args.output_dir.join(name);
+        if fs::write(&path, content).is_err() {
+            log_error(format!("Couldn't write file \"{}\"", path.display()).as_str());
+            return;
+        }
+    }
+
+    log_info(format!("Documented {} files in \"{}\"", files.len(), args.output_dir.display()).as_str());
+}
@@ -0,0 +1,66 @@
+use std::fs;
+use std::process;
+
+use carbon_lang_compiler::formatter::format::format_source;
+
+use crate::managers::diagnostics::report_issues;
+use crate::managers::logging::{log_error, log_info};
+use crate::managers::source_files::collect_source_files;
+use crate::models::command_args::FmtCommandArgs;
+
+/// Format source files in place, or only report unformatted files in check mode.
+/// The process exits with 1 if any file couldn't be formatted or is not formatted in check mode.
+pub fn format_files(args: FmtCommandArgs) {
+    let mut source_files = vec![];
+    for path in &args.input_paths {
+        if path.is_dir() {
+            source_files.extend(collect_source_files(path));
+        } else {
+            source_files.push(path.clone());
+        }
+    }
+
+    let mut failed = false;
+    let mut changed_count = 0;
+    for file_path in &source_files {
+        let content = fs::read_to_string(file_path);
+        if content.is_err() {
+            log_error(format!("Couldn't open file \"{}\"", file_path.display()).as_str());
+            failed = true;
+            continue;
+        }
+        let content = content.unwrap();
+
+        let formatted = format_source(content.as_str());
+        if formatted.is_err() {
+            let issue = formatted.unwrap_err().with_file_path(file_path.display().to_string().as_str());
+            report_issues(&issue.issues, Some(file_path));
+            failed = true;
+            continue;
+        }
+        let formatted = formatted.unwrap();
+
+        if formatted == content {
+            continue;
+        }
+        changed_count += 1;
+
+        if args.check {
+            log_error(format!("\"{}\" is not formatted", file_path.display()).as_str());
+            failed = true;
+        } else if fs::write(file_path, formatted).is_err() {
+            log_error(format!("Couldn't write file \"{}\"", file_path.display()).as_str());
+            failed = true;
+        } else {
+            log_info(format!("Formatted \"{}\"", file_path.display()).as_str());
+        }
+    }
+
+    if failed {
+        process::exit(1);
+    }
+
+    if args.check || changed_count == 0 {
+        log_info(format!("{} files are formatted", source_files.len()).as_str());
+    }
+}
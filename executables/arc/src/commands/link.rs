@@ -0,0 +1,63 @@
+use std::fs;
+
+use carbon_lang_compiler::linker::{object_linker, package_linker};
+use carbon_lang_compiler::package_reader::object_reader::{is_object_file, read_object_file};
+use carbon_lang_compiler::shared::package_generation::implementations::package_descriptor::{EXECUTABLE_PACKAGE_TYPE, LIBRARY_PACKAGE_TYPE};
+
+use crate::managers::diagnostics::report_issues;
+use crate::managers::logging::{log_error, log_info};
+use crate::models::command_args::LinkCommandArgs;
+
+/// Merge library packages into an executable, or objects into a package, duplicate or missing symbols are reported
+pub fn link_packages(args: LinkCommandArgs) {
+    let mut packages = vec![];
+    for path in &args.input_paths {
+        let bytes = fs::read(path);
+        if bytes.is_err() {
+            log_error(format!("Couldn't open package \"{}\"", path.display()).as_str());
+            return;
+        }
+        packages.push((path.display().to_string(), bytes.unwrap()));
+    }
+
+    let object_count = packages.iter().filter(|(_, bytes)| is_object_file(bytes)).count();
+    let package = if object_count == 0 {
+        if args.library {
+            log_error("Packages are always linked into an executable, \"--lib\" is only valid for objects");
+            return;
+        }
+        package_linker::link_packages(&packages, args.entry_function.as_str())
+    } else if object_count == packages.len() {
+        let mut objects = vec![];
+        for ((name, bytes), path) in packages.iter().zip(&args.input_paths) {
+            let object = read_object_file(bytes);
+            if object.is_err() {
+                report_issues(&object.unwrap_err().issues, Some(path));
+                log_error(format!("Invalid object \"{}\"", name).as_str());
+                return;
+            }
+            objects.push((name.clone(), object.unwrap()));
+        }
+
+        // The package shares alignments of the objects, which are checked by the linker
+        let mut metadata = objects[0].1.metadata.clone();
+        metadata.package_type = if args.library { LIBRARY_PACKAGE_TYPE } else { EXECUTABLE_PACKAGE_TYPE };
+        object_linker::link_objects(&objects, args.entry_function.as_str(), &metadata)
+    } else {
+        log_error("Objects and packages couldn't be linked together, link the objects into a library first");
+        return;
+    };
+
+    if package.is_err() {
+        report_issues(&package.unwrap_err().issues, None);
+        log_error("Linking failed");
+        return;
+    }
+
+    if fs::write(&args.output_path, package.unwrap()).is_err() {
+        log_error(format!("Couldn't write package to \"{}\"", args.output_path.display()).as_str());
+        return;
+    }
+
+    log_info(format!("Linked {} inputs to \"{}\"", packages.len(), args.output_path.display()).as_str());
+}
@@ -0,0 +1,98 @@
+use std::process;
+
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, Response};
+use lsp_types::notification::{DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument, Notification as _, PublishDiagnostics};
+use lsp_types::request::{Completion, GotoDefinition, HoverRequest, Request as _};
+use lsp_types::{
+    CompletionOptions, DidChangeTextDocumentParams, DidCloseTextDocumentParams, DidOpenTextDocumentParams, HoverProviderCapability,
+    OneOf, PublishDiagnosticsParams, ServerCapabilities, TextDocumentSyncCapability, TextDocumentSyncKind,
+};
+
+use crate::managers::language_server::DocumentStore;
+use crate::managers::logging::{log_error, log_info};
+use crate::models::command_args::LspCommandArgs;
+
+/// Serve the language server protocol on STDIN and STDOUT until the editor asks to exit
+pub fn start_language_server(args: LspCommandArgs) {
+    if args.socket.is_some() || args.pipe.is_some() {
+        log_error("Only the STDIO transport is supported, start the language server with \"--stdio\"");
+        process::exit(1);
+    }
+    if !args.stdio {
+        log_info("No transport is given, STDIN and STDOUT are used");
+    }
+
+    let (connection, io_threads) = Connection::stdio();
+
+    let capabilities = ServerCapabilities {
+        text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+        definition_provider: Some(OneOf::Left(true)),
+        hover_provider: Some(HoverProviderCapability::Simple(true)),
+        completion_provider: Some(CompletionOptions::default()),
+        ..ServerCapabilities::default()
+    };
+    if let Err(e) = connection.initialize(serde_json::to_value(capabilities).unwrap()) {
+        log_error(format!("Failed to initialize the language server: {}", e).as_str());
+        return;
+    }
+    log_info("Language server started");
+
+    let mut documents = DocumentStore::default();
+    for message in &connection.receiver {
+        match message {
+            Message::Request(request) => {
+                if connection.handle_shutdown(&request).unwrap_or(true) {
+                    break;
+                }
+                connection.sender.send(Message::Response(handle_request(&documents, request))).unwrap();
+            }
+            Message::Notification(notification) => {
+                if let Some(diagnostics) = handle_notification(&mut documents, notification) {
+                    let message = Notification::new(PublishDiagnostics::METHOD.to_string(), diagnostics);
+                    connection.sender.send(Message::Notification(message)).unwrap();
+                }
+            }
+            Message::Response(_) => {}
+        }
+    }
+
+    drop(connection);
+    io_threads.join().unwrap();
+    log_info("Language server stopped");
+}
+
+fn handle_request(documents: &DocumentStore, request: Request) -> Response {
+    let id = request.id.clone();
+    let result = match request.method.as_str() {
+        GotoDefinition::METHOD => serde_json::from_value(request.params).map(|p| serde_json::to_value(documents.definition(p))),
+        HoverRequest::METHOD => serde_json::from_value(request.params).map(|p| serde_json::to_value(documents.hover(p))),
+        Completion::METHOD => serde_json::from_value(request.params).map(|p| serde_json::to_value(documents.completion(p))),
+        _ => return Response::new_err(id, ErrorCode::MethodNotFound as i32, format!("Unsupported method \"{}\"", request.method)),
+    };
+
+    return match result {
+        Ok(value) => Response { id, result: Some(value.unwrap()), error: None },
+        Err(e) => Response::new_err(id, ErrorCode::InvalidParams as i32, e.to_string()),
+    };
+}
+
+/// Returns the diagnostics to be published if a document is changed
+fn handle_notification(documents: &mut DocumentStore, notification: Notification) -> Option<PublishDiagnosticsParams> {
+    return match notification.method.as_str() {
+        DidOpenTextDocument::METHOD => {
+            let params: DidOpenTextDocumentParams = serde_json::from_value(notification.params).ok()?;
+            Some(documents.update(params.text_document.uri, params.text_document.text.as_str()))
+        }
+        DidChangeTextDocument::METHOD => {
+            // The whole document is sent on every change
+            let params: DidChangeTextDocumentParams = serde_json::from_value(notification.params).ok()?;
+            let text = params.content_changes.last()?.text.clone();
+            Some(documents.update(params.text_document.uri, text.as_str()))
+        }
+        DidCloseTextDocument::METHOD => {
+            let params: DidCloseTextDocumentParams = serde_json::from_value(notification.params).ok()?;
+            Some(documents.close(params.text_document.uri))
+        }
+        _ => None,
+    };
+}
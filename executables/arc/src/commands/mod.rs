@@ -1 +1,17 @@
+pub mod add;
+pub mod asm;
+pub mod build;
+pub mod check;
 pub mod compile;
+pub mod debug;
+pub mod disasm;
+pub mod doc;
+pub mod fmt;
+pub mod link;
+pub mod lsp;
+pub mod new;
+pub mod publish;
+pub mod remove;
+pub mod repl;
+pub mod run;
+pub mod test;
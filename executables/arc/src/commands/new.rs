@@ -0,0 +1,64 @@
+use std::collections::BTreeMap;
+use std::fs;
+
+use carbon_lang_compiler::shared::package_generation::package_descriptor::PackageMetadata;
+
+use crate::managers::logging::{log_error, log_info};
+use crate::managers::manifest::{is_valid_package_name, MANIFEST_FILE_NAME};
+use crate::models::command_args::NewCommandArgs;
+use crate::models::manifest::{BuildSection, Manifest, MetadataSection, PackageSection, PackageType};
+
+const MAIN_SOURCE: &str = "decl func main()[number] {\n    return 0;\n}\n";
+// Only exported functions could be called by packages it is linked with
+const LIBRARY_SOURCE: &str = "export decl func add(number a, number b)[number] {\n    return a + b;\n}\n";
+
+/// Create a project directory with a manifest and an entry source file, or a library source file
+pub fn new_project(args: NewCommandArgs) {
+    let name = args.name.clone().or_else(|| args.project_path.file_name().map(|n| n.to_string_lossy().to_string()));
+    if name.is_none() || !is_valid_package_name(name.as_ref().unwrap()) {
+        log_error("Invalid package name, only letters, digits, \"_\" and \"-\" are allowed, use \"--name\" to set it");
+        return;
+    }
+    let name = name.unwrap();
+
+    if args.project_path.exists() && fs::read_dir(&args.project_path).map_or(true, |mut d| d.next().is_some()) {
+        log_error(format!("\"{}\" already exists and is not an empty directory", args.project_path.display()).as_str());
+        return;
+    }
+
+    let metadata = PackageMetadata::default();
+    let manifest = Manifest {
+        package: PackageSection {
+            name: name.clone(),
+            version: "0.1.0".to_string(),
+            author: String::new(),
+            entry: "main".to_string(),
+            package_type: if args.library { PackageType::Library } else { PackageType::Executable },
+        },
+        build: BuildSection::default(),
+        // Written out so they could be found easily
+        metadata: MetadataSection {
+            data_alignment: Some(metadata.data_alignment),
+            domain_layer_count_alignment: Some(metadata.domain_layer_count_alignment),
+            data_slot_alignment: Some(metadata.data_slot_alignment),
+            address_alignment: Some(metadata.address_alignment),
+        },
+        dependencies: BTreeMap::new(),
+        registry: None,
+    };
+
+    let source_dir = args.project_path.join(&manifest.build.source_dirs[0]);
+    let result = fs::create_dir_all(&source_dir)
+        .and_then(|_| fs::write(args.project_path.join(MANIFEST_FILE_NAME), toml::to_string(&manifest).unwrap()))
+        .and_then(|_| match manifest.package.package_type {
+            PackageType::Executable => fs::write(source_dir.join("main.cbs"), MAIN_SOURCE),
+            PackageType::Library => fs::write(source_dir.join("lib.cbs"), LIBRARY_SOURCE),
+        })
+        .and_then(|_| fs::write(args.project_path.join(".gitignore"), format!("/{}\n", manifest.build.output_dir)));
+    if result.is_err() {
+        log_error(format!("Couldn't create project \"{}\": {}", args.project_path.display(), result.unwrap_err()).as_str());
+        return;
+    }
+
+    log_info(format!("Created package \"{}\" in \"{}\"", name, args.project_path.display()).as_str());
+}
@@ -0,0 +1,51 @@
+use std::fs;
+
+use semver::Version;
+
+use crate::managers::logging::{log_error, log_info};
+use crate::managers::manifest::{open_project, MANIFEST_FILE_NAME};
+use crate::managers::registry::{copy_directory, package_path, registry_path};
+use crate::models::command_args::PublishCommandArgs;
+use crate::models::manifest::PackageType;
+
+/// Copy the manifest and source directories of a library into the registry.
+/// Published versions are never overwritten, projects locking them rely on their checksums.
+pub fn publish_package(args: PublishCommandArgs) {
+    let project = open_project(&args.project_path);
+    if project.is_none() {
+        return;
+    }
+    let (manifest_path, project_root, manifest) = project.unwrap();
+
+    if manifest.package.package_type != PackageType::Library {
+        log_error("Only libraries could be published, set \"type = \\\"library\\\"\" in the package section");
+        return;
+    }
+
+    let registry = registry_path(&manifest, &project_root);
+    if registry.is_none() {
+        log_error("Couldn't find the package registry, please set \"ARC_REGISTRY\" or \"ARC_HOME\"");
+        return;
+    }
+    let registry = registry.unwrap();
+
+    // The version is validated when the manifest is loaded
+    let version = Version::parse(manifest.package.version.as_str()).unwrap();
+    let target = package_path(&registry, manifest.package.name.as_str(), &version);
+    if target.exists() {
+        log_error(format!("{} v{} is already published, please bump the version", manifest.package.name, version).as_str());
+        return;
+    }
+
+    let mut result = fs::create_dir_all(&target).and_then(|_| fs::copy(&manifest_path, target.join(MANIFEST_FILE_NAME))).map(|_| ());
+    for directory in &manifest.build.source_dirs {
+        result = result.and_then(|_| copy_directory(&project_root.join(directory), &target.join(directory)));
+    }
+    if result.is_err() {
+        let _ = fs::remove_dir_all(&target);
+        log_error(format!("Couldn't publish to \"{}\": {}", target.display(), result.unwrap_err()).as_str());
+        return;
+    }
+
+    log_info(format!("Published {} v{} to \"{}\"", manifest.package.name, version, registry.display()).as_str());
+}
@@ -0,0 +1,39 @@
+use crate::managers::dependencies::{read_lockfile, resolve_dependencies, write_lockfile};
+use crate::managers::logging::{log_error, log_info};
+use crate::managers::manifest::{edit_dependency, open_project};
+use crate::managers::registry::registry_path;
+use crate::models::command_args::RemoveCommandArgs;
+
+/// Remove a dependency from the manifest, packages only it required are removed from the lockfile
+pub fn remove_dependency(args: RemoveCommandArgs) {
+    let project = open_project(&args.project_path);
+    if project.is_none() {
+        return;
+    }
+    let (manifest_path, project_root, manifest) = project.unwrap();
+
+    if !manifest.dependencies.contains_key(&args.name) {
+        log_error(format!("\"{}\" is not a dependency of \"{}\"", args.name, manifest.package.name).as_str());
+        return;
+    }
+
+    let registry = registry_path(&manifest, &project_root);
+    if registry.is_none() {
+        log_error("Couldn't find the package registry, please set \"ARC_REGISTRY\" or \"ARC_HOME\"");
+        return;
+    }
+
+    let mut updated_manifest = manifest.clone();
+    updated_manifest.dependencies.remove(&args.name);
+    let lockfile = resolve_dependencies(&updated_manifest, &registry.unwrap(), read_lockfile(&project_root).as_ref());
+    if lockfile.is_none() {
+        log_error(format!("Failed to remove \"{}\"", args.name).as_str());
+        return;
+    }
+
+    if !edit_dependency(&manifest_path, args.name.as_str(), None) || !write_lockfile(&project_root, &lockfile.unwrap()) {
+        return;
+    }
+
+    log_info(format!("Removed \"{}\" from dependencies", args.name).as_str());
+}
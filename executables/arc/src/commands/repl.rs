@@ -0,0 +1,82 @@
+use std::io::{stdin, BufRead};
+
+use crate::{
+    managers::logging::{log_error, log_info},
+    models::command_args::ReplCommandArgs,
+};
+use crate::managers::repl::{evaluate_input, format_value, is_input_complete};
+use crate::models::repl_session::{ReplOutcome, ReplSession};
+use crate::STDOUT;
+
+const PROMPT: &str = "> ";
+const CONTINUATION_PROMPT: &str = ". ";
+
+const HELP: &str = r#"Statements end with ";" and are executed, variables declared by them are kept.
+Expressions are evaluated and their values are printed.
+Functions declared with "decl func" could be called by later inputs, declaring one again replaces it.
+Inputs continue on the next line until braces and brackets are closed.
+Commands:
+  :vars   List variables and their values
+  :funcs  List functions
+  :help   Show this message
+  :quit   Leave the REPL"#;
+
+pub fn start_repl(args: ReplCommandArgs) {
+    log_info("Type \":help\" for help, \":quit\" or EOF to leave");
+
+    let mut session = ReplSession::new(args.max_steps);
+    let mut lines = stdin().lock().lines();
+    loop {
+        let input = read_input(&mut lines);
+        if input.is_none() {
+            return;
+        }
+        let input = input.unwrap();
+
+        match input.trim() {
+            "" => continue,
+            ":quit" | ":exit" => return,
+            ":help" => STDOUT.write_line(HELP).unwrap(),
+            ":vars" => {
+                for variable in &session.variables {
+                    let value = variable.value.as_ref().map_or("(not assigned)".to_string(), |v| format_value(v, &variable.definition.type_name));
+                    STDOUT.write_line(format!("{} {} = {}", variable.definition.type_name, variable.definition.identifier, value).as_str()).unwrap();
+                }
+            }
+            ":funcs" => {
+                for function in &session.functions {
+                    let parameters: Vec<String> = function.declarator.parameters.iter().map(|p| format!("{} {}", p.type_name, p.identifier)).collect();
+                    let return_type = Some(function.declarator.return_type.to_string()).filter(|t| !t.is_empty()).unwrap_or("none".to_string());
+                    STDOUT.write_line(format!("{}({})[{}]", function.declarator.identifier, parameters.join(", "), return_type).as_str()).unwrap();
+                }
+            }
+            x if x.starts_with(':') => log_error(format!("Unknown command \"{}\", type \":help\" for help", x).as_str()),
+            _ => {
+                match evaluate_input(&mut session, input.as_str()) {
+                    ReplOutcome::Executed(Some((value, type_name))) => STDOUT.write_line(format_value(&value, &type_name).as_str()).unwrap(),
+                    ReplOutcome::Declared(names) => log_info(format!("Declared {}", names.join(", ")).as_str()),
+                    ReplOutcome::Executed(None) | ReplOutcome::Failed => {}
+                }
+            }
+        }
+    }
+}
+
+/// Read lines until the input is complete, `None` at the end of STDIN
+fn read_input(lines: &mut impl Iterator<Item = std::io::Result<String>>) -> Option<String> {
+    let mut input = String::new();
+    loop {
+        STDOUT.write_str(if input.is_empty() { PROMPT } else { CONTINUATION_PROMPT }).unwrap();
+        let line = lines.next();
+        if line.is_none() || line.as_ref().unwrap().is_err() {
+            STDOUT.write_line("").unwrap();
+            return Some(input).filter(|i| !i.trim().is_empty());
+        }
+
+        input.push_str(line.unwrap().unwrap().as_str());
+        input.push('\n');
+        if is_input_complete(input.as_str()) {
+            return Some(input);
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use std::fs;
+
+use chrono::Local;
+
+use carbon_lang_vm::models::virtual_machine::VirtualMachine;
+
+use crate::{
+    managers::logging::{log_error, log_info, log_trace},
+    models::command_args::RunCommandArgs,
+};
+use crate::managers::execution::parse_arguments;
+
+pub fn run_package(args: RunCommandArgs) {
+    let package = fs::read(&args.package_path);
+    if package.is_err() {
+        log_error(format!("Couldn't open package \"{}\"", args.package_path.display()).as_str());
+        return;
+    }
+
+    let vm_result = VirtualMachine::from_bytes(package.unwrap().as_slice());
+    if vm_result.is_err() {
+        log_error(format!("Failed to load package: {}", vm_result.unwrap_err()).as_str());
+        return;
+    }
+    let mut vm = vm_result.unwrap();
+
+    let arguments = parse_arguments(&args.arguments);
+    log_trace(format!("Executing with {} arguments", arguments.len()).as_str());
+
+    let time_start = Local::now();
+    let result = vm.run(arguments);
+    let time_spanned = Local::now() - time_start;
+
+    match result {
+        Ok(Some(value)) => log_info(format!("Entry function returned {}", value).as_str()),
+        Ok(None) => log_info("Entry function returned without value"),
+        Err(issue) => {
+            match vm.source_location(issue.position) {
+                Some(location) => log_error(format!("Runtime error at {}: {}", location, issue).as_str()),
+                None => log_error(format!("Runtime error: {}", issue).as_str()),
+            }
+            return;
+        }
+    }
+
+    log_info(
+        format!(
+            "Execution finished in {}s",
+            (time_spanned.num_milliseconds() as f64 / 1000 as f64)
+        )
+            .as_str(),
+    );
+}
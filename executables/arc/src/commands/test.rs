@@ -0,0 +1,81 @@
+use std::process;
+
+use chrono::Local;
+
+use carbon_lang_compiler::shared::package_generation::package_descriptor::PackageMetadata;
+
+use crate::{
+    managers::logging::{log_error, log_info, log_terminal},
+    models::command_args::TestCommandArgs,
+};
+use crate::managers::diagnostics::render_location;
+use crate::managers::source_files::{find_source_files, load_package_sources};
+use crate::managers::test_runner::{discover_tests, filter_tests, prepare_test_package, run_test};
+
+/// Compile and run every test separately, exit with 1 if any of them fails
+pub fn run_tests(args: TestCommandArgs) {
+    if !test_once(&args) {
+        process::exit(1);
+    }
+}
+
+/// Run the selected tests once, returns whether all of them passed
+pub fn test_once(args: &TestCommandArgs) -> bool {
+    let time_start = Local::now();
+
+    let discovery = find_source_files(&args.input_path);
+    if discovery.is_none() {
+        log_error("Testing aborted!");
+        return false;
+    }
+    let (source_files, project_root) = discovery.unwrap();
+
+    // Tests are entry functions, the name here is not used
+    let load_result = load_package_sources(source_files, &project_root, "main".to_string());
+    if load_result.is_none() {
+        log_error("Failed to load source files, testing aborted!");
+        return false;
+    }
+    let (tree, string_pool) = load_result.unwrap();
+
+    let tests = discover_tests(&tree);
+    let test_names = filter_tests(&tests, args.filter.as_deref());
+    let metadata = PackageMetadata::default();
+    let package = prepare_test_package(tree, string_pool, &metadata);
+    if package.is_none() {
+        log_error("Availability check failed, testing aborted!");
+        return false;
+    }
+    let package = package.unwrap();
+
+    log_info(format!("Running {} tests", test_names.len()).as_str());
+    let mut failed_count = 0;
+    for name in test_names.iter() {
+        match run_test(&package, name.as_str(), &metadata, args.max_steps) {
+            Ok(()) => log_info(format!("Test \"{}\" passed", name).as_str()),
+            Err(failure) => {
+                failed_count += 1;
+                log_error(format!("Test \"{}\" failed: {}", name, failure.message).as_str());
+                if let Some(snippet) = failure.location.as_ref().and_then(render_location) {
+                    log_terminal().write_line(snippet.as_str()).unwrap();
+                }
+            }
+        }
+    }
+
+    let time_spanned = Local::now() - time_start;
+    let summary = format!(
+        "{} passed, {} failed, {} filtered out in {}s",
+        test_names.len() - failed_count,
+        failed_count,
+        tests.len() - test_names.len(),
+        time_spanned.num_milliseconds() as f64 / 1000_f64
+    );
+    if failed_count > 0 {
+        log_error(summary.as_str());
+        return false;
+    }
+    log_info(summary.as_str());
+
+    return true;
+}
@@ -1,6 +1,6 @@
 use console::{Term, style};
 use lazy_static::lazy_static;
-use managers::logging::{log_error, log_warn};
+use managers::logging::{log_error, log_terminal, log_warn, reserve_stdout, set_message_format};
 use models::command_args::SubCommands;
 use structopt::StructOpt;
 
@@ -10,20 +10,76 @@ mod commands;
 mod managers;
 mod models;
 
+#[cfg(test)]
+mod tests;
+
 lazy_static! {
     static ref STDOUT: Term = Term::stdout();
+    static ref STDERR: Term = Term::stderr();
 }
 
 fn main() {
-    STDOUT.write_line(format!("{}", style(" -----[ Arc build system (version: 0.0.1) ]-----").bold()).as_str()).unwrap();
-    log_warn("This is an internal version of the Arc build system. It is not stable yet. It is still unavailable to build your project!");
-
     let args = CommandArgs::from_args();
+    set_message_format(args.message_format);
+    if let Some(SubCommands::Lsp(_)) = args.sub_command {
+        reserve_stdout();
+    }
+
+    log_terminal().write_line(format!("{}", style(" -----[ Arc build system (version: 0.0.1) ]-----").bold()).as_str()).unwrap();
+    log_warn("This is an internal version of the Arc build system. It is not stable yet. It is still unavailable to build your project!");
 
     match args.sub_command {
         Some(SubCommands::Compile(compile_args)) => {
             commands::compile::compile_package(compile_args);
         }
+        Some(SubCommands::New(new_args)) => {
+            commands::new::new_project(new_args);
+        }
+        Some(SubCommands::Build(build_args)) => {
+            commands::build::build_project(build_args);
+        }
+        Some(SubCommands::Add(add_args)) => {
+            commands::add::add_dependency(add_args);
+        }
+        Some(SubCommands::Remove(remove_args)) => {
+            commands::remove::remove_dependency(remove_args);
+        }
+        Some(SubCommands::Publish(publish_args)) => {
+            commands::publish::publish_package(publish_args);
+        }
+        Some(SubCommands::Check(check_args)) => {
+            commands::check::check_project(check_args);
+        }
+        Some(SubCommands::Run(run_args)) => {
+            commands::run::run_package(run_args);
+        }
+        Some(SubCommands::Disasm(disasm_args)) => {
+            commands::disasm::disassemble(disasm_args);
+        }
+        Some(SubCommands::Asm(asm_args)) => {
+            commands::asm::assemble(asm_args);
+        }
+        Some(SubCommands::Link(link_args)) => {
+            commands::link::link_packages(link_args);
+        }
+        Some(SubCommands::Fmt(fmt_args)) => {
+            commands::fmt::format_files(fmt_args);
+        }
+        Some(SubCommands::Lsp(lsp_args)) => {
+            commands::lsp::start_language_server(lsp_args);
+        }
+        Some(SubCommands::Repl(repl_args)) => {
+            commands::repl::start_repl(repl_args);
+        }
+        Some(SubCommands::Test(test_args)) => {
+            commands::test::run_tests(test_args);
+        }
+        Some(SubCommands::Debug(debug_args)) => {
+            commands::debug::debug_package(debug_args);
+        }
+        Some(SubCommands::Doc(doc_args)) => {
+            commands::doc::generate_documentation(doc_args);
+        }
         _ => {
             log_error("Not enough arguments, please check your commands.");
         }
@@ -0,0 +1,79 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+use carbon_lang_compiler::shared::package_generation::implementations::package_descriptor::COMPILER_VERSION;
+
+use crate::managers::logging::log_trace;
+
+/// Cached tokens of source files
+pub const TOKENS_ENTRY_KIND: &str = "tokens";
+/// Cached syntax trees of source files
+pub const TREE_ENTRY_KIND: &str = "trees";
+
+const CACHE_ENTRY_EXTENSION: &str = "bin";
+
+/// Tokens only depend on the source and the compiler
+pub fn tokens_key(content: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(COMPILER_VERSION.as_bytes());
+    hasher.update([0]);
+    hasher.update(content.as_bytes());
+
+    return format!("{:x}", hasher.finalize());
+}
+
+/// Syntax trees depend on tokens and the entry function name recorded in them
+pub fn tree_key(tokens_key: &str, entry_function: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(tokens_key.as_bytes());
+    hasher.update([0]);
+    hasher.update(entry_function.as_bytes());
+
+    return format!("{:x}", hasher.finalize());
+}
+
+/// Where the entry is stored, entries are named by their keys so they never need to be invalidated
+pub fn entry_path(cache_dir: &Path, kind: &str, key: &str) -> PathBuf {
+    return cache_dir.join(kind).join(format!("{}.{}", key, CACHE_ENTRY_EXTENSION));
+}
+
+/// Broken or missing entries are treated the same, they are generated again
+pub fn read_entry<T: DeserializeOwned>(path: &Path) -> Option<T> {
+    let bytes = fs::read(path).ok()?;
+    return bincode::deserialize(&bytes).ok();
+}
+
+/// The cache is only an optimization, failing to write it doesn't fail the build
+pub fn write_entry<T: Serialize>(path: &Path, value: &T) {
+    let bytes = bincode::serialize(value);
+    if bytes.is_err() {
+        return;
+    }
+
+    let written = path.parent().map_or(Ok(()), fs::create_dir_all).and_then(|_| fs::write(path, bytes.unwrap()));
+    if written.is_err() {
+        log_trace(format!("Couldn't write cache \"{}\"", path.display()).as_str());
+    }
+}
+
+/// Remove entries of every kind which are not used by the current build, so the cache doesn't grow forever
+pub fn remove_unused_entries(cache_dir: &Path, used_entries: &HashSet<PathBuf>) {
+    for kind in [TOKENS_ENTRY_KIND, TREE_ENTRY_KIND] {
+        let entries = fs::read_dir(cache_dir.join(kind));
+        if entries.is_err() {
+            continue;
+        }
+
+        for entry in entries.unwrap().flatten() {
+            let path = entry.path();
+            if path.extension().is_some_and(|e| e == CACHE_ENTRY_EXTENSION) && !used_entries.contains(&path) {
+                let _ = fs::remove_file(&path);
+            }
+        }
+    }
+}
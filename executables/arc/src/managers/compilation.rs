@@ -1,36 +1,345 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use sha2::{Digest, Sha256};
+
 use carbon_lang_compiler::lexer::tokenize::tokenize;
-use carbon_lang_compiler::parser::decorator::decorate_token;
+use carbon_lang_compiler::package_generator::availability_check::package_check::check_package;
+use carbon_lang_compiler::linker::object_linker::link_objects;
+use carbon_lang_compiler::linker::package_linker::link_packages;
+use carbon_lang_compiler::package_generator::package_builder::build_relocatable_commands;
+use carbon_lang_compiler::package_reader::object_reader::read_object_file;
+use carbon_lang_compiler::parser::decorator::decorate_token_with_string_pool;
 use carbon_lang_compiler::parser::pipeline::build_whole_file;
+use carbon_lang_compiler::shared::ast::blocks::function::FunctionLinkage;
 use carbon_lang_compiler::shared::ast::decorated_token::DecoratedToken;
 use carbon_lang_compiler::shared::ast::package::ParserPackageStructure;
 use carbon_lang_compiler::shared::package_generation::data_descriptor::StringConstant;
+use carbon_lang_compiler::shared::package_generation::implementations::package_descriptor::{COMPILER_VERSION, LIBRARY_PACKAGE_TYPE};
+use carbon_lang_compiler::shared::package_generation::object_file::ObjectFile;
+use carbon_lang_compiler::shared::package_generation::package_descriptor::PackageMetadata;
+use carbon_lang_compiler::shared::session::compile_session::{CompileOptions, Session};
 use carbon_lang_compiler::shared::utils::identifier::Identifier;
-use crate::log_error;
+use crate::managers::diagnostics::report_issues;
+use crate::managers::logging::{log_error, log_info, log_trace};
+use crate::managers::source_files::{load_package_sources, load_separate_sources, merge_source_units, read_linked_sources};
+
+pub const OBJECT_FILE_EXTENSION: &str = "cbo";
+/// Where objects are kept inside the build directory
+pub const OBJECT_DIRECTORY_NAME: &str = "objects";
+/// Where tokens and structures of source files are cached inside the build directory
+pub const CACHE_DIRECTORY_NAME: &str = "cache";
 
-pub fn token_conversion(source: &str) -> Option<(Vec<DecoratedToken>, Vec<StringConstant>)> {
+pub fn token_conversion(source: &str, string_pool: Vec<StringConstant>, file_path: &Path) -> Option<(Vec<DecoratedToken>, Vec<StringConstant>)> {
     let lexical_analysis_result = tokenize(source, true);
 
     if lexical_analysis_result.is_err() {
-        let err = lexical_analysis_result.unwrap_err();
-        for item in err.issues {
-            log_error(format!("({}) {}", item.code, item.detail).as_str());
-        }
+        let issue = lexical_analysis_result.unwrap_err().with_file_path(file_path.display().to_string().as_str());
+        report_issues(&issue.issues, Some(file_path));
         return None;
     }
 
-    return Some(decorate_token(lexical_analysis_result.unwrap()));
+    return Some(decorate_token_with_string_pool(lexical_analysis_result.unwrap(), string_pool));
 }
 
-pub fn parse_tokens(tokens: Vec<DecoratedToken>, entry_function: Option<String>) -> Option<ParserPackageStructure> {
+pub fn parse_tokens(tokens: Vec<DecoratedToken>, entry_function: Option<String>, file_path: &Path) -> Option<ParserPackageStructure> {
     let tree_result = build_whole_file(tokens, Identifier::single(entry_function.unwrap().as_str()));
 
     if tree_result.is_err() {
-        let err = tree_result.unwrap_err();
-        for item in err.issues {
-            log_error(format!("({}) {}", item.code, item.detail).as_str());
+        let issue = tree_result.unwrap_err().with_file_path(file_path.display().to_string().as_str());
+        report_issues(&issue.issues, Some(file_path));
+        return None;
+    }
+
+    let mut tree = tree_result.unwrap();
+    tree.set_file_path(file_path.display().to_string().as_str());
+    return Some(tree);
+}
+
+/// Make sure the entry function exists and every availability check passes, all issues are logged.
+/// Libraries don't require an entry function.
+fn check_tree(tree: &ParserPackageStructure, require_entry: bool) -> bool {
+    let mut passed = true;
+
+    if require_entry && !tree.functions.iter().any(|f| f.declarator.identifier == tree.entry_point) {
+        log_error(format!("Entry function \"{}\" is not found", tree.entry_point).as_str());
+        passed = false;
+    }
+
+    let check_result = check_package(tree);
+    if check_result.is_err() {
+        report_issues(&check_result.unwrap_err().issues, None);
+        passed = false;
+    }
+
+    return passed;
+}
+
+/// Compile source files and every file they link to into a package, all issues are logged.
+/// Libraries don't need an entry function, while executables couldn't have external functions.
+/// Debug packages carry source locations of commands and names of local variables.
+pub fn build_sources(source_files: Vec<PathBuf>, project_root: &Path, entry_function: String, metadata: &PackageMetadata, debug: bool) -> Option<Vec<u8>> {
+    let session = load_session(source_files, project_root, entry_function, metadata, debug)?;
+
+    let package = session.compile();
+    if package.is_err() {
+        report_issues(&package.unwrap_err().issues, None);
+        return None;
+    }
+
+    return Some(package.unwrap());
+}
+
+/// Run lexical analysis, token parsing and availability checks on source files and every file they link to, all issues are logged
+pub fn check_sources(source_files: Vec<PathBuf>, project_root: &Path, entry_function: String) -> bool {
+    let session = load_session(source_files, project_root, entry_function, &PackageMetadata::default(), false);
+    if session.is_none() {
+        return false;
+    }
+
+    let check_result = session.unwrap().check();
+    if check_result.is_err() {
+        report_issues(&check_result.unwrap_err().issues, None);
+        return false;
+    }
+
+    return true;
+}
+
+/// Compile source files and every file they link to into an object, all issues are logged.
+/// The entry and external functions could be defined by other objects, which are linked together by `arc link`.
+pub fn build_object(source_files: Vec<PathBuf>, project_root: &Path, entry_function: String, metadata: &PackageMetadata) -> Option<ObjectFile> {
+    let load_result = load_package_sources(source_files, project_root, entry_function);
+    if load_result.is_none() {
+        log_error("Failed to load source files");
+        return None;
+    }
+    log_info("Lexical analysis and token parsing passed");
+    let (tree, string_pool) = load_result.unwrap();
+
+    if !validate_tree(&tree, true, false) {
+        return None;
+    }
+
+    let commands = build_relocatable_commands(&tree, string_pool, metadata);
+    if commands.is_err() {
+        report_issues(&commands.unwrap_err().issues, None);
+        log_error("Code generation failed");
+        return None;
+    }
+
+    return Some(ObjectFile {
+        metadata: metadata.clone(),
+        source_checksum: vec![],
+        commands: commands.unwrap(),
+    });
+}
+
+/// Like `build_sources`, but every source file is compiled into an object in `build_dir` before they are linked.
+/// Every file is still checked against the others, while tokens, structures and commands of files
+/// unchanged since the last build are read from the cache and their objects.
+/// A library with `export_entry` requires the entry function and exports it, so that it could be linked into an executable.
+pub fn build_sources_with_objects(
+    source_files: Vec<PathBuf>,
+    project_root: &Path,
+    entry_function: String,
+    metadata: &PackageMetadata,
+    build_dir: &Path,
+    export_entry: bool,
+) -> Option<Vec<u8>> {
+    let object_dir = build_dir.join(OBJECT_DIRECTORY_NAME);
+    let units = load_separate_sources(source_files, project_root, entry_function.clone(), Some(&build_dir.join(CACHE_DIRECTORY_NAME)));
+    if units.is_none() {
+        log_error("Failed to load source files");
+        return None;
+    }
+    log_info("Lexical analysis and token parsing passed");
+    let mut units = units.unwrap();
+
+    let is_library = metadata.package_type == LIBRARY_PACKAGE_TYPE;
+    if !validate_tree(&merge_source_units(&units)?, is_library, !is_library || export_entry) {
+        return None;
+    }
+    if export_entry {
+        for unit in &mut units {
+            let entry_point = unit.tree.entry_point.clone();
+            for function in unit.tree.functions.iter_mut().filter(|f| f.declarator.identifier == entry_point && f.linkage == FunctionLinkage::Internal) {
+                function.linkage = FunctionLinkage::Export;
+            }
         }
+    }
+
+    if fs::create_dir_all(&object_dir).is_err() {
+        log_error(format!("Couldn't create directory \"{}\"", object_dir.display()).as_str());
         return None;
     }
 
-    return Some(tree_result.unwrap());
+    let mut objects = vec![];
+    let mut object_paths = HashSet::new();
+    let mut compiled_count = 0;
+    for unit in units {
+        let object_path = object_dir.join(object_file_name(&unit.path));
+        let checksum = object_checksum(unit.content.as_str(), entry_function.as_str(), export_entry);
+
+        let cached = fs::read(&object_path).ok()
+                                           .and_then(|bytes| read_object_file(&bytes).ok())
+                                           .filter(|o| o.metadata == *metadata && o.source_checksum == checksum);
+        let object = match cached {
+            Some(object) => {
+                log_trace(format!("\"{}\" is up to date", unit.path.display()).as_str());
+                object
+            }
+            None => {
+                let commands = build_relocatable_commands(&unit.tree, unit.string_pool, metadata);
+                if commands.is_err() {
+                    report_issues(&commands.unwrap_err().issues, Some(&unit.path));
+                    log_error("Code generation failed");
+                    return None;
+                }
+
+                let object = ObjectFile {
+                    metadata: metadata.clone(),
+                    source_checksum: checksum,
+                    commands: commands.unwrap(),
+                };
+                let bytes = serialize_object(&object, Some(&unit.path))?;
+                if fs::write(&object_path, bytes).is_err() {
+                    log_error(format!("Couldn't write object \"{}\"", object_path.display()).as_str());
+                    return None;
+                }
+                compiled_count += 1;
+                object
+            }
+        };
+
+        objects.push((unit.path.display().to_string(), object));
+        object_paths.insert(object_path);
+    }
+    log_info(format!("Compiled {} of {} source files, others are up to date", compiled_count, objects.len()).as_str());
+
+    remove_stale_objects(&object_dir, &object_paths);
+
+    let package = link_objects(&objects, entry_function.as_str(), metadata);
+    if package.is_err() {
+        report_issues(&package.unwrap_err().issues, None);
+        log_error("Linking failed");
+        return None;
+    }
+
+    return Some(package.unwrap());
+}
+
+/// Bytes of an object, numbers which don't fit in the alignments are logged
+pub fn serialize_object(object: &ObjectFile, source_path: Option<&Path>) -> Option<Vec<u8>> {
+    let bytes = object.serialize();
+    if bytes.is_err() {
+        report_issues(&bytes.unwrap_err().issues, source_path);
+        log_error("Couldn't serialize the object");
+        return None;
+    }
+
+    return Some(bytes.unwrap());
+}
+
+/// Build source files into a library exporting the entry function, then link it with library packages of dependencies into an executable.
+/// Functions of dependencies are only visible if they are exported, and the sources declare them as external functions.
+pub fn build_linked_package(
+    source_files: Vec<PathBuf>,
+    project_root: &Path,
+    entry_function: String,
+    metadata: &PackageMetadata,
+    build_dir: &Path,
+    dependencies: Vec<(String, Vec<u8>)>,
+) -> Option<Vec<u8>> {
+    let library_metadata = PackageMetadata { package_type: LIBRARY_PACKAGE_TYPE, ..metadata.clone() };
+    let package = build_sources_with_objects(source_files, project_root, entry_function.clone(), &library_metadata, build_dir, true)?;
+
+    let mut packages = vec![(project_root.display().to_string(), package)];
+    packages.extend(dependencies);
+
+    let package = link_packages(&packages, entry_function.as_str());
+    if package.is_err() {
+        report_issues(&package.unwrap_err().issues, None);
+        log_error("Linking dependencies failed");
+        return None;
+    }
+
+    return Some(package.unwrap());
+}
+
+/// Reject external functions if they are not allowed, then run every availability check, all issues are logged
+fn validate_tree(tree: &ParserPackageStructure, allow_external: bool, require_entry: bool) -> bool {
+    if !allow_external {
+        if let Some(func) = tree.functions.iter().find(|f| f.linkage == FunctionLinkage::External) {
+            log_error(format!("External function \"{}\" is only allowed in libraries, which are linked by \"arc link\"", func.declarator.identifier).as_str());
+            return false;
+        }
+    }
+
+    if !check_tree(tree, require_entry) {
+        log_error("Availability check failed");
+        return false;
+    }
+
+    return true;
+}
+
+/// A session compiling `source_files` and every file they link to
+fn load_session(source_files: Vec<PathBuf>, project_root: &Path, entry_function: String, metadata: &PackageMetadata, debug: bool) -> Option<Session> {
+    let sources = read_linked_sources(source_files, project_root);
+    if sources.is_none() {
+        log_error("Failed to load source files");
+        return None;
+    }
+
+    let mut session = Session::new(CompileOptions {
+        entry_function,
+        metadata: metadata.clone(),
+        debug,
+        project_root: project_root.display().to_string(),
+    });
+    for (path, content) in sources.unwrap() {
+        session.add_source(path.display().to_string().as_str(), content.as_str());
+    }
+
+    return Some(session);
+}
+
+/// Objects are named after the source file and a hash of its path, since sources of dependencies live outside the project
+fn object_file_name(source_path: &Path) -> String {
+    let canonical_path = fs::canonicalize(source_path).unwrap_or_else(|_| source_path.to_path_buf());
+    let path_hash = format!("{:x}", Sha256::digest(canonical_path.display().to_string().as_bytes()));
+    let stem = source_path.file_stem().map(|s| s.to_string_lossy().to_string()).unwrap_or_default();
+
+    return format!("{}-{}.{}", stem, &path_hash[..16], OBJECT_FILE_EXTENSION);
+}
+
+/// Objects depend on the source, the entry function with its linkage and the compiler generating them,
+/// alignments are compared with the object metadata
+fn object_checksum(content: &str, entry_function: &str, export_entry: bool) -> Vec<u8> {
+    let mut hasher = Sha256::new();
+    hasher.update(COMPILER_VERSION.as_bytes());
+    hasher.update([0]);
+    hasher.update(entry_function.as_bytes());
+    hasher.update([0, export_entry as u8, 0]);
+    hasher.update(content.as_bytes());
+
+    return hasher.finalize().to_vec();
+}
+
+/// Objects of deleted or renamed source files are never used again
+fn remove_stale_objects(object_dir: &Path, object_paths: &HashSet<PathBuf>) {
+    let entries = fs::read_dir(object_dir);
+    if entries.is_err() {
+        return;
+    }
+
+    for entry in entries.unwrap().flatten() {
+        let path = entry.path();
+        if path.extension().is_some_and(|e| e == OBJECT_FILE_EXTENSION) && !object_paths.contains(&path) {
+            log_trace(format!("Removing stale object \"{}\"", path.display()).as_str());
+            let _ = fs::remove_file(&path);
+        }
+    }
 }
@@ -0,0 +1,245 @@
+use std::collections::HashMap;
+use std::fs;
+
+use carbon_lang_compiler::package_reader::disassembler::format_instruction;
+use carbon_lang_compiler::shared::package_reading::package_layout::PackageLayout;
+use carbon_lang_compiler::shared::utils::source_map::SourceMap;
+use carbon_lang_vm::models::value::Value;
+use carbon_lang_vm::models::virtual_machine::{MachineState, VirtualMachine};
+
+use crate::managers::execution::run_inspected;
+use crate::models::debug_session::{DebugSession, DebugStop, StepMode};
+use crate::models::execution::ExecutionStop;
+
+impl DebugSession {
+    pub fn new(vm: VirtualMachine, arguments: Vec<Value>, max_steps: usize) -> DebugSession {
+        return DebugSession { vm, arguments, breakpoints: vec![], max_steps };
+    }
+
+    pub fn is_running(&self) -> bool {
+        return self.vm.state == MachineState::Running;
+    }
+}
+
+/// Heads of commands where `target` starts, which is a function name, `file:line` or a line of any file.
+/// Lines are only known by packages with a debug section, while functions could be found by symbols of libraries as well.
+pub fn resolve_breakpoint(layout: &PackageLayout, target: &str) -> Result<Vec<usize>, String> {
+    let (file, line) = match target.rsplit_once(':') {
+        Some((file, line)) if line.parse::<usize>().is_ok() => (Some(file), line.parse::<usize>().ok()),
+        _ => (None, target.parse::<usize>().ok()),
+    };
+
+    if line.is_none() {
+        let slot = function_slot(layout, target);
+        if slot.is_none() {
+            return Err(format!("Function \"{}\" is not found", target));
+        }
+        return match layout.function_table.iter().find(|f| f.slot == slot.unwrap()) {
+            Some(function) => Ok(vec![function.entry_address]),
+            None => Err(format!("Function \"{}\" is external, it has no command in this package", target)),
+        };
+    }
+
+    if layout.debug_info.is_none() {
+        return Err("Lines are unknown, compile the package with \"--debug\" to break at them".to_string());
+    }
+    let debug_info = layout.debug_info.as_ref().unwrap();
+    let result: Vec<usize> = debug_info.line_table
+                                       .iter()
+                                       .filter(|e| e.line == line.unwrap())
+                                       .filter(|e| file.is_none_or(|f| debug_info.files[e.file_index].ends_with(f)))
+                                       .map(|e| e.address)
+                                       .collect();
+    if result.is_empty() {
+        return Err(format!("No command is compiled from line {}", target));
+    }
+
+    return Ok(result);
+}
+
+/// Start the entry function again, then run it until a breakpoint or the end
+pub fn start(session: &mut DebugSession) -> DebugStop {
+    if let Err(issue) = session.vm.start(session.arguments.clone()) {
+        return DebugStop::Failed(format!("Runtime error: {}", issue));
+    }
+
+    return execute(session, StepMode::Continue, false);
+}
+
+/// Continue a running machine until it stops as `mode` asks, breakpoints stop it in every mode
+pub fn resume(session: &mut DebugSession, mode: StepMode) -> DebugStop {
+    return execute(session, mode, true);
+}
+
+fn execute(session: &mut DebugSession, mode: StepMode, skip_current: bool) -> DebugStop {
+    let start_depth = session.vm.frames.len();
+    let breakpoints = &session.breakpoints;
+
+    let mut is_current = skip_current;
+    let mut hit = None;
+    let result = run_inspected(&mut session.vm, session.max_steps, |vm| {
+        // The command where the machine stopped last time is executed anyway
+        if is_current {
+            is_current = false;
+            return true;
+        }
+
+        hit = breakpoints.iter().position(|b| b.addresses.contains(&vm.program_counter));
+        return hit.is_none() && !reaches_step_end(vm, mode, start_depth);
+    });
+
+    return match result {
+        Ok(value) => DebugStop::Finished(value),
+        Err(ExecutionStop::Interrupted) => hit.map_or(DebugStop::Stepped, DebugStop::Breakpoint),
+        Err(ExecutionStop::Issue(issue)) => match session.vm.source_location(issue.position) {
+            Some(location) => DebugStop::Failed(format!("Runtime error at {}: {}", location, issue)),
+            None => DebugStop::Failed(format!("Runtime error: {}", issue)),
+        },
+        Err(e) => DebugStop::Failed(e.to_string()),
+    };
+}
+
+/// Whether stepping in `mode` ends before the next command, after at least one command is executed.
+/// Source lines are the heads of line table entries, packages without them are stepped command by command.
+/// Returning to the caller stops at the return address, since the rest of the calling line is not executed yet.
+fn reaches_step_end(vm: &VirtualMachine, mode: StepMode, start_depth: usize) -> bool {
+    let depth = vm.frames.len();
+    let is_line_head = match &vm.layout.debug_info {
+        Some(debug_info) => debug_info.line_at(vm.program_counter).is_some_and(|e| e.address == vm.program_counter),
+        None => true,
+    };
+    let is_returned = depth < start_depth;
+
+    return match mode {
+        StepMode::Continue => false,
+        StepMode::Instruction => true,
+        StepMode::Out => is_returned,
+        StepMode::Into => is_line_head || is_returned,
+        StepMode::Over => (is_line_head && depth <= start_depth) || is_returned,
+    };
+}
+
+/// Where the machine stops, with the source line and the command to be executed
+pub fn describe_position(vm: &VirtualMachine) -> Vec<String> {
+    let mut result = vec![];
+    let position = vm.program_counter;
+    let function = frame_function(vm, vm.current_frame().entry_address);
+
+    match vm.source_location(position) {
+        Some(location) => result.push(format!("0x{:08X} in {} at {}", position, function, location)),
+        None => result.push(format!("0x{:08X} in {}", position, function)),
+    }
+    if let Some(line) = source_line(&vm.layout, position) {
+        result.push(format!("    {}", line));
+    }
+
+    let labels: HashMap<usize, String> = vm.layout.function_table.iter().map(|f| (f.entry_address, format!("fn_{}", f.slot))).collect();
+    match vm.current_instruction() {
+        Ok(decoded) => result.push(format!("    => {}", format_instruction(&decoded, &labels))),
+        Err(issue) => result.push(format!("    => {}", issue)),
+    }
+
+    return result;
+}
+
+/// Every frame from the innermost one, with the function and where it stops
+pub fn backtrace(vm: &VirtualMachine) -> Vec<String> {
+    return (0..vm.frames.len()).rev().map(|index| {
+        let address = frame_address(vm, index);
+        let function = frame_function(vm, vm.frames[index].entry_address);
+        return match vm.source_location(address) {
+            Some(location) => format!("#{} {} at {} (0x{:08X})", vm.frames.len() - 1 - index, function, location, address),
+            None => format!("#{} {} at 0x{:08X}", vm.frames.len() - 1 - index, function, address),
+        };
+    }).collect();
+}
+
+/// Local slots of the frame numbered like `backtrace`, named by the debug section if possible
+pub fn frame_locals(vm: &VirtualMachine, frame_number: usize) -> Result<Vec<String>, String> {
+    if frame_number >= vm.frames.len() {
+        return Err(format!("Frame #{} doesn't exist, there are {} frames", frame_number, vm.frames.len()));
+    }
+    let index = vm.frames.len() - 1 - frame_number;
+    let address = frame_address(vm, index);
+
+    let names: HashMap<usize, String> = match &vm.layout.debug_info {
+        Some(debug_info) => debug_info.locals_at(address).iter().map(|l| (l.slot, l.name.clone())).collect(),
+        None => HashMap::new(),
+    };
+
+    return Ok(vm.frames[index].locals.iter().enumerate().map(|(slot, value)| {
+        let value = value.as_ref().map_or("(not assigned)".to_string(), |v| v.to_string());
+        return match names.get(&slot) {
+            Some(name) => format!("${} {} = {}", slot, name, value),
+            None => format!("${} = {}", slot, value),
+        };
+    }).collect());
+}
+
+/// Values on the operand stack from the top, values below the current frame are marked
+pub fn operand_stack(vm: &VirtualMachine) -> Vec<String> {
+    let stack_base = vm.current_frame().stack_base;
+    return vm.stack.iter().enumerate().rev().map(|(index, value)| {
+        let owner = if index < stack_base { " (caller)" } else { "" };
+        return format!("[{}] {}{}", index, value, owner);
+    }).collect();
+}
+
+/// Domains containing the current command from the outermost one, indented by their depth
+pub fn domain_nesting(vm: &VirtualMachine) -> Result<Vec<String>, String> {
+    if vm.layout.debug_info.is_none() {
+        return Err("Domains are unknown, compile the package with \"--debug\" to show them".to_string());
+    }
+    let debug_info = vm.layout.debug_info.as_ref().unwrap();
+
+    return Ok(debug_info.domains_at(vm.program_counter).iter().enumerate().map(|(depth, domain)| {
+        let location = debug_info.location_at(domain.start).map_or(String::new(), |l| format!(" from {}", l));
+        return format!("{}{} 0x{:08X}..0x{:08X}{}", "  ".repeat(depth), domain.kind, domain.start, domain.end, location);
+    }).collect());
+}
+
+/// The command a frame stops at, callers stop at the command calling the next frame
+fn frame_address(vm: &VirtualMachine, index: usize) -> usize {
+    if index + 1 == vm.frames.len() {
+        return vm.program_counter;
+    }
+
+    // Return addresses follow the calling commands, whose last byte is enough to find their lines and scopes
+    return vm.frames[index + 1].return_address.map_or(vm.program_counter, |a| a - 1);
+}
+
+/// Name of the function starting at `entry_address`, or the label used by the disassembler
+fn frame_function(vm: &VirtualMachine, entry_address: usize) -> String {
+    let slot = vm.layout.function_table.iter().find(|f| f.entry_address == entry_address).map(|f| f.slot);
+    if slot.is_none() {
+        return format!("0x{:08X}", entry_address);
+    }
+    let slot = slot.unwrap();
+
+    let name = vm.layout.debug_info.as_ref().and_then(|d| d.function_name(slot).map(|n| n.to_string()))
+                 .or_else(|| vm.layout.symbols.iter().find(|s| s.slot == slot).map(|s| s.name.clone()));
+    return name.unwrap_or(format!("fn_{}", slot));
+}
+
+fn function_slot(layout: &PackageLayout, name: &str) -> Option<usize> {
+    if let Some(slot) = layout.debug_info.as_ref().and_then(|d| d.functions.iter().find(|f| f.name == name).map(|f| f.slot)) {
+        return Some(slot);
+    }
+    if let Some(symbol) = layout.symbols.iter().find(|s| s.name == name) {
+        return Some(symbol.slot);
+    }
+
+    // Labels of the disassembler
+    return name.strip_prefix("fn_").and_then(|s| s.parse::<usize>().ok());
+}
+
+/// `<line> | <text>` of the source line where the command at `address` is written, if the file is still readable
+fn source_line(layout: &PackageLayout, address: usize) -> Option<String> {
+    let debug_info = layout.debug_info.as_ref()?;
+    let entry = debug_info.line_at(address)?;
+    let file_path = &debug_info.files[entry.file_index];
+
+    let source = fs::read_to_string(file_path).ok()?;
+    let source_map = SourceMap::new(file_path, source.as_str());
+    return Some(format!("{} | {}", entry.line, source_map.line_text(entry.line)));
+}
@@ -0,0 +1,270 @@
+use std::collections::{BTreeMap, HashMap};
+use std::collections::hash_map::Entry;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use semver::{Version, VersionReq};
+
+use carbon_lang_compiler::shared::package_generation::implementations::package_descriptor::LIBRARY_PACKAGE_TYPE;
+use carbon_lang_compiler::shared::package_generation::package_descriptor::PackageMetadata;
+
+use crate::managers::compilation::build_sources_with_objects;
+use crate::managers::logging::{log_error, log_info, log_trace, log_warn};
+use crate::managers::manifest::{load_manifest, MANIFEST_FILE_NAME};
+use crate::managers::registry::{available_versions, cache_path, copy_directory, directory_checksum, package_path, registry_path};
+use crate::managers::source_files::collect_source_files;
+use crate::models::lockfile::{LockedPackage, Lockfile};
+use crate::models::manifest::{Manifest, PackageType};
+
+pub const LOCKFILE_NAME: &str = "Arc.lock";
+
+const LOCKFILE_HEADER: &str = "# This file is generated by Arc, it should not be edited manually.\n";
+
+// Selecting a version could change the requirements of others, give up if it keeps changing
+const RESOLUTION_ROUND_LIMIT: usize = 64;
+
+/// Resolve the dependencies of a project, update `Arc.lock` and fetch them into the cache.
+/// Returns the directories of every dependency in the cache, all problems are logged.
+pub fn prepare_dependencies(manifest: &Manifest, project_root: &Path) -> Option<Vec<PathBuf>> {
+    let lockfile_path = project_root.join(LOCKFILE_NAME);
+    if manifest.dependencies.is_empty() && !lockfile_path.exists() {
+        return Some(vec![]);
+    }
+
+    let registry = registry_path(manifest, project_root);
+    if registry.is_none() {
+        log_error("Couldn't find the package registry, please set \"ARC_REGISTRY\" or \"ARC_HOME\"");
+        return None;
+    }
+    let registry = registry.unwrap();
+
+    let locked = read_lockfile(project_root);
+    let lockfile = resolve_dependencies(manifest, &registry, locked.as_ref())?;
+    if !write_lockfile(project_root, &lockfile) {
+        return None;
+    }
+
+    if lockfile.package.is_empty() {
+        return Some(vec![]);
+    }
+
+    let cache = cache_path();
+    if cache.is_none() {
+        log_error("Couldn't find the package cache, please set \"ARC_HOME\"");
+        return None;
+    }
+
+    return fetch_dependencies(&lockfile, &registry, &cache.unwrap());
+}
+
+/// Select one version of every package required by `manifest` directly or indirectly.
+/// The highest version satisfying every requirement is selected, unless the locked version still satisfies them.
+pub fn resolve_dependencies(manifest: &Manifest, registry: &Path, locked: Option<&Lockfile>) -> Option<Lockfile> {
+    let locked_packages: HashMap<&str, &LockedPackage> = locked
+        .map(|l| l.package.iter().map(|p| (p.name.as_str(), p)).collect())
+        .unwrap_or_default();
+    let mut manifests: HashMap<(String, Version), Manifest> = HashMap::new();
+    let mut selected: BTreeMap<String, Version> = BTreeMap::new();
+
+    for _ in 0..RESOLUTION_ROUND_LIMIT {
+        // Requirements of the project and every package selected in the last round
+        let mut requirements: BTreeMap<String, Vec<(String, VersionReq)>> = BTreeMap::new();
+        add_requirements(&mut requirements, manifest);
+        for (name, version) in &selected {
+            add_requirements(&mut requirements, &manifests[&(name.clone(), version.clone())]);
+        }
+
+        let mut next = BTreeMap::new();
+        for (name, package_requirements) in &requirements {
+            if *name == manifest.package.name {
+                log_error(format!("\"{}\" is required by its own dependencies", name).as_str());
+                return None;
+            }
+
+            let locked_version = locked_packages.get(name.as_str()).and_then(|p| Version::parse(p.version.as_str()).ok());
+            let version = select_version(registry, name, package_requirements, locked_version)?;
+            if let Entry::Vacant(entry) = manifests.entry((name.clone(), version.clone())) {
+                entry.insert(load_dependency_manifest(registry, name, &version)?);
+            }
+            next.insert(name.clone(), version);
+        }
+
+        if next == selected {
+            let mut lockfile = Lockfile::default();
+            for (name, version) in selected {
+                let version_text = version.to_string();
+                let checksum = match locked_packages.get(name.as_str()) {
+                    // Kept so that changes in the registry are noticed when fetching
+                    Some(p) if p.version == version_text => p.checksum.clone(),
+                    _ => directory_checksum(&package_path(registry, name.as_str(), &version))?,
+                };
+                let dependencies = manifests[&(name.clone(), version)].dependencies.keys().cloned().collect();
+
+                lockfile.package.push(LockedPackage { name, version: version_text, checksum, dependencies });
+            }
+
+            return Some(lockfile);
+        }
+        selected = next;
+    }
+
+    log_error("Couldn't resolve dependencies, the requirements keep changing");
+    return None;
+}
+
+fn add_requirements(requirements: &mut BTreeMap<String, Vec<(String, VersionReq)>>, manifest: &Manifest) {
+    for (name, requirement) in &manifest.dependencies {
+        // Requirements are validated when the manifest is loaded
+        let requirement = VersionReq::parse(requirement).unwrap();
+        requirements.entry(name.clone())
+                    .or_default()
+                    .push((manifest.package.name.clone(), requirement));
+    }
+}
+
+fn select_version(registry: &Path, name: &str, requirements: &[(String, VersionReq)], locked_version: Option<Version>) -> Option<Version> {
+    let versions = available_versions(registry, name);
+    if versions.is_empty() {
+        log_error(format!("Package \"{}\" is not found in registry \"{}\"", name, registry.display()).as_str());
+        return None;
+    }
+
+    let satisfies = |version: &Version| requirements.iter().all(|(_, r)| r.matches(version));
+    if let Some(version) = locked_version.filter(|v| versions.contains(v) && satisfies(v)) {
+        return Some(version);
+    }
+
+    let version = versions.into_iter().find(|v| satisfies(v));
+    if version.is_none() {
+        let descriptions = requirements.iter()
+                                       .map(|(requester, r)| format!("\"{}\" required by \"{}\"", r, requester))
+                                       .collect::<Vec<String>>()
+                                       .join(", ");
+        log_error(format!("No version of \"{}\" satisfies {}", name, descriptions).as_str());
+    }
+
+    return version;
+}
+
+fn load_dependency_manifest(registry: &Path, name: &str, version: &Version) -> Option<Manifest> {
+    let manifest_path = package_path(registry, name, version).join(MANIFEST_FILE_NAME);
+    let manifest = load_manifest(&manifest_path)?;
+
+    if manifest.package.name != name || Version::parse(manifest.package.version.as_str()).ok().as_ref() != Some(version) {
+        log_error(format!("\"{}\" doesn't describe {} v{}", manifest_path.display(), name, version).as_str());
+        return None;
+    }
+    if manifest.package.package_type != PackageType::Library {
+        log_error(format!("{} v{} is not a library, it couldn't be used as a dependency", name, version).as_str());
+        return None;
+    }
+
+    return Some(manifest);
+}
+
+/// Returns `None` if there is no lockfile, an invalid one is ignored with a warning
+pub fn read_lockfile(project_root: &Path) -> Option<Lockfile> {
+    let content = fs::read_to_string(project_root.join(LOCKFILE_NAME)).ok()?;
+
+    let lockfile = toml::from_str::<Lockfile>(content.as_str());
+    if lockfile.is_err() {
+        log_warn(format!("Ignored invalid \"{}\": {}", LOCKFILE_NAME, lockfile.unwrap_err().message()).as_str());
+        return None;
+    }
+
+    return lockfile.ok();
+}
+
+/// The file is only written when its content changes
+pub fn write_lockfile(project_root: &Path, lockfile: &Lockfile) -> bool {
+    let lockfile_path = project_root.join(LOCKFILE_NAME);
+    let content = format!("{}\n{}", LOCKFILE_HEADER, toml::to_string(lockfile).unwrap());
+    if fs::read_to_string(&lockfile_path).is_ok_and(|c| c == content) {
+        return true;
+    }
+
+    if fs::write(&lockfile_path, content).is_err() {
+        log_error(format!("Couldn't write \"{}\"", lockfile_path.display()).as_str());
+        return false;
+    }
+
+    log_trace(format!("Updated \"{}\"", lockfile_path.display()).as_str());
+    return true;
+}
+
+/// Copy locked packages from the registry into `cache` unless the cached copy is intact.
+/// Returns the directories of every package in the cache.
+pub fn fetch_dependencies(lockfile: &Lockfile, registry: &Path, cache: &Path) -> Option<Vec<PathBuf>> {
+    let mut result = vec![];
+    for package in &lockfile.package {
+        let cached_path = cache.join(format!("{}-{}", package.name, package.version));
+
+        if directory_checksum(&cached_path).as_ref() != Some(&package.checksum) {
+            let version = Version::parse(package.version.as_str());
+            if version.is_err() {
+                log_error(format!("Invalid version \"{}\" of \"{}\" in \"{}\"", package.version, package.name, LOCKFILE_NAME).as_str());
+                return None;
+            }
+            let registry_package_path = package_path(registry, package.name.as_str(), &version.unwrap());
+
+            let checksum = directory_checksum(&registry_package_path);
+            if checksum.is_none() {
+                log_error(format!("{} v{} is not found in registry \"{}\"", package.name, package.version, registry.display()).as_str());
+                return None;
+            }
+            if checksum.as_ref() != Some(&package.checksum) {
+                log_error(format!(
+                    "{} v{} in the registry has changed since it was locked, remove it from \"{}\" to accept the change",
+                    package.name,
+                    package.version,
+                    LOCKFILE_NAME
+                ).as_str());
+                return None;
+            }
+
+            let _ = fs::remove_dir_all(&cached_path);
+            if copy_directory(&registry_package_path, &cached_path).is_err() {
+                log_error(format!("Couldn't copy {} v{} into \"{}\"", package.name, package.version, cached_path.display()).as_str());
+                return None;
+            }
+            log_info(format!("Fetched {} v{}", package.name, package.version).as_str());
+        }
+
+        result.push(cached_path);
+    }
+
+    return Some(result);
+}
+
+/// Build every dependency into a library package with the alignments of `metadata`, their objects are kept in `build_dir`.
+/// Returns pairs of package names and packages, which are linked with the project so that only their exported functions are visible.
+pub fn build_dependencies(package_roots: &[PathBuf], metadata: &PackageMetadata, build_dir: &Path) -> Option<Vec<(String, Vec<u8>)>> {
+    let library_metadata = PackageMetadata { package_type: LIBRARY_PACKAGE_TYPE, ..metadata.clone() };
+
+    let mut result = vec![];
+    for package_root in package_roots {
+        let manifest = load_manifest(&package_root.join(MANIFEST_FILE_NAME))?;
+        let name = format!("{}-{}", manifest.package.name, manifest.package.version);
+
+        let mut source_files = vec![];
+        for directory in &manifest.build.source_dirs {
+            let path = package_root.join(directory);
+            if !path.is_dir() {
+                log_error(format!("Source directory \"{}\" of {} v{} is not found", path.display(), manifest.package.name, manifest.package.version).as_str());
+                return None;
+            }
+            source_files.extend(collect_source_files(&path));
+        }
+
+        log_info(format!("Building {} v{}", manifest.package.name, manifest.package.version).as_str());
+        let package = build_sources_with_objects(source_files, package_root, manifest.package.entry.clone(), &library_metadata, &build_dir.join(&name), false);
+        if package.is_none() {
+            log_error(format!("Failed to build {} v{}", manifest.package.name, manifest.package.version).as_str());
+            return None;
+        }
+
+        result.push((name, package.unwrap()));
+    }
+
+    return Some(result);
+}
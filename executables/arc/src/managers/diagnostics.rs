@@ -0,0 +1,121 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::fs;
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use serde_json::json;
+
+use carbon_lang_compiler::shared::error::general_issue::{FileMatch, IssueBase, IssueLevel, IssueLocation, IssuePosition, UNKNOWN_FILE_PATH};
+use carbon_lang_compiler::shared::error::snippet::{render_snippet, LabelledSpan};
+use carbon_lang_compiler::shared::utils::source_map::SourceMap;
+
+use crate::managers::logging::{log_error, log_terminal, message_format};
+use crate::models::message_format::MessageFormat;
+use crate::STDOUT;
+
+static REPORTED_ERRORS: AtomicUsize = AtomicUsize::new(0);
+static REPORTED_WARNINGS: AtomicUsize = AtomicUsize::new(0);
+
+/// Numbers of errors and warnings reported since the last call, so they could be summarized after each compilation
+pub fn take_reported_counts() -> (usize, usize) {
+    return (REPORTED_ERRORS.swap(0, Ordering::Relaxed), REPORTED_WARNINGS.swap(0, Ordering::Relaxed));
+}
+
+/// Report issues in the selected message format.
+/// `file_path` is used when an issue doesn't know which file it comes from.
+/// Human readable messages are followed by the source lines they point to.
+pub fn report_issues<T: Display + IssueLocation>(issues: &[IssueBase<T>], file_path: Option<&Path>) {
+    report_issues_with_sources(issues, file_path, load_source_map);
+}
+
+/// Report issues of a source which is not stored in any file, such as an input of the REPL.
+/// Issues without a known file path are located in `source`, which is called `source_name`.
+pub fn report_input_issues<T: Display + IssueLocation>(issues: &[IssueBase<T>], source_name: &str, source: &str) {
+    report_issues_with_sources(issues, Some(Path::new(source_name)), |path| {
+        return Some(path).filter(|p| *p == source_name).map(|p| SourceMap::new(p, source));
+    });
+}
+
+fn report_issues_with_sources<T: Display + IssueLocation>(
+    issues: &[IssueBase<T>],
+    file_path: Option<&Path>,
+    load_source_map: impl Fn(&str) -> Option<SourceMap>,
+) {
+    // Every source file is read once, packages are not source files so nothing is rendered for them
+    let mut source_maps: HashMap<String, Option<SourceMap>> = HashMap::new();
+
+    for issue in issues {
+        match issue.level {
+            IssueLevel::Error => REPORTED_ERRORS.fetch_add(1, Ordering::Relaxed),
+            IssueLevel::Warning => REPORTED_WARNINGS.fetch_add(1, Ordering::Relaxed),
+            IssueLevel::Info => 0,
+        };
+
+        let location = issue_location(issue, file_path);
+        let source_map = location.as_ref().filter(|_| !matches!(issue.position, IssuePosition::PackageReading)).and_then(|l| {
+            source_maps.entry(l.file_path.clone())
+                       .or_insert_with(|| load_source_map(l.file_path.as_str()))
+                       .as_ref()
+        });
+
+        match message_format() {
+            MessageFormat::Human => {
+                log_error(format!("({}) {}", issue.code, issue.detail).as_str());
+                if let (Some(l), Some(map)) = (&location, source_map) {
+                    let span = LabelledSpan { start_pos: l.start_pos, end_pos: l.end_pos, label: None };
+                    log_terminal().write_line(render_snippet(map, &[span]).as_str()).unwrap();
+                }
+            }
+            MessageFormat::Json => {
+                STDOUT.write_line(issue_to_json(issue, location.as_ref(), source_map, file_path).to_string().as_str()).unwrap()
+            }
+        }
+    }
+}
+
+/// Offsets, lines and columns are `null` if the issue has no source location
+pub fn issue_to_json<T: Display + IssueLocation>(
+    issue: &IssueBase<T>,
+    location: Option<&FileMatch>,
+    source_map: Option<&SourceMap>,
+    file_path: Option<&Path>,
+) -> serde_json::Value {
+    let line_column = location.zip(source_map).map(|(l, map)| map.line_column(l.start_pos));
+
+    return json!({
+        "level": issue.level.to_string(),
+        "position": issue.position.to_string(),
+        "code": issue.code,
+        "file_path": location.map(|l| l.file_path.clone()).or_else(|| file_path.map(|p| p.display().to_string())),
+        "start_pos": location.map(|l| l.start_pos),
+        "end_pos": location.map(|l| l.end_pos),
+        "line": line_column.map(|x| x.0),
+        "column": line_column.map(|x| x.1),
+        "message": issue.detail.to_string(),
+    });
+}
+
+/// Location of an issue with its file path filled in.
+/// Issues without a known file path fall back to `file_path`.
+fn issue_location<T: IssueLocation>(issue: &IssueBase<T>, file_path: Option<&Path>) -> Option<FileMatch> {
+    let mut location = issue.detail.location()?;
+    if location.file_path.is_empty() || location.file_path == UNKNOWN_FILE_PATH {
+        location.file_path = file_path?.display().to_string();
+    }
+
+    return Some(location);
+}
+
+/// Source lines `location` points to, `None` if the file couldn't be read
+pub fn render_location(location: &FileMatch) -> Option<String> {
+    let source_map = load_source_map(location.file_path.as_str())?;
+    let span = LabelledSpan { start_pos: location.start_pos, end_pos: location.end_pos, label: None };
+
+    return Some(render_snippet(&source_map, &[span]));
+}
+
+fn load_source_map(file_path: &str) -> Option<SourceMap> {
+    let content = fs::read_to_string(file_path).ok()?;
+    return Some(SourceMap::new(file_path, content.as_str()));
+}
@@ -0,0 +1,26 @@
+use std::fs;
+use std::path::Path;
+
+use carbon_lang_compiler::documentation::extractor::document_source;
+use carbon_lang_compiler::shared::documentation::file_documentation::FileDocumentation;
+
+use crate::managers::source_files::resolve_link;
+use crate::models::source_unit::SourceUnit;
+
+/// Document every unit, paths are shown relative to `project_root` if possible.
+/// Links pointing to other units are resolved, so their pages could be linked.
+pub fn document_units(units: &[SourceUnit], project_root: &Path) -> Vec<FileDocumentation> {
+    let display_path = |path: &Path| path.strip_prefix(project_root).unwrap_or(path).display().to_string();
+    let canonical_paths: Vec<_> = units.iter().map(|u| fs::canonicalize(&u.path).unwrap_or_else(|_| u.path.clone())).collect();
+
+    return units.iter().map(|unit| {
+        let mut result = document_source(display_path(&unit.path).as_str(), unit.content.as_str(), &unit.tree);
+        for link in &mut result.links {
+            let linked_file = resolve_link(&link.link, &unit.path, project_root).map(|p| fs::canonicalize(&p).unwrap_or(p));
+            link.target = linked_file.and_then(|p| canonical_paths.iter().position(|c| *c == p))
+                                     .map(|index| display_path(&units[index].path));
+        }
+
+        return result;
+    }).collect();
+}
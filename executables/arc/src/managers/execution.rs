@@ -0,0 +1,35 @@
+use carbon_lang_vm::models::value::Value;
+use carbon_lang_vm::models::virtual_machine::{MachineState, VirtualMachine};
+
+use crate::models::execution::ExecutionStop;
+
+/// Execute a started machine until the entry function returns, at most `max_steps` commands.
+/// `inspect` sees the machine before every command, and stops it by returning `false`.
+pub fn run_inspected(
+    vm: &mut VirtualMachine,
+    max_steps: usize,
+    mut inspect: impl FnMut(&VirtualMachine) -> bool,
+) -> Result<Option<Value>, ExecutionStop> {
+    for _ in 0..max_steps {
+        if let MachineState::Finished(value) = &vm.state {
+            return Ok(value.clone());
+        }
+
+        if !inspect(vm) {
+            return Err(ExecutionStop::Interrupted);
+        }
+        vm.step().map_err(ExecutionStop::Issue)?;
+    }
+
+    if let MachineState::Finished(value) = &vm.state {
+        return Ok(value.clone());
+    }
+    return Err(ExecutionStop::StepLimit(max_steps));
+}
+
+/// Arguments typed in the command line, numbers are passed as numbers and others as strings
+pub fn parse_arguments(arguments: &[String]) -> Vec<Value> {
+    return arguments.iter()
+                    .map(|a| a.parse::<i64>().map(Value::Number).unwrap_or_else(|_| Value::String(a.clone())))
+                    .collect();
+}
@@ -0,0 +1,128 @@
+use std::collections::HashMap;
+
+use lsp_types::{
+    CompletionItem, CompletionItemKind, CompletionParams, CompletionResponse, Diagnostic, DiagnosticSeverity,
+    GotoDefinitionParams, GotoDefinitionResponse, Hover, HoverContents, HoverParams, Location, MarkupContent, MarkupKind,
+    NumberOrString, Position, PublishDiagnosticsParams, Range, Url,
+};
+
+use carbon_lang_compiler::analysis::source::analyze_source;
+use carbon_lang_compiler::analysis::symbols::{completion_candidates, find_declaration};
+use carbon_lang_compiler::shared::analysis::source_analysis::SourceAnalysis;
+use carbon_lang_compiler::shared::analysis::symbol::SymbolKind;
+use carbon_lang_compiler::shared::error::general_issue::IssueLevel;
+use carbon_lang_compiler::shared::utils::source_map::SourceMap;
+
+/// Analysis of every document opened in the editor, files are analyzed again on each change
+#[derive(Default)]
+pub struct DocumentStore {
+    documents: HashMap<Url, SourceAnalysis>,
+}
+
+impl DocumentStore {
+    pub fn update(&mut self, uri: Url, text: &str) -> PublishDiagnosticsParams {
+        let file_path = uri.to_file_path().map(|p| p.display().to_string()).unwrap_or_else(|_| uri.to_string());
+        let analysis = analyze_source(file_path.as_str(), text);
+
+        let diagnostics = analysis.issues.iter().map(|issue| Diagnostic {
+            range: to_range(&analysis.source_map, issue.location.start_pos, issue.location.end_pos),
+            severity: Some(match issue.level {
+                IssueLevel::Info => DiagnosticSeverity::INFORMATION,
+                IssueLevel::Warning => DiagnosticSeverity::WARNING,
+                IssueLevel::Error => DiagnosticSeverity::ERROR,
+            }),
+            code: Some(NumberOrString::String(issue.code.clone())),
+            source: Some("arc".to_string()),
+            message: issue.message.clone(),
+            ..Diagnostic::default()
+        }).collect();
+
+        self.documents.insert(uri.clone(), analysis);
+        return PublishDiagnosticsParams { uri, diagnostics, version: None };
+    }
+
+    /// Diagnostics of a closed document are cleared
+    pub fn close(&mut self, uri: Url) -> PublishDiagnosticsParams {
+        self.documents.remove(&uri);
+        return PublishDiagnosticsParams { uri, diagnostics: vec![], version: None };
+    }
+
+    pub fn definition(&self, params: GotoDefinitionParams) -> Option<GotoDefinitionResponse> {
+        let position = params.text_document_position_params;
+        let uri = position.text_document.uri;
+        let analysis = self.documents.get(&uri)?;
+
+        let symbol = find_declaration(analysis, to_offset(&analysis.source_map, position.position))?;
+        return Some(GotoDefinitionResponse::Scalar(Location {
+            range: to_range(&analysis.source_map, symbol.location.start_pos, symbol.location.end_pos),
+            uri,
+        }));
+    }
+
+    pub fn hover(&self, params: HoverParams) -> Option<Hover> {
+        let position = params.text_document_position_params;
+        let analysis = self.documents.get(&position.text_document.uri)?;
+
+        let symbol = find_declaration(analysis, to_offset(&analysis.source_map, position.position))?;
+        return Some(Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: format!("```carbon\n{}\n```", symbol.detail),
+            }),
+            range: None,
+        });
+    }
+
+    pub fn completion(&self, params: CompletionParams) -> Option<CompletionResponse> {
+        let position = params.text_document_position;
+        let analysis = self.documents.get(&position.text_document.uri)?;
+
+        let items = completion_candidates(analysis, to_offset(&analysis.source_map, position.position))
+            .into_iter()
+            .map(|symbol| CompletionItem {
+                kind: Some(match symbol.kind {
+                    SymbolKind::Function => CompletionItemKind::FUNCTION,
+                    SymbolKind::Group => CompletionItemKind::STRUCT,
+                    SymbolKind::Variable => CompletionItemKind::VARIABLE,
+                    SymbolKind::Keyword => CompletionItemKind::KEYWORD,
+                }),
+                label: symbol.name,
+                detail: Some(symbol.detail),
+                ..CompletionItem::default()
+            })
+            .collect();
+
+        return Some(CompletionResponse::Array(items));
+    }
+}
+
+/// Protocol positions are 0-based, characters are counted in UTF-16 code units
+fn to_position(source_map: &SourceMap, offset: usize) -> Position {
+    let (line, column) = source_map.line_column(offset);
+    let character: usize = source_map.line_text(line).chars().take(column - 1).map(char::len_utf16).sum();
+
+    return Position::new((line - 1) as u32, character as u32);
+}
+
+fn to_range(source_map: &SourceMap, start_pos: usize, end_pos: usize) -> Range {
+    return Range::new(to_position(source_map, start_pos), to_position(source_map, end_pos));
+}
+
+fn to_offset(source_map: &SourceMap, position: Position) -> usize {
+    let line = position.line as usize + 1;
+    if line > source_map.line_count() {
+        return source_map.source.len();
+    }
+
+    let mut offset = source_map.line_starts[line - 1];
+    let mut character = 0;
+    for c in source_map.line_text(line).chars() {
+        if character >= position.character as usize {
+            break;
+        }
+        character += c.len_utf16();
+        offset += c.len_utf8();
+    }
+
+    return offset;
+}
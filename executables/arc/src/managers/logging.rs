@@ -1,6 +1,9 @@
-use console::style;
+use std::sync::atomic::{AtomicBool, Ordering};
 
-use crate::STDOUT;
+use console::{style, Term};
+
+use crate::models::message_format::MessageFormat;
+use crate::{STDERR, STDOUT};
 
 pub enum LogLevel {
     Trace,
@@ -9,12 +12,45 @@ pub enum LogLevel {
     Error,
 }
 
+static JSON_MESSAGE_FORMAT: AtomicBool = AtomicBool::new(false);
+static STDOUT_RESERVED: AtomicBool = AtomicBool::new(false);
+
+pub fn set_message_format(format: MessageFormat) {
+    JSON_MESSAGE_FORMAT.store(format == MessageFormat::Json, Ordering::Relaxed);
+}
+
+pub fn message_format() -> MessageFormat {
+    return if JSON_MESSAGE_FORMAT.load(Ordering::Relaxed) {
+        MessageFormat::Json
+    } else {
+        MessageFormat::Human
+    };
+}
+
+/// Keep STDOUT for a protocol like the language server, logs are written to STDERR
+pub fn reserve_stdout() {
+    STDOUT_RESERVED.store(true, Ordering::Relaxed);
+}
+
+/// Logs are moved to STDERR when STDOUT is reserved for JSON messages or a protocol
+pub fn log_terminal() -> &'static Term {
+    if STDOUT_RESERVED.load(Ordering::Relaxed) {
+        return &STDERR;
+    }
+
+    return match message_format() {
+        MessageFormat::Human => &STDOUT,
+        MessageFormat::Json => &STDERR,
+    };
+}
+
 pub fn log_string(level: LogLevel, message: &str) {
+    let terminal = log_terminal();
     match level {
-        LogLevel::Trace => STDOUT.write_line(format!("[{}] {}",style("Trace").blue(), message).as_str()).unwrap(),
-        LogLevel::Info => STDOUT.write_line(format!("[{}] {}",style("Info").green(), message).as_str()).unwrap(),
-        LogLevel::Warn => STDOUT.write_line(format!("[{}] {}",style("Warning").yellow(), message).as_str()).unwrap(),
-        LogLevel::Error => STDOUT.write_line(format!("[{}] {}",style("Error").red(), message).as_str()).unwrap(),
+        LogLevel::Trace => terminal.write_line(format!("[{}] {}",style("Trace").blue(), message).as_str()).unwrap(),
+        LogLevel::Info => terminal.write_line(format!("[{}] {}",style("Info").green(), message).as_str()).unwrap(),
+        LogLevel::Warn => terminal.write_line(format!("[{}] {}",style("Warning").yellow(), message).as_str()).unwrap(),
+        LogLevel::Error => terminal.write_line(format!("[{}] {}",style("Error").red(), message).as_str()).unwrap(),
     }
 }
 
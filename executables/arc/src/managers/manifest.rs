@@ -0,0 +1,157 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use semver::{Version, VersionReq};
+use toml_edit::{DocumentMut, table, value};
+
+use carbon_lang_compiler::shared::package_generation::implementations::package_descriptor::{EXECUTABLE_PACKAGE_TYPE, LIBRARY_PACKAGE_TYPE};
+use carbon_lang_compiler::shared::package_generation::package_descriptor::{PackageDescriptor, PackageMetadata};
+
+use crate::managers::logging::log_error;
+use crate::models::manifest::{Manifest, PackageType};
+
+pub const MANIFEST_FILE_NAME: &str = "Arc.toml";
+
+/// Find the manifest in `directory` or any of its parents
+pub fn find_manifest(directory: &Path) -> Option<PathBuf> {
+    let directory = fs::canonicalize(directory).unwrap_or_else(|_| directory.to_path_buf());
+
+    return directory.ancestors()
+                    .map(|d| d.join(MANIFEST_FILE_NAME))
+                    .find(|p| p.is_file());
+}
+
+/// Find and load the manifest of the project containing `directory`.
+/// Returns the manifest path, the project root and the manifest.
+pub fn open_project(directory: &Path) -> Option<(PathBuf, PathBuf, Manifest)> {
+    let manifest_path = find_manifest(directory);
+    if manifest_path.is_none() {
+        log_error(format!("Couldn't find \"{}\" in \"{}\" or its parents", MANIFEST_FILE_NAME, directory.display()).as_str());
+        return None;
+    }
+    let manifest_path = manifest_path.unwrap();
+    let project_root = manifest_path.parent().unwrap().to_path_buf();
+
+    let manifest = load_manifest(&manifest_path)?;
+    return Some((manifest_path, project_root, manifest));
+}
+
+/// Read and validate a manifest, every problem is logged
+pub fn load_manifest(manifest_path: &Path) -> Option<Manifest> {
+    let content = fs::read_to_string(manifest_path);
+    if content.is_err() {
+        log_error(format!("Couldn't open manifest \"{}\"", manifest_path.display()).as_str());
+        return None;
+    }
+
+    let manifest = toml::from_str::<Manifest>(content.unwrap().as_str());
+    if manifest.is_err() {
+        log_error(format!("Invalid manifest \"{}\": {}", manifest_path.display(), manifest.unwrap_err().message()).as_str());
+        return None;
+    }
+    let manifest = manifest.unwrap();
+
+    let mut valid = true;
+    if !is_valid_package_name(manifest.package.name.as_str()) {
+        log_error(format!("Invalid package name \"{}\", only letters, digits, \"_\" and \"-\" are allowed", manifest.package.name).as_str());
+        valid = false;
+    }
+    if Version::parse(manifest.package.version.as_str()).is_err() {
+        log_error(format!("Invalid package version \"{}\", it should be like \"1.2.3\"", manifest.package.version).as_str());
+        valid = false;
+    }
+    for (name, requirement) in &manifest.dependencies {
+        if !is_valid_package_name(name) {
+            log_error(format!("Invalid dependency name \"{}\"", name).as_str());
+            valid = false;
+        }
+        if VersionReq::parse(requirement).is_err() {
+            log_error(format!("Invalid version requirement \"{}\" of dependency \"{}\"", requirement, name).as_str());
+            valid = false;
+        }
+    }
+    if manifest.dependencies.contains_key(&manifest.package.name) {
+        log_error("A package could not depend on itself");
+        valid = false;
+    }
+    if manifest.build.source_dirs.is_empty() {
+        log_error("At least one source directory is required");
+        valid = false;
+    }
+
+    let metadata = &manifest.metadata;
+    let alignments = [
+        ("data_alignment", metadata.data_alignment),
+        ("domain_layer_count_alignment", metadata.domain_layer_count_alignment),
+        ("data_slot_alignment", metadata.data_slot_alignment),
+        ("address_alignment", metadata.address_alignment),
+    ];
+    for (name, value) in alignments {
+        // Packages with zero alignments couldn't be read
+        if value == Some(0) {
+            log_error(format!("\"{}\" should not be zero", name).as_str());
+            valid = false;
+        }
+    }
+
+    return if valid { Some(manifest) } else { None };
+}
+
+/// Set the requirement of a dependency in the manifest file, or remove it if `requirement` is `None`.
+/// The rest of the file is kept as it is, including comments.
+pub fn edit_dependency(manifest_path: &Path, name: &str, requirement: Option<&str>) -> bool {
+    let document = fs::read_to_string(manifest_path).ok().and_then(|c| c.parse::<DocumentMut>().ok());
+    if document.is_none() {
+        log_error(format!("Couldn't open manifest \"{}\"", manifest_path.display()).as_str());
+        return false;
+    }
+    let mut document = document.unwrap();
+
+    let dependencies = document.entry("dependencies").or_insert(table()).as_table_like_mut();
+    if dependencies.is_none() {
+        log_error("\"dependencies\" in the manifest should be a table");
+        return false;
+    }
+    let dependencies = dependencies.unwrap();
+    match requirement {
+        Some(requirement) => {
+            dependencies.insert(name, value(requirement));
+        }
+        None => {
+            dependencies.remove(name);
+        }
+    }
+
+    if fs::write(manifest_path, document.to_string()).is_err() {
+        log_error(format!("Couldn't write manifest \"{}\"", manifest_path.display()).as_str());
+        return false;
+    }
+
+    return true;
+}
+
+pub fn is_valid_package_name(name: &str) -> bool {
+    return !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+}
+
+impl Manifest {
+    /// Alignments which are not set are the same as `arc compile`
+    pub fn package_metadata(&self) -> PackageMetadata {
+        let default = PackageMetadata::default();
+        return PackageMetadata {
+            package_type: match self.package.package_type {
+                PackageType::Executable => EXECUTABLE_PACKAGE_TYPE,
+                PackageType::Library => LIBRARY_PACKAGE_TYPE,
+            },
+            data_alignment: self.metadata.data_alignment.unwrap_or(default.data_alignment),
+            domain_layer_count_alignment: self.metadata.domain_layer_count_alignment.unwrap_or(default.domain_layer_count_alignment),
+            data_slot_alignment: self.metadata.data_slot_alignment.unwrap_or(default.data_slot_alignment),
+            address_alignment: self.metadata.address_alignment.unwrap_or(default.address_alignment),
+            ..default
+        };
+    }
+
+    pub fn package_descriptor(&self) -> PackageDescriptor {
+        return PackageDescriptor::new(self.package.name.as_str(), self.package.author.as_str(), self.package_metadata());
+    }
+}
@@ -1,2 +1,15 @@
+pub mod build_cache;
 pub mod compilation;
+pub mod debugger;
+pub mod dependencies;
+pub mod diagnostics;
+pub mod documentation;
+pub mod execution;
+pub mod language_server;
 pub mod logging;
+pub mod manifest;
+pub mod registry;
+pub mod repl;
+pub mod source_files;
+pub mod test_runner;
+pub mod watcher;
@@ -0,0 +1,120 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use semver::Version;
+use sha2::{Digest, Sha256};
+
+use crate::models::manifest::Manifest;
+
+pub const HOME_VARIABLE: &str = "ARC_HOME";
+pub const REGISTRY_VARIABLE: &str = "ARC_REGISTRY";
+
+/// `$ARC_HOME`, or `.arc` in the home directory of the user
+pub fn arc_home() -> Option<PathBuf> {
+    if let Some(home) = env::var_os(HOME_VARIABLE) {
+        return Some(PathBuf::from(home));
+    }
+
+    return env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(|home| PathBuf::from(home).join(".arc"));
+}
+
+/// The registry in the manifest, then `$ARC_REGISTRY`, then `$ARC_HOME/registry`.
+/// Every package version in it is a project directory `<name>/<version>` with its own manifest.
+pub fn registry_path(manifest: &Manifest, project_root: &Path) -> Option<PathBuf> {
+    if let Some(registry) = &manifest.registry {
+        return Some(project_root.join(&registry.path));
+    }
+    if let Some(registry) = env::var_os(REGISTRY_VARIABLE) {
+        return Some(PathBuf::from(registry));
+    }
+
+    return arc_home().map(|home| home.join("registry"));
+}
+
+/// Where fetched packages are stored, as `<name>-<version>`
+pub fn cache_path() -> Option<PathBuf> {
+    return arc_home().map(|home| home.join("cache"));
+}
+
+pub fn package_path(registry: &Path, name: &str, version: &Version) -> PathBuf {
+    return registry.join(name).join(version.to_string());
+}
+
+/// Versions of a package in the registry from the highest to the lowest, directories which are not versions are ignored
+pub fn available_versions(registry: &Path, name: &str) -> Vec<Version> {
+    let entries = fs::read_dir(registry.join(name));
+    if entries.is_err() {
+        return vec![];
+    }
+
+    let mut result: Vec<Version> = entries.unwrap()
+        .flatten()
+        .filter(|e| e.path().is_dir())
+        .filter_map(|e| Version::parse(e.file_name().to_string_lossy().as_ref()).ok())
+        .collect();
+    result.sort_by(|a, b| b.cmp(a));
+
+    return result;
+}
+
+/// SHA-256 of every file under `directory` and their relative paths, in hex.
+/// Returns `None` if the directory couldn't be read.
+pub fn directory_checksum(directory: &Path) -> Option<String> {
+    if !directory.is_dir() {
+        return None;
+    }
+
+    let mut hasher = Sha256::new();
+    for relative_path in collect_files(directory).ok()? {
+        let content = fs::read(directory.join(&relative_path)).ok()?;
+        // Separators are normalized so the checksum is the same on every platform
+        let path = relative_path.components()
+                                .map(|c| c.as_os_str().to_string_lossy().to_string())
+                                .collect::<Vec<String>>()
+                                .join("/");
+        hasher.update(path.as_bytes());
+        hasher.update([0]);
+        hasher.update((content.len() as u64).to_le_bytes());
+        hasher.update(&content);
+    }
+
+    return Some(format!("{:x}", hasher.finalize()));
+}
+
+/// Copy a directory recursively, `to` is created if it doesn't exist
+pub fn copy_directory(from: &Path, to: &Path) -> io::Result<()> {
+    for relative_path in collect_files(from)? {
+        let target = to.join(&relative_path);
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::copy(from.join(&relative_path), target)?;
+    }
+
+    return fs::create_dir_all(to);
+}
+
+/// Paths of every file under `directory` relative to it, sorted
+fn collect_files(directory: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut result = vec![];
+    let mut pending = vec![PathBuf::new()];
+
+    while let Some(current) = pending.pop() {
+        for entry in fs::read_dir(directory.join(&current))? {
+            let entry = entry?;
+            let relative_path = current.join(entry.file_name());
+            if entry.file_type()?.is_dir() {
+                pending.push(relative_path);
+            } else {
+                result.push(relative_path);
+            }
+        }
+    }
+
+    result.sort();
+    return Ok(result);
+}
@@ -0,0 +1,316 @@
+use carbon_lang_compiler::lexer::tokenize::tokenize;
+use carbon_lang_compiler::package_generator::availability_check::package_check::compiler_defined_types;
+use carbon_lang_compiler::package_generator::type_inference::expression::{infer_expression_output_type, infer_expression_term_data_type};
+use carbon_lang_compiler::package_generator::utils::infer_every_expression_data_term_type;
+use carbon_lang_compiler::parser::decorator::decorate_token_with_string_pool;
+use carbon_lang_compiler::parser::pipeline::{build_action_block, build_expression, build_whole_file};
+use carbon_lang_compiler::shared::ast::action::{Action, ActionContent, ReturnAction, VariableDefinition};
+use carbon_lang_compiler::shared::ast::blocks::expression::{ExprDataTerm, SimpleExpression, TermContent};
+use carbon_lang_compiler::shared::ast::blocks::function::{Function, FunctionDeclarator, FunctionLinkage};
+use carbon_lang_compiler::shared::ast::decorated_token::{DecoratedToken, DecoratedTokenContent};
+use carbon_lang_compiler::shared::ast::package::ParserPackageStructure;
+use carbon_lang_compiler::shared::package_generation::data_descriptor::StringConstant;
+use carbon_lang_compiler::shared::package_generation::implementations::package_descriptor::LIBRARY_PACKAGE_TYPE;
+use carbon_lang_compiler::shared::package_generation::package_descriptor::PackageMetadata;
+use carbon_lang_compiler::shared::package_reading::instruction::Instruction;
+use carbon_lang_compiler::shared::session::compile_session::{CompileOptions, Session};
+use carbon_lang_compiler::shared::token::container::ContainerType;
+use carbon_lang_compiler::shared::token::keyword::KeywordType;
+use carbon_lang_compiler::shared::utils::identifier::Identifier;
+use carbon_lang_vm::models::value::Value;
+use carbon_lang_vm::models::virtual_machine::VirtualMachine;
+
+use crate::managers::diagnostics::report_input_issues;
+use crate::managers::execution::run_inspected;
+use crate::managers::logging::log_error;
+use crate::models::repl_session::{ReplOutcome, ReplSession, ReplVariable};
+
+/// Statements and expressions are compiled into this function, no source function could be named like it
+pub const REPL_ENTRY_FUNCTION: &str = "<repl>";
+
+impl ReplSession {
+    pub fn new(max_steps: usize) -> ReplSession {
+        return ReplSession {
+            functions: vec![],
+            variables: vec![],
+            string_pool: vec![],
+            metadata: PackageMetadata::default(),
+            max_steps,
+            input_count: 0,
+        };
+    }
+}
+
+/// Whether braces and brackets of `input` are closed, otherwise more lines are expected
+pub fn is_input_complete(input: &str) -> bool {
+    let mut depth: i64 = 0;
+    let mut in_string = false;
+    let mut escaped = false;
+    for c in input.chars() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+
+        match c {
+            '"' => in_string = true,
+            '{' | '(' => depth += 1,
+            '}' | ')' => depth -= 1,
+            _ => {}
+        }
+    }
+
+    return depth <= 0;
+}
+
+/// Values are printed like literals of their type, booleans are numbers in the virtual machine
+pub fn format_value(value: &Value, type_name: &Identifier) -> String {
+    return match value {
+        Value::Number(x) if *type_name == Identifier::single("bool") => (*x != 0).to_string(),
+        _ => value.to_string(),
+    };
+}
+
+/// Compile and run an input with everything declared before, all issues are logged.
+/// The session only changes if the input succeeds.
+pub fn evaluate_input(session: &mut ReplSession, input: &str) -> ReplOutcome {
+    session.input_count += 1;
+    let source_name = format!("<input {}>", session.input_count);
+
+    let tokens = tokenize(input, true);
+    if tokens.is_err() {
+        report_input_issues(&tokens.unwrap_err().with_file_path(source_name.as_str()).issues, source_name.as_str(), input);
+        return ReplOutcome::Failed;
+    }
+    let (tokens, string_pool) = decorate_token_with_string_pool(tokens.unwrap(), session.string_pool.clone());
+    if tokens.is_empty() {
+        return ReplOutcome::Executed(None);
+    }
+
+    let first_keyword = tokens[0].content.get_decorated_keyword();
+    let declares_function = matches!(first_keyword, Some(KeywordType::KwExport))
+        || (matches!(first_keyword, Some(KeywordType::KwDeclare))
+            && matches!(tokens.get(1).and_then(|t| t.content.get_decorated_keyword()), Some(KeywordType::KwFunc)));
+
+    return if declares_function {
+        declare_functions(session, tokens, string_pool, source_name.as_str(), input)
+    } else {
+        execute_snippet(session, tokens, string_pool, source_name.as_str(), input)
+    };
+}
+
+/// Functions replace earlier ones with the same name, so they could be fixed by declaring them again
+fn declare_functions(
+    session: &mut ReplSession,
+    tokens: Vec<DecoratedToken>,
+    string_pool: Vec<StringConstant>,
+    source_name: &str,
+    input: &str,
+) -> ReplOutcome {
+    let tree = build_whole_file(tokens, Identifier::empty());
+    if tree.is_err() {
+        report_input_issues(&tree.unwrap_err().with_file_path(source_name).issues, source_name, input);
+        return ReplOutcome::Failed;
+    }
+    let mut tree = tree.unwrap();
+    if !tree.linked_code_files.is_empty() || !tree.declared_groups.is_empty() || !tree.declared_implementations.is_empty() {
+        log_error("Only functions, statements and expressions could be entered");
+        return ReplOutcome::Failed;
+    }
+    tree.set_file_path(source_name);
+
+    let mut functions = session.functions.clone();
+    let mut declared = vec![];
+    for function in tree.functions {
+        if function.linkage == FunctionLinkage::External {
+            log_error(format!("External function \"{}\" couldn't be called in the REPL", function.declarator.identifier).as_str());
+            return ReplOutcome::Failed;
+        }
+
+        functions.retain(|f| f.declarator.identifier != function.declarator.identifier);
+        declared.push(function.declarator.identifier.to_string());
+        functions.push(function);
+    }
+
+    let package = ParserPackageStructure {
+        functions,
+        entry_point: Identifier::empty(),
+        linked_code_files: vec![],
+        declared_groups: vec![],
+        declared_implementations: vec![],
+    };
+    // There is no entry function until a snippet is executed, so functions are checked like a library
+    if let Err(e) = compile_session(&session.metadata, LIBRARY_PACKAGE_TYPE).analyze_package(&package) {
+        report_input_issues(&e.issues, source_name, input);
+        return ReplOutcome::Failed;
+    }
+
+    session.functions = package.functions;
+    session.string_pool = string_pool;
+    return ReplOutcome::Declared(declared);
+}
+
+/// Statements end with `;` or `}`, anything else is an expression whose value is returned
+fn execute_snippet(
+    session: &mut ReplSession,
+    tokens: Vec<DecoratedToken>,
+    string_pool: Vec<StringConstant>,
+    source_name: &str,
+    input: &str,
+) -> ReplOutcome {
+    let is_statement = matches!(
+        tokens.last().unwrap().content,
+        DecoratedTokenContent::StatementEndSign | DecoratedTokenContent::Container(ContainerType::AntiBrace)
+    );
+
+    let mut variables: Vec<VariableDefinition> = session.variables.iter().map(|v| v.definition.clone()).collect();
+    let (body, return_type) = if is_statement {
+        let actions = build_action_block(&tokens);
+        if actions.is_err() {
+            report_input_issues(&actions.unwrap_err().with_file_path(source_name).issues, source_name, input);
+            return ReplOutcome::Failed;
+        }
+        (actions.unwrap(), Identifier::empty())
+    } else {
+        let expression = build_expression(&tokens);
+        if expression.is_err() {
+            log_error("Invalid expression, statements should end with \";\"");
+            return ReplOutcome::Failed;
+        }
+        expression_action(expression.unwrap(), &tokens, &session.functions, &variables)
+    };
+
+    // Kept variables are parameters of the entry function, variables declared at the top level are kept after it
+    let declared_variables: Vec<VariableDefinition> = body.iter().filter_map(|a| match &a.content {
+        ActionContent::DeclarationStatement(x) => Some(VariableDefinition { type_name: x.data_type.clone(), identifier: x.identifier.clone() }),
+        _ => None,
+    }).collect();
+    let entry_function = Function {
+        declarator: FunctionDeclarator {
+            identifier: Identifier::single(REPL_ENTRY_FUNCTION),
+            parameters: variables.clone(),
+            return_type: return_type.clone(),
+        },
+        body,
+        file_path: source_name.to_string(),
+        linkage: FunctionLinkage::Internal,
+    };
+
+    let mut functions = session.functions.clone();
+    functions.push(entry_function);
+    let package = ParserPackageStructure {
+        functions,
+        entry_point: Identifier::single(REPL_ENTRY_FUNCTION),
+        linked_code_files: vec![],
+        declared_groups: vec![],
+        declared_implementations: vec![],
+    };
+    let compiler = compile_session(&session.metadata, session.metadata.package_type);
+    if let Err(e) = compiler.analyze_package(&package) {
+        report_input_issues(&e.issues, source_name, input);
+        return ReplOutcome::Failed;
+    }
+
+    let bytes = compiler.compile_package(&package, string_pool.clone());
+    if bytes.is_err() {
+        report_input_issues(&bytes.unwrap_err().issues, source_name, input);
+        return ReplOutcome::Failed;
+    }
+    let bytes = bytes.unwrap();
+    let locals = session.variables.iter().map(|v| v.value.clone()).collect();
+    let result = run_entry_function(&bytes, locals, session.max_steps);
+    if result.is_none() {
+        return ReplOutcome::Failed;
+    }
+    let (value, locals) = result.unwrap();
+
+    variables.extend(declared_variables);
+    session.variables = variables.into_iter().enumerate().map(|(slot, definition)| ReplVariable {
+        definition,
+        value: locals.get(slot).cloned().flatten(),
+    }).collect();
+    session.string_pool = string_pool;
+    return ReplOutcome::Executed(value.map(|v| (v, return_type)));
+}
+
+/// Returns the action printing the expression and the return type of the entry function.
+/// Calls to functions without a return value are executed as statements.
+fn expression_action(
+    expression: SimpleExpression,
+    tokens: &[DecoratedToken],
+    functions: &Vec<Function>,
+    variables: &Vec<VariableDefinition>,
+) -> (Vec<Action>, Identifier) {
+    let original_tokens = tokens.iter().map(|t| t.original_token.clone()).collect();
+    let term_types: Option<Vec<Identifier>> = expression.postfix_expr.iter()
+                                                        .filter_map(|t| t.content.get_data_term())
+                                                        .map(|d| infer_expression_term_data_type(d, functions, variables))
+                                                        .collect();
+
+    if let ([term], Some([term_type])) = (expression.postfix_expr.as_slice(), term_types.as_deref()) {
+        if let TermContent::Data(ExprDataTerm::FunctionCall(call)) = &term.content {
+            if *term_type == Identifier::empty() {
+                return (vec![Action::new(ActionContent::CallStatement(call.clone()), original_tokens)], Identifier::empty());
+            }
+        }
+    }
+
+    // Problems of the expression are reported by availability checks, any type works until then
+    let return_type = term_types.and_then(|_| {
+                                    let typed = infer_every_expression_data_term_type(&expression, functions, variables);
+                                    infer_expression_output_type(&typed, &compiler_defined_types())
+                                })
+                                .unwrap_or_else(|| Identifier::single("number"));
+    let action = Action::new(ActionContent::ReturnStatement(ReturnAction { value: Some(expression) }), original_tokens);
+
+    return (vec![action], return_type);
+}
+
+/// Inputs are compiled like sources of a package, the functions are put together by the REPL instead of parsed from files
+fn compile_session(metadata: &PackageMetadata, package_type: u8) -> Session {
+    return Session::new(CompileOptions {
+        entry_function: REPL_ENTRY_FUNCTION.to_string(),
+        metadata: PackageMetadata { package_type, ..metadata.clone() },
+        ..CompileOptions::default()
+    });
+}
+
+/// Returns the value of the entry function and its local slots right before it returns
+fn run_entry_function(bytes: &[u8], locals: Vec<Option<Value>>, max_steps: usize) -> Option<(Option<Value>, Vec<Option<Value>>)> {
+    let vm = VirtualMachine::from_bytes(bytes);
+    if vm.is_err() {
+        log_error(format!("Failed to load the compiled input: {}", vm.unwrap_err()).as_str());
+        return None;
+    }
+    let mut vm = vm.unwrap();
+    if let Err(e) = vm.start(vec![]) {
+        log_error(format!("Runtime error: {}", e).as_str());
+        return None;
+    }
+    // Unassigned variables stay unassigned, so they couldn't be passed as arguments
+    vm.frames[0].locals = locals;
+
+    let mut final_locals = vec![];
+    let result = run_inspected(&mut vm, max_steps, |vm| {
+        let leaving = vm.current_instruction().map(|i| matches!(
+            i.instruction,
+            Instruction::LeaveWithValue | Instruction::LeaveWithoutValue | Instruction::FunctionEndFlag
+        ));
+        if vm.frames.len() == 1 && leaving.unwrap_or(false) {
+            final_locals = vm.current_frame().locals.clone();
+        }
+
+        return true;
+    });
+    if result.is_err() {
+        log_error(result.unwrap_err().to_string().as_str());
+        return None;
+    }
+
+    return Some((result.unwrap(), final_locals));
+}
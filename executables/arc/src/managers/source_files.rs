@@ -0,0 +1,357 @@
+use std::collections::{HashSet, VecDeque};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use carbon_lang_compiler::lexer::tokenize::tokenize;
+use carbon_lang_compiler::parser::decorator::decorate_token;
+use carbon_lang_compiler::parser::pipeline::build_whole_file;
+use carbon_lang_compiler::session::pipeline::check_unique_functions;
+use carbon_lang_compiler::shared::ast::decorated_token::DecoratedToken;
+use carbon_lang_compiler::shared::ast::link::SourceFileLink;
+use carbon_lang_compiler::shared::ast::package::ParserPackageStructure;
+use carbon_lang_compiler::shared::package_generation::data_descriptor::StringConstant;
+use carbon_lang_compiler::shared::utils::identifier::Identifier;
+
+use crate::managers::build_cache::{entry_path, read_entry, remove_unused_entries, tokens_key, tree_key, write_entry, TOKENS_ENTRY_KIND, TREE_ENTRY_KIND};
+use crate::managers::compilation::{parse_tokens, token_conversion};
+use crate::managers::diagnostics::report_issues;
+use crate::managers::logging::{log_error, log_info, log_trace};
+use crate::models::build_cache::CachedTokens;
+use crate::models::source_unit::SourceUnit;
+
+pub use carbon_lang_compiler::session::pipeline::SOURCE_FILE_EXTENSION;
+
+/// Find out whether a directory or a single file is going to be compiled.
+/// Returns the source files and the project root, which is the directory itself or the parent of the file.
+pub fn find_source_files(input_path: &Path) -> Option<(Vec<PathBuf>, PathBuf)> {
+    let (source_files, project_root) = if input_path.is_dir() {
+        (collect_source_files(input_path), input_path.to_path_buf())
+    } else if input_path.is_file() {
+        let root = input_path.parent().map(|p| p.to_path_buf()).unwrap_or_default();
+        (vec![input_path.to_path_buf()], root)
+    } else {
+        log_error(format!("Couldn't open file \"{}\"", input_path.display()).as_str());
+        return None;
+    };
+
+    if source_files.is_empty() {
+        log_error("No source file found");
+        return None;
+    }
+    log_trace(format!("Found {} source files", source_files.len()).as_str());
+
+    return Some((source_files, project_root));
+}
+
+/// Find every source file under `directory` recursively, sorted by path so the output is stable.
+pub fn collect_source_files(directory: &Path) -> Vec<PathBuf> {
+    let mut result = vec![];
+    let mut pending = vec![directory.to_path_buf()];
+
+    while let Some(current) = pending.pop() {
+        let entries = fs::read_dir(&current);
+        if entries.is_err() {
+            continue;
+        }
+
+        for entry in entries.unwrap().flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                pending.push(path);
+            } else if path.extension().is_some_and(|e| e == SOURCE_FILE_EXTENSION) {
+                result.push(path);
+            }
+        }
+    }
+
+    result.sort();
+    return result;
+}
+
+/// `source_files` and every file they link to, which are what a compilation depends on.
+/// Files that couldn't be parsed are included but their links are not followed, no issue is reported.
+pub fn find_linked_files(source_files: Vec<PathBuf>, project_root: &Path) -> Vec<PathBuf> {
+    return walk_linked_files(source_files, project_root).into_iter()
+                                                        .map(|(path, _)| fs::canonicalize(&path).unwrap_or(path))
+                                                        .collect();
+}
+
+/// Contents of `source_files` and every file they link to, paths are kept as they are found.
+/// Links are followed without reporting any issue, which is left to the session compiling these files.
+pub fn read_linked_sources(source_files: Vec<PathBuf>, project_root: &Path) -> Option<Vec<(PathBuf, String)>> {
+    let mut result = vec![];
+    for (file_path, content) in walk_linked_files(source_files, project_root) {
+        if content.is_none() {
+            log_error(format!("Couldn't open file \"{}\"", file_path.display()).as_str());
+            return None;
+        }
+        log_trace(format!("Reading \"{}\"", file_path.display()).as_str());
+
+        result.push((file_path, content.unwrap()));
+    }
+
+    return Some(result);
+}
+
+/// Visit `source_files` and the files they link to breadth first, each file only once.
+/// Returns the paths as they are found with their contents, `None` if a file couldn't be read.
+/// Links of files which couldn't be read or parsed are not followed.
+fn walk_linked_files(source_files: Vec<PathBuf>, project_root: &Path) -> Vec<(PathBuf, Option<String>)> {
+    let mut pending: VecDeque<PathBuf> = source_files.into_iter().collect();
+    let mut visited: HashSet<PathBuf> = HashSet::new();
+    let mut result = vec![];
+
+    while let Some(file_path) = pending.pop_front() {
+        let canonical_path = fs::canonicalize(&file_path).unwrap_or_else(|_| file_path.clone());
+        if !visited.insert(canonical_path) {
+            continue;
+        }
+
+        let content = fs::read_to_string(&file_path).ok();
+        let tree = content.as_ref()
+                          .and_then(|c| tokenize(c.as_str(), true).ok())
+                          .and_then(|tokens| build_whole_file(decorate_token(tokens).0, Identifier::empty()).ok());
+        for link in tree.iter().flat_map(|t| t.linked_code_files.iter()) {
+            if let Some(linked_path) = resolve_link(link, &file_path, project_root) {
+                pending.push_back(linked_path);
+            }
+        }
+
+        result.push((file_path, content));
+    }
+
+    return result;
+}
+
+/// Find the file a `link` statement points to.
+/// Files are looked up relative to the linking file first, then relative to the project root.
+/// An identifier like `foo::bar` refers to `foo/bar.cbs`.
+pub fn resolve_link(link: &SourceFileLink, linking_file: &Path, project_root: &Path) -> Option<PathBuf> {
+    let relative_path = match link {
+        SourceFileLink::SourceFile(path) => path.clone(),
+        SourceFileLink::Identifier(identifier) => identifier_to_path(identifier),
+    };
+
+    let mut candidates = vec![];
+    if relative_path.is_absolute() {
+        candidates.push(relative_path);
+    } else {
+        if let Some(parent) = linking_file.parent() {
+            candidates.push(parent.join(&relative_path));
+        }
+        candidates.push(project_root.join(&relative_path));
+    }
+
+    return candidates.into_iter().find(|p| p.is_file());
+}
+
+fn identifier_to_path(identifier: &Identifier) -> PathBuf {
+    let mut result = PathBuf::new();
+    for scope in &identifier.scope {
+        result.push(scope);
+    }
+    result.push(format!("{}.{}", identifier.name, SOURCE_FILE_EXTENSION));
+
+    return result;
+}
+
+fn link_to_string(link: &SourceFileLink) -> String {
+    return match link {
+        SourceFileLink::SourceFile(path) => format!("\"{}\"", path.display()),
+        SourceFileLink::Identifier(identifier) => identifier.to_string(),
+    };
+}
+
+/// Lex and parse `source_files` and every file they link to (each file only once),
+/// then merge them into a single structure sharing one string pool.
+pub fn load_package_sources(
+    source_files: Vec<PathBuf>,
+    project_root: &Path,
+    entry_function: String,
+) -> Option<(ParserPackageStructure, Vec<StringConstant>)> {
+    let units = load_source_units(source_files, project_root, entry_function, true, None)?;
+
+    // The string pool is shared, so the last one contains constants of every file
+    let string_pool = units.last()?.string_pool.clone();
+    let package = merge_source_units(&units)?;
+    if !report_duplicate_functions(&package) {
+        return None;
+    }
+
+    return Some((package, string_pool));
+}
+
+/// Like `load_package_sources`, but every file keeps its own structure and string pool,
+/// so each of them could be compiled into an object separately.
+///
+/// Lexing and parsing a file don't depend on other files, so with `cache_dir` only changed files are processed again,
+/// while tokens and structures of others are read from the cache.
+pub fn load_separate_sources(
+    source_files: Vec<PathBuf>,
+    project_root: &Path,
+    entry_function: String,
+    cache_dir: Option<&Path>,
+) -> Option<Vec<SourceUnit>> {
+    let units = load_source_units(source_files, project_root, entry_function, false, cache_dir)?;
+    if !report_duplicate_functions(&merge_source_units(&units)?) {
+        return None;
+    }
+
+    return Some(units);
+}
+
+/// Merge structures of every unit for checks across files, the entry point of the first unit is kept
+pub fn merge_source_units(units: &[SourceUnit]) -> Option<ParserPackageStructure> {
+    let mut package = units.first()?.tree.clone();
+    for unit in &units[1..] {
+        package.merge(unit.tree.clone());
+    }
+
+    return Some(package);
+}
+
+fn load_source_units(
+    source_files: Vec<PathBuf>,
+    project_root: &Path,
+    entry_function: String,
+    share_string_pool: bool,
+    cache_dir: Option<&Path>,
+) -> Option<Vec<SourceUnit>> {
+    let mut pending: VecDeque<PathBuf> = source_files.into_iter().collect();
+    let mut visited: HashSet<PathBuf> = HashSet::new();
+
+    let mut units: Vec<SourceUnit> = vec![];
+    let mut string_pool: Vec<StringConstant> = vec![];
+    let mut used_cache_entries: HashSet<PathBuf> = HashSet::new();
+    let mut cached_count = 0;
+
+    while let Some(file_path) = pending.pop_front() {
+        let canonical_path = fs::canonicalize(&file_path).unwrap_or_else(|_| file_path.clone());
+        if !visited.insert(canonical_path) {
+            continue;
+        }
+
+        let file_content = fs::read_to_string(&file_path);
+        if file_content.is_err() {
+            log_error(format!("Couldn't open file \"{}\"", file_path.display()).as_str());
+            return None;
+        }
+        let file_content = file_content.unwrap();
+
+        if !share_string_pool {
+            string_pool = vec![];
+        }
+
+        // A shared string pool depends on previous files, so only separate files are cached
+        let (mut tree, extended_pool) = match cache_dir.filter(|_| !share_string_pool) {
+            Some(dir) => {
+                let (tree, pool, is_cached) = load_cached_file(file_content.as_str(), &file_path, entry_function.as_str(), dir, &mut used_cache_entries)?;
+                if is_cached {
+                    cached_count += 1;
+                }
+                (tree, pool)
+            }
+            None => {
+                let (tokens, pool) = lex_file(file_content.as_str(), string_pool, &file_path)?;
+                (parse_file(tokens, &file_path, entry_function.as_str())?, pool)
+            }
+        };
+        string_pool = extended_pool;
+        // Cached structures may come from another file with the same content
+        tree.set_file_path(file_path.display().to_string().as_str());
+
+        for link in &tree.linked_code_files {
+            match resolve_link(link, &file_path, project_root) {
+                Some(linked_path) => pending.push_back(linked_path),
+                None => {
+                    log_error(
+                        format!(
+                            "Couldn't resolve link {} in \"{}\"",
+                            link_to_string(link),
+                            file_path.display()
+                        )
+                            .as_str(),
+                    );
+                    return None;
+                }
+            }
+        }
+
+        units.push(SourceUnit { path: file_path, content: file_content, tree, string_pool: string_pool.clone() });
+    }
+
+    if let Some(dir) = cache_dir.filter(|_| !share_string_pool) {
+        log_info(format!("Lexed and parsed {} of {} source files, others are cached", units.len() - cached_count, units.len()).as_str());
+        remove_unused_entries(dir, &used_cache_entries);
+    }
+
+    return Some(units);
+}
+
+/// Read tokens and the structure of a file from the cache, the file is lexed or parsed again if they are missing.
+/// Returns whether both are found in the cache as well.
+fn load_cached_file(
+    content: &str,
+    file_path: &Path,
+    entry_function: &str,
+    cache_dir: &Path,
+    used_entries: &mut HashSet<PathBuf>,
+) -> Option<(ParserPackageStructure, Vec<StringConstant>, bool)> {
+    let tokens_key = tokens_key(content);
+    let tokens_path = entry_path(cache_dir, TOKENS_ENTRY_KIND, tokens_key.as_str());
+    let tree_path = entry_path(cache_dir, TREE_ENTRY_KIND, tree_key(tokens_key.as_str(), entry_function).as_str());
+    used_entries.insert(tokens_path.clone());
+    used_entries.insert(tree_path.clone());
+
+    let cached_tokens = read_entry::<CachedTokens>(&tokens_path);
+    if let (Some(tokens), Some(tree)) = (&cached_tokens, read_entry::<ParserPackageStructure>(&tree_path)) {
+        log_trace(format!("\"{}\" is not changed", file_path.display()).as_str());
+        return Some((tree, tokens.string_pool.clone(), true));
+    }
+
+    let tokens = match cached_tokens {
+        Some(tokens) => tokens,
+        None => {
+            let (tokens, string_pool) = lex_file(content, vec![], file_path)?;
+            let result = CachedTokens { tokens, string_pool };
+            write_entry(&tokens_path, &result);
+            result
+        }
+    };
+
+    let tree = parse_file(tokens.tokens, file_path, entry_function)?;
+    write_entry(&tree_path, &tree);
+
+    return Some((tree, tokens.string_pool, false));
+}
+
+fn lex_file(content: &str, string_pool: Vec<StringConstant>, file_path: &Path) -> Option<(Vec<DecoratedToken>, Vec<StringConstant>)> {
+    log_trace(format!("Compiling \"{}\"", file_path.display()).as_str());
+
+    let tokens_result = token_conversion(content, string_pool, file_path);
+    if tokens_result.is_none() {
+        log_error(format!("Failed to pass lexical analysis in \"{}\"", file_path.display()).as_str());
+    }
+
+    return tokens_result;
+}
+
+fn parse_file(tokens: Vec<DecoratedToken>, file_path: &Path, entry_function: &str) -> Option<ParserPackageStructure> {
+    let tree_result = parse_tokens(tokens, Some(entry_function.to_string()), file_path);
+    if tree_result.is_none() {
+        log_error(format!("Failed to pass token parsing in \"{}\"", file_path.display()).as_str());
+    }
+
+    return tree_result;
+}
+
+/// Whether every function has its own name, functions declared more than once are logged
+fn report_duplicate_functions(package: &ParserPackageStructure) -> bool {
+    let issues = check_unique_functions(package);
+    if !issues.is_empty() {
+        report_issues(&issues, None);
+        return false;
+    }
+
+    return true;
+}
@@ -0,0 +1,211 @@
+use carbon_lang_compiler::shared::ast::action::{Action, ActionContent};
+use carbon_lang_compiler::shared::ast::blocks::expression::{ExprDataTerm, ExprTerm, SimpleExpression, TermContent};
+use carbon_lang_compiler::shared::ast::blocks::function::{Function, FunctionDeclarator, FunctionLinkage};
+use carbon_lang_compiler::shared::ast::package::ParserPackageStructure;
+use carbon_lang_compiler::shared::ast::parameter::Parameter;
+use carbon_lang_compiler::shared::error::general_issue::FileMatch;
+use carbon_lang_compiler::shared::package_generation::data_descriptor::StringConstant;
+use carbon_lang_compiler::shared::package_generation::implementations::package_descriptor::LIBRARY_PACKAGE_TYPE;
+use carbon_lang_compiler::shared::package_generation::package_descriptor::PackageMetadata;
+use carbon_lang_compiler::shared::session::compile_session::{CompileOptions, Session};
+use carbon_lang_compiler::shared::utils::identifier::Identifier;
+use carbon_lang_compiler::shared::utils::position::Position;
+use carbon_lang_vm::models::value::Value;
+use carbon_lang_vm::models::virtual_machine::VirtualMachine;
+
+use crate::managers::diagnostics::report_issues;
+use crate::managers::execution::run_inspected;
+use crate::managers::logging::{log_error, log_warn};
+use crate::models::execution::ExecutionStop;
+use crate::models::test_case::{TestFailure, TestPackage};
+
+/// Functions named like this without parameters are tests
+pub const TEST_FUNCTION_PREFIX: &str = "test_";
+
+/// Functions provided by the test runner, whose calls are checked by the runner instead of the virtual machine
+const INTRINSICS: [(&str, &[(&str, &str)]); 3] = [
+    ("assert", &[("bool", "condition")]),
+    ("assert_eq", &[("number", "expected"), ("number", "actual")]),
+    ("assert_str_eq", &[("str", "expected"), ("str", "actual")]),
+];
+
+/// Parameter added to intrinsics, so a failed call knows which assertion it is
+const ASSERTION_PARAMETER: &str = "assertion";
+
+/// Names of tests in `tree`, tests with parameters are skipped with a warning
+pub fn discover_tests(tree: &ParserPackageStructure) -> Vec<String> {
+    let mut result = vec![];
+    for function in &tree.functions {
+        let name = function.declarator.identifier.to_string();
+        if !name.starts_with(TEST_FUNCTION_PREFIX) || function.linkage == FunctionLinkage::External {
+            continue;
+        }
+        if !function.declarator.parameters.is_empty() {
+            log_warn(format!("Test \"{}\" is skipped, tests couldn't have parameters", name).as_str());
+            continue;
+        }
+
+        result.push(name);
+    }
+
+    return result;
+}
+
+/// Tests whose names contain `filter`, or every test without a filter
+pub fn filter_tests<'a>(tests: &'a [String], filter: Option<&str>) -> Vec<&'a String> {
+    return tests.iter().filter(|n| filter.is_none_or(|f| n.contains(f))).collect();
+}
+
+/// Check sources with intrinsics declared, then number every assertion, all issues are logged
+pub fn prepare_test_package(tree: ParserPackageStructure, string_pool: Vec<StringConstant>, metadata: &PackageMetadata) -> Option<TestPackage> {
+    if let Some(function) = tree.functions.iter().find(|f| INTRINSICS.iter().any(|i| f.declarator.identifier.to_string() == i.0)) {
+        log_error(format!("Function \"{}\" is provided by the test runner, it couldn't be declared", function.declarator.identifier).as_str());
+        return None;
+    }
+    if let Some(function) = tree.functions.iter().find(|f| f.linkage == FunctionLinkage::External) {
+        log_error(format!("External function \"{}\" couldn't be called by tests", function.declarator.identifier).as_str());
+        return None;
+    }
+
+    let mut checked_tree = tree.clone();
+    checked_tree.functions = intrinsic_functions(false);
+    checked_tree.functions.extend(tree.functions);
+    // Every test is an entry function, so the package is checked like a library
+    let compiler = Session::new(CompileOptions {
+        metadata: PackageMetadata { package_type: LIBRARY_PACKAGE_TYPE, ..metadata.clone() },
+        ..CompileOptions::default()
+    });
+    if let Err(e) = compiler.analyze_package(&checked_tree) {
+        report_issues(&e.issues, None);
+        return None;
+    }
+
+    let mut assertions = vec![];
+    let mut functions = intrinsic_functions(true);
+    for mut function in checked_tree.functions.drain(INTRINSICS.len()..) {
+        number_assertions(&mut function.body, function.file_path.as_str(), &mut assertions);
+        functions.push(function);
+    }
+    checked_tree.functions = functions;
+
+    return Some(TestPackage { tree: checked_tree, string_pool, assertions });
+}
+
+/// Compile the package with `test_name` as the entry function and run it until an assertion fails
+pub fn run_test(package: &TestPackage, test_name: &str, metadata: &PackageMetadata, max_steps: usize) -> Result<(), TestFailure> {
+    let mut tree = package.tree.clone();
+    tree.entry_point = Identifier::single(test_name);
+    let compiler = Session::new(CompileOptions { entry_function: test_name.to_string(), metadata: metadata.clone(), ..CompileOptions::default() });
+    let bytes = compiler.compile_package(&tree, package.string_pool.clone());
+    if bytes.is_err() {
+        let message = bytes.unwrap_err().issues.iter().map(|i| i.detail.to_string()).collect::<Vec<String>>().join(", ");
+        return Err(TestFailure { message: format!("Code generation failed: {}", message), location: None });
+    }
+    let bytes = bytes.unwrap();
+
+    let vm = VirtualMachine::from_bytes(&bytes);
+    if vm.is_err() {
+        return Err(TestFailure { message: format!("Failed to load the test package: {}", vm.unwrap_err()), location: None });
+    }
+    let mut vm = vm.unwrap();
+    if let Err(e) = vm.start(vec![]) {
+        return Err(TestFailure { message: format!("Runtime error: {}", e), location: None });
+    }
+
+    // Intrinsics are the first functions, so their slots are their indexes
+    let intrinsic_entries: Vec<usize> = (0..INTRINSICS.len()).filter_map(|slot| {
+        return vm.layout.function_table.iter().find(|f| f.slot == slot).map(|f| f.entry_address);
+    }).collect();
+
+    let mut failure = None;
+    let result = run_inspected(&mut vm, max_steps, |vm| {
+        let frame = vm.current_frame();
+        let intrinsic = intrinsic_entries.iter().position(|e| *e == frame.entry_address);
+        if intrinsic.is_none() || vm.program_counter != frame.entry_address || vm.frames.len() < 2 {
+            return true;
+        }
+
+        let arguments: Vec<Value> = frame.locals.iter().flatten().cloned().collect();
+        failure = check_assertion(INTRINSICS[intrinsic.unwrap()].0, &arguments).map(|message| {
+            let location = arguments.last().and_then(|a| a.as_number()).and_then(|i| package.assertions.get(i as usize)).cloned();
+            return TestFailure { message, location };
+        });
+
+        return failure.is_none();
+    });
+
+    return match result {
+        Ok(_) => Ok(()),
+        Err(ExecutionStop::Interrupted) if failure.is_some() => Err(failure.unwrap()),
+        Err(e) => Err(TestFailure { message: e.to_string(), location: None }),
+    };
+}
+
+/// Declarations seen by availability checks take the parameters written in sources,
+/// while compiled ones take the index of the assertion as well
+fn intrinsic_functions(with_assertion: bool) -> Vec<Function> {
+    return INTRINSICS.iter().map(|(name, parameters)| {
+        let mut parameters: Vec<Parameter> = parameters.iter().map(|(type_name, identifier)| Parameter {
+            type_name: Identifier::single(type_name),
+            identifier: Identifier::single(identifier),
+        }).collect();
+        if with_assertion {
+            parameters.push(Parameter { type_name: Identifier::single("number"), identifier: Identifier::single(ASSERTION_PARAMETER) });
+        }
+
+        return Function {
+            declarator: FunctionDeclarator { identifier: Identifier::single(name), parameters, return_type: Identifier::empty() },
+            body: vec![],
+            file_path: String::new(),
+            linkage: FunctionLinkage::Internal,
+        };
+    }).collect();
+}
+
+/// Append the index of the assertion to every intrinsic call, and remember where the call is written
+fn number_assertions(actions: &mut Vec<Action>, file_path: &str, assertions: &mut Vec<FileMatch>) {
+    for action in actions {
+        match &mut action.content {
+            ActionContent::CallStatement(call) if INTRINSICS.iter().any(|i| call.function_name.to_string() == i.0) => {
+                call.arguments.push(SimpleExpression {
+                    postfix_expr: vec![ExprTerm {
+                        content: TermContent::Data(ExprDataTerm::Number(assertions.len().to_string())),
+                        position: Position::empty(),
+                    }],
+                    output_type: Identifier::single("number"),
+                });
+
+                let location = match (action.tokens.first(), action.tokens.last()) {
+                    (Some(first), Some(last)) => FileMatch {
+                        file_path: file_path.to_string(),
+                        start_pos: first.position.start,
+                        end_pos: last.position.start + last.position.length,
+                    },
+                    _ => FileMatch { file_path: file_path.to_string(), start_pos: 0, end_pos: 0 },
+                };
+                assertions.push(location);
+            }
+            ActionContent::IfBlock(x) => {
+                number_assertions(&mut x.if_block.body.actions, file_path, assertions);
+                for elif in &mut x.elif_collection {
+                    number_assertions(&mut elif.body.actions, file_path, assertions);
+                }
+                if let Some(else_block) = &mut x.else_action {
+                    number_assertions(&mut else_block.actions, file_path, assertions);
+                }
+            }
+            ActionContent::WhileStatement(x) => number_assertions(&mut x.body.actions, file_path, assertions),
+            ActionContent::LoopBlock(x) => number_assertions(&mut x.actions, file_path, assertions),
+            _ => {}
+        }
+    }
+}
+
+/// The failure message if the assertion doesn't hold, the last argument is the index of the assertion
+fn check_assertion(intrinsic: &str, arguments: &[Value]) -> Option<String> {
+    return match (intrinsic, arguments) {
+        ("assert", [condition, _]) if condition.as_number() == Some(0) => Some("Assertion failed".to_string()),
+        ("assert_eq" | "assert_str_eq", [expected, actual, _]) if expected != actual => Some(format!("Expected {}, found {}", expected, actual)),
+        _ => None,
+    };
+}
@@ -0,0 +1,151 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{channel, Receiver, RecvTimeoutError};
+use std::time::Duration;
+
+use chrono::Local;
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+
+use crate::managers::diagnostics::take_reported_counts;
+use crate::managers::logging::{log_error, log_info, log_trace, log_warn};
+use crate::managers::source_files::{find_linked_files, find_source_files, SOURCE_FILE_EXTENSION};
+
+/// Editors write a file in several steps, changes within this period are compiled together
+const DEBOUNCE_PERIOD: Duration = Duration::from_millis(200);
+
+/// Run `compile` every time `input_path` or a file linked by it changes, until the process is interrupted.
+/// `compile` returns whether it succeeded, failures are summarized and watching goes on.
+pub fn watch_sources<F: FnMut() -> bool>(input_path: &Path, compile: F) {
+    watch_sources_for(input_path, None, compile);
+}
+
+/// Same as `watch_sources`, but returns once `compile` has run `max_compilations` times
+pub(crate) fn watch_sources_for<F: FnMut() -> bool>(input_path: &Path, max_compilations: Option<usize>, mut compile: F) {
+    let (sender, receiver) = channel();
+    let watcher = notify::recommended_watcher(sender);
+    if watcher.is_err() {
+        log_error(format!("Couldn't watch files: {}", watcher.unwrap_err()).as_str());
+        return;
+    }
+    let mut watcher = watcher.unwrap();
+    let mut watched_directories = vec![];
+    let mut compilation_count = 0;
+
+    loop {
+        // Links may be changed by the last edit, so watched files are found again every time.
+        // Files are watched while compiling, changes saved meanwhile are queued and compiled next.
+        let watched = WatchedFiles::new(input_path);
+        watched_directories = watched.watch(&mut watcher, watched_directories);
+
+        let time_start = Local::now();
+        let succeeded = compile();
+        let (error_count, warning_count) = take_reported_counts();
+        let summary = format!(
+            "[{}] {} with {} errors and {} warnings in {}s",
+            time_start.format("%H:%M:%S"),
+            if succeeded { "Compiled" } else { "Failed" },
+            error_count,
+            warning_count,
+            (Local::now() - time_start).num_milliseconds() as f64 / 1000_f64
+        );
+        if succeeded {
+            log_info(summary.as_str());
+        } else {
+            log_warn(summary.as_str());
+        }
+
+        compilation_count += 1;
+        if max_compilations.is_some_and(|m| compilation_count >= m) {
+            return;
+        }
+
+        log_info(format!("Watching {} files for changes, press Ctrl-C to stop", watched.files.len()).as_str());
+        if !wait_for_change(&receiver, &watched) {
+            return;
+        }
+    }
+}
+
+/// Returns after a relevant change and a quiet period, or `false` if the watcher is gone
+fn wait_for_change(receiver: &Receiver<notify::Result<Event>>, watched: &WatchedFiles) -> bool {
+    loop {
+        match receiver.recv() {
+            Ok(Ok(event)) if watched.is_relevant(&event) => break,
+            Ok(Err(e)) => log_trace(format!("Watcher error: {}", e).as_str()),
+            Ok(_) => {}
+            Err(_) => return false,
+        }
+    }
+
+    loop {
+        match receiver.recv_timeout(DEBOUNCE_PERIOD) {
+            Ok(_) => {}
+            Err(RecvTimeoutError::Timeout) => return true,
+            Err(RecvTimeoutError::Disconnected) => return false,
+        }
+    }
+}
+
+struct WatchedFiles {
+    // Canonical paths of source files and files they link to
+    files: HashSet<PathBuf>,
+    // New source files in it are compiled as well, if the input is a directory
+    input_directory: Option<PathBuf>,
+}
+
+impl WatchedFiles {
+    fn new(input_path: &Path) -> WatchedFiles {
+        let mut files = HashSet::new();
+        if let Some((source_files, project_root)) = find_source_files(input_path) {
+            files.extend(find_linked_files(source_files, &project_root));
+        }
+
+        let input_directory = Some(input_path).filter(|p| p.is_dir()).map(|p| fs::canonicalize(p).unwrap_or_else(|_| p.to_path_buf()));
+        return WatchedFiles { files, input_directory };
+    }
+
+    /// Directories are watched instead of files, since editors often replace files rather than writing them.
+    /// Directories of `previous` which are not needed any more are unwatched, returns the watched directories.
+    fn watch(&self, watcher: &mut RecommendedWatcher, previous: Vec<PathBuf>) -> Vec<PathBuf> {
+        let mut wanted = vec![];
+        if let Some(directory) = &self.input_directory {
+            wanted.push((directory.clone(), RecursiveMode::Recursive));
+        }
+
+        let parents: HashSet<&Path> = self.files.iter().filter_map(|f| f.parent()).collect();
+        for parent in parents {
+            let covered = self.input_directory.as_ref().is_some_and(|d| parent.starts_with(d));
+            if !covered {
+                wanted.push((parent.to_path_buf(), RecursiveMode::NonRecursive));
+            }
+        }
+
+        for directory in previous.iter().filter(|d| !wanted.iter().any(|(w, _)| w == *d)) {
+            let _ = watcher.unwatch(directory);
+        }
+
+        let mut directories = vec![];
+        for (directory, mode) in wanted {
+            if previous.contains(&directory) || watcher.watch(&directory, mode).is_ok() {
+                directories.push(directory);
+            }
+        }
+
+        return directories;
+    }
+
+    fn is_relevant(&self, event: &Event) -> bool {
+        if matches!(event.kind, EventKind::Access(_)) {
+            return false;
+        }
+
+        return event.paths.iter().any(|path| {
+            let canonical_path = fs::canonicalize(path).unwrap_or_else(|_| path.clone());
+            let in_input_directory = self.input_directory.as_ref().is_some_and(|d| canonical_path.starts_with(d))
+                && path.extension().is_some_and(|e| e == SOURCE_FILE_EXTENSION);
+
+            return in_input_directory || self.files.contains(&canonical_path) || self.files.contains(path);
+        });
+    }
+}
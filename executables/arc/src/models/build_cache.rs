@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+use carbon_lang_compiler::shared::ast::decorated_token::DecoratedToken;
+use carbon_lang_compiler::shared::package_generation::data_descriptor::StringConstant;
+
+/// Lexing result of a source file, whose string pool starts from slot 0
+#[derive(Serialize, Deserialize, Clone)]
+pub struct CachedTokens {
+    pub tokens: Vec<DecoratedToken>,
+    pub string_pool: Vec<StringConstant>,
+}
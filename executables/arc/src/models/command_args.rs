@@ -1,5 +1,7 @@
 use structopt::StructOpt;
 
+use crate::models::message_format::MessageFormat;
+
 #[derive(StructOpt, Debug)]
 #[structopt(
     name = "Arc",
@@ -14,11 +16,145 @@ pub struct CommandArgs {
 
     #[structopt(short, long, parse(from_occurrences))]
     pub verbose: u8,
+
+    #[structopt(
+        long = "message-format",
+        global = true,
+        default_value = "human",
+        possible_values = &["human", "json"],
+        help = "How issues are reported, \"json\" prints one JSON object per line on STDOUT and moves logs to STDERR."
+    )]
+    pub message_format: MessageFormat,
 }
 
 #[derive(StructOpt, Debug)]
 pub enum SubCommands {
+    New(NewCommandArgs),
+    Build(BuildCommandArgs),
+    Add(AddCommandArgs),
+    Remove(RemoveCommandArgs),
+    Publish(PublishCommandArgs),
     Compile(CompileCommandArgs),
+    Check(CheckCommandArgs),
+    Run(RunCommandArgs),
+    Disasm(DisasmCommandArgs),
+    Asm(AsmCommandArgs),
+    Link(LinkCommandArgs),
+    Fmt(FmtCommandArgs),
+    Lsp(LspCommandArgs),
+    Repl(ReplCommandArgs),
+    Test(TestCommandArgs),
+    Debug(DebugCommandArgs),
+    Doc(DocCommandArgs),
+}
+
+#[derive(StructOpt, Debug)]
+#[structopt(
+    name = "new",
+    about = "Create a project with an \"Arc.toml\" manifest and an entry source file."
+)]
+pub struct NewCommandArgs {
+    #[structopt(
+        parse(from_os_str),
+        required = true,
+        help = "The directory to be created, it should not exist or be empty."
+    )]
+    pub project_path: std::path::PathBuf,
+
+    #[structopt(
+        long = "name",
+        help = "The package name, whose default value is the name of the directory."
+    )]
+    pub name: Option<String>,
+
+    #[structopt(
+        long = "lib",
+        help = "Create a library, which could be used as a dependency by other projects."
+    )]
+    pub library: bool,
+}
+
+#[derive(StructOpt, Debug)]
+#[structopt(
+    name = "build",
+    about = "Compile the project described by \"Arc.toml\" in the directory or its parents."
+)]
+pub struct BuildCommandArgs {
+    #[structopt(
+        short = "p",
+        long = "project",
+        parse(from_os_str),
+        default_value = ".",
+        help = "A directory inside the project."
+    )]
+    pub project_path: std::path::PathBuf,
+}
+
+#[derive(StructOpt, Debug)]
+#[structopt(
+    name = "add",
+    about = "Add a library from the registry to the dependencies in \"Arc.toml\" and update \"Arc.lock\"."
+)]
+pub struct AddCommandArgs {
+    #[structopt(
+        required = true,
+        help = "The name of the library."
+    )]
+    pub name: String,
+
+    #[structopt(
+        short = "r",
+        long = "requirement",
+        help = "A version requirement like \"^1.2\", whose default value is compatible with the latest version in the registry."
+    )]
+    pub requirement: Option<String>,
+
+    #[structopt(
+        short = "p",
+        long = "project",
+        parse(from_os_str),
+        default_value = ".",
+        help = "A directory inside the project."
+    )]
+    pub project_path: std::path::PathBuf,
+}
+
+#[derive(StructOpt, Debug)]
+#[structopt(
+    name = "remove",
+    about = "Remove a library from the dependencies in \"Arc.toml\" and update \"Arc.lock\"."
+)]
+pub struct RemoveCommandArgs {
+    #[structopt(
+        required = true,
+        help = "The name of the library."
+    )]
+    pub name: String,
+
+    #[structopt(
+        short = "p",
+        long = "project",
+        parse(from_os_str),
+        default_value = ".",
+        help = "A directory inside the project."
+    )]
+    pub project_path: std::path::PathBuf,
+}
+
+#[derive(StructOpt, Debug)]
+#[structopt(
+    name = "publish",
+    about = "Copy a library project into the registry so other projects could depend on it."
+)]
+pub struct PublishCommandArgs {
+    #[structopt(
+        short = "p",
+        long = "project",
+        parse(from_os_str),
+        default_value = ".",
+        help = "A directory inside the project."
+    )]
+    pub project_path: std::path::PathBuf,
 }
 
 #[derive(StructOpt, Debug)]
@@ -32,9 +168,8 @@ pub struct CompileCommandArgs {
         long = "input",
         parse(from_os_str),
         required = true,
-        help = "Input a series of file or a directory contains an TCPL project (only file is supported so far)."
+        help = "Input a file or a directory contains an TCPL project, linked files are compiled together."
     )]
-    // TODO: Support multiple files and directories
     pub input_path: std::path::PathBuf,
 
     #[structopt(
@@ -54,4 +189,313 @@ pub struct CompileCommandArgs {
         about = "The entry function name when the program starts up, whose default value is\"main\"."
     )]
     pub entry_function: String,
+
+    #[structopt(
+        long = "lib",
+        help = "Compile a library, whose exported functions could be linked into executables by \"link\"."
+    )]
+    pub library: bool,
+
+    #[structopt(
+        long = "object",
+        help = "Write a relocatable object instead of a package, objects are linked into a package by \"link\"."
+    )]
+    pub object: bool,
+
+    #[structopt(
+        long = "build-dir",
+        parse(from_os_str),
+        conflicts_with = "object",
+        help = "Keep tokens, syntax trees and objects of every source file in the directory, files unchanged since the last compilation are not compiled again."
+    )]
+    pub build_dir: Option<std::path::PathBuf>,
+
+    #[structopt(
+        short = "g",
+        long = "debug",
+        conflicts_with_all = &["object", "build_dir"],
+        help = "Place a debug section after function commands, which points them back to source files and names local variables."
+    )]
+    pub debug: bool,
+
+    #[structopt(
+        long = "watch",
+        help = "Keep running and compile again whenever the input or a linked file changes."
+    )]
+    pub watch: bool,
+}
+
+#[derive(StructOpt, Debug)]
+#[structopt(
+    name = "check",
+    about = "Check the target you specified (files or a directory) for errors without generating a package."
+)]
+pub struct CheckCommandArgs {
+    #[structopt(
+        short = "i",
+        long = "input",
+        parse(from_os_str),
+        required = true,
+        help = "Input a file or a directory contains an TCPL project, linked files are checked together."
+    )]
+    pub input_path: std::path::PathBuf,
+
+    #[structopt(
+        short = "e",
+        long = "entry",
+        required = false,
+        default_value = "main",
+        about = "The entry function name when the program starts up, whose default value is\"main\"."
+    )]
+    pub entry_function: String,
+}
+
+#[derive(StructOpt, Debug)]
+#[structopt(
+    name = "run",
+    about = "Execute a Carbon package on the reference virtual machine."
+)]
+pub struct RunCommandArgs {
+    #[structopt(
+        parse(from_os_str),
+        required = true,
+        help = "The Carbon package to be executed."
+    )]
+    pub package_path: std::path::PathBuf,
+
+    #[structopt(
+        help = "Arguments passed to the entry function, numbers are passed as numbers and others as strings."
+    )]
+    pub arguments: Vec<String>,
+}
+
+#[derive(StructOpt, Debug)]
+#[structopt(
+    name = "disasm",
+    about = "Print a readable listing of a Carbon package."
+)]
+pub struct DisasmCommandArgs {
+    #[structopt(
+        parse(from_os_str),
+        required = true,
+        help = "The Carbon package to be disassembled."
+    )]
+    pub package_path: std::path::PathBuf,
+
+    #[structopt(
+        short = "o",
+        long = "output",
+        parse(from_os_str),
+        help = "Write the listing to a file instead of the terminal."
+    )]
+    pub output_path: Option<std::path::PathBuf>,
+}
+
+#[derive(StructOpt, Debug)]
+#[structopt(
+    name = "asm",
+    about = "Assemble a Carbon assembly file to a Carbon package."
+)]
+pub struct AsmCommandArgs {
+    #[structopt(
+        parse(from_os_str),
+        required = true,
+        help = "The Carbon assembly file, the listing printed by \"disasm\" is accepted as well."
+    )]
+    pub input_path: std::path::PathBuf,
+
+    #[structopt(
+        short = "o",
+        long = "output",
+        parse(from_os_str),
+        required = true,
+        help = "The file name where the Carbon package is stored after assembling succeeded."
+    )]
+    pub output_path: std::path::PathBuf,
+}
+
+#[derive(StructOpt, Debug)]
+#[structopt(
+    name = "link",
+    about = "Link library packages into an executable package, or objects into a package."
+)]
+pub struct LinkCommandArgs {
+    #[structopt(
+        parse(from_os_str),
+        required = true,
+        help = "Library packages compiled with \"--lib\" which export the entry function, or objects compiled with \"--object\"."
+    )]
+    pub input_paths: Vec<std::path::PathBuf>,
+
+    #[structopt(
+        short = "o",
+        long = "output",
+        parse(from_os_str),
+        required = true,
+        help = "The file name where the linked package is stored."
+    )]
+    pub output_path: std::path::PathBuf,
+
+    #[structopt(
+        short = "e",
+        long = "entry",
+        default_value = "main",
+        help = "The exported function called when the program starts up, whose default value is \"main\"."
+    )]
+    pub entry_function: String,
+
+    #[structopt(
+        long = "lib",
+        help = "Link objects into a library instead of an executable, packages are always linked into executables."
+    )]
+    pub library: bool,
+}
+
+#[derive(StructOpt, Debug)]
+#[structopt(
+    name = "fmt",
+    about = "Format source files (files or directories) in the canonical style."
+)]
+pub struct FmtCommandArgs {
+    #[structopt(
+        parse(from_os_str),
+        required = true,
+        help = "Source files or directories containing source files."
+    )]
+    pub input_paths: Vec<std::path::PathBuf>,
+
+    #[structopt(
+        long = "check",
+        help = "Don't write any file, fail if any of them is not formatted."
+    )]
+    pub check: bool,
+}
+
+#[derive(StructOpt, Debug)]
+#[structopt(
+    name = "lsp",
+    about = "Start a language server for Carbon source files, editors talk to it through STDIN and STDOUT."
+)]
+pub struct LspCommandArgs {
+    #[structopt(
+        long = "stdio",
+        help = "Talk to the editor through STDIN and STDOUT, which is also used if no transport is given."
+    )]
+    pub stdio: bool,
+
+    #[structopt(
+        long = "socket",
+        help = "Rejected, only the STDIO transport is supported."
+    )]
+    pub socket: Option<String>,
+
+    #[structopt(
+        long = "pipe",
+        help = "Rejected, only the STDIO transport is supported."
+    )]
+    pub pipe: Option<String>,
+}
+
+#[derive(StructOpt, Debug)]
+#[structopt(
+    name = "repl",
+    about = "Read statements, expressions and functions line by line, and execute them on the reference virtual machine."
+)]
+pub struct ReplCommandArgs {
+    #[structopt(
+        long = "max-steps",
+        required = false,
+        default_value = "10000000",
+        help = "Stop an input after executing this number of commands, so endless loops don't end the session."
+    )]
+    pub max_steps: usize,
+}
+
+#[derive(StructOpt, Debug)]
+#[structopt(
+    name = "test",
+    about = "Run every function named \"test_*\" without parameters, assertions are checked by \"assert\", \"assert_eq\" and \"assert_str_eq\"."
+)]
+pub struct TestCommandArgs {
+    #[structopt(
+        short = "i",
+        long = "input",
+        parse(from_os_str),
+        required = true,
+        help = "Input a file or a directory contains an TCPL project, linked files are tested together."
+    )]
+    pub input_path: std::path::PathBuf,
+
+    #[structopt(
+        help = "Only run tests whose names contain this text."
+    )]
+    pub filter: Option<String>,
+
+    #[structopt(
+        long = "max-steps",
+        required = false,
+        default_value = "10000000",
+        help = "Fail a test after executing this number of commands, so endless loops don't block other tests."
+    )]
+    pub max_steps: usize,
+}
+
+#[derive(StructOpt, Debug)]
+#[structopt(
+    name = "debug",
+    about = "Execute a Carbon package step by step, with breakpoints and inspection of frames, local slots and domains."
+)]
+pub struct DebugCommandArgs {
+    #[structopt(
+        parse(from_os_str),
+        required = true,
+        help = "The Carbon package to be debugged, packages compiled with \"--debug\" are debugged by source lines."
+    )]
+    pub package_path: std::path::PathBuf,
+
+    #[structopt(
+        help = "Arguments passed to the entry function, numbers are passed as numbers and others as strings."
+    )]
+    pub arguments: Vec<String>,
+
+    #[structopt(
+        long = "max-steps",
+        required = false,
+        default_value = "10000000",
+        help = "Return to the prompt after executing this number of commands without stopping."
+    )]
+    pub max_steps: usize,
+}
+
+#[derive(StructOpt, Debug)]
+#[structopt(
+    name = "doc",
+    about = "Generate API documentation of functions, groups and linked files, described by the comments above them."
+)]
+pub struct DocCommandArgs {
+    #[structopt(
+        short = "i",
+        long = "input",
+        parse(from_os_str),
+        required = true,
+        help = "Input a file or a directory contains an TCPL project, linked files are documented as well."
+    )]
+    pub input_path: std::path::PathBuf,
+
+    #[structopt(
+        short = "o",
+        long = "output",
+        parse(from_os_str),
+        default_value = "docs",
+        help = "The directory where pages are written, an index page links every file."
+    )]
+    pub output_dir: std::path::PathBuf,
+
+    #[structopt(
+        long = "format",
+        default_value = "markdown",
+        possible_values = &["markdown", "html"],
+        help = "The format of pages."
+    )]
+    pub format: String,
 }
@@ -0,0 +1,57 @@
+use std::fmt::{Display, Formatter};
+
+use carbon_lang_vm::models::value::Value;
+use carbon_lang_vm::models::virtual_machine::VirtualMachine;
+
+/// A place the debugger stops at before executing the command there
+#[derive(Debug, Clone)]
+pub struct Breakpoint {
+    // What the user typed, like `main` or `main.cbs:3`
+    pub target: String,
+    // Heads of commands, a source line could be compiled into several parts
+    pub addresses: Vec<usize>,
+}
+
+/// How far `continue` and stepping commands go
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StepMode {
+    // Until a breakpoint or the end
+    Continue,
+    // Until the next source line, entering called functions
+    Into,
+    // Until the next source line of the current or a calling function
+    Over,
+    // Until the current function returns
+    Out,
+    // A single command
+    Instruction,
+}
+
+/// Why the debugged machine stopped
+#[derive(Debug, Clone)]
+pub enum DebugStop {
+    // Index of the breakpoint in the session
+    Breakpoint(usize),
+    Stepped,
+    Finished(Option<Value>),
+    // The message of the runtime error or the step limit
+    Failed(String),
+}
+
+/// A package being debugged, the machine is kept between commands of the debugger
+#[derive(Debug, Clone)]
+pub struct DebugSession {
+    pub vm: VirtualMachine,
+    // Passed to the entry function every time it is started
+    pub arguments: Vec<Value>,
+    pub breakpoints: Vec<Breakpoint>,
+    // Commands executed by a single debugger command are limited, so endless loops return to the prompt
+    pub max_steps: usize,
+}
+
+impl Display for Breakpoint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let addresses: Vec<String> = self.addresses.iter().map(|a| format!("0x{:08X}", a)).collect();
+        write!(f, "{} at {}", self.target, addresses.join(", "))
+    }
+}
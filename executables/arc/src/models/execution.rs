@@ -0,0 +1,23 @@
+use std::fmt::{Display, Formatter};
+
+use carbon_lang_vm::models::runtime_issue::RuntimeIssue;
+
+/// Why a function run by Arc didn't return
+#[derive(Debug, Clone)]
+pub enum ExecutionStop {
+    Issue(RuntimeIssue),
+    // Commands executed reached the limit
+    StepLimit(usize),
+    // The inspector asked to stop
+    Interrupted,
+}
+
+impl Display for ExecutionStop {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExecutionStop::Issue(issue) => write!(f, "Runtime error: {}", issue),
+            ExecutionStop::StepLimit(steps) => write!(f, "Execution stopped after {} steps", steps),
+            ExecutionStop::Interrupted => write!(f, "Execution interrupted"),
+        }
+    }
+}
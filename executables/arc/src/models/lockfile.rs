@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+
+/// Content of `Arc.lock`, packages are sorted by name so the file is stable
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+#[serde(deny_unknown_fields)]
+pub struct Lockfile {
+    #[serde(default)]
+    pub package: Vec<LockedPackage>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct LockedPackage {
+    pub name: String,
+    pub version: String,
+    // SHA-256 of every file in the registry directory of the package
+    pub checksum: String,
+    // Names of the packages it depends on, there is only one version of each package
+    #[serde(default)]
+    pub dependencies: Vec<String>,
+}
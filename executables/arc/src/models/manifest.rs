@@ -0,0 +1,99 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Content of `Arc.toml`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct Manifest {
+    pub package: PackageSection,
+    #[serde(default)]
+    pub build: BuildSection,
+    #[serde(default)]
+    pub metadata: MetadataSection,
+    // Package names to version requirements, like `^1.2.0`
+    #[serde(default)]
+    pub dependencies: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub registry: Option<RegistrySection>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct PackageSection {
+    pub name: String,
+    pub version: String,
+    #[serde(default)]
+    pub author: String,
+    #[serde(default = "default_entry")]
+    pub entry: String,
+    #[serde(default, rename = "type")]
+    pub package_type: PackageType,
+}
+
+/// Only libraries could be used as dependencies, they don't need an entry function
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum PackageType {
+    #[default]
+    Executable,
+    Library,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct BuildSection {
+    // Directories are relative to the manifest, files in them are compiled recursively
+    #[serde(default = "default_source_dirs")]
+    pub source_dirs: Vec<String>,
+    // Where the package is written, relative to the manifest
+    #[serde(default = "default_output_dir")]
+    pub output_dir: String,
+}
+
+/// Alignments of `PackageMetadata`, in bytes
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct MetadataSection {
+    pub data_alignment: Option<u8>,
+    pub domain_layer_count_alignment: Option<u8>,
+    pub data_slot_alignment: Option<u8>,
+    pub address_alignment: Option<u8>,
+}
+
+/// Where dependencies are looked up, overrides `ARC_REGISTRY`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct RegistrySection {
+    // Relative to the manifest
+    pub path: String,
+}
+
+fn default_entry() -> String {
+    return "main".to_string();
+}
+
+fn default_source_dirs() -> Vec<String> {
+    return vec!["src".to_string()];
+}
+
+fn default_output_dir() -> String {
+    return "build".to_string();
+}
+
+impl Default for BuildSection {
+    fn default() -> BuildSection {
+        return BuildSection { source_dirs: default_source_dirs(), output_dir: default_output_dir() };
+    }
+}
+
+impl Default for MetadataSection {
+    fn default() -> MetadataSection {
+        return MetadataSection {
+            data_alignment: None,
+            domain_layer_count_alignment: None,
+            data_slot_alignment: None,
+            address_alignment: None,
+        };
+    }
+}
@@ -0,0 +1,20 @@
+use std::str::FromStr;
+
+/// How issues are reported, `Json` prints one JSON object per line on STDOUT so tools could consume them
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MessageFormat {
+    Human,
+    Json,
+}
+
+impl FromStr for MessageFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        return match s {
+            "human" => Ok(MessageFormat::Human),
+            "json" => Ok(MessageFormat::Json),
+            _ => Err(format!("Unknown message format \"{}\", expect \"human\" or \"json\"", s)),
+        };
+    }
+}
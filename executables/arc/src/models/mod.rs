@@ -1 +1,10 @@
+pub mod build_cache;
 pub mod command_args;
+pub mod debug_session;
+pub mod execution;
+pub mod lockfile;
+pub mod manifest;
+pub mod message_format;
+pub mod repl_session;
+pub mod source_unit;
+pub mod test_case;
@@ -0,0 +1,39 @@
+use carbon_lang_compiler::shared::ast::action::VariableDefinition;
+use carbon_lang_compiler::shared::ast::blocks::function::Function;
+use carbon_lang_compiler::shared::package_generation::data_descriptor::StringConstant;
+use carbon_lang_compiler::shared::package_generation::package_descriptor::PackageMetadata;
+use carbon_lang_compiler::shared::utils::identifier::Identifier;
+use carbon_lang_vm::models::value::Value;
+
+/// A variable declared in the REPL, which is kept for later inputs
+#[derive(Debug, Clone)]
+pub struct ReplVariable {
+    pub definition: VariableDefinition,
+    // `None` if the variable is not assigned yet
+    pub value: Option<Value>,
+}
+
+/// Everything declared in the REPL so far, inputs are compiled together with it
+#[derive(Debug, Clone)]
+pub struct ReplSession {
+    pub functions: Vec<Function>,
+    pub variables: Vec<ReplVariable>,
+    // Shared by every input, so string constants of earlier functions keep their slots
+    pub string_pool: Vec<StringConstant>,
+    pub metadata: PackageMetadata,
+    // Inputs running longer than this are stopped, since the REPL can't be interrupted otherwise
+    pub max_steps: usize,
+    // Inputs are numbered in issues, since they are not stored in files
+    pub input_count: usize,
+}
+
+/// What an input of the REPL results in
+#[derive(Debug, Clone)]
+pub enum ReplOutcome {
+    // Statements are executed, expressions are evaluated to a value of their type
+    Executed(Option<(Value, Identifier)>),
+    // Names of functions declared or redeclared
+    Declared(Vec<String>),
+    // Issues are already reported
+    Failed,
+}
@@ -0,0 +1,14 @@
+use std::path::PathBuf;
+
+use carbon_lang_compiler::shared::ast::package::ParserPackageStructure;
+use carbon_lang_compiler::shared::package_generation::data_descriptor::StringConstant;
+
+/// A source file parsed on its own, so it could be compiled into an object without other files
+#[derive(Debug, Clone)]
+pub struct SourceUnit {
+    pub path: PathBuf,
+    pub content: String,
+    pub tree: ParserPackageStructure,
+    // Slots of string constants in this file start from 0
+    pub string_pool: Vec<StringConstant>,
+}
@@ -0,0 +1,21 @@
+use carbon_lang_compiler::shared::ast::package::ParserPackageStructure;
+use carbon_lang_compiler::shared::error::general_issue::FileMatch;
+use carbon_lang_compiler::shared::package_generation::data_descriptor::StringConstant;
+
+/// Sources with test functions, ready to be compiled once for every test
+#[derive(Debug, Clone)]
+pub struct TestPackage {
+    // Intrinsics come first, and calls to them carry the index of the assertion
+    pub tree: ParserPackageStructure,
+    pub string_pool: Vec<StringConstant>,
+    // Where each assertion is written, indexed by the extra argument of intrinsic calls
+    pub assertions: Vec<FileMatch>,
+}
+
+/// Why a test didn't pass
+#[derive(Debug, Clone)]
+pub struct TestFailure {
+    pub message: String,
+    // Only assertions know where they are written
+    pub location: Option<FileMatch>,
+}
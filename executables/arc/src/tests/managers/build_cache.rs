@@ -0,0 +1,123 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use carbon_lang_compiler::shared::package_generation::implementations::package_descriptor::LIBRARY_PACKAGE_TYPE;
+use carbon_lang_compiler::shared::package_generation::package_descriptor::PackageMetadata;
+
+use crate::managers::build_cache::{entry_path, tokens_key, TOKENS_ENTRY_KIND};
+use crate::managers::compilation::{build_sources_with_objects, CACHE_DIRECTORY_NAME, OBJECT_DIRECTORY_NAME};
+use crate::tests::temp_directory;
+
+const MAIN_SOURCE: &str = "decl func main()[number] {\n    return twice(2);\n}\n";
+const TWICE_SOURCE: &str = "decl func twice(number x)[number] {\n    return x * 2;\n}\n";
+
+fn build(project_root: &Path) -> Option<Vec<u8>> {
+    return build_with(project_root, "main", &PackageMetadata::default(), false);
+}
+
+fn build_with(project_root: &Path, entry_function: &str, metadata: &PackageMetadata, export_entry: bool) -> Option<Vec<u8>> {
+    let source_files = vec![project_root.join("main.cbs"), project_root.join("twice.cbs")];
+    return build_sources_with_objects(source_files, project_root, entry_function.to_string(), metadata, &project_root.join("build"), export_entry);
+}
+
+/// Objects written before are made to look old, the returned time tells them from rewritten ones
+fn age_objects(project_root: &Path) -> SystemTime {
+    let old_time = SystemTime::now() - Duration::from_secs(3600);
+    for (path, _) in objects(project_root) {
+        fs::File::options().write(true).open(path).unwrap().set_modified(old_time).unwrap();
+    }
+
+    return old_time;
+}
+
+fn rebuilt_count(project_root: &Path, old_time: SystemTime) -> usize {
+    return objects(project_root).into_iter().filter(|(_, time)| *time > old_time).count();
+}
+
+/// Objects of the build and their modification time
+fn objects(project_root: &Path) -> Vec<(PathBuf, SystemTime)> {
+    let mut result: Vec<(PathBuf, SystemTime)> = fs::read_dir(project_root.join("build").join(OBJECT_DIRECTORY_NAME))
+        .unwrap()
+        .flatten()
+        .map(|e| (e.path(), e.metadata().unwrap().modified().unwrap()))
+        .collect();
+    result.sort();
+
+    return result;
+}
+
+#[test]
+fn changed_file_rebuilt() {
+    let project_root = temp_directory("build-cache-changed");
+    fs::write(project_root.join("main.cbs"), MAIN_SOURCE).unwrap();
+    fs::write(project_root.join("twice.cbs"), TWICE_SOURCE).unwrap();
+    assert!(build(&project_root).is_some());
+
+    let old_time = age_objects(&project_root);
+
+    let changed_source = "decl func twice(number x)[number] {\n    return x + x;\n}\n";
+    fs::write(project_root.join("twice.cbs"), changed_source).unwrap();
+    assert!(build(&project_root).is_some());
+
+    let rebuilt: Vec<String> = objects(&project_root)
+        .into_iter()
+        .filter(|(_, time)| *time > old_time)
+        .map(|(path, _)| path.file_name().unwrap().to_string_lossy().to_string())
+        .collect();
+    assert_eq!(rebuilt.len(), 1);
+    assert!(rebuilt[0].starts_with("twice-"));
+
+    // Tokens of the old content are not used any more
+    let cache_dir = project_root.join("build").join(CACHE_DIRECTORY_NAME);
+    assert!(entry_path(&cache_dir, TOKENS_ENTRY_KIND, tokens_key(changed_source).as_str()).is_file());
+    assert!(!entry_path(&cache_dir, TOKENS_ENTRY_KIND, tokens_key(TWICE_SOURCE).as_str()).is_file());
+    assert!(entry_path(&cache_dir, TOKENS_ENTRY_KIND, tokens_key(MAIN_SOURCE).as_str()).is_file());
+}
+
+#[test]
+fn signature_change_checked() {
+    let project_root = temp_directory("build-cache-signature");
+    fs::write(project_root.join("main.cbs"), MAIN_SOURCE).unwrap();
+    fs::write(project_root.join("twice.cbs"), TWICE_SOURCE).unwrap();
+    assert!(build(&project_root).is_some());
+
+    // `main.cbs` is unchanged and cached, but its call doesn't match the new parameters
+    fs::write(project_root.join("twice.cbs"), "decl func twice(number x, number y)[number] {\n    return x * y;\n}\n").unwrap();
+    assert!(build(&project_root).is_none());
+}
+
+#[test]
+fn entry_change_rebuilt() {
+    let project_root = temp_directory("build-cache-entry");
+    let main_source = format!("{}decl func start()[number] {{\n    return twice(3);\n}}\n", MAIN_SOURCE);
+    fs::write(project_root.join("main.cbs"), main_source).unwrap();
+    fs::write(project_root.join("twice.cbs"), TWICE_SOURCE).unwrap();
+    let main_package = build_with(&project_root, "main", &PackageMetadata::default(), false);
+    assert!(main_package.is_some());
+
+    // Sources are unchanged, but objects built for another entry can't be reused
+    let old_time = age_objects(&project_root);
+    let start_package = build_with(&project_root, "start", &PackageMetadata::default(), false);
+    assert!(start_package.is_some());
+    assert_eq!(rebuilt_count(&project_root, old_time), 2);
+    assert_ne!(main_package, start_package);
+
+    let old_time = age_objects(&project_root);
+    assert!(build_with(&project_root, "start", &PackageMetadata::default(), false).is_some());
+    assert_eq!(rebuilt_count(&project_root, old_time), 0);
+}
+
+#[test]
+fn export_entry_change_rebuilt() {
+    let project_root = temp_directory("build-cache-export");
+    fs::write(project_root.join("main.cbs"), MAIN_SOURCE).unwrap();
+    fs::write(project_root.join("twice.cbs"), TWICE_SOURCE).unwrap();
+    let metadata = PackageMetadata { package_type: LIBRARY_PACKAGE_TYPE, ..PackageMetadata::default() };
+    assert!(build_with(&project_root, "main", &metadata, false).is_some());
+
+    // The exported entry changes the linkage in the objects
+    let old_time = age_objects(&project_root);
+    assert!(build_with(&project_root, "main", &metadata, true).is_some());
+    assert_eq!(rebuilt_count(&project_root, old_time), 2);
+}
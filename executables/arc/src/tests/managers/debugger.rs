@@ -0,0 +1,67 @@
+use std::fs;
+
+use carbon_lang_compiler::shared::package_generation::package_descriptor::PackageMetadata;
+use carbon_lang_vm::models::value::Value;
+use carbon_lang_vm::models::virtual_machine::VirtualMachine;
+
+use crate::managers::compilation::build_sources;
+use crate::managers::debugger::{resolve_breakpoint, resume, start};
+use crate::models::debug_session::{Breakpoint, DebugSession, DebugStop, StepMode};
+use crate::tests::temp_directory;
+
+const SOURCE: &str = "decl func main()[number] {
+    decl var number y;
+    y = twice(3) + 1;
+    return y;
+}
+
+decl func twice(number x)[number] {
+    return x * 2;
+}
+";
+
+/// A session stopped at the entry of `twice`
+fn session_in_callee(name: &str) -> DebugSession {
+    let project_root = temp_directory(name);
+    fs::write(project_root.join("main.cbs"), SOURCE).unwrap();
+    let package = build_sources(vec![project_root.join("main.cbs")], &project_root, "main".to_string(), &PackageMetadata::default(), true).unwrap();
+
+    let mut session = DebugSession::new(VirtualMachine::from_bytes(&package).unwrap(), vec![], 10000);
+    let addresses = resolve_breakpoint(&session.vm.layout, "twice").unwrap();
+    session.breakpoints.push(Breakpoint { target: "twice".to_string(), addresses });
+    assert!(matches!(start(&mut session), DebugStop::Breakpoint(0)));
+    assert_eq!(session.vm.frames.len(), 2);
+
+    return session;
+}
+
+fn current_line(session: &DebugSession) -> usize {
+    return session.vm.layout.debug_info.as_ref().unwrap().line_at(session.vm.program_counter).unwrap().line;
+}
+
+#[test]
+fn step_out() {
+    let mut session = session_in_callee("debugger-step-out");
+
+    // The rest of the calling line is executed by the next step
+    assert!(matches!(resume(&mut session, StepMode::Out), DebugStop::Stepped));
+    assert_eq!(session.vm.frames.len(), 1);
+    assert_eq!(current_line(&session), 3);
+
+    assert!(matches!(resume(&mut session, StepMode::Over), DebugStop::Stepped));
+    assert_eq!(current_line(&session), 4);
+}
+
+#[test]
+fn step_over_return() {
+    for mode in [StepMode::Over, StepMode::Into] {
+        let mut session = session_in_callee("debugger-step-return");
+        while session.vm.frames.len() > 1 {
+            assert!(matches!(resume(&mut session, mode), DebugStop::Stepped));
+        }
+
+        // Stepping past the end of the callee stops in the calling line, not the next one
+        assert_eq!(current_line(&session), 3);
+        assert!(matches!(resume(&mut session, StepMode::Continue), DebugStop::Finished(Some(Value::Number(7)))));
+    }
+}
@@ -0,0 +1,187 @@
+use std::fs;
+use std::path::Path;
+
+use carbon_lang_compiler::shared::package_generation::package_descriptor::PackageMetadata;
+use carbon_lang_vm::models::value::Value;
+use carbon_lang_vm::models::virtual_machine::VirtualMachine;
+
+use crate::managers::compilation::build_linked_package;
+use crate::managers::dependencies::{build_dependencies, fetch_dependencies, read_lockfile, resolve_dependencies, write_lockfile};
+use crate::managers::manifest::MANIFEST_FILE_NAME;
+use crate::managers::source_files::collect_source_files;
+use crate::models::lockfile::Lockfile;
+use crate::models::manifest::Manifest;
+use crate::tests::temp_directory;
+
+fn write_project(root: &Path, manifest: &str, source: &str) {
+    fs::create_dir_all(root.join("src")).unwrap();
+    fs::write(root.join(MANIFEST_FILE_NAME), manifest).unwrap();
+    fs::write(root.join("src").join("main.cbs"), source).unwrap();
+}
+
+/// A library `<registry>/<name>/<version>` requiring `dependencies`
+fn add_registry_package(registry: &Path, name: &str, version: &str, dependencies: &[(&str, &str)]) {
+    let requirements: String = dependencies.iter().map(|(n, r)| format!("{} = \"{}\"\n", n, r)).collect();
+    let manifest = format!("[package]\nname = \"{}\"\nversion = \"{}\"\ntype = \"library\"\n\n[dependencies]\n{}", name, version, requirements);
+    write_project(&registry.join(name).join(version), manifest.as_str(), "export decl func f()[number] { return 1; }");
+}
+
+fn project_manifest(dependencies: &[(&str, &str)]) -> Manifest {
+    let requirements: String = dependencies.iter().map(|(n, r)| format!("{} = \"{}\"\n", n, r)).collect();
+    return toml::from_str(format!("[package]\nname = \"app\"\nversion = \"0.1.0\"\n\n[dependencies]\n{}", requirements).as_str()).unwrap();
+}
+
+fn locked_versions(lockfile: &Lockfile) -> Vec<(String, String)> {
+    return lockfile.package.iter().map(|p| (p.name.clone(), p.version.clone())).collect();
+}
+
+#[test]
+fn highest_matching_version() {
+    let registry = temp_directory("resolve-highest");
+    for version in ["1.0.0", "1.2.0", "2.0.0"] {
+        add_registry_package(&registry, "calc", version, &[]);
+    }
+
+    let lockfile = resolve_dependencies(&project_manifest(&[("calc", "^1.0")]), &registry, None).unwrap();
+    assert_eq!(locked_versions(&lockfile), vec![("calc".to_string(), "1.2.0".to_string())]);
+}
+
+#[test]
+fn locked_version_kept() {
+    let registry = temp_directory("resolve-locked");
+    for version in ["1.0.0", "1.2.0"] {
+        add_registry_package(&registry, "calc", version, &[]);
+    }
+    let manifest = project_manifest(&[("calc", "^1.0")]);
+
+    let mut locked = resolve_dependencies(&manifest, &registry, None).unwrap();
+    locked.package[0].version = "1.0.0".to_string();
+    let lockfile = resolve_dependencies(&manifest, &registry, Some(&locked)).unwrap();
+    assert_eq!(locked_versions(&lockfile), vec![("calc".to_string(), "1.0.0".to_string())]);
+
+    // A locked version which no longer satisfies the requirement is replaced
+    let lockfile = resolve_dependencies(&project_manifest(&[("calc", ">=1.1")]), &registry, Some(&locked)).unwrap();
+    assert_eq!(locked_versions(&lockfile), vec![("calc".to_string(), "1.2.0".to_string())]);
+}
+
+#[test]
+fn transitive_requirements() {
+    let registry = temp_directory("resolve-transitive");
+    for version in ["1.0.0", "1.1.0", "1.3.0"] {
+        add_registry_package(&registry, "calc", version, &[]);
+    }
+    add_registry_package(&registry, "stats", "0.2.0", &[("calc", "~1.1")]);
+
+    let lockfile = resolve_dependencies(&project_manifest(&[("calc", "^1.0"), ("stats", "0.2")]), &registry, None).unwrap();
+    assert_eq!(locked_versions(&lockfile), vec![
+        ("calc".to_string(), "1.1.0".to_string()),
+        ("stats".to_string(), "0.2.0".to_string()),
+    ]);
+    assert_eq!(lockfile.package[1].dependencies, vec!["calc".to_string()]);
+}
+
+#[test]
+fn conflicting_requirements() {
+    let registry = temp_directory("resolve-conflict");
+    for version in ["1.0.0", "2.0.0"] {
+        add_registry_package(&registry, "calc", version, &[]);
+    }
+    add_registry_package(&registry, "stats", "0.2.0", &[("calc", "^2")]);
+
+    assert!(resolve_dependencies(&project_manifest(&[("calc", "^1"), ("stats", "0.2")]), &registry, None).is_none());
+    assert!(resolve_dependencies(&project_manifest(&[("missing", "^1")]), &registry, None).is_none());
+}
+
+#[test]
+fn lockfile_round_trip() {
+    let directory = temp_directory("lockfile-round-trip");
+    let registry = directory.join("registry");
+    add_registry_package(&registry, "calc", "1.0.0", &[]);
+    add_registry_package(&registry, "stats", "0.2.0", &[("calc", "^1")]);
+    let lockfile = resolve_dependencies(&project_manifest(&[("stats", "0.2")]), &registry, None).unwrap();
+
+    assert!(read_lockfile(&directory).is_none());
+    assert!(write_lockfile(&directory, &lockfile));
+    assert_eq!(read_lockfile(&directory), Some(lockfile));
+}
+
+#[test]
+fn checksum_mismatch() {
+    let directory = temp_directory("fetch-checksum");
+    let registry = directory.join("registry");
+    add_registry_package(&registry, "calc", "1.0.0", &[]);
+    let lockfile = resolve_dependencies(&project_manifest(&[("calc", "^1")]), &registry, None).unwrap();
+
+    let fetched = fetch_dependencies(&lockfile, &registry, &directory.join("cache")).unwrap();
+    assert_eq!(fetched, vec![directory.join("cache").join("calc-1.0.0")]);
+    assert!(fetched[0].join("src").join("main.cbs").is_file());
+
+    // The registry copy changes after it is locked, so it couldn't be fetched again
+    fs::write(registry.join("calc").join("1.0.0").join("src").join("main.cbs"), "export decl func f()[number] { return 2; }").unwrap();
+    assert!(fetch_dependencies(&lockfile, &registry, &directory.join("other-cache")).is_none());
+}
+
+#[test]
+fn linked_dependency() {
+    let directory = temp_directory("dependencies-linked");
+    let library_root = directory.join("calc-1.0.0");
+    let project_root = directory.join("app");
+
+    // Both of them define a private `helper`, which only conflicts if they are compiled together
+    write_project(&library_root, "[package]\nname = \"calc\"\nversion = \"1.0.0\"\ntype = \"library\"\n", r#"
+        export decl func add(number a, number b)[number] {
+            return helper(a) + b;
+        }
+
+        decl func helper(number x)[number] {
+            return x * 10;
+        }
+    "#);
+    write_project(&project_root, "[package]\nname = \"app\"\nversion = \"0.1.0\"\n\n[dependencies]\ncalc = \"^1\"\n", r#"
+        decl func add(number a, number b)[number];
+
+        decl func main(number x)[number] {
+            return add(helper(x), 1);
+        }
+
+        decl func helper(number x)[number] {
+            return x + 1;
+        }
+    "#);
+
+    let metadata = PackageMetadata::default();
+    let build_dir = project_root.join("build");
+    let dependencies = build_dependencies(&[library_root], &metadata, &build_dir.join("dependencies")).unwrap();
+    let package = build_linked_package(collect_source_files(&project_root.join("src")), &project_root, "main".to_string(), &metadata, &build_dir, dependencies);
+
+    let mut vm = VirtualMachine::from_bytes(&package.unwrap()).unwrap();
+    assert_eq!(vm.run(vec![Value::Number(2)]).unwrap(), Some(Value::Number(31)));
+}
+
+#[test]
+fn private_dependency_function() {
+    let directory = temp_directory("dependencies-private");
+    let library_root = directory.join("calc-1.0.0");
+    let project_root = directory.join("app");
+
+    write_project(&library_root, "[package]\nname = \"calc\"\nversion = \"1.0.0\"\ntype = \"library\"\n", r#"
+        decl func helper(number x)[number] {
+            return x * 10;
+        }
+    "#);
+    write_project(&project_root, "[package]\nname = \"app\"\nversion = \"0.1.0\"\n", r#"
+        decl func helper(number x)[number];
+
+        decl func main(number x)[number] {
+            return helper(x);
+        }
+    "#);
+
+    let metadata = PackageMetadata::default();
+    let build_dir = project_root.join("build");
+    let dependencies = build_dependencies(&[library_root], &metadata, &build_dir.join("dependencies")).unwrap();
+    let package = build_linked_package(collect_source_files(&project_root.join("src")), &project_root, "main".to_string(), &metadata, &build_dir, dependencies);
+
+    // `helper` is not exported by the library
+    assert!(package.is_none());
+}
@@ -0,0 +1,101 @@
+use std::fs;
+use std::path::PathBuf;
+
+use crate::managers::manifest::{load_manifest, MANIFEST_FILE_NAME};
+use crate::models::manifest::PackageType;
+use crate::tests::{temp_directory, TempDirectory};
+
+/// The directory is returned as well, since it is removed once dropped
+fn write_manifest(name: &str, content: &str) -> (TempDirectory, PathBuf) {
+    let directory = temp_directory(name);
+    let manifest_path = directory.join(MANIFEST_FILE_NAME);
+    fs::write(&manifest_path, content).unwrap();
+
+    return (directory, manifest_path);
+}
+
+#[test]
+fn valid_manifest() {
+    let (_directory, manifest_path) = write_manifest("manifest-valid", r#"
+        [package]
+        name = "calc"
+        version = "1.2.0"
+
+        [metadata]
+        data_alignment = 4
+    "#);
+    let manifest = load_manifest(&manifest_path).unwrap();
+
+    assert_eq!(manifest.package.name, "calc");
+    assert_eq!(manifest.package.entry, "main");
+    assert_eq!(manifest.build.source_dirs, vec!["src".to_string()]);
+    assert_eq!(manifest.package_metadata().data_alignment, 4);
+}
+
+#[test]
+fn missing_name() {
+    let (_directory, manifest_path) = write_manifest("manifest-missing-name", "[package]\nversion = \"1.0.0\"\n");
+
+    assert!(load_manifest(&manifest_path).is_none());
+}
+
+#[test]
+fn invalid_name() {
+    let (_directory, manifest_path) = write_manifest("manifest-invalid-name", "[package]\nname = \"my calc\"\nversion = \"1.0.0\"\n");
+
+    assert!(load_manifest(&manifest_path).is_none());
+}
+
+#[test]
+fn zero_alignment() {
+    let (_directory, manifest_path) = write_manifest("manifest-zero-alignment", "[package]\nname = \"calc\"\nversion = \"1.0.0\"\n\n[metadata]\naddress_alignment = 0\n");
+
+    assert!(load_manifest(&manifest_path).is_none());
+}
+
+#[test]
+fn dependencies() {
+    let (_directory, manifest_path) = write_manifest("manifest-dependencies", "[package]\nname = \"calc\"\nversion = \"1.2.0\"\n\n[dependencies]\nmath = \"^0.3\"\n");
+    let manifest = load_manifest(&manifest_path).unwrap();
+
+    assert_eq!(manifest.dependencies.get("math").map(|x| x.as_str()), Some("^0.3"));
+}
+
+#[test]
+fn invalid_version() {
+    let (_directory, manifest_path) = write_manifest("manifest-invalid-version", "[package]\nname = \"calc\"\nversion = \"1.0\"\n");
+
+    assert!(load_manifest(&manifest_path).is_none());
+}
+
+#[test]
+fn invalid_dependencies() {
+    let (_directory, manifest_path) = write_manifest("manifest-invalid-dependencies", r#"
+        [package]
+        name = "calc"
+        version = "1.0.0"
+
+        [dependencies]
+        calc = "1.0.0"
+        "bad name" = "^1"
+        math = "one"
+    "#);
+
+    assert!(load_manifest(&manifest_path).is_none());
+}
+
+#[test]
+fn package_type() {
+    let (_directory, manifest_path) = write_manifest("manifest-package-type", "[package]\nname = \"calc\"\nversion = \"1.0.0\"\n");
+    assert_eq!(load_manifest(&manifest_path).unwrap().package.package_type, PackageType::Executable);
+
+    let (_directory, manifest_path) = write_manifest("manifest-library", "[package]\nname = \"calc\"\nversion = \"1.0.0\"\ntype = \"library\"\n");
+    assert_eq!(load_manifest(&manifest_path).unwrap().package.package_type, PackageType::Library);
+}
+
+#[test]
+fn unknown_package_type() {
+    let (_directory, manifest_path) = write_manifest("manifest-unknown-type", "[package]\nname = \"calc\"\nversion = \"1.0.0\"\ntype = \"plugin\"\n");
+
+    assert!(load_manifest(&manifest_path).is_none());
+}
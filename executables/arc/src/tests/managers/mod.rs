@@ -0,0 +1,7 @@
+mod build_cache;
+mod debugger;
+mod dependencies;
+mod manifest;
+mod repl;
+mod test_runner;
+mod watcher;
@@ -0,0 +1,58 @@
+use carbon_lang_compiler::shared::utils::identifier::Identifier;
+use carbon_lang_vm::models::value::Value;
+
+use crate::managers::repl::{evaluate_input, format_value, is_input_complete};
+use crate::models::repl_session::{ReplOutcome, ReplSession};
+
+const MAX_STEPS: usize = 10000;
+
+/// The printed value of an expression, `None` if the input isn't evaluated to a value
+fn echo(session: &mut ReplSession, input: &str) -> Option<String> {
+    return match evaluate_input(session, input) {
+        ReplOutcome::Executed(Some((value, type_name))) => Some(format_value(&value, &type_name)),
+        _ => None,
+    };
+}
+
+#[test]
+fn multi_line_input() {
+    assert!(is_input_complete("1 + 2\n"));
+    assert!(!is_input_complete("decl func twice(number x)[number] {\n"));
+    assert!(!is_input_complete("decl func twice(number x)[number] {\n    return (x\n"));
+    assert!(is_input_complete("decl func twice(number x)[number] {\n    return (x) * 2;\n}\n"));
+
+    // Braces in strings don't open anything
+    assert!(is_input_complete("decl var str s = \"{(\";\n"));
+    assert!(!is_input_complete("decl var str s = \"\\\"}\"; {\n"));
+}
+
+#[test]
+fn declarations_kept() {
+    let mut session = ReplSession::new(MAX_STEPS);
+    assert!(matches!(evaluate_input(&mut session, "decl var number x;\n"), ReplOutcome::Executed(None)));
+    assert!(matches!(evaluate_input(&mut session, "x = 5;\n"), ReplOutcome::Executed(None)));
+    assert!(matches!(
+        evaluate_input(&mut session, "decl func twice(number x)[number] {\n    return x * 2;\n}\n"),
+        ReplOutcome::Declared(names) if names == vec!["twice".to_string()]
+    ));
+    assert_eq!(echo(&mut session, "twice(x) + 1\n"), Some("11".to_string()));
+
+    // A failed input leaves the session as it was
+    assert!(matches!(evaluate_input(&mut session, "decl var number z;\nz = y;\n"), ReplOutcome::Failed));
+    assert_eq!(session.variables.len(), 1);
+    assert_eq!(session.variables[0].value, Some(Value::Number(5)));
+}
+
+#[test]
+fn expression_echo() {
+    let mut session = ReplSession::new(MAX_STEPS);
+    assert_eq!(echo(&mut session, "1 + 2\n"), Some("3".to_string()));
+    assert_eq!(echo(&mut session, "3 > 1\n"), Some("true".to_string()));
+    assert_eq!(echo(&mut session, "1 > 3\n"), Some("false".to_string()));
+
+    // Statements are executed without printing anything
+    assert!(matches!(evaluate_input(&mut session, "decl var bool ok;\nok = 2 > 1;\n"), ReplOutcome::Executed(None)));
+    let variable = &session.variables[0];
+    assert_eq!(format_value(variable.value.as_ref().unwrap(), &variable.definition.type_name), "true");
+    assert_eq!(format_value(&Value::Number(1), &Identifier::single("number")), "1");
+}
@@ -0,0 +1,89 @@
+use std::fs;
+use std::path::Path;
+
+use carbon_lang_compiler::shared::package_generation::package_descriptor::PackageMetadata;
+
+use crate::commands::test::test_once;
+use crate::managers::source_files::load_package_sources;
+use crate::managers::test_runner::{discover_tests, filter_tests, prepare_test_package, run_test};
+use crate::models::command_args::TestCommandArgs;
+use crate::models::test_case::TestPackage;
+use crate::tests::temp_directory;
+
+const MAX_STEPS: usize = 10000;
+
+const SOURCE: &str = r#"decl func twice(number x)[number] {
+    return x * 2;
+}
+
+decl func test_twice()[none] {
+    call assert_eq(4, twice(2));
+}
+
+decl func test_wrong()[none] {
+    call assert(twice(1) == 2);
+    call assert_eq(3, twice(2));
+}
+
+decl func test_with_parameter(number x)[none] {
+    call assert(x > 0);
+}
+"#;
+
+fn load_test_package(source_path: &Path) -> (Vec<String>, TestPackage) {
+    let (tree, string_pool) = load_package_sources(vec![source_path.to_path_buf()], source_path.parent().unwrap(), "main".to_string()).unwrap();
+    let tests = discover_tests(&tree);
+    let package = prepare_test_package(tree, string_pool, &PackageMetadata::default()).unwrap();
+
+    return (tests, package);
+}
+
+fn test_args(input_path: &Path, filter: Option<&str>) -> TestCommandArgs {
+    return TestCommandArgs { input_path: input_path.to_path_buf(), filter: filter.map(|f| f.to_string()), max_steps: MAX_STEPS };
+}
+
+#[test]
+fn tests_discovered() {
+    let root = temp_directory("test-runner-discover");
+    fs::write(root.join("main.cbs"), SOURCE).unwrap();
+
+    // Tests with parameters are skipped, other functions are not tests
+    let (tests, _) = load_test_package(&root.join("main.cbs"));
+    assert_eq!(tests, vec!["test_twice".to_string(), "test_wrong".to_string()]);
+}
+
+#[test]
+fn tests_filtered() {
+    let tests = vec!["test_twice".to_string(), "test_wrong".to_string()];
+    assert_eq!(filter_tests(&tests, None), vec!["test_twice", "test_wrong"]);
+    assert_eq!(filter_tests(&tests, Some("wrong")), vec!["test_wrong"]);
+    assert_eq!(filter_tests(&tests, Some("test_")), vec!["test_twice", "test_wrong"]);
+    assert!(filter_tests(&tests, Some("missing")).is_empty());
+}
+
+#[test]
+fn assertion_failure_reported() {
+    let root = temp_directory("test-runner-failure");
+    let source_path = root.join("main.cbs");
+    fs::write(&source_path, SOURCE).unwrap();
+    let (_, package) = load_test_package(&source_path);
+
+    assert!(run_test(&package, "test_twice", &PackageMetadata::default(), MAX_STEPS).is_ok());
+
+    // The assertion passing before the failed one is not reported
+    let failure = run_test(&package, "test_wrong", &PackageMetadata::default(), MAX_STEPS).unwrap_err();
+    assert_eq!(failure.message, "Expected 3, found 4");
+    let location = failure.location.unwrap();
+    assert_eq!(location.file_path, source_path.display().to_string());
+    assert_eq!(&SOURCE[location.start_pos..location.end_pos], "call assert_eq(3, twice(2));");
+}
+
+#[test]
+fn failure_status() {
+    let root = temp_directory("test-runner-status");
+    fs::write(root.join("main.cbs"), SOURCE).unwrap();
+
+    assert!(!test_once(&test_args(&root.join("main.cbs"), None)));
+    assert!(test_once(&test_args(&root.join("main.cbs"), Some("twice"))));
+    assert!(!test_once(&test_args(&root.join("missing.cbs"), None)));
+}
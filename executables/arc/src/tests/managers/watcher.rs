@@ -0,0 +1,98 @@
+use std::fs;
+use std::path::PathBuf;
+use std::sync::mpsc::{channel, Receiver};
+use std::thread;
+use std::time::Duration;
+
+use crate::managers::watcher::watch_sources_for;
+use crate::tests::temp_directory;
+
+const MAIN_SOURCE: &str = "link \"../shared/helper.cbs\";\ndecl func main()[number] {\n    return helper();\n}\n";
+const HELPER_SOURCE: &str = "decl func helper()[number] {\n    return 1;\n}\n";
+
+/// Long enough for a change to be noticed and debounced
+const CHANGE_TIMEOUT: Duration = Duration::from_secs(5);
+/// Longer than the debounce period, no compilation is expected within it
+const QUIET_PERIOD: Duration = Duration::from_millis(800);
+
+/// Watch `input_path` in another thread, every compilation sends its number through the receiver
+fn start_watching(input_path: PathBuf, max_compilations: usize) -> (Receiver<usize>, thread::JoinHandle<()>) {
+    let (sender, receiver) = channel();
+    let handle = thread::spawn(move || {
+        let mut count = 0;
+        watch_sources_for(&input_path, Some(max_compilations), || {
+            count += 1;
+            sender.send(count).unwrap();
+            return true;
+        });
+    });
+
+    return (receiver, handle);
+}
+
+#[test]
+fn linked_file_rebuilt() {
+    let root = temp_directory("watcher-linked");
+    fs::create_dir_all(root.join("app")).unwrap();
+    fs::create_dir_all(root.join("shared")).unwrap();
+    fs::write(root.join("app").join("main.cbs"), MAIN_SOURCE).unwrap();
+    fs::write(root.join("shared").join("helper.cbs"), HELPER_SOURCE).unwrap();
+
+    let (compilations, handle) = start_watching(root.join("app").join("main.cbs"), 2);
+    assert_eq!(compilations.recv_timeout(CHANGE_TIMEOUT), Ok(1));
+
+    // Files next to the linked one are not compiled
+    fs::write(root.join("shared").join("notes.txt"), "unrelated").unwrap();
+    assert!(compilations.recv_timeout(QUIET_PERIOD).is_err());
+
+    fs::write(root.join("shared").join("helper.cbs"), "decl func helper()[number] {\n    return 2;\n}\n").unwrap();
+    assert_eq!(compilations.recv_timeout(CHANGE_TIMEOUT), Ok(2));
+    handle.join().unwrap();
+}
+
+#[test]
+fn changes_debounced() {
+    let root = temp_directory("watcher-debounce");
+    fs::write(root.join("main.cbs"), HELPER_SOURCE).unwrap();
+
+    let (compilations, handle) = start_watching(root.join("main.cbs"), 3);
+    assert_eq!(compilations.recv_timeout(CHANGE_TIMEOUT), Ok(1));
+
+    // An editor saving in several steps is compiled once
+    for i in 0..5 {
+        fs::write(root.join("main.cbs"), format!("decl func main()[number] {{\n    return {};\n}}\n", i)).unwrap();
+        thread::sleep(Duration::from_millis(20));
+    }
+    assert_eq!(compilations.recv_timeout(CHANGE_TIMEOUT), Ok(2));
+    assert!(compilations.recv_timeout(QUIET_PERIOD).is_err());
+
+    fs::write(root.join("main.cbs"), HELPER_SOURCE).unwrap();
+    assert_eq!(compilations.recv_timeout(CHANGE_TIMEOUT), Ok(3));
+    handle.join().unwrap();
+}
+
+#[test]
+fn change_during_compilation_rebuilt() {
+    let root = temp_directory("watcher-during");
+    let input_path = root.join("main.cbs");
+    fs::write(&input_path, HELPER_SOURCE).unwrap();
+
+    let (sender, compilations) = channel();
+    let handle = thread::spawn(move || {
+        let mut count = 0;
+        watch_sources_for(&input_path, Some(2), || {
+            count += 1;
+            // Saved before the first compilation finishes, nothing is changed afterwards
+            if count == 1 {
+                fs::write(&input_path, "decl func main()[number] {\n    return 2;\n}\n").unwrap();
+                thread::sleep(Duration::from_millis(100));
+            }
+            sender.send(count).unwrap();
+            return true;
+        });
+    });
+
+    assert_eq!(compilations.recv_timeout(CHANGE_TIMEOUT), Ok(1));
+    assert_eq!(compilations.recv_timeout(CHANGE_TIMEOUT), Ok(2));
+    handle.join().unwrap();
+}
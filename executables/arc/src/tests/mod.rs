@@ -0,0 +1,35 @@
+use std::env;
+use std::fs;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use std::process;
+
+mod managers;
+
+/// A directory which is removed with everything in it once the test drops it
+pub struct TempDirectory {
+    path: PathBuf,
+}
+
+impl Deref for TempDirectory {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        return &self.path;
+    }
+}
+
+impl Drop for TempDirectory {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+/// An empty directory for one test, tests run in parallel so every test should use its own `name`
+pub fn temp_directory(name: &str) -> TempDirectory {
+    let path = env::temp_dir().join(format!("arc-test-{}-{}", process::id(), name));
+    let _ = fs::remove_dir_all(&path);
+    fs::create_dir_all(&path).unwrap();
+
+    return TempDirectory { path };
+}
@@ -0,0 +1,65 @@
+use std::fmt::Display;
+
+use crate::lexer::tokenize::tokenize;
+use crate::package_generator::availability_check::package_check::check_package;
+use crate::parser::decorator::decorate_token;
+use crate::parser::pipeline::build_whole_file;
+use crate::shared::analysis::source_analysis::{SourceAnalysis, SourceIssue};
+use crate::shared::error::general_issue::{FileMatch, IssueBase, IssueLocation, UNKNOWN_FILE_PATH};
+use crate::shared::utils::identifier::Identifier;
+use crate::shared::utils::source_map::SourceMap;
+
+/// Run lexical analysis, parsing and availability checks on a single file, every issue is collected.
+pub fn analyze_source(file_path: &str, source: &str) -> SourceAnalysis {
+    let mut result = SourceAnalysis {
+        source_map: SourceMap::new(file_path, source),
+        tokens: vec![],
+        tree: None,
+        issues: vec![],
+    };
+
+    let tokens = tokenize(source, true);
+    if tokens.is_err() {
+        result.issues = convert_issues(&tokens.unwrap_err().issues, file_path);
+        return result;
+    }
+    result.tokens = tokens.unwrap();
+
+    // The entry point doesn't matter for a single file
+    let tokens = result.tokens.clone();
+    let tree = build_whole_file(decorate_token(tokens).0, Identifier::single("main"));
+    if tree.is_err() {
+        result.issues = convert_issues(&tree.unwrap_err().issues, file_path);
+        return result;
+    }
+
+    let mut tree = tree.unwrap();
+    tree.set_file_path(file_path);
+    if let Err(e) = check_package(&tree) {
+        result.issues = convert_issues(&e.issues, file_path);
+    }
+    result.tree = Some(tree);
+
+    return result;
+}
+
+fn convert_issues<T: Display + IssueLocation>(issues: &[IssueBase<T>], file_path: &str) -> Vec<SourceIssue> {
+    return issues.iter().map(|issue| {
+        let mut location = issue.detail.location().unwrap_or(FileMatch {
+            file_path: UNKNOWN_FILE_PATH.to_string(),
+            start_pos: 0,
+            end_pos: 0,
+        });
+        if location.file_path == UNKNOWN_FILE_PATH {
+            location.file_path = file_path.to_string();
+        }
+
+        SourceIssue {
+            level: issue.level.clone(),
+            position: issue.position.clone(),
+            code: issue.code.clone(),
+            message: issue.detail.to_string(),
+            location,
+        }
+    }).collect();
+}
@@ -0,0 +1,210 @@
+use itertools::Itertools;
+
+use crate::lexer::lex_rules::keyword::KEYWORDS;
+use crate::shared::analysis::source_analysis::SourceAnalysis;
+use crate::shared::analysis::symbol::{Symbol, SymbolKind};
+use crate::shared::error::general_issue::FileMatch;
+use crate::shared::token::container::ContainerType;
+use crate::shared::token::keyword::KeywordType;
+use crate::shared::token::token::{Token, TokenContent};
+
+/// Functions, groups, parameters and variables declared in a file.
+/// Declarations are found from tokens, so they are still available when the file couldn't be parsed.
+pub fn collect_symbols(analysis: &SourceAnalysis) -> Vec<Symbol> {
+    let tokens = &analysis.tokens;
+    let mut result = vec![];
+
+    for (index, token) in tokens.iter().enumerate() {
+        let name_token = tokens.get(index + 1).filter(|t| matches!(t.content, TokenContent::Identifier(_)));
+        let keyword = match &token.content {
+            TokenContent::Keyword(x) => x,
+            _ => continue,
+        };
+
+        match keyword {
+            KeywordType::KwFunc if name_token.is_some() => {
+                let name = token_text(analysis, name_token.unwrap());
+                let declarator = analysis.tree.as_ref().and_then(|tree| {
+                    tree.functions.iter().find(|f| f.declarator.identifier.to_string() == name).map(|f| &f.declarator)
+                });
+                result.push(Symbol {
+                    kind: SymbolKind::Function,
+                    detail: match declarator {
+                        Some(x) => format!("decl func {}", x),
+                        None => format!("decl func {}", name),
+                    },
+                    name,
+                    location: token_location(analysis, name_token.unwrap()),
+                    scope: None,
+                });
+
+                result.extend(collect_parameters(analysis, index + 2));
+            }
+            KeywordType::KwGroup if name_token.is_some() => {
+                let name = token_text(analysis, name_token.unwrap());
+                result.push(Symbol {
+                    kind: SymbolKind::Group,
+                    detail: format!("group {}", name),
+                    name,
+                    location: token_location(analysis, name_token.unwrap()),
+                    scope: None,
+                });
+            }
+            // decl var <type> <name>
+            KeywordType::KwVar | KeywordType::KwConst if index > 0 && tokens[index - 1].content == TokenContent::Keyword(KeywordType::KwDeclare) => {
+                let type_token = tokens.get(index + 1);
+                let name_token = tokens.get(index + 2).filter(|t| matches!(t.content, TokenContent::Identifier(_)));
+                if type_token.is_none() || name_token.is_none() {
+                    continue;
+                }
+
+                let name_token = name_token.unwrap();
+                let name = token_text(analysis, name_token);
+                let location = token_location(analysis, name_token);
+                result.push(Symbol {
+                    kind: SymbolKind::Variable,
+                    detail: format!("decl {} {} {}", token_text(analysis, token), token_text(analysis, type_token.unwrap()), name),
+                    name,
+                    scope: Some((location.end_pos, block_end(analysis, index + 3))),
+                    location,
+                });
+            }
+            _ => {}
+        }
+    }
+
+    return result;
+}
+
+/// Keywords are always available
+pub fn keyword_symbols() -> Vec<Symbol> {
+    return KEYWORDS.values().sorted().dedup().map(|keyword| Symbol {
+        kind: SymbolKind::Keyword,
+        name: keyword.to_string(),
+        detail: "keyword".to_string(),
+        location: FileMatch { file_path: String::new(), start_pos: 0, end_pos: 0 },
+        scope: None,
+    }).collect();
+}
+
+/// Symbols which could be used at `offset`, variables declared in the same block are only visible after their declarations
+pub fn symbols_in_scope(analysis: &SourceAnalysis, offset: usize) -> Vec<Symbol> {
+    let mut result: Vec<Symbol> = collect_symbols(analysis).into_iter().filter(|s| is_visible(s, offset)).collect();
+    // Inner declarations shadow outer ones
+    result.reverse();
+
+    return result.into_iter().unique_by(|s| s.name.clone()).sorted_by(|a, b| a.name.cmp(&b.name)).collect();
+}
+
+/// Keywords and symbols in scope
+pub fn completion_candidates(analysis: &SourceAnalysis, offset: usize) -> Vec<Symbol> {
+    let mut result = keyword_symbols();
+    result.extend(symbols_in_scope(analysis, offset));
+
+    return result;
+}
+
+/// Find the declaration of the identifier at `offset`.
+/// An identifier followed by `(` refers to a function, otherwise variables are looked up first.
+pub fn find_declaration(analysis: &SourceAnalysis, offset: usize) -> Option<Symbol> {
+    let index = analysis.tokens.iter().position(|t| t.position.start <= offset && offset <= t.position.start + t.position.length);
+    let index = index?;
+    let token = &analysis.tokens[index];
+    if !matches!(token.content, TokenContent::Identifier(_)) {
+        return None;
+    }
+
+    let name = token_text(analysis, token);
+    let is_call = analysis.tokens.get(index + 1).is_some_and(|t| t.content == TokenContent::Container(ContainerType::Bracket));
+    let candidates: Vec<Symbol> = collect_symbols(analysis)
+        .into_iter()
+        .filter(|s| s.name == name)
+        .filter(|s| is_visible(s, offset) || (s.location.start_pos <= offset && offset <= s.location.end_pos))
+        .collect();
+
+    let priority = if is_call {
+        [SymbolKind::Function, SymbolKind::Group, SymbolKind::Variable]
+    } else {
+        [SymbolKind::Variable, SymbolKind::Group, SymbolKind::Function]
+    };
+    for kind in priority {
+        // The innermost declaration is the last one
+        if let Some(symbol) = candidates.iter().rev().find(|s| s.kind == kind) {
+            return Some(symbol.clone());
+        }
+    }
+
+    return None;
+}
+
+/// Parameters between the brackets starting at `start`, each of them is a type followed by a name
+fn collect_parameters(analysis: &SourceAnalysis, start: usize) -> Vec<Symbol> {
+    let tokens = &analysis.tokens;
+    if tokens.get(start).map(|t| &t.content) != Some(&TokenContent::Container(ContainerType::Bracket)) {
+        return vec![];
+    }
+
+    let end = find_token(tokens, start, ContainerType::AntiBracket);
+    let body_end = block_end(analysis, find_token(tokens, end, ContainerType::Brace) + 1);
+
+    let mut result = vec![];
+    for index in (start + 2)..end {
+        if !matches!(tokens[index].content, TokenContent::Identifier(_)) || matches!(tokens[index - 1].content, TokenContent::Operator(_)) {
+            continue;
+        }
+
+        let name = token_text(analysis, &tokens[index]);
+        let location = token_location(analysis, &tokens[index]);
+        result.push(Symbol {
+            kind: SymbolKind::Variable,
+            detail: format!("{} {}", token_text(analysis, &tokens[index - 1]), name),
+            name,
+            scope: Some((location.end_pos, body_end)),
+            location,
+        });
+    }
+
+    return result;
+}
+
+/// Index of the first container token of `container_type` from `start`, or the number of tokens
+fn find_token(tokens: &[Token], start: usize, container_type: ContainerType) -> usize {
+    let position = tokens.iter().skip(start).position(|t| t.content == TokenContent::Container(container_type));
+    return position.map_or(tokens.len(), |x| start + x);
+}
+
+/// Byte offset of the `}` closing the block which contains the token at `start`, or the end of the file
+fn block_end(analysis: &SourceAnalysis, start: usize) -> usize {
+    let mut depth = 0;
+    for token in analysis.tokens.iter().skip(start) {
+        match token.content {
+            TokenContent::Container(ContainerType::Brace) => depth += 1,
+            TokenContent::Container(ContainerType::AntiBrace) if depth == 0 => return token.position.start,
+            TokenContent::Container(ContainerType::AntiBrace) => depth -= 1,
+            _ => {}
+        }
+    }
+
+    return analysis.source_map.source.len();
+}
+
+fn is_visible(symbol: &Symbol, offset: usize) -> bool {
+    return match symbol.scope {
+        Some((start, end)) => start <= offset && offset <= end,
+        None => symbol.kind != SymbolKind::Keyword,
+    };
+}
+
+fn token_text(analysis: &SourceAnalysis, token: &Token) -> String {
+    let source = &analysis.source_map.source;
+    let end = (token.position.start + token.position.length).min(source.len());
+    return source.get(token.position.start..end).unwrap_or_default().to_string();
+}
+
+fn token_location(analysis: &SourceAnalysis, token: &Token) -> FileMatch {
+    return FileMatch {
+        file_path: analysis.source_map.file_path.clone(),
+        start_pos: token.position.start,
+        end_pos: token.position.start + token.position.length,
+    };
+}
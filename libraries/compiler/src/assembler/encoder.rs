@@ -0,0 +1,330 @@
+use std::collections::HashMap;
+
+use crate::assembler::parser::{assembly_issue, parse_assembly};
+use crate::package_generator::command_builder::allocators::mutable_data_alloc::dac_builder;
+use crate::package_generator::command_builder::data_commands::build_data_declaration_command;
+use crate::package_generator::package_builder::build_package_from_commands;
+use crate::package_generator::utils::{align_array_width, combine_command, jump_command_address_placeholder, jump_command_address_placeholder_len};
+use crate::shared::assembly::assembled_package::AssembledPackage;
+use crate::shared::assembly::assembly_line::{AssemblyDataAccess, AssemblyInstruction, AssemblyLine, AssemblyStatement, JumpDestination};
+use crate::shared::ast::blocks::function::FunctionLinkage;
+use crate::shared::command_map::{FunctionCommand, JumpCommand, MathCommand, ObjectCommand, RootCommand, StackCommand};
+use crate::shared::error::assembly_issue::AssemblyIssue;
+use crate::shared::error::general_issue::{GeneralIssue, IssueBase, IssuePosition};
+use crate::shared::package_generation::data_descriptor::{DataAccessDescriptor, DataDeclarator, DataLocation, StringConstant};
+use crate::shared::package_generation::func_table::FunctionTableEntry;
+use crate::shared::package_generation::package_descriptor::PackageMetadata;
+use crate::shared::package_generation::relocation_reference::{RelocatableCommandList, RelocationReference, RelocationReferenceType, RelocationTarget, RelocationTargetElement};
+use crate::shared::utils::identifier::Identifier;
+
+/// Assemble Carbon assembly into a package
+pub fn assemble_package(source: &str) -> Result<Vec<u8>, GeneralIssue<AssemblyIssue>> {
+    let lines = parse_assembly(source)?;
+    let assembled = assemble_commands(&lines)?;
+
+    let package = build_package_from_commands(
+        assembled.commands,
+        &assembled.entry_point.unwrap_or_else(Identifier::empty),
+        &assembled.metadata,
+    );
+
+    // Lines are checked while assembling, what is left is placing the whole package
+    return package.map_err(|e| GeneralIssue {
+        issues: e.issues.iter().map(|i| assembly_issue(i.detail.content.as_str(), 0, IssuePosition::CodeGeneration)).collect(),
+    });
+}
+
+/// Encode parsed assembly into function commands, labels are turned into relative relocation targets
+pub fn assemble_commands(lines: &[AssemblyLine]) -> Result<AssembledPackage, GeneralIssue<AssemblyIssue>> {
+    let mut issues: Vec<IssueBase<AssemblyIssue>> = vec![];
+
+    let metadata = read_metadata(lines, &mut issues);
+
+    let mut result = RelocatableCommandList::new();
+    let mut entry_point: Option<(Identifier, usize)> = None;
+    let mut labels: HashMap<String, usize> = HashMap::new();
+    // Index of relocation target, destination and line
+    let mut pending_jumps: Vec<(usize, JumpDestination, usize)> = vec![];
+    // Called function and line
+    let mut called_functions: Vec<(String, usize)> = vec![];
+
+    for line in lines {
+        match &line.statement {
+            AssemblyStatement::Metadata(_) => {}
+            AssemblyStatement::Entry(name) => entry_point = Some((Identifier::from_string(name), line.line)),
+            AssemblyStatement::StringConstant(value) => {
+                let slot = result.string_pool.len();
+                result.string_pool.push(StringConstant { value: value.clone(), slot });
+            }
+            AssemblyStatement::Function(name) | AssemblyStatement::Label(name) => {
+                if labels.insert(name.clone(), result.commands.len()).is_some() {
+                    issues.push(assembly_issue(format!("\"{}\" is defined more than once", name).as_str(), line.line, IssuePosition::CodeGeneration));
+                }
+
+                if let AssemblyStatement::Function(_) = &line.statement {
+                    let identifier = Identifier::from_string(name);
+                    result.function_table.push(FunctionTableEntry {
+                        slot: result.function_table.len(),
+                        name: identifier.clone(),
+                        relocated_entry_address: result.commands.len(),
+                        linkage: FunctionLinkage::Internal,
+                    });
+                    result.descriptors.references.push(RelocationReference {
+                        ref_type: RelocationReferenceType::FunctionEntrance(identifier),
+                        command_array_position: result.commands.len(),
+                    });
+                }
+            }
+            AssemblyStatement::Instruction(instruction) => {
+                let position = result.commands.len();
+                match encode_instruction(instruction, &mut result, &metadata) {
+                    Ok(destinations) => {
+                        // Targets of the command are the last ones
+                        let first_target = result.descriptors.targets.len() - destinations.len();
+                        for (index, destination) in destinations.into_iter().enumerate() {
+                            pending_jumps.push((first_target + index, destination, line.line));
+                        }
+                    }
+                    Err(content) => issues.push(assembly_issue(content.as_str(), line.line, IssuePosition::CodeGeneration)),
+                }
+
+                if let AssemblyInstruction::EnterFunction { function, .. } = instruction {
+                    called_functions.push((function.clone(), line.line));
+                }
+                result.command_entries.push(position);
+            }
+        }
+    }
+
+    // Resolve labels into relative addresses
+    let address_limit = capacity_of(metadata.address_alignment);
+    for (target_index, destination, line) in pending_jumps {
+        let target = &mut result.descriptors.targets[target_index];
+        let offset = match destination {
+            JumpDestination::Relative(x) => x,
+            JumpDestination::Label(name) => match labels.get(&name) {
+                Some(position) => *position as i64 - target.command_array_position as i64,
+                None => {
+                    issues.push(assembly_issue(format!("Label \"{}\" is not defined", name).as_str(), line, IssuePosition::CodeGeneration));
+                    continue;
+                }
+            },
+        };
+
+        if offset.unsigned_abs() as u128 >= address_limit || offset.unsigned_abs() > i32::MAX as u64 {
+            issues.push(assembly_issue("Jump offset doesn't fit in the address alignment", line, IssuePosition::CodeGeneration));
+            continue;
+        }
+        target.relocation_elements = vec![RelocationTargetElement::Relative(offset as i32)];
+    }
+
+    for (function, line) in called_functions {
+        if !result.function_table.iter().any(|f| f.name == Identifier::from_string(&function)) {
+            issues.push(assembly_issue(format!("Function \"{}\" is not defined", function).as_str(), line, IssuePosition::CodeGeneration));
+        }
+    }
+
+    if result.function_table.len() as u128 >= address_limit {
+        issues.push(assembly_issue("Too many functions for the address alignment", 0, IssuePosition::CodeGeneration));
+    }
+
+    if metadata.package_type == 0 {
+        match &entry_point {
+            Some((name, line)) => {
+                if !result.function_table.iter().any(|f| f.name == *name) {
+                    issues.push(assembly_issue(format!("Entry function \"{}\" is not defined", name).as_str(), *line, IssuePosition::CodeGeneration));
+                }
+            }
+            None => issues.push(assembly_issue("Executable package requires an `.entry`", 0, IssuePosition::CodeGeneration)),
+        }
+    }
+
+    if !issues.is_empty() {
+        return Err(GeneralIssue { issues });
+    }
+
+    return Ok(AssembledPackage {
+        commands: result,
+        metadata,
+        entry_point: entry_point.map(|e| e.0),
+    });
+}
+
+fn read_metadata(lines: &[AssemblyLine], issues: &mut Vec<IssueBase<AssemblyIssue>>) -> PackageMetadata {
+    // The same as `arc compile`
+    let mut metadata = PackageMetadata::default();
+
+    for line in lines {
+        if let AssemblyStatement::Metadata(pairs) = &line.statement {
+            for (key, value) in pairs {
+                let field = match key.as_str() {
+                    "type" => &mut metadata.package_type,
+                    "data" => &mut metadata.data_alignment,
+                    "domain_layer" => &mut metadata.domain_layer_count_alignment,
+                    "data_slot" => &mut metadata.data_slot_alignment,
+                    "address" => &mut metadata.address_alignment,
+                    _ => {
+                        issues.push(assembly_issue(format!("Unknown metadata field \"{}\"", key).as_str(), line.line, IssuePosition::Parsing));
+                        continue;
+                    }
+                };
+                *field = *value;
+            }
+        }
+    }
+
+    if metadata.data_alignment == 0 || metadata.data_alignment > 8 || metadata.data_slot_alignment == 0
+        || metadata.address_alignment == 0 || metadata.address_alignment > 8 {
+        issues.push(assembly_issue("Alignments should be between 1 and 8", 0, IssuePosition::Parsing));
+    }
+
+    return metadata;
+}
+
+/// Returns the destinations of jump targets which are appended to `result`
+fn encode_instruction(
+    instruction: &AssemblyInstruction,
+    result: &mut RelocatableCommandList,
+    metadata: &PackageMetadata,
+) -> Result<Vec<JumpDestination>, String> {
+    let position = result.commands.len();
+
+    match instruction {
+        AssemblyInstruction::CreateObject { is_global } => {
+            result.combine(build_data_declaration_command(*is_global));
+        }
+        AssemblyInstruction::DestroyObject { slot } => {
+            check_capacity(*slot as u128, metadata.data_slot_alignment, "Slot")?;
+            result.append_commands(vec![combine_command(RootCommand::Object.to_opcode(), ObjectCommand::Destroy.to_opcode())]);
+            result.append_commands(align_array_width(&slot.to_be_bytes().to_vec(), metadata.data_slot_alignment));
+        }
+        AssemblyInstruction::Push(dac) => encode_stack_command(StackCommand::Push, dac, result, metadata)?,
+        AssemblyInstruction::PushFromObject(dac) => encode_stack_command(StackCommand::PushFromObject, dac, result, metadata)?,
+        AssemblyInstruction::Pop => {
+            result.append_commands(vec![combine_command(RootCommand::Stack.to_opcode(), StackCommand::Pop.to_opcode())]);
+        }
+        AssemblyInstruction::PopToObject(dac) => encode_stack_command(StackCommand::PopToObject, dac, result, metadata)?,
+        AssemblyInstruction::JumpToRelative(destination) => {
+            push_jump_target(result, position, 1);
+            result.append_commands(vec![combine_command(RootCommand::Jump.to_opcode(), JumpCommand::ToRelative.to_opcode())]);
+            result.append_commands(jump_command_address_placeholder(metadata));
+
+            return Ok(vec![destination.clone()]);
+        }
+        AssemblyInstruction::JumpByStackTop { positive, negative, zero } => {
+            let placeholder_len = jump_command_address_placeholder_len(metadata.address_alignment) as i32;
+            for index in 0..3 {
+                push_jump_target(result, position, 1 + placeholder_len * index);
+            }
+            result.append_commands(vec![combine_command(RootCommand::Jump.to_opcode(), JumpCommand::ByStackTop.to_opcode())]);
+            result.append_commands(jump_command_address_placeholder(metadata).repeat(3));
+
+            return Ok(vec![positive.clone(), negative.clone(), zero.clone()]);
+        }
+        AssemblyInstruction::EnterFunction { function, argument_count } => {
+            // Layout: `0xE1 0x00 <slot> <argument count>`
+            result.descriptors.targets.push(RelocationTarget {
+                relocation_elements: vec![RelocationTargetElement::EnterFunction(Identifier::from_string(function))],
+                command_array_position: position,
+                offset: 1,
+                relocated_address: 0,
+            });
+            result.append_commands(vec![combine_command(RootCommand::Function.to_opcode(), FunctionCommand::Enter.to_opcode())]);
+            result.append_commands(jump_command_address_placeholder(metadata));
+            result.append_commands(vec![*argument_count]);
+        }
+        AssemblyInstruction::LeaveWithoutValue => {
+            result.append_commands(vec![combine_command(RootCommand::Function.to_opcode(), FunctionCommand::LeaveWithoutValue.to_opcode())]);
+        }
+        AssemblyInstruction::LeaveWithValue => {
+            result.append_commands(vec![combine_command(RootCommand::Function.to_opcode(), FunctionCommand::LeaveWithValue.to_opcode())]);
+        }
+        AssemblyInstruction::FunctionEndFlag => {
+            result.append_commands(vec![combine_command(RootCommand::Function.to_opcode(), FunctionCommand::FunctionEndFlag.to_opcode())]);
+        }
+        AssemblyInstruction::Calculation(x) => {
+            result.append_commands(vec![combine_command(RootCommand::Math.to_opcode(), MathCommand::Calculation.to_opcode()), x.to_opcode()]);
+        }
+        AssemblyInstruction::Logical(x) => {
+            result.append_commands(vec![combine_command(RootCommand::Math.to_opcode(), MathCommand::Logical.to_opcode()), x.to_opcode()]);
+        }
+    }
+
+    return Ok(vec![]);
+}
+
+fn encode_stack_command(
+    command: StackCommand,
+    dac: &AssemblyDataAccess,
+    result: &mut RelocatableCommandList,
+    metadata: &PackageMetadata,
+) -> Result<(), String> {
+    let descriptor = match dac {
+        AssemblyDataAccess::InstantValue(x) => {
+            // Values are stored in two's complement, only non-negative values could be narrowed
+            if metadata.data_alignment < 8 && (*x < 0 || *x as u128 >= capacity_of(metadata.data_alignment) / 2) {
+                return Err(format!("Value {} doesn't fit in the data alignment", x));
+            }
+            DataAccessDescriptor::new_instant_value(x.to_string())
+        }
+        AssemblyDataAccess::LocalSlot(slot) => {
+            check_capacity(*slot as u128, metadata.data_slot_alignment, "Slot")?;
+            DataAccessDescriptor::new_identifier(DataDeclarator {
+                name: Identifier::empty(),
+                slot: *slot,
+                location: DataLocation::Local,
+                is_string: false,
+            })
+        }
+        AssemblyDataAccess::StringSlot(slot) => {
+            let constant = result.string_pool.iter().find(|s| s.slot == *slot);
+            if constant.is_none() {
+                return Err(format!("String slot {} is not defined", slot));
+            }
+            DataAccessDescriptor::new_string_constant(constant.unwrap().clone())
+        }
+        AssemblyDataAccess::StringLiteral(value) => {
+            let constant = add_string_constant(&mut result.string_pool, value);
+            check_capacity(constant.slot as u128, metadata.data_slot_alignment, "String slot")?;
+            DataAccessDescriptor::new_string_constant(constant)
+        }
+    };
+
+    result.append_commands(vec![combine_command(RootCommand::Stack.to_opcode(), command.to_opcode())]);
+    result.combine(dac_builder(descriptor, metadata).map_err(|_| "Invalid data access".to_string())?);
+
+    return Ok(());
+}
+
+fn push_jump_target(result: &mut RelocatableCommandList, position: usize, offset: i32) {
+    result.descriptors.targets.push(RelocationTarget {
+        relocation_elements: vec![RelocationTargetElement::Undefined],
+        command_array_position: position,
+        offset,
+        relocated_address: 0,
+    });
+}
+
+/// String literals in commands share the slot of an equal constant
+fn add_string_constant(string_pool: &mut Vec<StringConstant>, value: &str) -> StringConstant {
+    if let Some(constant) = string_pool.iter().find(|s| s.value == value) {
+        return constant.clone();
+    }
+
+    let constant = StringConstant { value: value.to_string(), slot: string_pool.len() };
+    string_pool.push(constant.clone());
+    return constant;
+}
+
+/// How many different values could be stored in `width` bytes
+fn capacity_of(width: u8) -> u128 {
+    return 1u128 << (8 * width.min(15) as u32);
+}
+
+fn check_capacity(value: u128, width: u8, name: &str) -> Result<(), String> {
+    if value >= capacity_of(width) {
+        return Err(format!("{} {} doesn't fit in the alignment", name, value));
+    }
+
+    return Ok(());
+}
@@ -0,0 +1,293 @@
+use crate::shared::assembly::assembly_line::{AssemblyDataAccess, AssemblyInstruction, AssemblyLine, AssemblyStatement, JumpDestination};
+use crate::shared::command_map::{MathCalcCommand, MathLogicalCommand};
+use crate::shared::error::assembly_issue::AssemblyIssue;
+use crate::shared::error::general_issue::{GeneralIssue, IssueBase, IssueLevel, IssuePosition};
+
+/// Parse Carbon assembly line by line, every invalid line is reported
+pub fn parse_assembly(source: &str) -> Result<Vec<AssemblyLine>, GeneralIssue<AssemblyIssue>> {
+    let mut result = vec![];
+    let mut issues = vec![];
+
+    for (index, raw_line) in source.lines().enumerate() {
+        let line = index + 1;
+        let words = split_line(raw_line);
+        if words.is_err() {
+            issues.push(assembly_issue(words.unwrap_err().as_str(), line, IssuePosition::Parsing));
+            continue;
+        }
+
+        let words = words.unwrap();
+        if words.is_empty() {
+            continue;
+        }
+
+        match parse_statement(&words) {
+            Ok(statement) => result.push(AssemblyLine { line, statement }),
+            Err(content) => issues.push(assembly_issue(content.as_str(), line, IssuePosition::Parsing)),
+        }
+    }
+
+    if !issues.is_empty() {
+        return Err(GeneralIssue { issues });
+    }
+
+    return Ok(result);
+}
+
+pub fn assembly_issue(content: &str, line: usize, position: IssuePosition) -> IssueBase<AssemblyIssue> {
+    return IssueBase {
+        level: IssueLevel::Error,
+        position,
+        code: "-1".to_string(),
+        detail: AssemblyIssue { content: content.to_string(), line },
+    };
+}
+
+/// Split a line into words, comments (`;`) are removed and quoted strings are kept as a whole
+fn split_line(line: &str) -> Result<Vec<String>, String> {
+    let mut result = vec![];
+    let mut current = String::new();
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for c in line.chars() {
+        if in_string {
+            current.push(c);
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '"' {
+                in_string = false;
+            }
+            continue;
+        }
+
+        match c {
+            ';' => break,
+            '"' => {
+                in_string = true;
+                current.push(c);
+            }
+            c if c.is_whitespace() => {
+                if !current.is_empty() {
+                    result.push(current.clone());
+                    current.clear();
+                }
+            }
+            _ => current.push(c),
+        }
+    }
+
+    if in_string {
+        return Err("String literal is not closed".to_string());
+    }
+    if !current.is_empty() {
+        result.push(current);
+    }
+
+    return Ok(result);
+}
+
+fn parse_statement(words: &[String]) -> Result<AssemblyStatement, String> {
+    let head = words[0].as_str();
+    let operands = &words[1..];
+
+    if head.starts_with('.') {
+        return match head {
+            ".metadata" => {
+                let mut pairs = vec![];
+                for operand in operands {
+                    let pair: Vec<&str> = operand.splitn(2, '=').collect();
+                    if pair.len() != 2 {
+                        return Err(format!("Invalid metadata field \"{}\"", operand));
+                    }
+                    let value = pair[1].parse::<u8>().map_err(|_| format!("Invalid metadata value \"{}\"", pair[1]))?;
+                    pairs.push((pair[0].to_string(), value));
+                }
+                Ok(AssemblyStatement::Metadata(pairs))
+            }
+            ".entry" => Ok(AssemblyStatement::Entry(single_name(operands)?)),
+            ".string" => {
+                expect_operand_count(operands, 1)?;
+                Ok(AssemblyStatement::StringConstant(parse_string_literal(&operands[0])?))
+            }
+            ".func" => Ok(AssemblyStatement::Function(single_name(operands)?)),
+            _ => Err(format!("Unknown directive \"{}\"", head)),
+        };
+    }
+
+    if head.ends_with(':') && operands.is_empty() {
+        let name = &head[..head.len() - 1];
+        if !is_valid_name(name) {
+            return Err(format!("Invalid label \"{}\"", name));
+        }
+        return Ok(AssemblyStatement::Label(name.to_string()));
+    }
+
+    return parse_instruction(head, operands).map(AssemblyStatement::Instruction);
+}
+
+fn parse_instruction(mnemonic: &str, operands: &[String]) -> Result<AssemblyInstruction, String> {
+    let no_operand = |instruction: AssemblyInstruction| -> Result<AssemblyInstruction, String> {
+        expect_operand_count(operands, 0)?;
+        return Ok(instruction);
+    };
+
+    return match mnemonic {
+        "object.create" => {
+            expect_operand_count(operands, 1)?;
+            match operands[0].as_str() {
+                "local" => Ok(AssemblyInstruction::CreateObject { is_global: false }),
+                "global" => Ok(AssemblyInstruction::CreateObject { is_global: true }),
+                x => Err(format!("Expect \"local\" or \"global\", found \"{}\"", x)),
+            }
+        }
+        "object.destroy" => {
+            expect_operand_count(operands, 1)?;
+            match parse_data_access(&operands[0])? {
+                AssemblyDataAccess::LocalSlot(slot) => Ok(AssemblyInstruction::DestroyObject { slot }),
+                _ => Err("Only local slots could be destroyed".to_string()),
+            }
+        }
+        "stack.push" => {
+            expect_operand_count(operands, 1)?;
+            Ok(AssemblyInstruction::Push(parse_data_access(&operands[0])?))
+        }
+        "stack.push_object" => {
+            expect_operand_count(operands, 1)?;
+            Ok(AssemblyInstruction::PushFromObject(parse_data_access(&operands[0])?))
+        }
+        "stack.pop" => no_operand(AssemblyInstruction::Pop),
+        "stack.pop_object" => {
+            expect_operand_count(operands, 1)?;
+            Ok(AssemblyInstruction::PopToObject(parse_data_access(&operands[0])?))
+        }
+        "jump.relative" => {
+            expect_operand_count(operands, 1)?;
+            Ok(AssemblyInstruction::JumpToRelative(parse_jump_destination(&operands[0])?))
+        }
+        "jump.stack_top" => {
+            expect_operand_count(operands, 3)?;
+            Ok(AssemblyInstruction::JumpByStackTop {
+                positive: parse_jump_destination(&operands[0])?,
+                negative: parse_jump_destination(&operands[1])?,
+                zero: parse_jump_destination(&operands[2])?,
+            })
+        }
+        "function.enter" => {
+            expect_operand_count(operands, 2)?;
+            if !is_valid_name(&operands[0]) {
+                return Err(format!("Invalid function name \"{}\"", operands[0]));
+            }
+            let argument_count = operands[1].parse::<u8>().map_err(|_| format!("Invalid argument count \"{}\"", operands[1]))?;
+            Ok(AssemblyInstruction::EnterFunction { function: operands[0].clone(), argument_count })
+        }
+        "function.leave" => no_operand(AssemblyInstruction::LeaveWithoutValue),
+        "function.leave_value" => no_operand(AssemblyInstruction::LeaveWithValue),
+        "function.end" => no_operand(AssemblyInstruction::FunctionEndFlag),
+        "calc.plus" => no_operand(AssemblyInstruction::Calculation(MathCalcCommand::Plus)),
+        "calc.minus" => no_operand(AssemblyInstruction::Calculation(MathCalcCommand::Minus)),
+        "calc.times" => no_operand(AssemblyInstruction::Calculation(MathCalcCommand::Times)),
+        "calc.divide" => no_operand(AssemblyInstruction::Calculation(MathCalcCommand::Divide)),
+        "calc.mod" => no_operand(AssemblyInstruction::Calculation(MathCalcCommand::Mod)),
+        "calc.inverse" => no_operand(AssemblyInstruction::Calculation(MathCalcCommand::Inverse)),
+        "logic.and" => no_operand(AssemblyInstruction::Logical(MathLogicalCommand::And)),
+        "logic.or" => no_operand(AssemblyInstruction::Logical(MathLogicalCommand::Or)),
+        "logic.not" => no_operand(AssemblyInstruction::Logical(MathLogicalCommand::Not)),
+        _ => Err(format!("Unknown instruction \"{}\"", mnemonic)),
+    };
+}
+
+fn parse_data_access(word: &str) -> Result<AssemblyDataAccess, String> {
+    let invalid = || format!("Invalid data access \"{}\"", word);
+
+    if word.starts_with('"') {
+        return Ok(AssemblyDataAccess::StringLiteral(parse_string_literal(word)?));
+    }
+
+    let (prefix, value) = word.split_at(word.chars().next().map_or(0, |c| c.len_utf8()));
+    return match prefix {
+        "#" => value.parse::<i64>().map(AssemblyDataAccess::InstantValue).map_err(|_| invalid()),
+        "$" => value.parse::<usize>().map(AssemblyDataAccess::LocalSlot).map_err(|_| invalid()),
+        "@" => value.parse::<usize>().map(AssemblyDataAccess::StringSlot).map_err(|_| invalid()),
+        _ => Err(invalid()),
+    };
+}
+
+fn parse_jump_destination(word: &str) -> Result<JumpDestination, String> {
+    if word.starts_with('+') || word.starts_with('-') {
+        return word.parse::<i64>()
+                   .map(JumpDestination::Relative)
+                   .map_err(|_| format!("Invalid relative offset \"{}\"", word));
+    }
+
+    if !is_valid_name(word) {
+        return Err(format!("Invalid label \"{}\"", word));
+    }
+
+    return Ok(JumpDestination::Label(word.to_string()));
+}
+
+/// Strings are written like Rust string literals, which is also how the disassembler prints them
+pub fn parse_string_literal(word: &str) -> Result<String, String> {
+    let invalid = || format!("Invalid string literal {}", word);
+    if word.len() < 2 || !word.starts_with('"') || !word.ends_with('"') {
+        return Err(invalid());
+    }
+
+    let mut result = String::new();
+    let mut chars = word[1..word.len() - 1].chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('n') => result.push('\n'),
+            Some('t') => result.push('\t'),
+            Some('r') => result.push('\r'),
+            Some('0') => result.push('\0'),
+            Some('\\') => result.push('\\'),
+            Some('"') => result.push('"'),
+            Some('\'') => result.push('\''),
+            Some('u') => {
+                // `\u{XXXX}`
+                if chars.next() != Some('{') {
+                    return Err(invalid());
+                }
+                let code: String = chars.by_ref().take_while(|c| *c != '}').collect();
+                let value = u32::from_str_radix(code.as_str(), 16).ok().and_then(std::char::from_u32);
+                result.push(value.ok_or_else(invalid)?);
+            }
+            _ => return Err(invalid()),
+        }
+    }
+
+    return Ok(result);
+}
+
+fn single_name(operands: &[String]) -> Result<String, String> {
+    expect_operand_count(operands, 1)?;
+    if !is_valid_name(&operands[0]) {
+        return Err(format!("Invalid name \"{}\"", operands[0]));
+    }
+
+    return Ok(operands[0].clone());
+}
+
+/// Names are made of letters, digits, `_`, `.` and `::`
+fn is_valid_name(name: &str) -> bool {
+    let first = name.chars().next();
+    return first.is_some_and(|c| c.is_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '.' || c == ':');
+}
+
+fn expect_operand_count(operands: &[String], count: usize) -> Result<(), String> {
+    if operands.len() != count {
+        return Err(format!("Expect {} operands, found {}", count, operands.len()));
+    }
+
+    return Ok(());
+}
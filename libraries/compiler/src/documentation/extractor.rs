@@ -0,0 +1,143 @@
+use std::collections::HashMap;
+
+use crate::lexer::tokenize::tokenize;
+use crate::shared::ast::blocks::function::{FunctionDeclarator, FunctionLinkage};
+use crate::shared::ast::package::ParserPackageStructure;
+use crate::shared::documentation::file_documentation::{FieldDocumentation, FileDocumentation, FunctionDocumentation, GroupDocumentation, LinkDocumentation};
+use crate::shared::token::container::ContainerType;
+use crate::shared::token::keyword::KeywordType;
+use crate::shared::token::token::{Token, TokenContent};
+
+/// The group a member belongs to (`None` for top-level declarations), the declaring keyword and the declared name
+type DeclarationKey = (Option<String>, KeywordType, String);
+
+/// Document declarations of `tree`, which is parsed from `source`.
+/// A block of line comments documents the declaration right below it, a blank line or any other token between them detaches it.
+pub fn document_source(file_path: &str, source: &str, tree: &ParserPackageStructure) -> FileDocumentation {
+    let (summary, comments) = collect_comments(source);
+    let comment_of = |group: Option<&str>, keyword: KeywordType, name: String| -> Vec<String> {
+        return comments.get(&(group.map(|g| g.to_string()), keyword, name)).cloned().unwrap_or_default();
+    };
+    let document_function = |group: Option<&str>, keyword: KeywordType, declarator: &FunctionDeclarator, linkage: FunctionLinkage| {
+        return FunctionDocumentation {
+            declarator: declarator.clone(),
+            linkage,
+            comment: comment_of(group, keyword, declarator.identifier.to_string()),
+        };
+    };
+
+    let groups = tree.declared_groups.iter().map(|group| {
+        let name = group.identifier.to_string();
+        return GroupDocumentation {
+            identifier: group.identifier.clone(),
+            comment: comment_of(None, KeywordType::KwGroup, name.clone()),
+            fields: group.fields.iter().map(|field| FieldDocumentation {
+                field: field.clone(),
+                comment: comment_of(Some(&name), KeywordType::KwField, field.identifier.to_string()),
+            }).collect(),
+            methods: group.methods.iter().map(|m| document_function(Some(&name), KeywordType::KwMethod, m, FunctionLinkage::Internal)).collect(),
+            functions: group.functions.iter().map(|f| document_function(Some(&name), KeywordType::KwFunc, f, FunctionLinkage::Internal)).collect(),
+        };
+    }).collect();
+
+    return FileDocumentation {
+        file_path: file_path.to_string(),
+        summary,
+        links: tree.linked_code_files.iter().map(|link| LinkDocumentation { link: link.clone(), target: None }).collect(),
+        functions: tree.functions.iter().map(|f| document_function(None, KeywordType::KwFunc, &f.declarator, f.linkage)).collect(),
+        groups,
+    };
+}
+
+/// The summary of the file and comments of every declaration found in tokens.
+/// Comments are only documentation, so a file which couldn't be lexed is documented without them.
+fn collect_comments(source: &str) -> (Vec<String>, HashMap<DeclarationKey, Vec<String>>) {
+    let tokens = tokenize(source, false).unwrap_or_default();
+    let mut summary = vec![];
+    let mut result = HashMap::new();
+
+    let mut pending: Vec<String> = vec![];
+    let mut is_file_head = true;
+    let mut depth = 0;
+    let mut group: Option<String> = None;
+    for (index, token) in tokens.iter().enumerate() {
+        match &token.content {
+            TokenContent::Comment(text) => {
+                pending.push(comment_line(text));
+                continue;
+            }
+            TokenContent::Whitespace(text) => {
+                if text.matches('\n').count() > 1 && !pending.is_empty() {
+                    if is_file_head && summary.is_empty() {
+                        summary = std::mem::take(&mut pending);
+                    }
+                    pending.clear();
+                }
+                continue;
+            }
+            _ => is_file_head = false,
+        }
+
+        let keyword = match &token.content {
+            TokenContent::Keyword(x) => Some(*x),
+            _ => None,
+        };
+        let key = match (keyword, &group, depth) {
+            // `decl` and `export` come before `func`, which takes the comment
+            (Some(KeywordType::KwDeclare | KeywordType::KwExport), _, _) => continue,
+            (Some(KeywordType::KwFunc | KeywordType::KwGroup), None, 0) => {
+                declared_name(&tokens, index, 1).map(|name| (None, keyword.unwrap(), name))
+            }
+            // field <type> <name>
+            (Some(KeywordType::KwField), Some(group), 1) => {
+                declared_name(&tokens, index, 2).map(|name| (Some(group.clone()), KeywordType::KwField, name))
+            }
+            (Some(KeywordType::KwMethod | KeywordType::KwFunc), Some(group), 1) => {
+                declared_name(&tokens, index, 1).map(|name| (Some(group.clone()), keyword.unwrap(), name))
+            }
+            _ => None,
+        };
+
+        if let Some(key) = key {
+            if key.1 == KeywordType::KwGroup {
+                group = Some(key.2.clone());
+            }
+            result.insert(key, std::mem::take(&mut pending));
+        }
+
+        match token.content {
+            TokenContent::Container(ContainerType::Brace) => depth += 1,
+            TokenContent::Container(ContainerType::AntiBrace) if depth > 0 => {
+                depth -= 1;
+                if depth == 0 {
+                    group = None;
+                }
+            }
+            _ => {}
+        }
+        pending.clear();
+    }
+
+    return (summary, result);
+}
+
+/// Text of the `nth` identifier-like token after the keyword at `index`, whitespaces and comments are skipped
+fn declared_name(tokens: &[Token], index: usize, nth: usize) -> Option<String> {
+    let token = tokens.iter()
+                      .skip(index + 1)
+                      .filter(|t| !matches!(t.content, TokenContent::Whitespace(_) | TokenContent::Comment(_)))
+                      .nth(nth - 1)?;
+
+    return match &token.content {
+        TokenContent::Identifier(name) => Some(name.clone()),
+        _ => None,
+    };
+}
+
+/// `/// text` and `// text` are both documented as `text`
+fn comment_line(text: &str) -> String {
+    let text = text.strip_prefix('/').unwrap_or(text);
+    let text = text.strip_prefix(' ').unwrap_or(text);
+
+    return text.trim_end().to_string();
+}
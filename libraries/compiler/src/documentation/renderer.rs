@@ -0,0 +1,261 @@
+use itertools::Itertools;
+
+use crate::shared::ast::blocks::function::FunctionLinkage;
+use crate::shared::ast::link::SourceFileLink;
+use crate::shared::documentation::file_documentation::{DocumentationFormat, FileDocumentation, FunctionDocumentation, GroupDocumentation};
+use crate::shared::utils::identifier::Identifier;
+
+/// Content of a page being written, text is escaped as the format requires
+struct Page {
+    format: DocumentationFormat,
+    output: String,
+}
+
+/// Name of the page documenting `file_path`.
+/// Directories are flattened, so every page is placed in the same directory and links between them are plain names.
+pub fn page_name(file_path: &str, format: DocumentationFormat) -> String {
+    let name = file_path.replace(['/', '\\', ':'], ".");
+    let extension = match format {
+        DocumentationFormat::Markdown => "md",
+        DocumentationFormat::Html => "html",
+    };
+
+    return format!("{}.{}", name.trim_start_matches('.'), extension);
+}
+
+/// The page linking every documented file, followed by the first line of its summary
+pub fn render_index(title: &str, files: &[FileDocumentation], format: DocumentationFormat) -> String {
+    let mut page = Page { format, output: String::new() };
+    page.heading(1, &page.text(title), None);
+
+    let items = files.iter().map(|file| {
+        let link = page.link(&page.code(&file.file_path), &page_name(&file.file_path, format));
+        return match file.summary.first() {
+            Some(line) => format!("{}: {}", link, page.text(line)),
+            None => link,
+        };
+    }).collect();
+    page.list(items);
+
+    return page.finish(title);
+}
+
+/// The page of every declaration in `file`, types declared as groups by any of `files` link to them
+pub fn render_file(file: &FileDocumentation, files: &[FileDocumentation], format: DocumentationFormat) -> String {
+    let mut page = Page { format, output: String::new() };
+    page.heading(1, &page.code(&file.file_path), None);
+    page.paragraphs(&file.summary);
+
+    if !file.links.is_empty() {
+        page.heading(2, "Linked files", None);
+        let items = file.links.iter().map(|link| {
+            let text = page.code(&link_to_string(&link.link));
+            return match &link.target {
+                Some(target) => page.link(&text, &page_name(target, format)),
+                None => text,
+            };
+        }).collect();
+        page.list(items);
+    }
+
+    if !file.functions.is_empty() {
+        page.heading(2, "Functions", None);
+        for function in &file.functions {
+            let signature = match function.linkage {
+                FunctionLinkage::Internal => format!("decl func {}", function.declarator),
+                FunctionLinkage::Export => format!("export decl func {}", function.declarator),
+                FunctionLinkage::External => format!("decl func {};", function.declarator),
+            };
+            let anchor = format!("func-{}", function.declarator.identifier);
+            page.function(3, &signature, function, &anchor, files);
+        }
+    }
+
+    if !file.groups.is_empty() {
+        page.heading(2, "Groups", None);
+        for group in &file.groups {
+            page.group(group, files);
+        }
+    }
+
+    return page.finish(&file.file_path);
+}
+
+impl Page {
+    fn group(&mut self, group: &GroupDocumentation, files: &[FileDocumentation]) {
+        let anchor = format!("group-{}", group.identifier);
+        self.heading(3, &self.code(&format!("group {}", group.identifier)), Some(&anchor));
+        self.paragraphs(&group.comment);
+
+        if !group.fields.is_empty() {
+            self.heading(4, "Fields", None);
+            let rows = group.fields.iter().map(|field| {
+                let access = match (field.field.has_get, field.field.has_set) {
+                    (true, true) => "get, set",
+                    (true, false) => "get",
+                    (false, true) => "set",
+                    (false, false) => "none",
+                };
+                return vec![
+                    self.code(&field.field.identifier.to_string()),
+                    self.type_reference(&field.field.data_type, files),
+                    self.text(access),
+                    self.text(&field.comment.join(" ")),
+                ];
+            }).collect();
+            self.table(&["Field", "Type", "Access", "Description"], rows);
+        }
+
+        for (title, keyword, members) in [("Methods", "method", &group.methods), ("Functions", "func", &group.functions)] {
+            if members.is_empty() {
+                continue;
+            }
+            self.heading(4, title, None);
+            for member in members {
+                let anchor = format!("{}-{}-{}", group.identifier, keyword, member.declarator.identifier);
+                self.function(5, &format!("{} {}", keyword, member.declarator), member, &anchor, files);
+            }
+        }
+    }
+
+    fn function(&mut self, level: usize, signature: &str, function: &FunctionDocumentation, anchor: &str, files: &[FileDocumentation]) {
+        self.heading(level, &self.code(&function.declarator.identifier.to_string()), Some(anchor));
+        self.code_block(signature);
+        self.paragraphs(&function.comment);
+
+        if !function.declarator.parameters.is_empty() {
+            let rows = function.declarator.parameters.iter().map(|p| {
+                vec![self.code(&p.identifier.to_string()), self.type_reference(&p.type_name, files)]
+            }).collect();
+            self.table(&["Parameter", "Type"], rows);
+        }
+
+        let returns = if function.declarator.return_type.name.is_empty() {
+            "Returns nothing.".to_string()
+        } else {
+            format!("Returns {}.", self.type_reference(&function.declarator.return_type, files))
+        };
+        self.paragraph(&returns);
+    }
+
+    /// A link to the group named `type_name` if it is documented, otherwise the name itself
+    fn type_reference(&self, type_name: &Identifier, files: &[FileDocumentation]) -> String {
+        let text = self.code(&type_name.to_string());
+        let file = files.iter().find(|f| f.groups.iter().any(|g| g.identifier == *type_name));
+
+        return match file {
+            Some(file) => self.link(&text, &format!("{}#group-{}", page_name(&file.file_path, self.format), type_name)),
+            None => text,
+        };
+    }
+
+    fn heading(&mut self, level: usize, content: &str, anchor: Option<&str>) {
+        match (self.format, anchor) {
+            (DocumentationFormat::Markdown, Some(anchor)) => {
+                self.output += &format!("<a id=\"{}\"></a>\n\n{} {}\n\n", escape_html(anchor), "#".repeat(level), content);
+            }
+            (DocumentationFormat::Markdown, None) => self.output += &format!("{} {}\n\n", "#".repeat(level), content),
+            (DocumentationFormat::Html, Some(anchor)) => {
+                self.output += &format!("<h{0} id=\"{1}\">{2}</h{0}>\n", level, escape_html(anchor), content);
+            }
+            (DocumentationFormat::Html, None) => self.output += &format!("<h{0}>{1}</h{0}>\n", level, content),
+        }
+    }
+
+    /// Comment lines, blank lines separate paragraphs
+    fn paragraphs(&mut self, lines: &[String]) {
+        for paragraph in lines.split(|l| l.trim().is_empty()).filter(|p| !p.is_empty()) {
+            // Markdown written in comments is kept as it is
+            let content = match self.format {
+                DocumentationFormat::Markdown => paragraph.join("\n"),
+                DocumentationFormat::Html => escape_html(&paragraph.join("\n")),
+            };
+            self.paragraph(&content);
+        }
+    }
+
+    fn paragraph(&mut self, content: &str) {
+        match self.format {
+            DocumentationFormat::Markdown => self.output += &format!("{}\n\n", content),
+            DocumentationFormat::Html => self.output += &format!("<p>{}</p>\n", content),
+        }
+    }
+
+    fn code_block(&mut self, code: &str) {
+        match self.format {
+            DocumentationFormat::Markdown => self.output += &format!("```\n{}\n```\n\n", code),
+            DocumentationFormat::Html => self.output += &format!("<pre><code>{}</code></pre>\n", escape_html(code)),
+        }
+    }
+
+    fn list(&mut self, items: Vec<String>) {
+        match self.format {
+            DocumentationFormat::Markdown => self.output += &format!("{}\n\n", items.iter().map(|i| format!("- {}", i)).join("\n")),
+            DocumentationFormat::Html => self.output += &format!("<ul>\n{}\n</ul>\n", items.iter().map(|i| format!("<li>{}</li>", i)).join("\n")),
+        }
+    }
+
+    fn table(&mut self, headers: &[&str], rows: Vec<Vec<String>>) {
+        match self.format {
+            DocumentationFormat::Markdown => {
+                let row = |cells: Vec<String>| format!("| {} |", cells.iter().map(|c| c.replace('|', "\\|")).join(" | "));
+                self.output += &row(headers.iter().map(|h| h.to_string()).collect());
+                self.output += &format!("\n|{}\n", " --- |".repeat(headers.len()));
+                self.output += &format!("{}\n\n", rows.into_iter().map(row).join("\n"));
+            }
+            DocumentationFormat::Html => {
+                let row = |tag: &str, cells: Vec<String>| format!("<tr>{}</tr>", cells.iter().map(|c| format!("<{0}>{1}</{0}>", tag, c)).join(""));
+                self.output += "<table>\n";
+                self.output += &format!("{}\n", row("th", headers.iter().map(|h| h.to_string()).collect()));
+                for cells in rows {
+                    self.output += &format!("{}\n", row("td", cells));
+                }
+                self.output += "</table>\n";
+            }
+        }
+    }
+
+    fn text(&self, text: &str) -> String {
+        return match self.format {
+            DocumentationFormat::Markdown => text.to_string(),
+            DocumentationFormat::Html => escape_html(text),
+        };
+    }
+
+    fn code(&self, code: &str) -> String {
+        return match self.format {
+            DocumentationFormat::Markdown => format!("`{}`", code),
+            DocumentationFormat::Html => format!("<code>{}</code>", escape_html(code)),
+        };
+    }
+
+    /// `content` is already formatted
+    fn link(&self, content: &str, target: &str) -> String {
+        return match self.format {
+            DocumentationFormat::Markdown => format!("[{}]({})", content, target.replace(' ', "%20")),
+            DocumentationFormat::Html => format!("<a href=\"{}\">{}</a>", escape_html(target), content),
+        };
+    }
+
+    fn finish(self, title: &str) -> String {
+        return match self.format {
+            DocumentationFormat::Markdown => self.output,
+            DocumentationFormat::Html => format!(
+                "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n</head>\n<body>\n{}</body>\n</html>\n",
+                escape_html(title),
+                self.output
+            ),
+        };
+    }
+}
+
+fn link_to_string(link: &SourceFileLink) -> String {
+    return match link {
+        SourceFileLink::SourceFile(path) => format!("link \"{}\";", path.display()),
+        SourceFileLink::Identifier(identifier) => format!("link {};", identifier),
+    };
+}
+
+fn escape_html(text: &str) -> String {
+    return text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;");
+}
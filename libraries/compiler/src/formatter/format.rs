@@ -0,0 +1,183 @@
+use crate::lexer::tokenize::tokenize;
+use crate::shared::error::general_issue::GeneralIssue;
+use crate::shared::error::lexical_analysis_issue::LexicalAnalysisIssue;
+use crate::shared::token::container::ContainerType;
+use crate::shared::token::keyword::KeywordType;
+use crate::shared::token::operator::{CalculationOperator, LogicalOperator, Operator};
+use crate::shared::token::token::{Token, TokenContent};
+
+const INDENT: &str = "    ";
+
+/// Layout state while writing tokens one by one
+struct Formatter<'a> {
+    source: &'a str,
+    output: String,
+    indent: usize,
+    // Nesting of `(` and `[`, statements are not split inside them
+    bracket_depth: usize,
+    // The last token which is not a whitespace or a comment
+    previous: Option<&'a Token>,
+    previous_is_unary: bool,
+    // Line breaks to be written before the next token, at most one blank line is kept
+    pending_breaks: usize,
+    // Line breaks between the previous token and the current one in the original source
+    source_breaks: usize,
+}
+
+/// Produce the canonical layout of a source file.
+/// Every block member is placed on its own line and indented by 4 spaces, comments and single blank lines are kept.
+/// The content of every token is written as it is, so the formatted source is lexed to the same tokens.
+pub fn format_source(source: &str) -> Result<String, GeneralIssue<LexicalAnalysisIssue>> {
+    let tokens = tokenize(source, false)?;
+
+    let mut formatter = Formatter {
+        source,
+        output: String::new(),
+        indent: 0,
+        bracket_depth: 0,
+        previous: None,
+        previous_is_unary: false,
+        pending_breaks: 0,
+        source_breaks: 0,
+    };
+
+    for token in &tokens {
+        match &token.content {
+            TokenContent::Whitespace(x) => formatter.source_breaks += x.matches('\n').count(),
+            TokenContent::Comment(_) => formatter.write_comment(token),
+            _ => formatter.write_token(token),
+        }
+    }
+
+    let mut result = formatter.output;
+    if !result.is_empty() {
+        result.push('\n');
+    }
+
+    return Ok(result);
+}
+
+impl<'a> Formatter<'a> {
+    fn write_token(&mut self, token: &'a Token) {
+        let previous = self.previous.map(|t| &t.content);
+        let content = &token.content;
+
+        if *content == TokenContent::Container(ContainerType::AntiBrace) {
+            self.indent = self.indent.saturating_sub(1);
+            self.pending_breaks = self.pending_breaks.max(1);
+        }
+        // `} elif`, `} else`
+        if previous == Some(&TokenContent::Container(ContainerType::AntiBrace))
+            && matches!(content, TokenContent::Keyword(KeywordType::KwElseIf) | TokenContent::Keyword(KeywordType::KwElse)) {
+            self.pending_breaks = 0;
+        }
+
+        if self.pending_breaks > 0 {
+            self.start_line(*content == TokenContent::Container(ContainerType::AntiBrace));
+        } else if self.previous.is_some() && self.needs_space(content) {
+            self.output.push(' ');
+        }
+
+        self.output.push_str(self.text(token));
+        self.previous_is_unary = self.is_unary(content);
+        self.previous = Some(token);
+        self.source_breaks = 0;
+
+        match content {
+            TokenContent::Container(ContainerType::Brace) => {
+                self.indent += 1;
+                self.pending_breaks = 1;
+            }
+            TokenContent::Container(ContainerType::AntiBrace) => {
+                // Top level declarations are separated by a blank line
+                self.pending_breaks = if self.indent == 0 { 2 } else { 1 };
+            }
+            TokenContent::Container(ContainerType::Bracket) | TokenContent::Container(ContainerType::Index) => self.bracket_depth += 1,
+            TokenContent::Container(ContainerType::AntiBracket) | TokenContent::Container(ContainerType::AntiIndex) => {
+                self.bracket_depth = self.bracket_depth.saturating_sub(1);
+            }
+            TokenContent::Semicolon if self.bracket_depth == 0 => self.pending_breaks = 1,
+            _ => {}
+        }
+    }
+
+    /// Comments following a token on the same line are kept there, others are placed on their own lines
+    fn write_comment(&mut self, token: &Token) {
+        let text = format!("//{}", self.text(token)[2..].trim_end());
+
+        if self.source_breaks == 0 && !self.output.is_empty() {
+            self.output.push(' ');
+        } else {
+            self.pending_breaks = self.pending_breaks.max(1);
+            self.start_line(false);
+        }
+
+        self.output.push_str(text.as_str());
+        self.pending_breaks = self.pending_breaks.max(1);
+        self.source_breaks = 0;
+    }
+
+    /// Write pending line breaks and the indentation of the new line
+    fn start_line(&mut self, closes_block: bool) {
+        if self.output.is_empty() {
+            self.pending_breaks = 0;
+            return;
+        }
+
+        // Blank lines in the source are kept, except at the beginning and the end of blocks
+        let mut breaks = self.pending_breaks.max(self.source_breaks.min(2));
+        let opens_block = self.previous.is_some_and(|t| t.content == TokenContent::Container(ContainerType::Brace));
+        if opens_block || closes_block {
+            breaks = breaks.min(1);
+        }
+
+        for _ in 0..breaks {
+            self.output.push('\n');
+        }
+        self.output.push_str(INDENT.repeat(self.indent).as_str());
+        self.pending_breaks = 0;
+    }
+
+    fn needs_space(&self, content: &TokenContent) -> bool {
+        let previous = &self.previous.unwrap().content;
+
+        if self.previous_is_unary {
+            return false;
+        }
+
+        return match (previous, content) {
+            (_, TokenContent::Semicolon) => false,
+            (_, TokenContent::Operator(Operator::Comma)) => false,
+            (_, TokenContent::Container(ContainerType::AntiBracket | ContainerType::AntiIndex)) => false,
+            (TokenContent::Container(ContainerType::Bracket | ContainerType::Index), _) => false,
+            (TokenContent::Operator(Operator::Dot | Operator::Scope), _) => false,
+            (_, TokenContent::Operator(Operator::Dot | Operator::Scope)) => false,
+            // Calls, declarations and return types: `foo(x)`, `main()[number]`
+            (TokenContent::Identifier(_), TokenContent::Container(ContainerType::Bracket | ContainerType::Index)) => false,
+            (TokenContent::Container(ContainerType::AntiBracket | ContainerType::AntiIndex), TokenContent::Container(ContainerType::Bracket | ContainerType::Index)) => false,
+            _ => true,
+        };
+    }
+
+    /// `!` is always unary, `-` is unary if there is no operand before it
+    fn is_unary(&self, content: &TokenContent) -> bool {
+        let is_operand_end = |previous: Option<&Token>| -> bool {
+            return matches!(
+                previous.map(|t| &t.content),
+                Some(TokenContent::Identifier(_) | TokenContent::Number(_) | TokenContent::String(_))
+                    | Some(TokenContent::Container(ContainerType::AntiBracket | ContainerType::AntiIndex))
+                    | Some(TokenContent::Keyword(KeywordType::KwTrue | KeywordType::KwFalse | KeywordType::KwSelf))
+            );
+        };
+
+        return match content {
+            TokenContent::Operator(Operator::Logical(LogicalOperator::Not)) => true,
+            TokenContent::Operator(Operator::Calculation(CalculationOperator::Subtraction)) => !is_operand_end(self.previous),
+            _ => false,
+        };
+    }
+
+    fn text(&self, token: &Token) -> &'a str {
+        return &self.source[token.position.start..(token.position.start + token.position.length).min(self.source.len())];
+    }
+}
@@ -98,14 +98,16 @@ pub fn match_logical_operator(content: &str, base_pos: usize) -> Token {
     return Token::new_invalid();
 }
 
+/// The longest operator is matched, so that `>=` isn't taken as `>`
 pub fn match_relation_operator(content: &str, base_pos: usize) -> Token {
-    for (&operator, operator_str) in RELATION_OPERATOR.iter() {
-        if content.starts_with(operator_str) {
-            return Token::new(
-                TokenContent::Operator(Operator::Relation(operator)),
-                Position::new(base_pos, operator_str.len()),
-            );
-        }
+    let matched = RELATION_OPERATOR.iter()
+                                   .filter(|(_, operator_str)| content.starts_with(*operator_str))
+                                   .max_by_key(|(_, operator_str)| operator_str.len());
+    if let Some((&operator, operator_str)) = matched {
+        return Token::new(
+            TokenContent::Operator(Operator::Relation(operator)),
+            Position::new(base_pos, operator_str.len()),
+        );
     }
 
     return Token::new_invalid();
@@ -1,2 +1,2 @@
-mod lex_rules;
+pub(crate) mod lex_rules;
 pub mod tokenize;
@@ -7,7 +7,7 @@ use crate::lexer::lex_rules::operator::match_operator;
 use crate::lexer::lex_rules::semicolon::match_semicolon;
 use crate::lexer::lex_rules::space::match_spaces;
 use crate::lexer::lex_rules::string::match_string;
-use crate::shared::error::general_issue::{FileMatch, GeneralIssue, IssueBase, IssueLevel, IssuePosition};
+use crate::shared::error::general_issue::{FileMatch, GeneralIssue, IssueBase, IssueLevel, IssuePosition, UNKNOWN_FILE_PATH};
 use crate::shared::error::lexical_analysis_issue::LexicalAnalysisIssue;
 use crate::shared::token::token::Token;
 
@@ -49,9 +49,9 @@ pub fn tokenize(source_code: &str, remove_unnecessary_token: bool) -> Result<Vec
                     code: "0001".to_string(),
                     detail: LexicalAnalysisIssue {
                         location: FileMatch {
-                            file_path: "N/A".to_string(),
+                            file_path: UNKNOWN_FILE_PATH.to_string(),
                             start_pos: error_start_index,
-                            end_pos: index - 1,
+                            end_pos: index,
                         }
                     },
                 });
@@ -72,9 +72,9 @@ pub fn tokenize(source_code: &str, remove_unnecessary_token: bool) -> Result<Vec
                     code: "0001".to_string(),
                     detail: LexicalAnalysisIssue {
                         location: FileMatch {
-                            file_path: "N/A".to_string(),
+                            file_path: UNKNOWN_FILE_PATH.to_string(),
                             start_pos: error_start_index,
-                            end_pos: index - 1,
+                            end_pos: index,
                         }
                     },
                 });
@@ -95,9 +95,9 @@ pub fn tokenize(source_code: &str, remove_unnecessary_token: bool) -> Result<Vec
                     code: "0001".to_string(),
                     detail: LexicalAnalysisIssue {
                         location: FileMatch {
-                            file_path: "N/A".to_string(),
+                            file_path: UNKNOWN_FILE_PATH.to_string(),
                             start_pos: error_start_index,
-                            end_pos: index - 1,
+                            end_pos: index,
                         }
                     },
                 });
@@ -118,9 +118,9 @@ pub fn tokenize(source_code: &str, remove_unnecessary_token: bool) -> Result<Vec
                     code: "0001".to_string(),
                     detail: LexicalAnalysisIssue {
                         location: FileMatch {
-                            file_path: "N/A".to_string(),
+                            file_path: UNKNOWN_FILE_PATH.to_string(),
                             start_pos: error_start_index,
-                            end_pos: index - 1,
+                            end_pos: index,
                         }
                     },
                 });
@@ -141,9 +141,9 @@ pub fn tokenize(source_code: &str, remove_unnecessary_token: bool) -> Result<Vec
                     code: "0001".to_string(),
                     detail: LexicalAnalysisIssue {
                         location: FileMatch {
-                            file_path: "N/A".to_string(),
+                            file_path: UNKNOWN_FILE_PATH.to_string(),
                             start_pos: error_start_index,
-                            end_pos: index - 1,
+                            end_pos: index,
                         }
                     },
                 });
@@ -164,9 +164,9 @@ pub fn tokenize(source_code: &str, remove_unnecessary_token: bool) -> Result<Vec
                     code: "0001".to_string(),
                     detail: LexicalAnalysisIssue {
                         location: FileMatch {
-                            file_path: "N/A".to_string(),
+                            file_path: UNKNOWN_FILE_PATH.to_string(),
                             start_pos: error_start_index,
-                            end_pos: index - 1,
+                            end_pos: index,
                         }
                     },
                 });
@@ -187,9 +187,9 @@ pub fn tokenize(source_code: &str, remove_unnecessary_token: bool) -> Result<Vec
                     code: "0001".to_string(),
                     detail: LexicalAnalysisIssue {
                         location: FileMatch {
-                            file_path: "N/A".to_string(),
+                            file_path: UNKNOWN_FILE_PATH.to_string(),
                             start_pos: error_start_index,
-                            end_pos: index - 1,
+                            end_pos: index,
                         }
                     },
                 });
@@ -210,9 +210,9 @@ pub fn tokenize(source_code: &str, remove_unnecessary_token: bool) -> Result<Vec
                     code: "0001".to_string(),
                     detail: LexicalAnalysisIssue {
                         location: FileMatch {
-                            file_path: "N/A".to_string(),
+                            file_path: UNKNOWN_FILE_PATH.to_string(),
                             start_pos: error_start_index,
-                            end_pos: index - 1,
+                            end_pos: index,
                         }
                     },
                 });
@@ -233,9 +233,9 @@ pub fn tokenize(source_code: &str, remove_unnecessary_token: bool) -> Result<Vec
                     code: "0001".to_string(),
                     detail: LexicalAnalysisIssue {
                         location: FileMatch {
-                            file_path: "N/A".to_string(),
+                            file_path: UNKNOWN_FILE_PATH.to_string(),
                             start_pos: error_start_index,
-                            end_pos: index - 1,
+                            end_pos: index,
                         }
                     },
                 });
@@ -251,6 +251,24 @@ pub fn tokenize(source_code: &str, remove_unnecessary_token: bool) -> Result<Vec
             errored = true;
             error_start_index = index;
         }
+        // Skip the unrecognized character, then try again from the next one
+        index += source_code[index..].chars().next().map_or(1, |c| c.len_utf8());
+    }
+
+    // The source ends with unrecognized characters
+    if errored {
+        error_list.push(IssueBase {
+            level: IssueLevel::Error,
+            position: IssuePosition::LexicalAnalysis,
+            code: "0001".to_string(),
+            detail: LexicalAnalysisIssue {
+                location: FileMatch {
+                    file_path: UNKNOWN_FILE_PATH.to_string(),
+                    start_pos: error_start_index,
+                    end_pos: index,
+                }
+            },
+        });
     }
 
     if remove_unnecessary_token {
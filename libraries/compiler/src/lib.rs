@@ -1,6 +1,13 @@
+pub mod analysis;
+pub mod assembler;
+pub mod documentation;
+pub mod formatter;
 pub mod lexer;
+pub mod linker;
 pub mod package_generator;
+pub mod package_reader;
 pub mod parser;
+pub mod session;
 pub mod shared;
 
 #[cfg(test)]
@@ -0,0 +1,3 @@
+pub mod object_linker;
+pub mod package_linker;
+pub mod utils;
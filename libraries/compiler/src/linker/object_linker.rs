@@ -0,0 +1,165 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::linker::utils::{fits_in, linking_issue, placing_issues};
+use crate::package_generator::package_builder::build_package_from_commands;
+use crate::package_generator::utils::align_array_width;
+use crate::package_reader::instruction_decoder::decode_instructions;
+use crate::shared::ast::blocks::function::FunctionLinkage;
+use crate::shared::error::general_issue::GeneralIssue;
+use crate::shared::error::linking_issue::LinkingIssue;
+use crate::shared::error::package_reading_issue::PackageReadingIssue;
+use crate::shared::package_generation::data_descriptor::StringConstant;
+use crate::shared::package_generation::func_table::FunctionTableEntry;
+use crate::shared::package_generation::implementations::package_descriptor::LIBRARY_PACKAGE_TYPE;
+use crate::shared::package_generation::object_file::ObjectFile;
+use crate::shared::package_generation::package_descriptor::PackageMetadata;
+use crate::shared::package_generation::relocation_reference::{RelocatableCommandList, RelocationTargetElement};
+use crate::shared::package_reading::instruction::{DataAccess, Instruction};
+use crate::shared::utils::identifier::Identifier;
+
+/// Merge objects into a package described by `metadata`, whose entry is `entry_point` if it is an executable.
+/// `objects` are pairs of names, which are used in issues, and objects.
+///
+/// Commands of every object are placed one after another and their string slots are rebased,
+/// calls are bound to functions defined in any object by name.
+/// Libraries keep external functions which no object defines, while executables must define every called function.
+pub fn link_objects(objects: &[(String, ObjectFile)], entry_point: &str, metadata: &PackageMetadata) -> Result<Vec<u8>, GeneralIssue<LinkingIssue>> {
+    let mut issues = vec![];
+    let is_library = metadata.package_type == LIBRARY_PACKAGE_TYPE;
+
+    if objects.is_empty() {
+        issues.push(linking_issue("No object to link", None));
+    }
+    for (name, object) in objects {
+        if object.metadata.serialize()[1..] != metadata.serialize()[1..] {
+            issues.push(linking_issue("Alignments are different from the package", Some(name)));
+        }
+    }
+
+    // Defined function -> the object defining it
+    let mut definitions: HashMap<String, &String> = HashMap::new();
+    let mut external_functions: Vec<Identifier> = vec![];
+    for (name, object) in objects {
+        for function in &object.commands.function_table {
+            if function.linkage == FunctionLinkage::External {
+                if !external_functions.contains(&function.name) {
+                    external_functions.push(function.name.clone());
+                }
+            } else if let Some(definer) = definitions.insert(function.name.to_string(), name) {
+                issues.push(linking_issue(format!("Function \"{}\" is defined by \"{}\" as well", function.name, definer).as_str(), Some(name)));
+            }
+        }
+    }
+    external_functions.retain(|f| !definitions.contains_key(&f.to_string()));
+
+    let mut undefined_functions = HashSet::new();
+    for (name, object) in objects {
+        let mut required: Vec<&Identifier> = object.commands.function_table.iter().filter(|f| f.linkage == FunctionLinkage::External).map(|f| &f.name).collect();
+        for target in &object.commands.descriptors.targets {
+            if let Some(RelocationTargetElement::EnterFunction(id)) = target.relocation_elements.first() {
+                required.push(id);
+            }
+        }
+
+        for id in required {
+            let resolved = definitions.contains_key(&id.to_string()) || (is_library && external_functions.contains(id));
+            // Every missing function is reported once
+            if !resolved && undefined_functions.insert(id.to_string()) {
+                issues.push(linking_issue(format!("Function \"{}\" is not defined in any object", id).as_str(), Some(name)));
+            }
+        }
+    }
+    if !is_library && !definitions.contains_key(entry_point) {
+        issues.push(linking_issue(format!("Entry function \"{}\" is not defined in any object", entry_point).as_str(), None));
+    }
+    if !issues.is_empty() {
+        return Err(GeneralIssue { issues });
+    }
+
+    // Rebased string slots are written over the original ones, so all of them should fit before rebasing
+    let string_count: usize = objects.iter().map(|(_, object)| object.commands.string_pool.len()).sum();
+    if !fits_in(string_count, metadata.data_slot_alignment) {
+        return Err(GeneralIssue { issues: vec![linking_issue("Too many string constants for the data slot alignment", None)] });
+    }
+
+    let mut output = RelocatableCommandList::new();
+    for (name, object) in objects {
+        let string_base = output.string_pool.len();
+        output.string_pool.extend(object.commands.string_pool.iter().map(|s| StringConstant { value: s.value.clone(), slot: s.slot + string_base }));
+
+        let mut commands = object.commands.clone();
+        if let Err(e) = rebase_string_slots(&mut commands, string_base, metadata) {
+            for issue in e.issues {
+                issues.push(linking_issue(format!("Invalid object: {}", issue.detail).as_str(), Some(name)));
+            }
+            continue;
+        }
+
+        // Targets and references are rebased by `combine`, entries of the function table are rebased here
+        let command_base = output.commands.len();
+        for function in commands.function_table.iter().filter(|f| f.linkage != FunctionLinkage::External) {
+            output.function_table.push(FunctionTableEntry {
+                slot: output.function_table.len(),
+                name: function.name.clone(),
+                relocated_entry_address: function.relocated_entry_address + command_base,
+                linkage: if is_library { function.linkage } else { FunctionLinkage::Internal },
+            });
+        }
+        output.combine(commands);
+    }
+
+    // External functions only take slots, the package linker binds them later
+    for function in external_functions.iter().filter(|_| is_library) {
+        output.function_table.push(FunctionTableEntry {
+            slot: output.function_table.len(),
+            name: function.clone(),
+            relocated_entry_address: 0,
+            linkage: FunctionLinkage::External,
+        });
+    }
+
+    if !fits_in(output.function_table.len(), metadata.address_alignment) {
+        issues.push(linking_issue("Too many functions for the address alignment", None));
+    }
+    if !issues.is_empty() {
+        return Err(GeneralIssue { issues });
+    }
+
+    return build_package_from_commands(output, &Identifier::from_string(entry_point), metadata).map_err(placing_issues);
+}
+
+/// String slots are written into commands directly, so they are found by decoding the commands.
+/// Addresses of relocation targets are still placeholders, they are filled with zero offsets in a copy before decoding.
+fn rebase_string_slots(
+    commands: &mut RelocatableCommandList,
+    string_base: usize,
+    metadata: &PackageMetadata,
+) -> Result<(), GeneralIssue<PackageReadingIssue>> {
+    if string_base == 0 {
+        return Ok(());
+    }
+
+    let mut decodable = commands.commands.clone();
+    for target in &commands.descriptors.targets {
+        let sign_position = target.command_array_position + target.offset as usize;
+        if sign_position < decodable.len() {
+            decodable[sign_position] = 0x0F;
+        }
+    }
+
+    for item in decode_instructions(&decodable, 0, decodable.len(), metadata)? {
+        match &item.instruction {
+            Instruction::Push(DataAccess::StringSlot(slot))
+            | Instruction::PushFromObject(DataAccess::StringSlot(slot))
+            | Instruction::PopToObject(DataAccess::StringSlot(slot)) => {
+                // Layout: `<command> 0x02 <slot>`
+                let slot_bytes = align_array_width(&(slot + string_base).to_be_bytes().to_vec(), metadata.data_slot_alignment);
+                let begin_pos = item.position + 2;
+                commands.commands.splice(begin_pos..(begin_pos + slot_bytes.len()), slot_bytes);
+            }
+            _ => {}
+        }
+    }
+
+    return Ok(());
+}
@@ -0,0 +1,193 @@
+use std::collections::HashMap;
+
+use crate::linker::utils::{fits_in, linking_issue, placing_issues};
+use crate::package_generator::package_builder::build_package_from_commands;
+use crate::package_generator::utils::align_array_width;
+use crate::package_reader::instruction_decoder::decode_instructions;
+use crate::package_reader::layout_reader::read_package_layout;
+use crate::shared::ast::blocks::function::FunctionLinkage;
+use crate::shared::error::general_issue::{GeneralIssue, IssueBase};
+use crate::shared::error::linking_issue::LinkingIssue;
+use crate::shared::package_generation::data_descriptor::StringConstant;
+use crate::shared::package_generation::func_table::FunctionTableEntry;
+use crate::shared::package_generation::implementations::package_descriptor::{EXECUTABLE_PACKAGE_TYPE, LIBRARY_PACKAGE_TYPE};
+use crate::shared::package_generation::relocation_reference::{RelocatableCommandList, RelocationReference, RelocationReferenceType, RelocationTarget, RelocationTargetElement};
+use crate::shared::package_reading::instruction::{DataAccess, Instruction};
+use crate::shared::package_reading::package_layout::PackageLayout;
+use crate::shared::utils::identifier::Identifier;
+
+/// Merge library packages into one executable whose entry is the exported function `entry_point`.
+/// `packages` are pairs of names, which are used in issues, and package bytes.
+///
+/// Functions and string constants of every package are placed one after another,
+/// so their slots are rebased, calls of external functions are bound to the exported ones by name.
+/// All packages must share the same alignments.
+pub fn link_packages(packages: &[(String, Vec<u8>)], entry_point: &str) -> Result<Vec<u8>, GeneralIssue<LinkingIssue>> {
+    let mut issues = vec![];
+
+    let mut layouts: Vec<(&String, PackageLayout)> = vec![];
+    for (name, bytes) in packages {
+        match read_package_layout(bytes) {
+            Ok(layout) if layout.metadata.package_type != LIBRARY_PACKAGE_TYPE => {
+                issues.push(linking_issue("Only library packages could be linked", Some(name)));
+            }
+            Ok(layout) => layouts.push((name, layout)),
+            Err(e) => {
+                for issue in e.issues {
+                    issues.push(linking_issue(format!("Invalid package: {}", issue.detail).as_str(), Some(name)));
+                }
+            }
+        }
+    }
+    if layouts.is_empty() && issues.is_empty() {
+        issues.push(linking_issue("No package to link", None));
+    }
+    if !issues.is_empty() {
+        return Err(GeneralIssue { issues });
+    }
+
+    let mut metadata = layouts[0].1.metadata.clone();
+    for (name, layout) in &layouts[1..] {
+        if layout.metadata.serialize()[1..] != metadata.serialize()[1..] {
+            issues.push(linking_issue(format!("Alignments are different from \"{}\"", layouts[0].0).as_str(), Some(name)));
+        }
+    }
+
+    // Exported symbol -> the package exporting it
+    let mut exports: HashMap<&str, &String> = HashMap::new();
+    for (name, layout) in &layouts {
+        for symbol in layout.symbols.iter().filter(|s| s.linkage == FunctionLinkage::Export) {
+            if let Some(exporter) = exports.insert(symbol.name.as_str(), name) {
+                issues.push(linking_issue(format!("Symbol \"{}\" is exported by \"{}\" as well", symbol.name, exporter).as_str(), Some(name)));
+            }
+        }
+    }
+    for (name, layout) in &layouts {
+        for symbol in layout.symbols.iter().filter(|s| s.linkage == FunctionLinkage::External) {
+            if !exports.contains_key(symbol.name.as_str()) {
+                issues.push(linking_issue(format!("Symbol \"{}\" is not exported by any package", symbol.name).as_str(), Some(name)));
+            }
+        }
+    }
+    if !exports.contains_key(entry_point) {
+        issues.push(linking_issue(format!("Entry function \"{}\" is not exported by any package", entry_point).as_str(), None));
+    }
+    if !issues.is_empty() {
+        return Err(GeneralIssue { issues });
+    }
+
+    // Rebased string slots are written over the original ones, so all of them should fit before rebasing
+    let string_count: usize = layouts.iter().map(|(_, layout)| layout.string_pool.len()).sum();
+    if !fits_in(string_count, metadata.data_slot_alignment) {
+        return Err(GeneralIssue { issues: vec![linking_issue("Too many string constants for the data slot alignment", None)] });
+    }
+
+    let mut output = RelocatableCommandList::new();
+    for (index, (name, layout)) in layouts.iter().enumerate() {
+        let string_base = output.string_pool.len();
+        output.string_pool.extend(layout.string_pool.iter().map(|s| StringConstant { value: s.value.clone(), slot: s.slot + string_base }));
+
+        let commands = package_commands(index, name, layout, string_base, &mut issues);
+        if commands.is_none() {
+            continue;
+        }
+        let commands = commands.unwrap();
+
+        // Targets and references are rebased by `combine`, entries of the function table are rebased here
+        let command_base = output.commands.len();
+        for function in commands.function_table.iter() {
+            output.function_table.push(FunctionTableEntry {
+                slot: output.function_table.len(),
+                name: function.name.clone(),
+                relocated_entry_address: function.relocated_entry_address + command_base,
+                linkage: FunctionLinkage::Internal,
+            });
+        }
+        output.combine(commands);
+    }
+
+    if !fits_in(output.function_table.len(), metadata.address_alignment) {
+        issues.push(linking_issue("Too many functions for the address alignment", None));
+    }
+    if !issues.is_empty() {
+        return Err(GeneralIssue { issues });
+    }
+
+    metadata.package_type = EXECUTABLE_PACKAGE_TYPE;
+    return build_package_from_commands(output, &Identifier::from_string(entry_point), &metadata).map_err(placing_issues);
+}
+
+/// Turn function commands of a package back into a relocatable list.
+/// String slots are rebased in place, every call becomes a relocation target naming the called function,
+/// exported functions keep their symbol names while others are named after their package and slot.
+fn package_commands(
+    index: usize,
+    name: &str,
+    layout: &PackageLayout,
+    string_base: usize,
+    issues: &mut Vec<IssueBase<LinkingIssue>>,
+) -> Option<RelocatableCommandList> {
+    let metadata = &layout.metadata;
+
+    let mut function_names: HashMap<usize, Identifier> = layout.function_table
+                                                               .iter()
+                                                               .map(|f| (f.slot, Identifier { name: format!("fn_{}", f.slot), scope: vec![format!("#{}", index)] }))
+                                                               .collect();
+    for symbol in &layout.symbols {
+        function_names.insert(symbol.slot, Identifier::from_string(symbol.name.as_str()));
+    }
+
+    let instructions = decode_instructions(&layout.commands, layout.code_offset, layout.commands.len(), metadata);
+    if instructions.is_err() {
+        for issue in instructions.unwrap_err().issues {
+            issues.push(linking_issue(format!("Invalid package: {}", issue.detail).as_str(), Some(name)));
+        }
+        return None;
+    }
+
+    let mut result = RelocatableCommandList::new();
+    result.commands = layout.commands[layout.code_offset..].to_vec();
+
+    for function in &layout.function_table {
+        let identifier = function_names[&function.slot].clone();
+        let position = function.entry_address - layout.code_offset;
+        result.function_table.push(FunctionTableEntry {
+            slot: function.slot,
+            name: identifier.clone(),
+            relocated_entry_address: position,
+            linkage: FunctionLinkage::Internal,
+        });
+        result.descriptors.references.push(RelocationReference { ref_type: RelocationReferenceType::FunctionEntrance(identifier), command_array_position: position });
+    }
+
+    for item in instructions.unwrap() {
+        let position = item.position - layout.code_offset;
+        match &item.instruction {
+            Instruction::Push(DataAccess::StringSlot(slot))
+            | Instruction::PushFromObject(DataAccess::StringSlot(slot))
+            | Instruction::PopToObject(DataAccess::StringSlot(slot)) => {
+                if *slot >= layout.string_pool.len() {
+                    issues.push(linking_issue(format!("String slot {} is used but not defined", slot).as_str(), Some(name)));
+                    continue;
+                }
+
+                // Layout: `<command> 0x02 <slot>`
+                let slot_bytes = align_array_width(&(slot + string_base).to_be_bytes().to_vec(), metadata.data_slot_alignment);
+                let begin_pos = position + 2;
+                result.commands.splice(begin_pos..(begin_pos + slot_bytes.len()), slot_bytes);
+            }
+            Instruction::EnterFunction { slot, .. } => match function_names.get(slot) {
+                Some(identifier) => result.descriptors.targets.push(RelocationTarget {
+                    relocation_elements: vec![RelocationTargetElement::EnterFunction(identifier.clone())],
+                    command_array_position: position,
+                    offset: 1,
+                    relocated_address: 0,
+                }),
+                None => issues.push(linking_issue(format!("Function slot {} is called but not defined", slot).as_str(), Some(name))),
+            },
+            _ => {}
+        }
+    }
+
+    return Some(result);
+}
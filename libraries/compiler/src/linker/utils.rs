@@ -0,0 +1,22 @@
+use crate::shared::error::general_issue::{GeneralIssue, IssueBase, IssueLevel, IssuePosition};
+use crate::shared::error::linking_issue::LinkingIssue;
+use crate::shared::error::pkg_gen_issue::PackageGenerationIssue;
+
+/// Whether `count` slots could be addressed by numbers `width` bytes wide
+pub fn fits_in(count: usize, width: u8) -> bool {
+    return width >= 8 || (count as u64) < (1_u64 << (width as u32 * 8));
+}
+
+pub fn linking_issue(content: &str, package: Option<&str>) -> IssueBase<LinkingIssue> {
+    return IssueBase {
+        level: IssueLevel::Error,
+        position: IssuePosition::Linking,
+        code: "-1".to_string(),
+        detail: LinkingIssue { content: content.to_string(), package: package.map(|p| p.to_string()) },
+    };
+}
+
+/// Issues of placing the linked commands into a package, which belong to none of the linked packages
+pub fn placing_issues(issues: GeneralIssue<PackageGenerationIssue>) -> GeneralIssue<LinkingIssue> {
+    return GeneralIssue { issues: issues.issues.iter().map(|i| linking_issue(i.detail.content.as_str(), None)).collect() };
+}
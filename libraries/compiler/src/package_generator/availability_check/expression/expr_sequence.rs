@@ -1,43 +1,29 @@
 // TODO: Move this step to compiler/parser, check expression sequence right after ExpressionBuilder
 
-use crate::shared::ast::blocks::expression::{ExprTerm, SimpleExpression, TermContent};
+use crate::shared::ast::blocks::expression::{SimpleExpression, TermContent};
+use crate::shared::token::operator::{CalculationOperator, LogicalOperator, Operator};
 
+/// Every operator should find its operands on the stack when the postfix expression is evaluated,
+/// and only the result is left at the end. `!` and negations take one operand and other operators take two.
 pub fn check_expression_sequence(expression: SimpleExpression) -> bool {
-    let mut expr_sequence = expression.postfix_expr.clone();
-    while !(expr_sequence.len() == 1 && expr_sequence[0].content == TermContent::Validated) {
-        // Become true if current loop processed something
-        let mut turn_processed = false;
-        for index in 0..(expr_sequence.len() - 2) {
-            if is_valid_data_term(expr_sequence[index].clone())
-                && is_valid_data_term(expr_sequence[index + 1].clone())
-                && expr_sequence[index + 2].content.get_operator().is_some()
-            {
-                // Remove 3 elements from current index
-                expr_sequence.remove(index);
-                expr_sequence.remove(index);
-                expr_sequence.remove(index);
-
-                expr_sequence.insert(
-                    index,
-                    ExprTerm {
-                        content: TermContent::Validated,
-                        original_token: vec![],
-                    },
-                );
-
-                turn_processed = true;
-                break;
+    let mut stack_depth: usize = 0;
+    for term in &expression.postfix_expr {
+        let operand_count = match term.content {
+            TermContent::Data(_) | TermContent::Validated => {
+                stack_depth += 1;
+                continue;
             }
-        }
+            TermContent::Operator(Operator::Logical(LogicalOperator::Not) | Operator::Calculation(CalculationOperator::Negation)) => 1,
+            TermContent::Operator(_) => 2,
+            TermContent::Priority(_) => return false,
+        };
 
-        if !turn_processed {
+        if stack_depth < operand_count {
             return false;
         }
+        // The result is pushed back
+        stack_depth -= operand_count - 1;
     }
 
-    return true;
-}
-
-fn is_valid_data_term(term: ExprTerm) -> bool {
-    return term.content == TermContent::Validated || term.content.get_data_term().is_some();
+    return stack_depth == 1;
 }
@@ -1,3 +1,4 @@
 pub mod expression;
 pub mod function;
+pub mod package_check;
 pub mod variable;
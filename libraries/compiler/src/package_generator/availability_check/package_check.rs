@@ -0,0 +1,332 @@
+use crate::package_generator::availability_check::expression::expr_sequence::check_expression_sequence;
+use crate::package_generator::availability_check::function::existence::check_function_existence;
+use crate::package_generator::availability_check::function::parameter::check_function_parameter_types;
+use crate::package_generator::availability_check::function::return_type::check_function_return_type;
+use crate::package_generator::availability_check::variable::assignment::check_variable_assignment;
+use crate::package_generator::availability_check::variable::definition::check_variable_definition;
+use crate::package_generator::availability_check::variable::existence::check_variable_existence_by_name;
+use crate::package_generator::type_inference::expression::{infer_expression_output_type, infer_expression_term_data_type};
+use crate::package_generator::utils::{find_function, infer_every_expression_data_term_type};
+use crate::shared::ast::action::{Action, ActionContent, AssignmentAction, CallAction, DeclarationAction, VariableDefinition};
+use crate::shared::ast::blocks::expression::{ExprDataTerm, ExprNode, SimpleExpression};
+use crate::shared::ast::blocks::function::Function;
+use crate::shared::ast::package::ParserPackageStructure;
+use crate::shared::error::general_issue::{FileMatch, GeneralIssue, IssueBase, IssueLevel, IssuePosition};
+use crate::shared::error::pkg_gen_issue::PackageGenerationIssue;
+use crate::shared::token::operator::Operator;
+use crate::shared::token::token::Token;
+use crate::shared::utils::identifier::Identifier;
+
+/// Shared state while checking the body of one function
+struct CheckContext<'a> {
+    functions: &'a Vec<Function>,
+    current_function: &'a Function,
+    defined_types: Vec<Identifier>,
+    issues: Vec<IssueBase<PackageGenerationIssue>>,
+}
+
+/// Types provided by the compiler
+pub fn compiler_defined_types() -> Vec<Identifier> {
+    return vec![
+        Identifier::single("number"),
+        Identifier::single("str"),
+        Identifier::single("char"),
+        Identifier::single("bool"),
+    ];
+}
+
+/// Run every availability check on a parsed package without generating any command.
+/// All problems are collected instead of stopping at the first one.
+pub fn check_package(package: &ParserPackageStructure) -> Result<(), GeneralIssue<PackageGenerationIssue>> {
+    let mut issues = vec![];
+
+    for func in &package.functions {
+        let mut context = CheckContext {
+            functions: &package.functions,
+            current_function: func,
+            defined_types: compiler_defined_types(),
+            issues: vec![],
+        };
+        check_function(&mut context);
+        issues.extend(context.issues);
+    }
+
+    if !issues.is_empty() {
+        return Err(GeneralIssue { issues });
+    }
+
+    return Ok(());
+}
+
+fn check_function(context: &mut CheckContext) {
+    let func = context.current_function;
+
+    // `none` is stored as an empty identifier
+    let mut return_types = context.defined_types.clone();
+    return_types.push(Identifier::empty());
+    if !check_function_return_type(func, &return_types) {
+        context.report("0101", format!("Return type \"{}\" of function \"{}\" is not defined", func.declarator.return_type, func.declarator.identifier), &vec![]);
+    }
+
+    // Parameters are defined in the same way as variables
+    let mut defined_variables: Vec<VariableDefinition> = vec![];
+    for param in &func.declarator.parameters {
+        let declaration = DeclarationAction {
+            is_variable: true,
+            identifier: param.identifier.clone(),
+            data_type: param.type_name.clone(),
+        };
+        if check_declaration(context, &declaration, &defined_variables, &vec![]) {
+            defined_variables.push(param.clone());
+        }
+    }
+
+    check_action_block(context, &func.body, defined_variables, false);
+}
+
+/// Variables declared inside a block are visible in the rest of the block and its sub-blocks.
+/// `in_iteration` tells whether the block is in the body of an iteration, where `break` and `continue` are allowed.
+fn check_action_block(context: &mut CheckContext, actions: &Vec<Action>, mut defined_variables: Vec<VariableDefinition>, in_iteration: bool) {
+    for action in actions {
+        match &action.content {
+            ActionContent::DeclarationStatement(x) => {
+                if check_declaration(context, x, &defined_variables, &action.tokens) {
+                    defined_variables.push(VariableDefinition {
+                        type_name: x.data_type.clone(),
+                        identifier: x.identifier.clone(),
+                    });
+                }
+            }
+            ActionContent::AssignmentStatement(x) => check_assignment(context, x, &defined_variables, &action.tokens),
+            ActionContent::CallStatement(x) => {
+                check_call(context, x, &defined_variables, &action.tokens);
+            }
+            ActionContent::ReturnStatement(x) => {
+                let return_type = &context.current_function.declarator.return_type;
+                // `return;` is parsed as an empty expression
+                match x.value.as_ref().filter(|v| !v.postfix_expr.is_empty()) {
+                    None => {
+                        if *return_type != Identifier::empty() {
+                            context.report("0108", format!("A value of type \"{}\" should be returned", return_type), &action.tokens);
+                        }
+                    }
+                    Some(value) => {
+                        if *return_type == Identifier::empty() {
+                            context.report("0108", format!("Function \"{}\" doesn't return any value", context.current_function.declarator.identifier), &action.tokens);
+                        } else if let Some(typed) = check_expression(context, value, &defined_variables, &action.tokens) {
+                            if typed.output_type != *return_type {
+                                context.report("0108", format!("Expect a return value of type \"{}\", found \"{}\"", return_type, typed.output_type), &action.tokens);
+                            }
+                        }
+                    }
+                }
+            }
+            ActionContent::IfBlock(x) => {
+                check_condition(context, &x.if_block.condition, &defined_variables, &action.tokens);
+                check_action_block(context, &x.if_block.body.actions, defined_variables.clone(), in_iteration);
+                for elif in &x.elif_collection {
+                    check_condition(context, &elif.condition, &defined_variables, &action.tokens);
+                    check_action_block(context, &elif.body.actions, defined_variables.clone(), in_iteration);
+                }
+                if let Some(else_block) = &x.else_action {
+                    check_action_block(context, &else_block.actions, defined_variables.clone(), in_iteration);
+                }
+            }
+            ActionContent::WhileStatement(x) => {
+                check_condition(context, &x.condition, &defined_variables, &action.tokens);
+                check_action_block(context, &x.body.actions, defined_variables.clone(), true);
+            }
+            ActionContent::LoopBlock(_) | ActionContent::SwitchBlock(_) => {
+                context.report("0109", "This statement is not supported by code generation yet".to_string(), &action.tokens);
+            }
+            ActionContent::BreakStatement | ActionContent::ContinueStatement if !in_iteration => {
+                context.report("0110", "\"break\" and \"continue\" are only allowed in an iteration".to_string(), &action.tokens);
+            }
+            ActionContent::BreakStatement | ActionContent::ContinueStatement | ActionContent::EmptyAction => {}
+        }
+    }
+}
+
+fn check_declaration(
+    context: &mut CheckContext,
+    declaration: &DeclarationAction,
+    defined_variables: &Vec<VariableDefinition>,
+    tokens: &Vec<Token>,
+) -> bool {
+    if check_variable_definition(declaration, defined_variables, &context.defined_types) {
+        return true;
+    }
+
+    if !context.defined_types.contains(&declaration.data_type) {
+        context.report("0101", format!("Type \"{}\" is not defined", declaration.data_type), tokens);
+    } else {
+        context.report("0102", format!("\"{}\" is already defined", declaration.identifier), tokens);
+    }
+
+    return false;
+}
+
+fn check_assignment(context: &mut CheckContext, assignment: &AssignmentAction, defined_variables: &Vec<VariableDefinition>, tokens: &Vec<Token>) {
+    if !check_variable_existence_by_name(&assignment.identifier, defined_variables) {
+        context.report("0103", format!("Variable \"{}\" is not defined", assignment.identifier), tokens);
+        return;
+    }
+
+    let typed = check_expression(context, &assignment.eval_expression, defined_variables, tokens);
+    if typed.is_none() {
+        return;
+    }
+    let typed = typed.unwrap();
+
+    let typed_assignment = AssignmentAction {
+        identifier: assignment.identifier.clone(),
+        eval_expression: typed.clone(),
+    };
+    if !check_variable_assignment(&typed_assignment, defined_variables.clone(), context.defined_types.clone()) {
+        context.report("0105", format!("Unable to assign to \"{}\"", assignment.identifier), tokens);
+        return;
+    }
+
+    let variable = defined_variables.iter().find(|v| v.identifier == assignment.identifier).unwrap();
+    if variable.type_name != typed.output_type {
+        context.report(
+            "0105",
+            format!("Unable to assign a value of type \"{}\" to \"{}\" of type \"{}\"", typed.output_type, assignment.identifier, variable.type_name),
+            tokens,
+        );
+    }
+}
+
+/// Returns `false` if any issue is found
+fn check_call(context: &mut CheckContext, call: &CallAction, defined_variables: &Vec<VariableDefinition>, tokens: &Vec<Token>) -> bool {
+    if !check_function_existence(context.functions, call) {
+        context.report("0106", format!("Function \"{}\" is not defined", call.function_name), tokens);
+        return false;
+    }
+    let target_function = find_function(&call.function_name, context.functions).unwrap();
+
+    let mut typed_arguments = vec![];
+    for argument in &call.arguments {
+        match check_expression(context, argument, defined_variables, tokens) {
+            Some(x) => typed_arguments.push(x),
+            None => return false,
+        }
+    }
+
+    let parameters = &target_function.declarator.parameters;
+    if typed_arguments.len() != parameters.len() {
+        context.report(
+            "0107",
+            format!("Function \"{}\" takes {} arguments, but {} are given", call.function_name, parameters.len(), typed_arguments.len()),
+            tokens,
+        );
+        return false;
+    }
+
+    let typed_call = CallAction {
+        function_name: call.function_name.clone(),
+        arguments: typed_arguments,
+    };
+    if !check_function_parameter_types(&typed_call, &target_function) {
+        let expected: Vec<String> = parameters.iter().map(|p| p.type_name.to_string()).collect();
+        let found: Vec<String> = typed_call.arguments.iter().map(|a| a.output_type.to_string()).collect();
+        context.report(
+            "0107",
+            format!("Function \"{}\" expects arguments ({}), found ({})", call.function_name, expected.join(", "), found.join(", ")),
+            tokens,
+        );
+        return false;
+    }
+
+    return true;
+}
+
+/// A condition is a bool expression, comparisons in it are checked like any other operator
+fn check_condition(context: &mut CheckContext, condition: &ExprNode, defined_variables: &Vec<VariableDefinition>, tokens: &Vec<Token>) {
+    let expression = SimpleExpression { postfix_expr: condition.to_postfix(), output_type: Identifier::empty() };
+    if let Some(typed) = check_expression(context, &expression, defined_variables, tokens) {
+        if typed.output_type != Identifier::single("bool") {
+            context.report("0105", format!("Expect a condition of type \"bool\", found \"{}\"", typed.output_type), tokens);
+        }
+    }
+}
+
+/// Returns the expression whose data terms are replaced by their types, and `output_type` is set
+fn check_expression(
+    context: &mut CheckContext,
+    expression: &SimpleExpression,
+    defined_variables: &Vec<VariableDefinition>,
+    tokens: &Vec<Token>,
+) -> Option<SimpleExpression> {
+    if expression.postfix_expr.iter().any(|t| t.content.get_operator() == Some(&Operator::Dot)) {
+        context.report("0109", "Member access is not supported by code generation yet".to_string(), tokens);
+        return None;
+    }
+    if !check_expression_sequence(expression.clone()) {
+        context.report("0104", "Invalid expression".to_string(), tokens);
+        return None;
+    }
+
+    let mut valid = true;
+    for term in &expression.postfix_expr {
+        let data = term.content.get_data_term();
+        if data.is_none() {
+            continue;
+        }
+
+        match data.unwrap() {
+            ExprDataTerm::Identifier(x) if !check_variable_existence_by_name(x, defined_variables) => {
+                context.report("0103", format!("Variable \"{}\" is not defined", x), tokens);
+                valid = false;
+            }
+            ExprDataTerm::FunctionCall(x) => {
+                if !check_call(context, x, defined_variables, tokens) {
+                    valid = false;
+                } else if infer_expression_term_data_type(data.unwrap(), context.functions, defined_variables) == Some(Identifier::empty()) {
+                    context.report("0105", format!("Function \"{}\" doesn't return any value", x.function_name), tokens);
+                    valid = false;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if !valid {
+        return None;
+    }
+
+    let mut typed = infer_every_expression_data_term_type(expression, context.functions, defined_variables);
+    match infer_expression_output_type(&typed, &context.defined_types) {
+        Some(x) => typed.output_type = x,
+        None => {
+            context.report("0105", "Operators of the expression couldn't take the types of their operands".to_string(), tokens);
+            return None;
+        }
+    }
+
+    return Some(typed);
+}
+
+impl CheckContext<'_> {
+    fn report(&mut self, code: &str, content: String, tokens: &[Token]) {
+        let file_path = self.current_function.file_path.clone();
+        let location = match (tokens.first(), tokens.last()) {
+            (Some(first), Some(last)) => FileMatch {
+                file_path,
+                start_pos: first.position.start,
+                end_pos: last.position.start + last.position.length,
+            },
+            _ => FileMatch { file_path, start_pos: 0, end_pos: 0 },
+        };
+
+        self.issues.push(IssueBase {
+            level: IssueLevel::Error,
+            position: IssuePosition::CodeGeneration,
+            code: code.to_string(),
+            detail: PackageGenerationIssue {
+                content: format!("{} (in function \"{}\")", content, self.current_function.declarator.identifier),
+                location,
+            },
+        });
+    }
+}
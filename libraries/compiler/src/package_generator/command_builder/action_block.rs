@@ -8,20 +8,27 @@ use crate::package_generator::command_builder::loop_interception::{
     break_action_command_builder, continue_action_command_builder,
 };
 use crate::package_generator::command_builder::return_from_function::return_command_builder;
+use crate::package_generator::utils::generation_issue;
 use crate::shared::ast::action::{ActionBlock, ActionContent};
+use crate::shared::error::general_issue::GeneralIssue;
+use crate::shared::error::pkg_gen_issue::PackageGenerationIssue;
 use crate::shared::package_generation::data_descriptor::{DataDeclarator, DataLocation};
+use crate::shared::package_generation::debug_info::{CommandSource, LocalVariableScope};
 use crate::shared::package_generation::package_descriptor::PackageMetadata;
 use crate::shared::package_generation::relocation_reference::{RelocatableCommandList, RelocationReference, RelocationReferenceType};
+use crate::shared::token::token::Token;
 
 pub fn action_block_command_builder(
     block: &ActionBlock,
     surround_domain: bool,
     defined_data: &Vec<DataDeclarator>,
     metadata: &PackageMetadata,
-) -> RelocatableCommandList {
+) -> Result<RelocatableCommandList, GeneralIssue<PackageGenerationIssue>> {
     let mut result = RelocatableCommandList::new();
 
     let mut available_defined_data: Vec<DataDeclarator> = defined_data.clone();
+    // Variables declared in this block live until its end
+    let mut declared_scopes: Vec<LocalVariableScope> = vec![];
 
     // Create a new domain if necessary
     if surround_domain {
@@ -32,55 +39,79 @@ pub fn action_block_command_builder(
     }
 
     for action in &block.actions {
-        match &action.content {
+        if let Some(token) = action.tokens.first() {
+            result.command_sources.push(CommandSource {
+                command_array_position: result.commands.len(),
+                file_path: String::new(),
+                offset: token.position.start,
+            });
+        }
+
+        let commands = match &action.content {
             ActionContent::DeclarationStatement(x) => {
+                declared_scopes.push(LocalVariableScope {
+                    name: x.identifier.to_string(),
+                    slot: available_defined_data.len(),
+                    start: result.commands.len(),
+                    end: 0,
+                });
                 result.command_entries.push(result.commands.len());
-                result.combine(build_data_declaration_command(false));
                 available_defined_data.push(DataDeclarator {
                     name: x.identifier.clone(),
                     slot: available_defined_data.len(),
                     location: DataLocation::Local,
                     is_string: false,
                 });
+                Ok(build_data_declaration_command(false))
             }
             ActionContent::AssignmentStatement(x) => {
                 result.command_entries.push(result.commands.len());
-                result.combine(build_assignment_command(
+                build_assignment_command(
                     &x,
                     &available_defined_data,
                     metadata,
-                ));
+                )
             }
             ActionContent::CallStatement(x) => {
                 result.command_entries.push(result.commands.len());
-                result.combine(build_function_call_command(
+                build_function_call_command(
                     &x,
                     &available_defined_data,
                     metadata,
-                ));
+                )
             }
             ActionContent::ReturnStatement(x) => {
                 // Interrupt function execution
-                result.combine(return_command_builder(x, &available_defined_data, metadata));
+                return_command_builder(x, &available_defined_data, metadata)
             }
             ActionContent::IfBlock(x) => {
-                result.combine(if_command_builder(x, &available_defined_data, &metadata));
+                if_command_builder(x, &available_defined_data, &metadata)
             }
             ActionContent::WhileStatement(x) => {
-                result.combine(while_command_builder(x, &available_defined_data, &metadata));
+                while_command_builder(x, &available_defined_data, &metadata)
             }
             ActionContent::BreakStatement => {
                 result.command_entries.push(result.commands.len());
-                result.combine(break_action_command_builder(&metadata));
+                Ok(break_action_command_builder(&metadata))
             }
             ActionContent::ContinueStatement => {
                 result.command_entries.push(result.commands.len());
-                result.combine(continue_action_command_builder(&metadata));
+                Ok(continue_action_command_builder(&metadata))
             }
             _ => {
-                panic!("Command not supported!")
+                Err(generation_issue("Command not supported!"))
             }
+        };
+
+        if commands.is_err() {
+            return Err(locate_issues(commands.unwrap_err(), &action.tokens));
         }
+        result.combine(commands.unwrap());
+    }
+
+    for mut scope in declared_scopes {
+        scope.end = result.commands.len();
+        result.local_scopes.push(scope);
     }
 
     // Destroy the domain if created
@@ -91,5 +122,17 @@ pub fn action_block_command_builder(
         })
     }
 
-    return result;
+    return Ok(result);
+}
+
+/// Issues of the commands of an action are located at the action, unless they are found in a nested one
+fn locate_issues(mut issues: GeneralIssue<PackageGenerationIssue>, tokens: &[Token]) -> GeneralIssue<PackageGenerationIssue> {
+    if let (Some(first), Some(last)) = (tokens.first(), tokens.last()) {
+        for issue in issues.issues.iter_mut().filter(|i| i.detail.location.end_pos == 0) {
+            issue.detail.location.start_pos = first.position.start;
+            issue.detail.location.end_pos = last.position.start + last.position.length;
+        }
+    }
+
+    return issues;
 }
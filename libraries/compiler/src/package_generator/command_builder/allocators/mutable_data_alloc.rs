@@ -1,5 +1,5 @@
-use crate::package_generator::utils::{align_array_width, convert_to_u8_array};
-use crate::shared::error::general_issue::{GeneralIssue, IssueBase, IssueLevel, IssuePosition};
+use crate::package_generator::utils::{align_generated_width, convert_to_u8_array, generation_issue};
+use crate::shared::error::general_issue::GeneralIssue;
 use crate::shared::error::pkg_gen_issue::PackageGenerationIssue;
 use crate::shared::package_generation::data_descriptor::DataAccessDescriptor;
 use crate::shared::package_generation::package_descriptor::PackageMetadata;
@@ -21,28 +21,26 @@ pub fn dac_builder(data: DataAccessDescriptor, metadata: &PackageMetadata) -> Re
         result.push(0x01);
 
         // Push identifier slot
-        result.extend(align_array_width(&identifier.slot.to_be_bytes().to_vec(), metadata.data_slot_alignment));
+        result.extend(align_generated_width(&identifier.slot.to_be_bytes().to_vec(), metadata.data_slot_alignment, "Slot of a variable")?);
     } else if data.instant_value.is_some() {
         let value = data.instant_value.unwrap();
         result.push(0x00);
 
         // Push value
-        result.extend(align_array_width(&convert_to_u8_array(value), metadata.data_alignment));
+        let name = format!("Number {}", value);
+        let bytes = convert_to_u8_array(value);
+        if bytes.is_none() {
+            return Err(generation_issue(format!("{} is out of range", name).as_str()));
+        }
+        result.extend(align_generated_width(&bytes.unwrap(), metadata.data_alignment, name.as_str())?);
     } else if data.string_constant.is_some() {
         let string_value = data.string_constant.unwrap();
         result.push(0x02);
 
         // Push slot id on string heap
-        result.extend(align_array_width(&string_value.slot.to_be_bytes().to_vec(), metadata.data_slot_alignment));
+        result.extend(align_generated_width(&string_value.slot.to_be_bytes().to_vec(), metadata.data_slot_alignment, "Slot of a string constant")?);
     } else {
-        return Err(GeneralIssue {
-            issues: vec![IssueBase {
-                level: IssueLevel::Error,
-                position: IssuePosition::CodeGeneration,
-                code: "-1".to_string(),
-                detail: PackageGenerationIssue {},
-            }]
-        });
+        return Err(generation_issue("Data access descriptor is empty"));
     }
 
     return Ok(RelocatableCommandList::new_no_relocation(result));
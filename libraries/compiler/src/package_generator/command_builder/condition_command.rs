@@ -1,196 +1,97 @@
 use crate::package_generator::command_builder::action_block::action_block_command_builder;
-use crate::package_generator::command_builder::templates::jump_command::{direct_jump_command_builder, jump_by_stack_top_command_template_builder};
+use crate::package_generator::command_builder::templates::branch_command::branch_command_builder;
+use crate::package_generator::command_builder::templates::jump_command::direct_jump_command_builder;
 use crate::shared::ast::action::{ConditionBlock, IfAction, WhileBlock};
 use crate::shared::command_map::JumpCommand;
+use crate::shared::error::general_issue::GeneralIssue;
+use crate::shared::error::pkg_gen_issue::PackageGenerationIssue;
 use crate::shared::package_generation::data_descriptor::DataDeclarator;
 use crate::shared::package_generation::package_descriptor::PackageMetadata;
 use crate::shared::package_generation::relocation_reference::{RelocatableCommandList, RelocationReference, RelocationReferenceType, RelocationTargetElement};
 
-struct ConditionBlockStagingStructure {
-    eval_jump: (RelocatableCommandList, (bool, bool, bool)),
-    body: RelocatableCommandList,
-    jump_out: RelocatableCommandList,
-}
-
 pub fn if_command_builder(action: &IfAction,
                           defined_data: &Vec<DataDeclarator>,
                           metadata: &PackageMetadata,
-) -> RelocatableCommandList {
-    let mut result = RelocatableCommandList::new();
-
+) -> Result<RelocatableCommandList, GeneralIssue<PackageGenerationIssue>> {
     let mut condition_blocks: Vec<ConditionBlock> = action.elif_collection.clone();
     condition_blocks.insert(0, action.if_block.clone());
 
-    let else_block_build_result: Option<RelocatableCommandList>;
+    // Sections are built from the last one, so that every jump knows the length of the code it skips
+    let mut sections = vec![];
+    let mut rest_len: usize = 0;
+
     if action.else_action.is_some() {
         let else_block = action.else_action.clone().unwrap();
-        let mut body = action_block_command_builder(&else_block, true, defined_data, metadata);
+        let mut body = action_block_command_builder(&else_block, true, defined_data, metadata)?;
 
         body.descriptors.references.push(RelocationReference{ ref_type: RelocationReferenceType::ElseEntrance, command_array_position: 0 });
         body.descriptors.references.push(RelocationReference{ ref_type: RelocationReferenceType::EndElse, command_array_position: body.commands.len() });
 
-        else_block_build_result = Some(body);
-    } else {
-        else_block_build_result = None;
+        rest_len += body.commands.len();
+        sections.push(body);
     }
 
-    let mut domain_count: usize = condition_blocks.len() + action.else_action.is_some() as usize;
+    for (idx, block) in condition_blocks.iter().enumerate().rev() {
+        let body = action_block_command_builder(&block.body, true, defined_data, metadata)?;
 
-    let mut reloc_sections = vec![];
-    for block in &condition_blocks {
-        let eval_jump = jump_by_stack_top_command_template_builder(&block.condition, defined_data, metadata);
-        let body = action_block_command_builder(&block.body, true, defined_data, metadata);
-        let jump_out = direct_jump_command_builder(vec![], metadata);
-
-        let mut current_build = ConditionBlockStagingStructure {
-            eval_jump,
-            body,
-            jump_out,
+        // The last section has nothing to jump over
+        let jump_out = if rest_len > 0 {
+            direct_jump_command_builder(vec![RelocationTargetElement::Relative((JumpCommand::ToRelative.get_len(metadata.address_alignment) + rest_len) as i32)], metadata)
+        } else {
+            RelocatableCommandList::new()
         };
 
         // Command layout:
         // [if/elif entrance]
-        // <expression evaluation>
+        // <condition check, which jumps to the next section if it is false>
         // <if/elif body>
         // <jump to end of block>
         // [if/elif end]
-        let args = &current_build.eval_jump.1;
-        if args.0 {
-            // Jump to next instruction
-            current_build.eval_jump.0.descriptors.targets[0].relocation_elements = vec![RelocationTargetElement::Relative(JumpCommand::ByStackTop.get_len(metadata.address_alignment) as i32)];
-        } else {
-            // Jump out of the whole statement
-            current_build.eval_jump.0.descriptors.targets[0].relocation_elements = vec![RelocationTargetElement::BreakDomain(1)];
-        }
+        let mut current = branch_command_builder(&block.condition, body.commands.len() + jump_out.commands.len(), defined_data, metadata)?;
+        current.combine(body);
+        current.combine(jump_out);
 
-        if args.1 {
-            current_build.eval_jump.0.descriptors.targets[1].relocation_elements = vec![RelocationTargetElement::Relative(JumpCommand::ByStackTop.get_len(metadata.address_alignment) as i32)];
+        let (entrance, end) = if idx == 0 {
+            (RelocationReferenceType::IfEntrance, RelocationReferenceType::EndIf)
         } else {
-            current_build.eval_jump.0.descriptors.targets[1].relocation_elements = vec![RelocationTargetElement::BreakDomain(1)];
-        }
-
-        if args.2 {
-            current_build.eval_jump.0.descriptors.targets[2].relocation_elements = vec![RelocationTargetElement::Relative(JumpCommand::ByStackTop.get_len(metadata.address_alignment) as i32)];
-        } else {
-            current_build.eval_jump.0.descriptors.targets[2].relocation_elements = vec![RelocationTargetElement::BreakDomain(1)];
-        }
-
-        if domain_count >= 1 {
-            domain_count -= 1;
-        }
-
-        current_build.jump_out.descriptors.targets[0].relocation_elements.push(RelocationTargetElement::IgnoreDomain(domain_count));
-
-        reloc_sections.push(current_build);
-    }
-
-    // If there's no else block, then remove the last jump_out
-    if else_block_build_result.is_none() {
-        let last_build = reloc_sections.last_mut().unwrap();
-        last_build.jump_out = RelocatableCommandList::new();
-    }
-
-    let len = reloc_sections.len();
-
-    // Calculate the full length of the IfBlock command
-    let mut total_len: usize = 0;
-    for (idx, section) in reloc_sections.iter().enumerate() {
-        total_len += section.eval_jump.0.commands.len() + section.body.commands.len() + section.jump_out.commands.len();
-
-        // Push else block or remove last jump out
-        if idx == len - 1 {
-            if else_block_build_result.is_none() {
-                total_len -= section.jump_out.commands.len();
-            } else {
-                total_len += else_block_build_result.clone().unwrap().commands.len();
-            }
-        }
-    }
-
-    for (idx, section) in reloc_sections.iter().enumerate() {
-        let mut current = RelocatableCommandList::new();
-        current.combine(section.eval_jump.0.clone());
-        current.combine(section.body.clone());
-
-        // Generate references
-        if idx == 0 {
-            current.combine(section.jump_out.clone());
-
-            current.descriptors.references.push(RelocationReference{ ref_type: RelocationReferenceType::IfEntrance, command_array_position: 0 });
-            current.descriptors.references.push(RelocationReference{ ref_type: RelocationReferenceType::EndIf, command_array_position: current.commands.len() });
-
-        } else {
-            if else_block_build_result.is_none() && idx == len - 1 {
-                // Modify the last one if there's no else branch
-                current.descriptors.references.push(RelocationReference{ ref_type: RelocationReferenceType::ElifEntrance, command_array_position: 0 });
-                current.descriptors.references.push(RelocationReference{ ref_type: RelocationReferenceType::EndElif, command_array_position: current.commands.len() });
-
-                continue;
-            } else {
-                current.combine(section.jump_out.clone());
-
-                current.descriptors.references.push(RelocationReference{ ref_type: RelocationReferenceType::ElifEntrance, command_array_position: 0 });
-                current.descriptors.references.push(RelocationReference{ ref_type: RelocationReferenceType::EndElif, command_array_position: current.commands.len() });
-            }
-        }
-
-        result.combine(current);
+            (RelocationReferenceType::ElifEntrance, RelocationReferenceType::EndElif)
+        };
+        current.descriptors.references.push(RelocationReference{ ref_type: entrance, command_array_position: 0 });
+        current.descriptors.references.push(RelocationReference{ ref_type: end, command_array_position: current.commands.len() });
 
-        // We are not capable to jump out directly from the IfBlock, so we need to calculate the relative to the end manually
-        let last_reloc_target = result.descriptors.targets.last_mut().unwrap();
-        last_reloc_target.relocation_elements[0] = RelocationTargetElement::Relative((total_len - last_reloc_target.command_array_position) as i32);
+        rest_len += current.commands.len();
+        sections.push(current);
     }
 
-    if else_block_build_result.is_some() {
-        result.combine(else_block_build_result.unwrap());
+    let mut result = RelocatableCommandList::new();
+    for section in sections.into_iter().rev() {
+        result.combine(section);
     }
 
-    return result;
+    return Ok(result);
 }
 
 pub fn while_command_builder(action: &WhileBlock,
                              defined_data: &Vec<DataDeclarator>,
                              metadata: &PackageMetadata,
-) -> RelocatableCommandList {
-    // Evaluate the expression first
-    let mut eval_jump = jump_by_stack_top_command_template_builder(&action.condition, defined_data, metadata);
+) -> Result<RelocatableCommandList, GeneralIssue<PackageGenerationIssue>> {
     // Generate body commands
-    let while_body = action_block_command_builder(&action.body, true, defined_data, metadata);
-    // Jump back to re-evaluate the expression, judge if the condition is still satisfied
-    let back_jump = direct_jump_command_builder(vec![RelocationTargetElement::Relative(-((eval_jump.0.commands.len() + while_body.commands.len()) as i32))], metadata);
-
-    // Modify relocation elements from expression evaluation result
-    let args = &eval_jump.1;
-    if args.0 {
-        // Jump to next instruction
-        eval_jump.0.descriptors.targets[0].relocation_elements = vec![RelocationTargetElement::Relative(JumpCommand::ByStackTop.get_len(metadata.address_alignment) as i32)];
-    } else {
-        // Jump out of the whole statement
-        eval_jump.0.descriptors.targets[0].relocation_elements = vec![RelocationTargetElement::BreakIteration];
-    }
-
-    if args.1 {
-        eval_jump.0.descriptors.targets[1].relocation_elements = vec![RelocationTargetElement::Relative(JumpCommand::ByStackTop.get_len(metadata.address_alignment) as i32)];
-    } else {
-        eval_jump.0.descriptors.targets[1].relocation_elements = vec![RelocationTargetElement::BreakIteration];
-    }
-
-    if args.2 {
-        eval_jump.0.descriptors.targets[2].relocation_elements = vec![RelocationTargetElement::Relative(JumpCommand::ByStackTop.get_len(metadata.address_alignment) as i32)];
-    } else {
-        eval_jump.0.descriptors.targets[2].relocation_elements = vec![RelocationTargetElement::BreakIteration];
-    }
+    let while_body = action_block_command_builder(&action.body, true, defined_data, metadata)?;
+    // Check the condition first, leave the loop by skipping the body and the jump back if it is false
+    let branch = branch_command_builder(&action.condition, while_body.commands.len() + JumpCommand::ToRelative.get_len(metadata.address_alignment), defined_data, metadata)?;
+    // Jump back to check the condition again
+    let back_jump = direct_jump_command_builder(vec![RelocationTargetElement::Relative(-((branch.commands.len() + while_body.commands.len()) as i32))], metadata);
 
     // Combine command sections
     let mut result = RelocatableCommandList::new();
-    result.combine(eval_jump.0);
+    result.combine(branch);
     result.combine(while_body);
     result.combine(back_jump);
 
     // Generate references
     // Layout:
     // [while entrance]
-    // <expression evaluation>
+    // <condition check>
     // <while body>
     // <jump to entrance>
     // [while end]
@@ -203,5 +104,5 @@ pub fn while_command_builder(action: &WhileBlock,
         command_array_position: result.commands.len(),
     });
 
-    return result;
+    return Ok(result);
 }
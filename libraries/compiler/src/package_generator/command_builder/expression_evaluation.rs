@@ -1,15 +1,19 @@
 use crate::package_generator::command_builder::allocators::mutable_data_alloc::dac_builder;
 use crate::package_generator::command_builder::function_call::build_function_call_command;
 use crate::package_generator::command_builder::math::calculation::{
-    divide_command, minus_command, mod_command, multiplication_command, plus_command,
+    divide_command, inverse_command, minus_command, mod_command, multiplication_command, plus_command,
 };
-use crate::package_generator::utils::combine_command;
+use crate::package_generator::command_builder::math::logical::{and_command, not_command, or_command};
+use crate::package_generator::command_builder::templates::jump_command::relation_value_command_builder;
+use crate::package_generator::utils::{combine_command, generation_issue};
 use crate::shared::ast::blocks::expression::{ExprDataTerm, SimpleExpression};
 use crate::shared::command_map::{RootCommand, StackCommand};
+use crate::shared::error::general_issue::GeneralIssue;
+use crate::shared::error::pkg_gen_issue::PackageGenerationIssue;
 use crate::shared::package_generation::data_descriptor::{DataAccessDescriptor, DataDeclarator};
 use crate::shared::package_generation::package_descriptor::PackageMetadata;
 use crate::shared::package_generation::relocation_reference::RelocatableCommandList;
-use crate::shared::token::operator::{CalculationOperator, Operator};
+use crate::shared::token::operator::{CalculationOperator, LogicalOperator, Operator};
 
 // TODO: Mark commands by `result.command_entries.push(result.commands.len());`
 
@@ -18,7 +22,7 @@ pub fn build_expression_evaluation_command(
     expr: &SimpleExpression,
     defined_data: &Vec<DataDeclarator>,
     metadata: &PackageMetadata,
-) -> RelocatableCommandList {
+) -> Result<RelocatableCommandList, GeneralIssue<PackageGenerationIssue>> {
     let mut result = RelocatableCommandList::new();
 
     for term in &expr.postfix_expr {
@@ -35,35 +39,19 @@ pub fn build_expression_evaluation_command(
                     )]);
 
                     // Seek existing identifiers
-                    let identifier = defined_data
-                        .iter()
-                        .find(|&dd| dd.name == *x)
-                        .unwrap();
-
-                    let dac_build_result = dac_builder(DataAccessDescriptor::new_identifier(identifier.clone()), metadata);
-                    if dac_build_result.is_ok() {
-                        result.combine(dac_build_result.unwrap());
-                    } else {
-                        panic!("Failed to build data access command for identifier: {}", x);
+                    let identifier = defined_data.iter().find(|&dd| dd.name == *x);
+                    if identifier.is_none() {
+                        return Err(generation_issue(format!("Variable \"{}\" is not defined", x).as_str()));
                     }
+
+                    result.combine(dac_builder(DataAccessDescriptor::new_identifier(identifier.unwrap().clone()), metadata)?);
                 },
                 ExprDataTerm::Number(x) => {
-                    result.command_entries.push(result.commands.len());
-                    result.append_commands(vec![combine_command(
-                        RootCommand::Stack.to_opcode(),
-                        StackCommand::Push.to_opcode(),
-                    )]);
-
-                    let dac_build_result = dac_builder(DataAccessDescriptor::new_instant_value(x.clone()), metadata);
-                    if dac_build_result.is_ok() {
-                        result.combine(dac_build_result.unwrap());
-                    } else {
-                        panic!("Failed to build data access command for number: {}", x);
-                    }
+                    result.combine(push_instant_value_command(x.clone(), metadata)?);
                 },
                 ExprDataTerm::FunctionCall(x) => {
                     // The called function will automatically put the return value on the top of the stack
-                    result.combine(build_function_call_command(x, defined_data, metadata));
+                    result.combine(build_function_call_command(x, defined_data, metadata)?);
                 },
                 ExprDataTerm::String(x) => {
                     result.command_entries.push(result.commands.len());
@@ -72,35 +60,54 @@ pub fn build_expression_evaluation_command(
                         StackCommand::PushFromObject.to_opcode(),
                     )]);
 
-                    let dac_build_result = dac_builder(DataAccessDescriptor::new_string_constant(x.clone()), metadata);
-                    if dac_build_result.is_ok() {
-                        result.combine(dac_build_result.unwrap());
-                    } else {
-                        panic!("Failed to build data access command for string: {}", x.value);
-                    }
+                    result.combine(dac_builder(DataAccessDescriptor::new_string_constant(x.clone()), metadata)?);
                 }
             }
         } else if term.content.get_operator().is_some() {
             let operator = term.content.get_operator().unwrap();
-            result.append_commands(operator_opcode_builder(&operator));
+            match operator {
+                // Comparisons jump to push their results
+                Operator::Relation(x) => result.combine(relation_value_command_builder(x, metadata)?),
+                _ => result.append_commands(operator_opcode_builder(operator)?),
+            }
         }
     }
 
-    return result;
+    return Ok(result);
 }
 
-pub fn operator_opcode_builder(operator: &Operator) -> Vec<u8> {
+pub fn push_instant_value_command(value: String, metadata: &PackageMetadata) -> Result<RelocatableCommandList, GeneralIssue<PackageGenerationIssue>> {
+    let mut result = RelocatableCommandList::new();
+
+    result.command_entries.push(result.commands.len());
+    result.append_commands(vec![combine_command(
+        RootCommand::Stack.to_opcode(),
+        StackCommand::Push.to_opcode(),
+    )]);
+
+    result.combine(dac_builder(DataAccessDescriptor::new_instant_value(value), metadata)?);
+
+    return Ok(result);
+}
+
+/// `Relation` operators take relocatable commands, other operators couldn't be evaluated
+pub fn operator_opcode_builder(operator: &Operator) -> Result<Vec<u8>, GeneralIssue<PackageGenerationIssue>> {
     return match operator {
         Operator::Calculation(x) => match x {
-            CalculationOperator::Addition => plus_command(),
-            CalculationOperator::Subtraction => minus_command(),
-            CalculationOperator::Multiply => multiplication_command(),
-            CalculationOperator::Division => divide_command(),
-            CalculationOperator::Modulo => mod_command(),
-            _ => panic!("Invalid calculation operator"),
+            CalculationOperator::Addition => Ok(plus_command()),
+            CalculationOperator::Subtraction => Ok(minus_command()),
+            CalculationOperator::Multiply => Ok(multiplication_command()),
+            CalculationOperator::Division => Ok(divide_command()),
+            CalculationOperator::Modulo => Ok(mod_command()),
+            CalculationOperator::Negation => Ok(inverse_command()),
+            _ => Err(generation_issue("Invalid calculation operator")),
         },
-        _ => {
-            panic!("`Logical` and `Relation` operators are not implemented so far :(");
-        }
+        Operator::Logical(x) => match x {
+            LogicalOperator::And => Ok(and_command()),
+            LogicalOperator::Or => Ok(or_command()),
+            LogicalOperator::Not => Ok(not_command()),
+            _ => Err(generation_issue("Invalid logical operator")),
+        },
+        _ => Err(generation_issue("Operator couldn't be evaluated")),
     };
 }
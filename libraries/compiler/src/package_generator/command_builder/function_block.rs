@@ -3,12 +3,15 @@ use crate::package_generator::utils::combine_command;
 use crate::shared::ast::action::ActionBlock;
 use crate::shared::ast::blocks::function::Function;
 use crate::shared::command_map::{FunctionCommand, RootCommand};
+use crate::shared::error::general_issue::GeneralIssue;
+use crate::shared::error::pkg_gen_issue::PackageGenerationIssue;
 use crate::shared::package_generation::data_descriptor::{DataDeclarator, DataLocation};
+use crate::shared::package_generation::debug_info::LocalVariableScope;
 use crate::shared::package_generation::package_descriptor::PackageMetadata;
 use crate::shared::package_generation::relocation_reference::{RelocatableCommandList, RelocationReference};
 use crate::shared::package_generation::relocation_reference::RelocationReferenceType::{EndFunction, FunctionEntrance};
 
-pub fn build_function_command(func: &Function, metadata: &PackageMetadata) -> RelocatableCommandList {
+pub fn build_function_command(func: &Function, metadata: &PackageMetadata) -> Result<RelocatableCommandList, GeneralIssue<PackageGenerationIssue>> {
     let mut params: Vec<DataDeclarator> = vec![];
     for (index, param) in func.declarator.parameters.iter().enumerate() {
         params.push(DataDeclarator {
@@ -19,12 +22,25 @@ pub fn build_function_command(func: &Function, metadata: &PackageMetadata) -> Re
         });
     }
 
-    let mut result = action_block_command_builder(&ActionBlock { actions: func.body.clone() }, true, &params, metadata);
+    let result = action_block_command_builder(&ActionBlock { actions: func.body.clone() }, true, &params, metadata);
+    if result.is_err() {
+        return Err(result.unwrap_err().with_file_path(func.file_path.as_str()));
+    }
+
+    let mut result = result.unwrap();
     // Push end function flag
     result.commands.push(combine_command(RootCommand::Function.to_opcode(), FunctionCommand::FunctionEndFlag.to_opcode()));
     // Place refs
     result.descriptors.references.push(RelocationReference { ref_type: FunctionEntrance(func.declarator.identifier.clone()), command_array_position: 0 });
     result.descriptors.references.push(RelocationReference { ref_type: EndFunction(func.declarator.identifier.clone()), command_array_position: result.commands.len() - 1 });
 
-    return result;
+    // Parameters live in the whole function
+    for param in &params {
+        result.local_scopes.push(LocalVariableScope { name: param.name.to_string(), slot: param.slot, start: 0, end: result.commands.len() });
+    }
+    for source in result.command_sources.iter_mut() {
+        source.file_path = func.file_path.clone();
+    }
+
+    return Ok(result);
 }
@@ -2,6 +2,8 @@ use crate::package_generator::command_builder::expression_evaluation::build_expr
 use crate::package_generator::utils::{combine_command, jump_command_address_placeholder};
 use crate::shared::ast::action::CallAction;
 use crate::shared::command_map::{FunctionCommand, RootCommand};
+use crate::shared::error::general_issue::GeneralIssue;
+use crate::shared::error::pkg_gen_issue::PackageGenerationIssue;
 use crate::shared::package_generation::data_descriptor::DataDeclarator;
 use crate::shared::package_generation::package_descriptor::PackageMetadata;
 use crate::shared::package_generation::relocation_reference::{RelocatableCommandList, RelocationTarget, RelocationTargetElement};
@@ -10,14 +12,14 @@ pub fn build_function_call_command(
     action: &CallAction,
     defined_data: &Vec<DataDeclarator>,
     metadata: &PackageMetadata,
-) -> RelocatableCommandList {
+) -> Result<RelocatableCommandList, GeneralIssue<PackageGenerationIssue>> {
     let mut result = RelocatableCommandList::new();
 
     // Build all parameters
     let mut sorted_params = action.arguments.clone();
     sorted_params.reverse();
     for expr in &sorted_params {
-        let expr_eval_cmd = build_expression_evaluation_command(expr, defined_data, metadata);
+        let expr_eval_cmd = build_expression_evaluation_command(expr, defined_data, metadata)?;
         result.combine(expr_eval_cmd);
     }
 
@@ -35,5 +37,5 @@ pub fn build_function_call_command(
     result.append_commands(jump_command_address_placeholder(metadata));
     result.append_commands(vec![sorted_params.len() as u8]);
 
-    return result;
+    return Ok(result);
 }
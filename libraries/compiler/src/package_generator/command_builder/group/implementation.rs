@@ -3,12 +3,20 @@ use crate::package_generator::command_builder::function_block::build_function_co
 use crate::package_generator::utils::align_array_width;
 use crate::shared::ast::group::declaration::GroupDeclarationBlock;
 use crate::shared::ast::group::implementation::GroupImplementationBlock;
+use crate::shared::error::general_issue::GeneralIssue;
+use crate::shared::error::pkg_gen_issue::PackageGenerationIssue;
 use crate::shared::package_generation::group_context::GeneratedGroup;
 use crate::shared::package_generation::package_descriptor::PackageMetadata;
 use crate::shared::package_generation::relocation_reference::RelocatableCommandList;
 use crate::shared::utils::identifier::Identifier;
 
-pub fn group_implementation_builder(impl_block: GroupImplementationBlock, source_group: &GroupDeclarationBlock, generated_groups: &Vec<GeneratedGroup>, metadata: &PackageMetadata) -> Result<RelocatableCommandList, Vec<Identifier>> {
+/// `Ok(Err(dependencies))` if groups which the implementation depends on are not generated yet
+pub fn group_implementation_builder(
+    impl_block: GroupImplementationBlock,
+    source_group: &GroupDeclarationBlock,
+    generated_groups: &Vec<GeneratedGroup>,
+    metadata: &PackageMetadata,
+) -> Result<Result<RelocatableCommandList, Vec<Identifier>>, GeneralIssue<PackageGenerationIssue>> {
     let mut result = RelocatableCommandList::new();
     let mut dependency = vec![];
 
@@ -32,7 +40,7 @@ pub fn group_implementation_builder(impl_block: GroupImplementationBlock, source
             result.commands.push('G' as u8);
 
             // TODO: Self value should be in the defined data
-            let commands = action_block_command_builder(get, true, &vec![], metadata);
+            let commands = action_block_command_builder(get, true, &vec![], metadata)?;
 
             // Push command length
             result.commands.extend(align_array_width(&commands.commands.len().to_be_bytes().to_vec(), metadata.data_slot_alignment));
@@ -48,7 +56,7 @@ pub fn group_implementation_builder(impl_block: GroupImplementationBlock, source
             result.commands.push('S' as u8);
 
             // TODO: Self value should be in the defined data
-            let rcl = action_block_command_builder(get, true, &vec![], metadata);
+            let rcl = action_block_command_builder(get, true, &vec![], metadata)?;
 
             // Push command length
             result.commands.extend(align_array_width(&rcl.commands.len().to_be_bytes().to_vec(), metadata.data_slot_alignment));
@@ -66,7 +74,7 @@ pub fn group_implementation_builder(impl_block: GroupImplementationBlock, source
         // Assign method slot
         result.commands.extend(align_array_width(&source_method_slot.to_be_bytes().to_vec(), metadata.data_slot_alignment));
 
-        let rcl = build_function_command(method, metadata);
+        let rcl = build_function_command(method, metadata)?;
 
         // Push command length
         result.commands.extend(align_array_width(&rcl.commands.len().to_be_bytes().to_vec(), metadata.data_slot_alignment));
@@ -84,7 +92,7 @@ pub fn group_implementation_builder(impl_block: GroupImplementationBlock, source
         // Assign function slot
         result.commands.extend(align_array_width(&source_func_slot.to_be_bytes().to_vec(), metadata.data_slot_alignment));
 
-        let rcl = build_function_command(func, metadata);
+        let rcl = build_function_command(func, metadata)?;
 
         // Push command length
         result.commands.extend(align_array_width(&rcl.commands.len().to_be_bytes().to_vec(), metadata.data_slot_alignment));
@@ -93,8 +101,8 @@ pub fn group_implementation_builder(impl_block: GroupImplementationBlock, source
     }
 
     return if dependency.is_empty() {
-        Ok(result)
+        Ok(Ok(result))
     } else {
-        Err(dependency)
+        Ok(Err(dependency))
     };
 }
@@ -1,11 +1,13 @@
 use crate::package_generator::command_builder::assignment_action::build_assignment_command;
 use crate::package_generator::command_builder::data_commands::build_data_declaration_command;
+use crate::shared::error::general_issue::GeneralIssue;
+use crate::shared::error::pkg_gen_issue::PackageGenerationIssue;
 use crate::shared::package_generation::data_descriptor::{DataDeclarator, DataLocation};
 use crate::shared::package_generation::linear_action_tree::{LinearActionTree, LinearActionType};
 use crate::shared::package_generation::package_descriptor::PackageMetadata;
 use crate::shared::package_generation::relocation_reference::RelocatableCommandList;
 
-pub fn linear_action_tree_command(tree: LinearActionTree, metadata: &PackageMetadata) -> RelocatableCommandList {
+pub fn linear_action_tree_command(tree: LinearActionTree, metadata: &PackageMetadata) -> Result<RelocatableCommandList, GeneralIssue<PackageGenerationIssue>> {
     let mut result = RelocatableCommandList::new();
     let mut defined_data: Vec<DataDeclarator> = vec![];
 
@@ -24,7 +26,7 @@ pub fn linear_action_tree_command(tree: LinearActionTree, metadata: &PackageMeta
             LinearActionType::BreakStatement => {}
             LinearActionType::ContinueStatement => {}
             LinearActionType::AssignmentAction(x) => {
-                result.combine(build_assignment_command(&x, &defined_data, metadata));
+                result.combine(build_assignment_command(&x, &defined_data, metadata)?);
             }
             LinearActionType::DeclarationAction(x) => {
                 let decl_cmd = build_data_declaration_command(false);
@@ -42,5 +44,5 @@ pub fn linear_action_tree_command(tree: LinearActionTree, metadata: &PackageMeta
         }
     }
 
-    return result;
+    return Ok(result);
 }
@@ -0,0 +1,32 @@
+use crate::package_generator::utils::combine_command;
+use crate::shared::command_map::{MathCommand, MathLogicalCommand, RootCommand};
+
+pub fn and_command() -> Vec<u8> {
+    return vec![
+        combine_command(
+            RootCommand::Math.to_opcode(),
+            MathCommand::Logical.to_opcode(),
+        ),
+        MathLogicalCommand::And.to_opcode(),
+    ];
+}
+
+pub fn or_command() -> Vec<u8> {
+    return vec![
+        combine_command(
+            RootCommand::Math.to_opcode(),
+            MathCommand::Logical.to_opcode(),
+        ),
+        MathLogicalCommand::Or.to_opcode(),
+    ];
+}
+
+pub fn not_command() -> Vec<u8> {
+    return vec![
+        combine_command(
+            RootCommand::Math.to_opcode(),
+            MathCommand::Logical.to_opcode(),
+        ),
+        MathLogicalCommand::Not.to_opcode(),
+    ];
+}
@@ -1 +1,2 @@
 pub mod calculation;
+pub mod logical;
@@ -1,5 +1,5 @@
 mod math;
-mod templates;
+pub(crate) mod templates;
 pub mod action_block;
 pub mod assignment_action;
 pub mod data_commands;
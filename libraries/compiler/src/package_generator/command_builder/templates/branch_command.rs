@@ -0,0 +1,134 @@
+use crate::package_generator::command_builder::expression_evaluation::build_expression_evaluation_command;
+use crate::package_generator::command_builder::math::calculation::minus_command;
+use crate::package_generator::command_builder::templates::jump_command::relation_jump_directions;
+use crate::package_generator::utils::{combine_command, jump_command_address_placeholder};
+use crate::shared::ast::blocks::expression::{ExprNode, NodeContent, SimpleExpression};
+use crate::shared::command_map::{JumpCommand, RootCommand};
+use crate::shared::error::general_issue::GeneralIssue;
+use crate::shared::error::pkg_gen_issue::PackageGenerationIssue;
+use crate::shared::package_generation::data_descriptor::DataDeclarator;
+use crate::shared::package_generation::package_descriptor::PackageMetadata;
+use crate::shared::package_generation::relocation_reference::{RelocatableCommandList, RelocationTarget, RelocationTargetElement};
+use crate::shared::token::operator::{LogicalOperator, Operator};
+use crate::shared::utils::identifier::Identifier;
+
+/// Commands of a part of the condition, and indexes of the relocation targets leaving it.
+/// Those targets jump to the end of the part until they are extended by the commands following it.
+struct BranchPiece {
+    commands: RelocatableCommandList,
+    exits: Vec<usize>,
+}
+
+impl BranchPiece {
+    /// Append `next` and make the exits of both of them jump to the end of the result
+    fn chain(&mut self, next: BranchPiece) {
+        self.extend_exits(next.commands.commands.len());
+
+        let base = self.commands.descriptors.targets.len();
+        self.exits.extend(next.exits.iter().map(|x| x + base));
+        self.commands.combine(next.commands);
+    }
+
+    /// Append `next`, the exits of this piece jump to the end of the result and only the ones of `next` are kept
+    fn resolve_before(&mut self, next: BranchPiece) {
+        self.extend_exits(next.commands.commands.len());
+
+        let base = self.commands.descriptors.targets.len();
+        self.exits = next.exits.iter().map(|x| x + base).collect();
+        self.commands.combine(next.commands);
+    }
+
+    fn extend_exits(&mut self, distance: usize) {
+        for index in &self.exits {
+            self.commands.descriptors.targets[*index].relocation_elements.push(RelocationTargetElement::Relative(distance as i32));
+        }
+    }
+}
+
+/// Commands checking a bool condition, which go on after themselves if it is true
+/// and skip `false_offset` more bytes after themselves if it is false.
+///
+/// `&&` and `||` are short-circuited: their right operands are not evaluated if the left ones decide the result.
+/// Comparisons and other values are checked by `jump by stack top` commands, so no bool value is pushed for them.
+pub fn branch_command_builder(
+    condition: &ExprNode,
+    false_offset: usize,
+    defined_data: &Vec<DataDeclarator>,
+    metadata: &PackageMetadata,
+) -> Result<RelocatableCommandList, GeneralIssue<PackageGenerationIssue>> {
+    let mut result = branch_piece_builder(condition, false, defined_data, metadata)?;
+    result.extend_exits(false_offset);
+
+    return Ok(result.commands);
+}
+
+/// Exits of the piece are taken if the value of `node` equals `jump_when`, otherwise the piece goes on after itself
+fn branch_piece_builder(
+    node: &ExprNode,
+    jump_when: bool,
+    defined_data: &Vec<DataDeclarator>,
+    metadata: &PackageMetadata,
+) -> Result<BranchPiece, GeneralIssue<PackageGenerationIssue>> {
+    return match &node.content {
+        NodeContent::Unary(Operator::Logical(LogicalOperator::Not), operand) => branch_piece_builder(operand, !jump_when, defined_data, metadata),
+        NodeContent::Binary(Operator::Logical(x @ (LogicalOperator::And | LogicalOperator::Or)), left, right) => {
+            // `a && b` is false as soon as `a` is false, and `a || b` is true as soon as `a` is true
+            let decided_by_left = *x == LogicalOperator::Or;
+            let mut result = branch_piece_builder(left, decided_by_left, defined_data, metadata)?;
+            let right_piece = branch_piece_builder(right, jump_when, defined_data, metadata)?;
+
+            if decided_by_left == jump_when {
+                result.chain(right_piece);
+            } else {
+                // The left operand leaving the piece means it goes on after the whole operation
+                result.resolve_before(right_piece);
+            }
+            Ok(result)
+        }
+        NodeContent::Binary(Operator::Relation(relation), left, right) => {
+            let mut commands = RelocatableCommandList::new();
+            commands.combine(build_expression_evaluation_command(&postfix_of(left), defined_data, metadata)?);
+            commands.combine(build_expression_evaluation_command(&postfix_of(right), defined_data, metadata)?);
+            commands.append_commands(minus_command());
+
+            Ok(stack_top_jump_builder(commands, relation_jump_directions(relation)?, jump_when, metadata))
+        }
+        _ => {
+            // A bool value is true if it isn't zero
+            let commands = build_expression_evaluation_command(&postfix_of(node), defined_data, metadata)?;
+
+            Ok(stack_top_jump_builder(commands, (true, true, false), jump_when, metadata))
+        }
+    };
+}
+
+/// Jump by the value on the stack top, `directions` tell whether the positive, negative and zero value means true
+fn stack_top_jump_builder(mut commands: RelocatableCommandList, directions: (bool, bool, bool), jump_when: bool, metadata: &PackageMetadata) -> BranchPiece {
+    let placeholder = jump_command_address_placeholder(metadata);
+    let jump_len = JumpCommand::ByStackTop.get_len(metadata.address_alignment);
+
+    // Command scheme: `0xD2 <PositiveLocation(0)> <NegativePosition(1)> <ZeroPosition(2)>`
+    // Every address jumps to the end of the piece at first, exits are extended by the caller
+    let mut exits = vec![];
+    for (index, is_true) in [directions.0, directions.1, directions.2].iter().enumerate() {
+        if *is_true == jump_when {
+            exits.push(commands.descriptors.targets.len());
+        }
+        commands.descriptors.targets.push(RelocationTarget {
+            relocation_elements: vec![RelocationTargetElement::Relative(jump_len as i32)],
+            command_array_position: commands.commands.len(),
+            offset: 1 + (placeholder.len() * index) as i32,
+            relocated_address: 0,
+        });
+    }
+
+    commands.command_entries.push(commands.commands.len());
+    commands.append_commands(vec![combine_command(RootCommand::Jump.to_opcode(), JumpCommand::ByStackTop.to_opcode())]);
+    commands.append_commands(vec![0; placeholder.len() * 3]);
+
+    return BranchPiece { commands, exits };
+}
+
+fn postfix_of(node: &ExprNode) -> SimpleExpression {
+    return SimpleExpression { postfix_expr: node.to_postfix(), output_type: Identifier::empty() };
+}
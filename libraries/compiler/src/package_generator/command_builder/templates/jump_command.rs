@@ -1,87 +1,33 @@
-use crate::package_generator::command_builder::expression_evaluation::build_expression_evaluation_command;
+use crate::package_generator::command_builder::expression_evaluation::push_instant_value_command;
 use crate::package_generator::command_builder::math::calculation::minus_command;
-use crate::package_generator::utils::{combine_command, jump_command_address_placeholder};
-use crate::shared::ast::blocks::expression::RelationExpression;
+use crate::package_generator::utils::{combine_command, generation_issue, jump_command_address_placeholder};
 use crate::shared::command_map::{JumpCommand, RootCommand};
-use crate::shared::package_generation::data_descriptor::DataDeclarator;
+use crate::shared::error::general_issue::GeneralIssue;
+use crate::shared::error::pkg_gen_issue::PackageGenerationIssue;
 use crate::shared::package_generation::package_descriptor::PackageMetadata;
 use crate::shared::package_generation::relocation_reference::{RelocatableCommandList, RelocationCredential, RelocationTarget, RelocationTargetElement};
 use crate::shared::token::operator::RelationOperator;
 
-/// ### Return value
-/// - Index `0`: An uncompleted relocation descriptor
-/// - Index `1`: The relocation target to be jumped when expression result could be True
-/// - Index `2`: The length of the bare jump command
-///
-pub fn jump_by_stack_top_command_template_builder(expr: &RelationExpression, defined_data: &Vec<DataDeclarator>, metadata: &PackageMetadata) -> (RelocatableCommandList, (bool, bool, bool)) {
-    let mut result = RelocatableCommandList::new();
-
-    // Evaluate expressions
-    // The evaluation result of the left-side expression is on the 2nd top position
-    // The right-side result is on the first position
-    result.combine(build_expression_evaluation_command(&expr.left, defined_data, metadata));
-    result.combine(build_expression_evaluation_command(&expr.right, defined_data, metadata));
-
-    // Current stack layout
-    // |      left        |
-    // |      right       |
-    // |      other       |
-
-    result.commands.extend(minus_command());
-
-    // Current stack layout
-    // |   right - left   |
-    // |      other       |
-
-    // Pre-save relocation target
-    // Add descriptors
-    let placeholder = jump_command_address_placeholder(metadata);
-    result.descriptors.targets.extend(vec![
-        RelocationTarget {
-            relocation_elements: vec![RelocationTargetElement::Undefined],
-            command_array_position: result.commands.len(),
-            offset: 1,
-            relocated_address: 0,
-        },
-        RelocationTarget {
-            relocation_elements: vec![RelocationTargetElement::Undefined],
-            command_array_position: result.commands.len(),
-            offset: 1 + placeholder.len() as i32,
-            relocated_address: 0,
-        },
-        RelocationTarget {
-            relocation_elements: vec![RelocationTargetElement::Undefined],
-            command_array_position: result.commands.len(),
-            offset: 1 + placeholder.len() as i32 * 2,
-            relocated_address: 0,
-        },
-    ]);
-
-    // Place conditional jump command
-    result.append_commands(vec![combine_command(RootCommand::Jump.to_opcode(), JumpCommand::ByStackTop.to_opcode())]);
-
-    // Push placeholder
-    result.append_commands(vec![0; placeholder.len() * 3]);
-
-    // Command scheme: `0xD2 <PositiveLocation(0)> <NegativePosition(1)> <ZeroPosition(2)>`
+/// Which ones of the positive, negative and zero `left - right` satisfy the relation
+pub fn relation_jump_directions(relation: &RelationOperator) -> Result<(bool, bool, bool), GeneralIssue<PackageGenerationIssue>> {
     let mut true_pos = (false, false, false);
-    match expr.expected_relation {
+    match relation {
         RelationOperator::Greater => {
-            // left - right < 0
-            true_pos.1 = true;
+            // left - right > 0
+            true_pos.0 = true;
         }
         RelationOperator::GreaterOrEqual => {
-            // left - right <= 0
-            true_pos.1 = true;
+            // left - right >= 0
+            true_pos.0 = true;
             true_pos.2 = true;
         }
         RelationOperator::Less => {
-            // left - right > 0
-            true_pos.0 = true;
+            // left - right < 0
+            true_pos.1 = true;
         }
         RelationOperator::LessOrEqual => {
-            // left - right >= 0
-            true_pos.0 = true;
+            // left - right <= 0
+            true_pos.1 = true;
             true_pos.2 = true;
         }
         RelationOperator::NotEqual => {
@@ -93,10 +39,52 @@ pub fn jump_by_stack_top_command_template_builder(expr: &RelationExpression, def
             // left - right == 0
             true_pos.2 = true;
         }
-        _ => panic!("Illegal operator")
+        _ => return Err(generation_issue("Illegal operator")),
     };
 
-    return (result, true_pos);
+    return Ok(true_pos);
+}
+
+/// Replace `left` and `right` on the top of the stack by the result of comparing them, `1` if it is true and `0` otherwise
+pub fn relation_value_command_builder(relation: &RelationOperator, metadata: &PackageMetadata) -> Result<RelocatableCommandList, GeneralIssue<PackageGenerationIssue>> {
+    let push_true = push_instant_value_command(String::from("1"), metadata)?;
+    let push_false = push_instant_value_command(String::from("0"), metadata)?;
+    let skip_false = direct_jump_command_builder(
+        vec![RelocationTargetElement::Relative((JumpCommand::ToRelative.get_len(metadata.address_alignment) + push_false.commands.len()) as i32)],
+        metadata,
+    );
+
+    // Command layout:
+    // <left - right>
+    // <jump by stack top>
+    // [true] <push 1> <jump to end>
+    // [false] <push 0>
+    // [end]
+    let true_offset = JumpCommand::ByStackTop.get_len(metadata.address_alignment);
+    let false_offset = true_offset + push_true.commands.len() + skip_false.commands.len();
+    let directions = relation_jump_directions(relation)?;
+
+    let mut result = RelocatableCommandList::new();
+    result.append_commands(minus_command());
+
+    let placeholder = jump_command_address_placeholder(metadata);
+    for (index, is_true) in [directions.0, directions.1, directions.2].iter().enumerate() {
+        result.descriptors.targets.push(RelocationTarget {
+            relocation_elements: vec![RelocationTargetElement::Relative(if *is_true { true_offset } else { false_offset } as i32)],
+            command_array_position: result.commands.len(),
+            offset: 1 + (placeholder.len() * index) as i32,
+            relocated_address: 0,
+        });
+    }
+    result.command_entries.push(result.commands.len());
+    result.append_commands(vec![combine_command(RootCommand::Jump.to_opcode(), JumpCommand::ByStackTop.to_opcode())]);
+    result.append_commands(vec![0; placeholder.len() * 3]);
+
+    result.combine(push_true);
+    result.combine(skip_false);
+    result.combine(push_false);
+
+    return Ok(result);
 }
 
 pub fn direct_jump_command_builder(elements: Vec<RelocationTargetElement>, metadata: &PackageMetadata) -> RelocatableCommandList {
@@ -119,6 +107,8 @@ pub fn direct_jump_command_builder(elements: Vec<RelocationTargetElement>, metad
         command_entries: vec![0],
         string_pool: vec![],
         function_table: vec![],
-        group_table: vec![]
+        group_table: vec![],
+        command_sources: vec![],
+        local_scopes: vec![],
     };
 }
@@ -1 +1,2 @@
+pub mod branch_command;
 pub mod jump_command;
@@ -3,3 +3,4 @@ pub mod command_builder;
 pub mod type_inference;
 pub mod utils;
 pub mod linear_action_tree_adapter;
+pub mod package_builder;
@@ -0,0 +1,140 @@
+use crate::package_generator::command_builder::function_block::build_function_command;
+use crate::package_generator::utils::{align_array_width, align_generated_width, generation_issue};
+use crate::shared::ast::blocks::function::FunctionLinkage;
+use crate::shared::ast::package::ParserPackageStructure;
+use crate::shared::error::general_issue::GeneralIssue;
+use crate::shared::error::pkg_gen_issue::PackageGenerationIssue;
+use crate::shared::package_generation::data_descriptor::StringConstant;
+use crate::shared::package_generation::debug_info::DebugInfo;
+use crate::shared::package_generation::implementations::package_descriptor::{EXECUTABLE_PACKAGE_TYPE, LIBRARY_PACKAGE_TYPE};
+use crate::shared::package_generation::package_descriptor::PackageMetadata;
+use crate::shared::package_generation::relocation_reference::{RelocatableCommandList, RelocationReferenceType};
+use crate::shared::utils::identifier::Identifier;
+use crate::shared::utils::source_map::SourceMap;
+
+/// Generate a whole package from a parsed structure
+///
+/// Layout:
+/// ```text
+/// <metadata> <entry point (executable only)> <string pool> <function table> <symbol table (library only)> <function commands>
+/// ```
+///
+/// External functions only take slots in the function table, their commands are provided by the linker.
+/// The tree is expected to pass `check_package`, code which couldn't be generated is still reported instead of panicking.
+pub fn build_package(tree: &ParserPackageStructure, string_pool: Vec<StringConstant>, metadata: &PackageMetadata) -> Result<Vec<u8>, GeneralIssue<PackageGenerationIssue>> {
+    let func_commands = build_relocatable_commands(tree, string_pool, metadata)?;
+    return build_package_from_commands(func_commands, &tree.entry_point, metadata);
+}
+
+/// Generate a package like `build_package`, followed by a debug section built from `sources`
+///
+/// Layout:
+/// ```text
+/// <package> <debug section>
+/// ```
+///
+/// Functions written in files missing in `sources` only have their local variables in the debug section.
+pub fn build_package_with_debug_info(
+    tree: &ParserPackageStructure,
+    string_pool: Vec<StringConstant>,
+    metadata: &PackageMetadata,
+    sources: &[SourceMap],
+) -> Result<Vec<u8>, GeneralIssue<PackageGenerationIssue>> {
+    let func_commands = build_relocatable_commands(tree, string_pool, metadata)?;
+    let mut output = place_commands(func_commands, &tree.entry_point, metadata)?;
+
+    let debug_info = DebugInfo::from_commands(&output, sources);
+    let section_offset = output.commands.len();
+    output.append_commands(debug_info.serialize(section_offset, metadata)?);
+
+    return Ok(output.commands);
+}
+
+/// Generate commands of functions defined in `tree` one after another, relocation is left to `build_package_from_commands`.
+/// Calls are kept as `EnterFunction` targets naming the called functions, so they could be defined in other objects.
+pub fn build_relocatable_commands(
+    tree: &ParserPackageStructure,
+    string_pool: Vec<StringConstant>,
+    metadata: &PackageMetadata,
+) -> Result<RelocatableCommandList, GeneralIssue<PackageGenerationIssue>> {
+    let mut func_commands = RelocatableCommandList::new();
+    func_commands.string_pool = string_pool;
+    func_commands.function_table = tree.export_function_table();
+
+    // Generate function commands
+    for func in tree.functions.iter().filter(|f| f.linkage != FunctionLinkage::External) {
+        // Set function entry point address in command section
+        let table_target = func_commands.function_table.iter_mut().find(|f| f.name == func.declarator.identifier);
+        if table_target.is_none() {
+            return Err(generation_issue(format!("Function \"{}\" is not found in the function table", func.declarator.identifier).as_str()));
+        }
+        table_target.unwrap().relocated_entry_address = func_commands.commands.len();
+
+        func_commands.combine(build_function_command(func, metadata)?);
+    }
+
+    return Ok(func_commands);
+}
+
+/// Place metadata, string pool and function table in front of function commands, then apply relocation.
+/// `func_commands` carries the string pool and the function table,
+/// the entry point is located by the `FunctionEntrance` reference of `entry_point`.
+pub fn build_package_from_commands(
+    func_commands: RelocatableCommandList,
+    entry_point: &Identifier,
+    metadata: &PackageMetadata,
+) -> Result<Vec<u8>, GeneralIssue<PackageGenerationIssue>> {
+    return Ok(place_commands(func_commands, entry_point, metadata)?.commands);
+}
+
+/// Relocated package with positions recorded in `func_commands` rebased to absolute offsets
+fn place_commands(
+    func_commands: RelocatableCommandList,
+    entry_point: &Identifier,
+    metadata: &PackageMetadata,
+) -> Result<RelocatableCommandList, GeneralIssue<PackageGenerationIssue>> {
+    let mut output = RelocatableCommandList::new();
+    output.string_pool = func_commands.string_pool.clone();
+    output.function_table = func_commands.function_table.clone();
+    // Place metadata
+    let serialized_metadata = metadata.serialize();
+    output.append_commands(serialized_metadata);
+    let prefix_len = output.commands.len();
+
+    // Reserve space for entry point if it is an executable
+    if metadata.package_type == EXECUTABLE_PACKAGE_TYPE {
+        output.append_commands(align_array_width(&vec![0x00], metadata.address_alignment));
+    }
+
+    // Place string pool
+    let string_pool_command = output.generate_string_pool(metadata.data_slot_alignment)?;
+    output.append_commands(string_pool_command);
+
+    // Place function table
+    let function_table_command = output.generate_function_table(metadata.address_alignment)?;
+    output.append_commands(function_table_command);
+
+    // Place symbol table, so the linker knows which functions are exported or external
+    if metadata.package_type == LIBRARY_PACKAGE_TYPE {
+        let symbol_table_command = output.generate_symbol_table(metadata)?;
+        output.append_commands(symbol_table_command);
+    }
+
+    output.combine(func_commands);
+
+    output.calculate_ref_to_target()?;
+    output.apply_relocation(metadata.address_alignment)?;
+
+    // Place entry_point
+    if metadata.package_type == EXECUTABLE_PACKAGE_TYPE {
+        let entry_function = output.descriptors.references.iter()
+                                   .find(|&p| p.ref_type == RelocationReferenceType::FunctionEntrance(entry_point.clone()));
+        if entry_function.is_none() {
+            return Err(generation_issue(format!("Entry function \"{}\" is not found", entry_point).as_str()));
+        }
+        let addr_u8_vec = align_generated_width(entry_function.unwrap().command_array_position.to_be_bytes().to_vec().as_ref(), metadata.address_alignment, "Entry point address")?;
+        output.commands.splice(prefix_len..(prefix_len + metadata.address_alignment as usize), addr_u8_vec);
+    }
+
+    return Ok(output);
+}
@@ -1,6 +1,7 @@
 use crate::shared::ast::action::VariableDefinition;
-use crate::shared::ast::blocks::expression::{ExprDataTerm, ExprTerm, SimpleExpression};
+use crate::shared::ast::blocks::expression::{ExprDataTerm, SimpleExpression, TermContent};
 use crate::shared::ast::blocks::function::Function;
+use crate::shared::token::operator::{CalculationOperator, LogicalOperator, Operator};
 use crate::shared::utils::identifier::Identifier;
 
 // Term must be DataTerm
@@ -33,47 +34,62 @@ pub fn infer_expression_term_data_type(
     };
 }
 
+/// Types of data terms are popped by operators like evaluating the expression.
+/// Calculations keep the type of their operands, comparisons of numbers and logical operations on bools give bools.
+/// Only numbers could be negated.
 pub fn infer_expression_output_type(
     expression: &SimpleExpression,
     defined_types: &Vec<Identifier>,
 ) -> Option<Identifier> {
-    let mut possible_types = defined_types.clone();
+    let mut type_stack: Vec<Identifier> = vec![];
     for term in &expression.postfix_expr {
-        let mut indexes_to_remove: Vec<usize> = vec![];
-        // Find out impossible data type
-        if term.content.get_data_term().is_some() {
-            for current in possible_types.iter().enumerate() {
-                if !is_castable_to_type(term, current.1) {
-                    indexes_to_remove.push(current.0);
+        let output_type = match &term.content {
+            TermContent::Data(_) => {
+                let data_type = term.content.get_data_term().unwrap().get_identifier()?;
+                // TODO: User defined types, try to find if the type can be casted into target type
+                if !defined_types.contains(data_type) {
+                    return None;
                 }
+
+                data_type.clone()
             }
-        }
+            TermContent::Operator(Operator::Logical(LogicalOperator::Not)) => {
+                if type_stack.pop()? != Identifier::single("bool") {
+                    return None;
+                }
 
-        // Delete indexes from the last one, prevent affecting the head of the possible_types array
-        indexes_to_remove.reverse();
+                Identifier::single("bool")
+            }
+            TermContent::Operator(Operator::Calculation(CalculationOperator::Negation)) => {
+                if type_stack.pop()? != Identifier::single("number") {
+                    return None;
+                }
 
-        // Remove impossible types
-        for index in indexes_to_remove {
-            possible_types.remove(index);
-        }
+                Identifier::single("number")
+            }
+            TermContent::Operator(operator) => {
+                let right = type_stack.pop()?;
+                let left = type_stack.pop()?;
+                if left != right {
+                    return None;
+                }
+
+                match operator {
+                    Operator::Calculation(_) => left,
+                    Operator::Relation(_) if left == Identifier::single("number") => Identifier::single("bool"),
+                    Operator::Logical(_) if left == Identifier::single("bool") => left,
+                    _ => return None,
+                }
+            }
+            _ => return None,
+        };
+
+        type_stack.push(output_type);
     }
 
-    return if possible_types.len() == 1 {
-        Option::from(possible_types[0].clone())
+    return if type_stack.len() == 1 {
+        type_stack.pop()
     } else {
         None
     };
 }
-
-// TermType must be Data, check it before calling this function
-fn is_castable_to_type(term: &ExprTerm, target_type: &Identifier) -> bool {
-    // Compiler defined types
-    let data = term.content.get_data_term().unwrap();
-    if data.get_identifier().unwrap() == target_type {
-        return true;
-    }
-
-    // TODO: User defined types, try to find if the type can be casted into target type
-
-    return false;
-}
@@ -4,6 +4,8 @@ use crate::package_generator::type_inference::expression::infer_expression_term_
 use crate::shared::ast::action::VariableDefinition;
 use crate::shared::ast::blocks::expression::{ExprDataTerm, SimpleExpression, TermContent};
 use crate::shared::ast::blocks::function::Function;
+use crate::shared::error::general_issue::{FileMatch, GeneralIssue, IssueBase, IssueLevel, IssuePosition, UNKNOWN_FILE_PATH};
+use crate::shared::error::pkg_gen_issue::PackageGenerationIssue;
 use crate::shared::package_generation::package_descriptor::PackageMetadata;
 use crate::shared::package_generation::relocation_reference::{
     RelocationReference, RelocationReferenceType,
@@ -37,6 +39,21 @@ pub fn infer_every_expression_data_term_type(
     return expr;
 }
 
+/// An issue found while generating commands, it is located by the caller which knows the source of the commands
+pub fn generation_issue(content: &str) -> GeneralIssue<PackageGenerationIssue> {
+    return GeneralIssue {
+        issues: vec![IssueBase {
+            level: IssueLevel::Error,
+            position: IssuePosition::CodeGeneration,
+            code: "-1".to_string(),
+            detail: PackageGenerationIssue {
+                content: content.to_string(),
+                location: FileMatch { file_path: UNKNOWN_FILE_PATH.to_string(), start_pos: 0, end_pos: 0 },
+            },
+        }]
+    };
+}
+
 pub fn combine_command(master: u8, sub: u8) -> u8 {
     return master * 0x10 + sub;
 }
@@ -66,14 +83,26 @@ pub fn convert_number_to_hex(mut number: String) -> String {
 /// `1234567` -> `[01, 23, 45, 67]`
 ///
 /// `0x7c00` -> `[7c, 00]`
-pub fn convert_to_u8_array(number: String) -> Vec<u8> {
-    let t = number.parse::<isize>().unwrap();
+///
+/// Returns `None` if the number is out of the range of `isize`.
+pub fn convert_to_u8_array(number: String) -> Option<Vec<u8>> {
+    let t = number.parse::<isize>().ok()?;
     let result = t.to_be_bytes();
 
-    return result.to_vec();
+    return Some(result.to_vec());
 }
 
 pub fn align_array_width(data_array: &Vec<u8>, target_len: u8) -> Vec<u8> {
+    let result = try_align_array_width(data_array, target_len);
+    if result.is_none() {
+        panic!("Data width is too short, consider changing it into a longer width (data/target : {}/{})", data_array.len(), target_len);
+    }
+
+    return result.unwrap();
+}
+
+/// Like `align_array_width`, but returns `None` instead of panicking if the data doesn't fit in `target_len` bytes
+pub fn try_align_array_width(data_array: &Vec<u8>, target_len: u8) -> Option<Vec<u8>> {
     let mut result: Vec<u8> = vec![];
 
     if data_array.len() < target_len as usize {
@@ -87,13 +116,19 @@ pub fn align_array_width(data_array: &Vec<u8>, target_len: u8) -> Vec<u8> {
             if result[0] == 0x00 {
                 result.remove(0);
             } else {
-                panic!("Data width is too short, consider changing it into a longer width (data/target : {}/{})", data_array.len(), target_len);
+                return None;
             }
         }
     } else {
         result = data_array.clone();
     }
-    return result;
+    return Some(result);
+}
+
+/// `try_align_array_width` for generated commands, `name` tells what the data is in the issue
+pub fn align_generated_width(data_array: &Vec<u8>, target_len: u8, name: &str) -> Result<Vec<u8>, GeneralIssue<PackageGenerationIssue>> {
+    return try_align_array_width(data_array, target_len)
+        .ok_or_else(|| generation_issue(format!("{} doesn't fit in {} bytes, consider changing the alignment into a longer width", name, target_len).as_str()));
 }
 
 pub fn string_to_hex_char(s: String) -> char {
@@ -198,8 +233,10 @@ pub fn is_function_begin_command(reloc_ref: &RelocationReference) -> bool {
     }
 }
 
-pub fn pair_container_action(references: &[RelocationReference]) -> (&RelocationReference, usize)  {
-    let reloc_type = &references[0].ref_type;
+/// The reference closing the container opened by the first one of `references`, and its index.
+/// Returns `None` if the first reference doesn't open a container.
+pub fn pair_container_action(references: &[RelocationReference]) -> Option<(&RelocationReference, usize)>  {
+    let reloc_type = &references.first()?.ref_type;
     let opp_reloc_type = match reloc_type{
         RelocationReferenceType::IfEntrance => RelocationReferenceType::EndIf,
         RelocationReferenceType::ElifEntrance => RelocationReferenceType::EndElif,
@@ -207,7 +244,7 @@ pub fn pair_container_action(references: &[RelocationReference]) -> (&Relocation
         RelocationReferenceType::WhileEntrance => RelocationReferenceType::EndWhile,
         RelocationReferenceType::LoopEntrance => RelocationReferenceType::EndLoop,
         RelocationReferenceType::DomainEntrance => RelocationReferenceType::EndDomain,
-        _ => panic!("Invalid container pair type")
+        _ => return None,
     };
 
     let mut layer: usize = 0;
@@ -219,9 +256,9 @@ pub fn pair_container_action(references: &[RelocationReference]) -> (&Relocation
         }
 
         if layer == 0 {
-            return (reference, idx);
+            return Some((reference, idx));
         }
     }
 
-    return (&references[0], 0);
+    return Some((&references[0], 0));
 }
@@ -0,0 +1,133 @@
+use crate::package_reader::utils::{read_bytes, read_unsigned, reading_issue};
+use crate::shared::error::general_issue::GeneralIssue;
+use crate::shared::error::package_reading_issue::PackageReadingIssue;
+use crate::shared::package_generation::debug_info::{DebugDomain, DebugFunction, DebugInfo, DomainKind, LineTableEntry, LocalVariableScope};
+use crate::shared::package_generation::implementations::debug_info::DEBUG_SECTION_SIGNATURE;
+use crate::shared::package_generation::package_descriptor::PackageMetadata;
+
+/// Offset of the debug section placed at the end of a package, packages without it end with commands.
+/// Sections are only recognized after `code_offset` and between two signatures.
+pub fn find_debug_section(bytes: &[u8], code_offset: usize, metadata: &PackageMetadata) -> Option<usize> {
+    let trailer_len = metadata.address_alignment as usize + DEBUG_SECTION_SIGNATURE.len();
+    if bytes.len() < code_offset + DEBUG_SECTION_SIGNATURE.len() + trailer_len || !bytes.ends_with(DEBUG_SECTION_SIGNATURE) {
+        return None;
+    }
+
+    let section_offset = read_unsigned(bytes, bytes.len() - trailer_len, metadata.address_alignment).ok()?;
+    if section_offset < code_offset || section_offset > bytes.len() - trailer_len - DEBUG_SECTION_SIGNATURE.len() {
+        return None;
+    }
+    if !bytes[section_offset..].starts_with(DEBUG_SECTION_SIGNATURE) {
+        return None;
+    }
+
+    return Some(section_offset);
+}
+
+/// Read a debug section written by `DebugInfo::serialize` at `section_offset`.
+/// Addresses in it must point into `0..section_offset`, which is the rest of the package.
+pub fn read_debug_info(bytes: &[u8], section_offset: usize, metadata: &PackageMetadata) -> Result<DebugInfo, GeneralIssue<PackageReadingIssue>> {
+    let address_width = metadata.address_alignment;
+    let slot_width = metadata.data_slot_alignment;
+
+    let mut result = DebugInfo { files: vec![], line_table: vec![], locals: vec![], functions: vec![], domains: vec![] };
+    let mut position = section_offset + DEBUG_SECTION_SIGNATURE.len();
+
+    let file_count = read_unsigned(bytes, position, address_width)?;
+    position += address_width as usize;
+    for _ in 0..file_count {
+        let (file, len) = read_string(bytes, position, slot_width)?;
+        position += len;
+        result.files.push(file);
+    }
+
+    let line_count = read_unsigned(bytes, position, address_width)?;
+    position += address_width as usize;
+    for _ in 0..line_count {
+        let address = read_unsigned(bytes, position, address_width)?;
+        if address >= section_offset {
+            return Err(reading_issue("Line table address is out of package", position));
+        }
+        position += address_width as usize;
+
+        let file_index = read_unsigned(bytes, position, slot_width)?;
+        if file_index >= result.files.len() {
+            return Err(reading_issue(format!("File {} of the line table is not found", file_index).as_str(), position));
+        }
+        position += slot_width as usize;
+
+        let line = read_unsigned(bytes, position, address_width)?;
+        position += address_width as usize;
+        let column = read_unsigned(bytes, position, address_width)?;
+        position += address_width as usize;
+
+        if result.line_table.last().is_some_and(|e: &LineTableEntry| e.address >= address) {
+            return Err(reading_issue("Line table is not sorted by addresses", position));
+        }
+        result.line_table.push(LineTableEntry { address, file_index, line, column });
+    }
+
+    let local_count = read_unsigned(bytes, position, address_width)?;
+    position += address_width as usize;
+    for _ in 0..local_count {
+        let slot = read_unsigned(bytes, position, slot_width)?;
+        position += slot_width as usize;
+
+        let start = read_unsigned(bytes, position, address_width)?;
+        let end = read_unsigned(bytes, position + address_width as usize, address_width)?;
+        if start > end || end > section_offset {
+            return Err(reading_issue("Scope of local variable is out of package", position));
+        }
+        position += 2 * address_width as usize;
+
+        let (name, len) = read_string(bytes, position, slot_width)?;
+        position += len;
+        result.locals.push(LocalVariableScope { name, slot, start, end });
+    }
+
+    let function_count = read_unsigned(bytes, position, address_width)?;
+    position += address_width as usize;
+    for _ in 0..function_count {
+        let slot = read_unsigned(bytes, position, slot_width)?;
+        position += slot_width as usize;
+
+        let (name, len) = read_string(bytes, position, slot_width)?;
+        position += len;
+        result.functions.push(DebugFunction { slot, name });
+    }
+
+    let domain_count = read_unsigned(bytes, position, address_width)?;
+    position += address_width as usize;
+    for _ in 0..domain_count {
+        let flag = read_bytes(bytes, position, 1)?[0];
+        let kind = DomainKind::from_flag(flag);
+        if kind.is_none() {
+            return Err(reading_issue(format!("Unknown domain kind 0x{:02X}", flag).as_str(), position));
+        }
+        position += 1;
+
+        let start = read_unsigned(bytes, position, address_width)?;
+        let end = read_unsigned(bytes, position + address_width as usize, address_width)?;
+        if start > end || end > section_offset {
+            return Err(reading_issue("Domain is out of package", position));
+        }
+        position += 2 * address_width as usize;
+
+        result.domains.push(DebugDomain { kind: kind.unwrap(), start, end });
+    }
+
+    return Ok(result);
+}
+
+/// A string led by its length, returns it with the count of bytes read
+fn read_string(bytes: &[u8], position: usize, len_width: u8) -> Result<(String, usize), GeneralIssue<PackageReadingIssue>> {
+    let len = read_unsigned(bytes, position, len_width)?;
+    let begin = position + len_width as usize;
+
+    let value = String::from_utf8(read_bytes(bytes, begin, len)?.to_vec());
+    if value.is_err() {
+        return Err(reading_issue("String in debug section is not valid UTF-8", begin));
+    }
+
+    return Ok((value.unwrap(), len_width as usize + len));
+}
@@ -0,0 +1,208 @@
+use std::collections::HashMap;
+
+use itertools::Itertools;
+
+use crate::package_reader::instruction_decoder::decode_instruction;
+use crate::package_reader::layout_reader::read_package_layout;
+use crate::shared::ast::blocks::function::FunctionLinkage;
+use crate::shared::error::general_issue::GeneralIssue;
+use crate::shared::error::package_reading_issue::PackageReadingIssue;
+use crate::shared::package_reading::instruction::{DataAccess, DecodedInstruction, Instruction};
+use crate::shared::package_reading::package_layout::PackageLayout;
+
+const COMMENT_COLUMN: usize = 40;
+
+/// Produce a readable listing of a package.
+/// Jump targets are replaced by labels and functions are named after their slots (`fn_<slot>`),
+/// the address and raw bytes of every command are placed in comments.
+/// Packages with a debug section also get source locations and names of local variables in comments.
+pub fn disassemble_package(bytes: &[u8]) -> Result<String, GeneralIssue<PackageReadingIssue>> {
+    let layout = read_package_layout(bytes)?;
+    let metadata = &layout.metadata;
+
+    let mut lines: Vec<String> = vec![];
+    lines.push(format!("; Carbon package, {} bytes", bytes.len()));
+    lines.push(format!(
+        ".metadata type={} data={} domain_layer={} data_slot={} address={}",
+        metadata.package_type,
+        metadata.data_alignment,
+        metadata.domain_layer_count_alignment,
+        metadata.data_slot_alignment,
+        metadata.address_alignment
+    ));
+
+    let function_labels: HashMap<usize, String> = layout.function_table
+                                                        .iter()
+                                                        .map(|f| (f.entry_address, format!("fn_{}", f.slot)))
+                                                        .collect();
+
+    if let Some(entry) = layout.entry_point {
+        match function_labels.get(&entry) {
+            Some(label) => lines.push(format!(".entry {}", label)),
+            None => lines.push(format!("; Entry point 0x{:08X} is not the head of any function", entry)),
+        }
+    }
+
+    // Symbols are not part of the assembly, they are listed for reference
+    for symbol in &layout.symbols {
+        let linkage = if symbol.linkage == FunctionLinkage::Export { "export" } else { "external" };
+        lines.push(format!("; {} {} = fn_{}", linkage, symbol.name, symbol.slot));
+    }
+
+    lines.push(String::new());
+    lines.push(format!("; String pool ({} constants)", layout.string_pool.len()));
+    for constant in &layout.string_pool {
+        lines.push(with_comment(format!(".string {:?}", constant.value), format!("@{}", constant.slot)));
+    }
+
+    lines.push(String::new());
+    lines.push(format!("; Function table ({} functions), commands start at 0x{:08X}", layout.function_table.len(), layout.code_offset));
+
+    if let Some(debug_info) = &layout.debug_info {
+        lines.push(format!(
+            "; Debug section ({} files, {} line entries, {} local variables)",
+            debug_info.files.len(),
+            debug_info.line_table.len(),
+            debug_info.locals.len()
+        ));
+    }
+
+    let instructions = decode_all(&layout);
+
+    // Every jump target gets a label unless it is the head of a function
+    let mut labels: HashMap<usize, String> = function_labels.clone();
+    for item in instructions.iter().filter_map(|i| i.as_ref().ok()) {
+        for offset in item.instruction.jump_offsets() {
+            let target = (item.position as i64 + offset) as usize;
+            labels.entry(target).or_insert_with(|| format!("L{:08X}", target));
+        }
+    }
+
+    for item in &instructions {
+        let position = match item {
+            Ok(x) => x.position,
+            Err(x) => x.0,
+        };
+
+        if let Some(function) = layout.function_table.iter().find(|f| f.entry_address == position) {
+            lines.push(String::new());
+            let mut comment = format!("slot {}, entry 0x{:08X}", function.slot, function.entry_address);
+            if let Some(name) = layout.debug_info.as_ref().and_then(|d| d.function_name(function.slot)) {
+                comment.push_str(format!(", {}", name).as_str());
+            }
+            lines.push(with_comment(format!(".func fn_{}", function.slot), comment));
+            lines.extend(local_comments(&layout, function.entry_address));
+        } else if let Some(label) = labels.get(&position) {
+            lines.push(format!("{}:", label));
+        }
+
+        if let Some(debug_info) = &layout.debug_info {
+            if debug_info.line_at(position).is_some_and(|e| e.address == position) {
+                lines.push(format!("    ; {}", debug_info.location_at(position).unwrap()));
+            }
+        }
+
+        match item {
+            Ok(decoded) => {
+                let raw = &bytes[decoded.position..(decoded.position + decoded.length)];
+                let mut comment = format!("{:08X}  {}", decoded.position, hex_bytes(raw));
+                if let Some(value) = string_operand(decoded, &layout) {
+                    comment.push_str(format!("  {:?}", value).as_str());
+                }
+
+                lines.push(with_comment(format!("    {}", format_instruction(decoded, &labels)), comment));
+            }
+            Err((position, message)) => {
+                lines.push(with_comment(
+                    format!("    .byte 0x{:02X}", bytes[*position]),
+                    format!("{:08X}  {}", position, message),
+                ));
+            }
+        }
+    }
+
+    let mut result = lines.join("\n");
+    result.push('\n');
+    return Ok(result);
+}
+
+/// Decode the function command section, bytes which couldn't be decoded are kept one by one
+fn decode_all(layout: &PackageLayout) -> Vec<Result<DecodedInstruction, (usize, String)>> {
+    let mut result = vec![];
+
+    let mut position = layout.code_offset;
+    while position < layout.commands.len() {
+        match decode_instruction(&layout.commands, position, &layout.metadata) {
+            Ok(decoded) => {
+                position += decoded.length;
+                result.push(Ok(decoded));
+            }
+            Err(issue) => {
+                result.push(Err((position, issue.issues[0].detail.content.clone())));
+                position += 1;
+            }
+        }
+    }
+
+    return result;
+}
+
+/// Names of local variables declared in the function starting at `entry_address`
+fn local_comments(layout: &PackageLayout, entry_address: usize) -> Vec<String> {
+    if layout.debug_info.is_none() {
+        return vec![];
+    }
+
+    // A function ends where the next one starts
+    let end = layout.function_table
+                    .iter()
+                    .map(|f| f.entry_address)
+                    .filter(|a| *a > entry_address)
+                    .min()
+                    .unwrap_or(layout.commands.len());
+
+    return layout.debug_info
+                 .as_ref()
+                 .unwrap()
+                 .locals
+                 .iter()
+                 .filter(|l| entry_address <= l.start && l.start < end)
+                 .map(|l| format!("    ; local ${} {} (0x{:08X}..0x{:08X})", l.slot, l.name, l.start, l.end))
+                 .collect();
+}
+
+/// Assembly of a single command, jump targets found in `labels` are replaced by them
+pub fn format_instruction(decoded: &DecodedInstruction, labels: &HashMap<usize, String>) -> String {
+    let label_of = |offset: &i64| -> String {
+        let target = (decoded.position as i64 + offset) as usize;
+        return labels.get(&target).cloned().unwrap_or_else(|| format!("{:+}", offset));
+    };
+
+    let mnemonic = decoded.instruction.mnemonic();
+    return match &decoded.instruction {
+        Instruction::CreateObject { is_global } => format!("{} {}", mnemonic, if *is_global { "global" } else { "local" }),
+        Instruction::DestroyObject { slot } => format!("{} ${}", mnemonic, slot),
+        Instruction::Push(dac) | Instruction::PushFromObject(dac) | Instruction::PopToObject(dac) => format!("{} {}", mnemonic, dac),
+        Instruction::JumpToRelative(offset) => format!("{} {}", mnemonic, label_of(offset)),
+        Instruction::JumpByStackTop { .. } => format!("{} {}", mnemonic, decoded.instruction.jump_offsets().iter().map(label_of).join(" ")),
+        Instruction::EnterFunction { slot, argument_count } => format!("{} fn_{} {}", mnemonic, slot, argument_count),
+        _ => mnemonic.to_string(),
+    };
+}
+
+fn string_operand(decoded: &DecodedInstruction, layout: &PackageLayout) -> Option<String> {
+    return match &decoded.instruction {
+        Instruction::Push(DataAccess::StringSlot(slot)) | Instruction::PushFromObject(DataAccess::StringSlot(slot)) => {
+            layout.string_pool.iter().find(|s| s.slot == *slot).map(|s| s.value.clone())
+        }
+        _ => None,
+    };
+}
+
+fn hex_bytes(bytes: &[u8]) -> String {
+    return bytes.iter().map(|b| format!("{:02X}", b)).join(" ");
+}
+
+fn with_comment(content: String, comment: String) -> String {
+    return format!("{:<width$} ; {}", content, comment, width = COMMENT_COLUMN);
+}
@@ -0,0 +1,141 @@
+use crate::package_generator::utils::jump_command_address_placeholder_len;
+use crate::package_reader::utils::{read_bytes, read_signed, read_unsigned, reading_issue};
+use crate::shared::command_map::{FunctionCommand, JumpCommand, MathCalcCommand, MathCommand, MathLogicalCommand, ObjectCommand, RootCommand, StackCommand};
+use crate::shared::error::general_issue::GeneralIssue;
+use crate::shared::error::package_reading_issue::PackageReadingIssue;
+use crate::shared::package_generation::package_descriptor::PackageMetadata;
+use crate::shared::package_reading::instruction::{DataAccess, DecodedInstruction, Instruction};
+
+/// Decode the command whose head is at `position`
+pub fn decode_instruction(
+    bytes: &[u8],
+    position: usize,
+    metadata: &PackageMetadata,
+) -> Result<DecodedInstruction, GeneralIssue<PackageReadingIssue>> {
+    let head = read_bytes(bytes, position, 1)?[0];
+    let root = RootCommand::from_opcode(head / 0x10);
+    if root.is_none() {
+        return Err(reading_issue(format!("Unknown command 0x{:02X}", head).as_str(), position));
+    }
+
+    let sub = head % 0x10;
+    let unknown_sub = || reading_issue(format!("Unknown command 0x{:02X}", head).as_str(), position);
+    // Operands start right after the command head
+    let operand = position + 1;
+
+    let (instruction, length) = match root.unwrap() {
+        RootCommand::Object => match ObjectCommand::from_opcode(sub).ok_or_else(unknown_sub)? {
+            ObjectCommand::Create => {
+                let flag = read_bytes(bytes, operand, 1)?[0];
+                (Instruction::CreateObject { is_global: flag / 0x10 != 0 }, 2)
+            }
+            ObjectCommand::Destroy => {
+                let slot = read_unsigned(bytes, operand, metadata.data_slot_alignment)?;
+                (Instruction::DestroyObject { slot }, 1 + metadata.data_slot_alignment as usize)
+            }
+        },
+        RootCommand::Stack => match StackCommand::from_opcode(sub).ok_or_else(unknown_sub)? {
+            StackCommand::Push => {
+                let (dac, len) = decode_data_access(bytes, operand, metadata)?;
+                (Instruction::Push(dac), 1 + len)
+            }
+            StackCommand::PushFromObject => {
+                let (dac, len) = decode_data_access(bytes, operand, metadata)?;
+                (Instruction::PushFromObject(dac), 1 + len)
+            }
+            StackCommand::Pop => (Instruction::Pop, 1),
+            StackCommand::PopToObject => {
+                let (dac, len) = decode_data_access(bytes, operand, metadata)?;
+                (Instruction::PopToObject(dac), 1 + len)
+            }
+        },
+        RootCommand::Domain => return Err(unknown_sub()),
+        RootCommand::Jump => {
+            let address_len = jump_command_address_placeholder_len(metadata.address_alignment);
+            match JumpCommand::from_opcode(sub).ok_or_else(unknown_sub)? {
+                JumpCommand::ToRelative => {
+                    let offset = decode_relative_address(bytes, operand, metadata)?;
+                    (Instruction::JumpToRelative(offset), 1 + address_len)
+                }
+                JumpCommand::ByStackTop => {
+                    let positive = decode_relative_address(bytes, operand, metadata)?;
+                    let negative = decode_relative_address(bytes, operand + address_len, metadata)?;
+                    let zero = decode_relative_address(bytes, operand + address_len * 2, metadata)?;
+                    (Instruction::JumpByStackTop { positive, negative, zero }, 1 + address_len * 3)
+                }
+            }
+        }
+        RootCommand::Function => match FunctionCommand::from_opcode(sub).ok_or_else(unknown_sub)? {
+            FunctionCommand::Enter => {
+                // Layout: `0xE1 0x00 <slot> <argument count>`
+                let address_len = jump_command_address_placeholder_len(metadata.address_alignment);
+                let slot = read_unsigned(bytes, operand + 1, metadata.address_alignment)?;
+                let argument_count = read_bytes(bytes, operand + address_len, 1)?[0];
+                (Instruction::EnterFunction { slot, argument_count }, 1 + address_len + 1)
+            }
+            FunctionCommand::LeaveWithoutValue => (Instruction::LeaveWithoutValue, 1),
+            FunctionCommand::LeaveWithValue => (Instruction::LeaveWithValue, 1),
+            FunctionCommand::FunctionEndFlag => (Instruction::FunctionEndFlag, 1),
+        },
+        RootCommand::Math => {
+            let sub_command = read_bytes(bytes, operand, 1)?[0];
+            let unknown_math = || reading_issue(format!("Unknown math command 0x{:02X} 0x{:02X}", head, sub_command).as_str(), position);
+            match MathCommand::from_opcode(sub).ok_or_else(unknown_sub)? {
+                MathCommand::Calculation => (Instruction::Calculation(MathCalcCommand::from_opcode(sub_command).ok_or_else(unknown_math)?), 2),
+                MathCommand::Logical => (Instruction::Logical(MathLogicalCommand::from_opcode(sub_command).ok_or_else(unknown_math)?), 2),
+            }
+        }
+    };
+
+    return Ok(DecodedInstruction { position, length, instruction });
+}
+
+/// Decode commands one by one from `start` until `end` (exclusive)
+pub fn decode_instructions(
+    bytes: &[u8],
+    start: usize,
+    end: usize,
+    metadata: &PackageMetadata,
+) -> Result<Vec<DecodedInstruction>, GeneralIssue<PackageReadingIssue>> {
+    let mut result = vec![];
+
+    let mut position = start;
+    while position < end {
+        let instruction = decode_instruction(bytes, position, metadata)?;
+        position += instruction.length;
+        result.push(instruction);
+    }
+
+    return Ok(result);
+}
+
+fn decode_data_access(
+    bytes: &[u8],
+    position: usize,
+    metadata: &PackageMetadata,
+) -> Result<(DataAccess, usize), GeneralIssue<PackageReadingIssue>> {
+    let flag = read_bytes(bytes, position, 1)?[0];
+
+    return match flag {
+        0x00 => Ok((DataAccess::InstantValue(read_signed(bytes, position + 1, metadata.data_alignment)?), 1 + metadata.data_alignment as usize)),
+        0x01 => Ok((DataAccess::LocalSlot(read_unsigned(bytes, position + 1, metadata.data_slot_alignment)?), 1 + metadata.data_slot_alignment as usize)),
+        0x02 => Ok((DataAccess::StringSlot(read_unsigned(bytes, position + 1, metadata.data_slot_alignment)?), 1 + metadata.data_slot_alignment as usize)),
+        _ => Err(reading_issue(format!("Unknown data access type 0x{:02X}", flag).as_str(), position)),
+    };
+}
+
+/// Layout: `<sign> <absolute value>`, sign is `0x0F` for positive and `0x0B` for negative
+fn decode_relative_address(
+    bytes: &[u8],
+    position: usize,
+    metadata: &PackageMetadata,
+) -> Result<i64, GeneralIssue<PackageReadingIssue>> {
+    let sign = read_bytes(bytes, position, 1)?[0];
+    let value = read_unsigned(bytes, position + 1, metadata.address_alignment)? as i64;
+
+    return match sign {
+        0x0F => Ok(value),
+        0x0B => Ok(-value),
+        _ => Err(reading_issue(format!("Invalid jump address sign 0x{:02X}", sign).as_str(), position)),
+    };
+}
@@ -0,0 +1,135 @@
+use crate::package_reader::debug_info_reader::{find_debug_section, read_debug_info};
+use crate::package_reader::utils::{read_bytes, read_unsigned, reading_issue};
+use crate::shared::error::general_issue::GeneralIssue;
+use crate::shared::error::package_reading_issue::PackageReadingIssue;
+use crate::shared::package_generation::data_descriptor::StringConstant;
+use crate::shared::ast::blocks::function::FunctionLinkage;
+use crate::shared::package_generation::implementations::package_descriptor::{EXECUTABLE_PACKAGE_TYPE, LIBRARY_PACKAGE_TYPE, PACKAGE_METADATA_LEN};
+use crate::shared::package_generation::package_descriptor::PackageMetadata;
+use crate::shared::package_reading::package_layout::{PackageFunctionEntry, PackageLayout, PackageSymbol};
+
+/// Split a package into sections
+///
+/// Layout:
+/// ```text
+/// <metadata> <entry point (executable only)> <string pool> <function table> <symbol table (library only)> <function commands> <debug section (optional)>
+/// ```
+///
+/// The debug section is left out of `commands`, so commands end at the end of them.
+pub fn read_package_layout(bytes: &[u8]) -> Result<PackageLayout, GeneralIssue<PackageReadingIssue>> {
+    let metadata = PackageMetadata::deserialize(bytes);
+    if metadata.is_none() {
+        return Err(reading_issue("Package metadata is incomplete", 0));
+    }
+    let metadata = metadata.unwrap();
+    if metadata.address_alignment == 0 || metadata.data_slot_alignment == 0 || metadata.data_alignment == 0 {
+        return Err(reading_issue("Package metadata contains zero alignment", 0));
+    }
+
+    let mut position = PACKAGE_METADATA_LEN;
+
+    // Entry point is an absolute offset
+    let mut entry_point = None;
+    if metadata.package_type == EXECUTABLE_PACKAGE_TYPE {
+        entry_point = Some(read_unsigned(bytes, position, metadata.address_alignment)?);
+        position += metadata.address_alignment as usize;
+    }
+
+    // String pool
+    let string_count = read_unsigned(bytes, position, metadata.data_slot_alignment)?;
+    position += metadata.data_slot_alignment as usize;
+
+    let mut string_pool = vec![];
+    for slot in 0..string_count {
+        let len = read_unsigned(bytes, position, metadata.data_slot_alignment)?;
+        position += metadata.data_slot_alignment as usize;
+
+        let value = String::from_utf8(read_bytes(bytes, position, len)?.to_vec());
+        if value.is_err() {
+            return Err(reading_issue("String constant is not valid UTF-8", position));
+        }
+        position += len;
+
+        string_pool.push(StringConstant { value: value.unwrap(), slot });
+    }
+
+    // Function table, entry addresses are relative to the function command section which follows it
+    let function_count = read_unsigned(bytes, position, metadata.address_alignment)?;
+    position += metadata.address_alignment as usize;
+
+    let mut relative_entries = vec![];
+    for _ in 0..function_count {
+        let slot = read_unsigned(bytes, position, metadata.address_alignment)?;
+        position += metadata.address_alignment as usize;
+        let address = read_unsigned(bytes, position, metadata.address_alignment)?;
+        position += metadata.address_alignment as usize;
+
+        relative_entries.push((slot, address));
+    }
+
+    let mut symbols = vec![];
+    if metadata.package_type == LIBRARY_PACKAGE_TYPE {
+        let symbol_count = read_unsigned(bytes, position, metadata.address_alignment)?;
+        position += metadata.address_alignment as usize;
+
+        for _ in 0..symbol_count {
+            let linkage = match read_bytes(bytes, position, 1)?[0] {
+                0x01 => FunctionLinkage::Export,
+                0x02 => FunctionLinkage::External,
+                x => return Err(reading_issue(format!("Unknown symbol linkage 0x{:02X}", x).as_str(), position)),
+            };
+            position += 1;
+            let slot = read_unsigned(bytes, position, metadata.address_alignment)?;
+            position += metadata.address_alignment as usize;
+            let len = read_unsigned(bytes, position, metadata.data_slot_alignment)?;
+            position += metadata.data_slot_alignment as usize;
+
+            let name = String::from_utf8(read_bytes(bytes, position, len)?.to_vec());
+            if name.is_err() {
+                return Err(reading_issue("Symbol name is not valid UTF-8", position));
+            }
+            position += len;
+
+            // Exported functions must have an entry, while external ones must not
+            let defined = relative_entries.iter().any(|(s, _)| *s == slot);
+            if defined != (linkage == FunctionLinkage::Export) {
+                return Err(reading_issue(format!("Symbol \"{}\" doesn't match the function table", name.unwrap()).as_str(), position));
+            }
+
+            symbols.push(PackageSymbol { name: name.unwrap(), slot, linkage });
+        }
+    }
+
+    let code_offset = position;
+
+    let mut code_end = bytes.len();
+    let mut debug_info = None;
+    if let Some(section_offset) = find_debug_section(bytes, code_offset, &metadata) {
+        debug_info = Some(read_debug_info(bytes, section_offset, &metadata)?);
+        code_end = section_offset;
+    }
+
+    let mut function_table = vec![];
+    for (slot, address) in relative_entries {
+        if code_offset + address >= code_end {
+            return Err(reading_issue(format!("Entry of function slot {} is out of package", slot).as_str(), code_offset));
+        }
+
+        function_table.push(PackageFunctionEntry { slot, entry_address: code_offset + address });
+    }
+
+    if entry_point.is_some() && entry_point.unwrap() >= code_end {
+        return Err(reading_issue("Entry point is out of package", PACKAGE_METADATA_LEN));
+    }
+
+    return Ok(PackageLayout {
+        metadata,
+        entry_point,
+        string_pool,
+        function_table,
+        symbols,
+        code_offset,
+        commands: bytes[..code_end].to_vec(),
+        debug_info,
+    });
+}
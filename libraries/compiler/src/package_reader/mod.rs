@@ -0,0 +1,6 @@
+pub mod debug_info_reader;
+pub mod disassembler;
+pub mod instruction_decoder;
+pub mod layout_reader;
+pub mod object_reader;
+pub mod utils;
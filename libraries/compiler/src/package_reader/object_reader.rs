@@ -0,0 +1,209 @@
+use crate::package_reader::utils::{read_bytes, read_unsigned, reading_issue};
+use crate::shared::ast::blocks::function::FunctionLinkage;
+use crate::shared::error::general_issue::GeneralIssue;
+use crate::shared::error::package_reading_issue::PackageReadingIssue;
+use crate::shared::package_generation::data_descriptor::StringConstant;
+use crate::shared::package_generation::func_table::FunctionTableEntry;
+use crate::shared::package_generation::implementations::object_file::{OBJECT_FILE_SIGNATURE, OBJECT_FILE_VERSION};
+use crate::shared::package_generation::implementations::package_descriptor::PACKAGE_METADATA_LEN;
+use crate::shared::package_generation::object_file::ObjectFile;
+use crate::shared::package_generation::package_descriptor::PackageMetadata;
+use crate::shared::package_generation::relocation_reference::{RelocatableCommandList,
+                                                              RelocationReference,
+                                                              RelocationReferenceType,
+                                                              RelocationTarget,
+                                                              RelocationTargetElement};
+use crate::shared::utils::identifier::Identifier;
+
+/// Whether `bytes` start with the signature of object files, packages don't
+pub fn is_object_file(bytes: &[u8]) -> bool {
+    return bytes.starts_with(OBJECT_FILE_SIGNATURE);
+}
+
+/// Read an object written by `ObjectFile::serialize`
+pub fn read_object_file(bytes: &[u8]) -> Result<ObjectFile, GeneralIssue<PackageReadingIssue>> {
+    if !is_object_file(bytes) {
+        return Err(reading_issue("Object file signature is not found", 0));
+    }
+    let version_position = OBJECT_FILE_SIGNATURE.len();
+    let version = read_bytes(bytes, version_position, 1)?[0];
+    if version != OBJECT_FILE_VERSION {
+        return Err(reading_issue(format!("Object file version {} is not supported", version).as_str(), version_position));
+    }
+
+    let metadata_position = version_position + 1;
+    let metadata = PackageMetadata::deserialize(&bytes[metadata_position..]);
+    if metadata.is_none() {
+        return Err(reading_issue("Object metadata is incomplete", metadata_position));
+    }
+    let metadata = metadata.unwrap();
+    if metadata.address_alignment == 0 || metadata.data_slot_alignment == 0 || metadata.data_alignment == 0 {
+        return Err(reading_issue("Object metadata contains zero alignment", metadata_position));
+    }
+
+    let mut reader = ObjectReader { bytes, position: metadata_position + PACKAGE_METADATA_LEN, metadata: &metadata };
+
+    let checksum_len = reader.unsigned()?;
+    let source_checksum = reader.bytes(checksum_len)?;
+
+    let mut commands = RelocatableCommandList::new();
+
+    let string_count = reader.aligned(metadata.data_slot_alignment)?;
+    for slot in 0..string_count {
+        commands.string_pool.push(StringConstant { value: reader.string()?, slot });
+    }
+
+    let function_count = reader.unsigned()?;
+    for _ in 0..function_count {
+        let linkage = match reader.bytes(1)?[0] {
+            0x00 => FunctionLinkage::Internal,
+            0x01 => FunctionLinkage::Export,
+            0x02 => FunctionLinkage::External,
+            x => return Err(reading_issue(format!("Unknown function linkage 0x{:02X}", x).as_str(), reader.position - 1)),
+        };
+        let slot = reader.unsigned()?;
+        let relocated_entry_address = reader.unsigned()?;
+        let name = reader.identifier()?;
+
+        commands.function_table.push(FunctionTableEntry { slot, name, relocated_entry_address, linkage });
+    }
+
+    let command_len = reader.unsigned()?;
+    commands.commands = reader.bytes(command_len)?;
+    let entry_count = reader.unsigned()?;
+    for _ in 0..entry_count {
+        commands.command_entries.push(reader.position(command_len)?);
+    }
+
+    let target_count = reader.unsigned()?;
+    for _ in 0..target_count {
+        let command_array_position = reader.position(command_len)?;
+        let offset = reader.signed()? as i32;
+        let relocated_address = reader.signed()? as i32;
+
+        let element_count = reader.unsigned()?;
+        let mut relocation_elements = vec![];
+        for _ in 0..element_count {
+            relocation_elements.push(reader.target_element()?);
+        }
+
+        commands.descriptors.targets.push(RelocationTarget { relocation_elements, command_array_position, offset, relocated_address });
+    }
+
+    let reference_count = reader.unsigned()?;
+    for _ in 0..reference_count {
+        let command_array_position = reader.position(command_len)?;
+        let ref_type = reader.reference_type()?;
+
+        commands.descriptors.references.push(RelocationReference { ref_type, command_array_position });
+    }
+
+    if reader.position != bytes.len() {
+        return Err(reading_issue("Unexpected data after the object", reader.position));
+    }
+
+    return Ok(ObjectFile { metadata, source_checksum, commands });
+}
+
+struct ObjectReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+    metadata: &'a PackageMetadata,
+}
+
+impl ObjectReader<'_> {
+    fn bytes(&mut self, len: usize) -> Result<Vec<u8>, GeneralIssue<PackageReadingIssue>> {
+        let result = read_bytes(self.bytes, self.position, len)?.to_vec();
+        self.position += len;
+
+        return Ok(result);
+    }
+
+    fn aligned(&mut self, width: u8) -> Result<usize, GeneralIssue<PackageReadingIssue>> {
+        let result = read_unsigned(self.bytes, self.position, width)?;
+        self.position += width as usize;
+
+        return Ok(result);
+    }
+
+    fn unsigned(&mut self) -> Result<usize, GeneralIssue<PackageReadingIssue>> {
+        return self.aligned(self.metadata.address_alignment);
+    }
+
+    /// A position in the command section, which is at most its length
+    fn position(&mut self, command_len: usize) -> Result<usize, GeneralIssue<PackageReadingIssue>> {
+        let begin = self.position;
+        let result = self.unsigned()?;
+        if result > command_len {
+            return Err(reading_issue("Command position is out of the command section", begin));
+        }
+
+        return Ok(result);
+    }
+
+    fn signed(&mut self) -> Result<i64, GeneralIssue<PackageReadingIssue>> {
+        let begin = self.position;
+        let sign = self.bytes(1)?[0];
+        let value = self.unsigned()?;
+        if value > i32::MAX as usize {
+            return Err(reading_issue("Relocation address is out of range", begin));
+        }
+
+        return match sign {
+            0x0F => Ok(value as i64),
+            0x0B => Ok(-(value as i64)),
+            _ => Err(reading_issue(format!("Invalid sign 0x{:02X}", sign).as_str(), begin)),
+        };
+    }
+
+    fn string(&mut self) -> Result<String, GeneralIssue<PackageReadingIssue>> {
+        let len = self.aligned(self.metadata.data_slot_alignment)?;
+        let begin = self.position;
+        let value = String::from_utf8(self.bytes(len)?);
+        if value.is_err() {
+            return Err(reading_issue("String is not valid UTF-8", begin));
+        }
+
+        return Ok(value.unwrap());
+    }
+
+    fn identifier(&mut self) -> Result<Identifier, GeneralIssue<PackageReadingIssue>> {
+        return Ok(Identifier::from_string(self.string()?.as_str()));
+    }
+
+    fn target_element(&mut self) -> Result<RelocationTargetElement, GeneralIssue<PackageReadingIssue>> {
+        let begin = self.position;
+        return match self.bytes(1)?[0] {
+            0x00 => Ok(RelocationTargetElement::Relative(self.signed()? as i32)),
+            0x01 => Ok(RelocationTargetElement::IterationHead),
+            0x02 => Ok(RelocationTargetElement::BreakIteration),
+            0x03 => Ok(RelocationTargetElement::DomainHead),
+            0x04 => Ok(RelocationTargetElement::BreakDomain(self.unsigned()?)),
+            0x05 => Ok(RelocationTargetElement::IgnoreDomain(self.unsigned()?)),
+            0x06 => Ok(RelocationTargetElement::EnterFunction(self.identifier()?)),
+            0x07 => Ok(RelocationTargetElement::Undefined),
+            x => Err(reading_issue(format!("Unknown relocation target element 0x{:02X}", x).as_str(), begin)),
+        };
+    }
+
+    fn reference_type(&mut self) -> Result<RelocationReferenceType, GeneralIssue<PackageReadingIssue>> {
+        let begin = self.position;
+        return match self.bytes(1)?[0] {
+            0x00 => Ok(RelocationReferenceType::FunctionEntrance(self.identifier()?)),
+            0x01 => Ok(RelocationReferenceType::EndFunction(self.identifier()?)),
+            0x02 => Ok(RelocationReferenceType::IfEntrance),
+            0x03 => Ok(RelocationReferenceType::ElifEntrance),
+            0x04 => Ok(RelocationReferenceType::ElseEntrance),
+            0x05 => Ok(RelocationReferenceType::EndIf),
+            0x06 => Ok(RelocationReferenceType::EndElif),
+            0x07 => Ok(RelocationReferenceType::EndElse),
+            0x08 => Ok(RelocationReferenceType::WhileEntrance),
+            0x09 => Ok(RelocationReferenceType::EndWhile),
+            0x0A => Ok(RelocationReferenceType::LoopEntrance),
+            0x0B => Ok(RelocationReferenceType::EndLoop),
+            0x0C => Ok(RelocationReferenceType::DomainEntrance),
+            0x0D => Ok(RelocationReferenceType::EndDomain),
+            x => Err(reading_issue(format!("Unknown relocation reference type 0x{:02X}", x).as_str(), begin)),
+        };
+    }
+}
@@ -0,0 +1,59 @@
+use std::convert::TryInto;
+
+use crate::shared::error::general_issue::{GeneralIssue, IssueBase, IssueLevel, IssuePosition};
+use crate::shared::error::package_reading_issue::PackageReadingIssue;
+
+pub fn reading_issue(content: &str, position: usize) -> GeneralIssue<PackageReadingIssue> {
+    return GeneralIssue {
+        issues: vec![IssueBase {
+            level: IssueLevel::Error,
+            position: IssuePosition::PackageReading,
+            code: "-1".to_string(),
+            detail: PackageReadingIssue { content: content.to_string(), position },
+        }]
+    };
+}
+
+pub fn read_bytes(bytes: &[u8], position: usize, width: usize) -> Result<&[u8], GeneralIssue<PackageReadingIssue>> {
+    if position + width > bytes.len() {
+        return Err(reading_issue("Unexpected end of package", position));
+    }
+
+    return Ok(&bytes[position..(position + width)]);
+}
+
+/// Read a big-endian unsigned number which is aligned by `align_array_width`
+pub fn read_unsigned(bytes: &[u8], position: usize, width: u8) -> Result<usize, GeneralIssue<PackageReadingIssue>> {
+    let data = read_bytes(bytes, position, width as usize)?;
+
+    let mut result: usize = 0;
+    for byte in data {
+        if result > (usize::MAX >> 8) {
+            return Err(reading_issue("Number is too large for the current platform", position));
+        }
+        result = (result << 8) | *byte as usize;
+    }
+
+    return Ok(result);
+}
+
+/// Read a big-endian two's complement number, whose sign is extended from the highest bit
+pub fn read_signed(bytes: &[u8], position: usize, width: u8) -> Result<i64, GeneralIssue<PackageReadingIssue>> {
+    let data = read_bytes(bytes, position, width as usize)?;
+    if data.len() > 8 {
+        // Wider values are only valid when their extra bytes are just sign extension
+        let (extra, rest) = data.split_at(data.len() - 8);
+        let fill = if rest[0] & 0x80 != 0 { 0xFF } else { 0x00 };
+        if extra.iter().any(|b| *b != fill) {
+            return Err(reading_issue("Instant value is out of range", position));
+        }
+        return Ok(i64::from_be_bytes(rest.try_into().unwrap()));
+    }
+
+    let mut result: i64 = if !data.is_empty() && data[0] & 0x80 != 0 { -1 } else { 0 };
+    for byte in data {
+        result = (result << 8) | *byte as i64;
+    }
+
+    return Ok(result);
+}
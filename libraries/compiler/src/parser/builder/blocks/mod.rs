@@ -1,9 +0,0 @@
-pub mod action_block;
-pub mod assignment;
-pub mod call;
-pub mod condition;
-pub mod declaration;
-pub mod link;
-pub mod loops;
-pub mod return_expression;
-pub mod short_actions;
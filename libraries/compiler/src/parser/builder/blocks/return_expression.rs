@@ -1,59 +0,0 @@
-use crate::parser::builder::expression_builder::{expression_infix_to_postfix, expression_term_decorator};
-use crate::parser::utils::find_next_semicolon;
-use crate::shared::ast::action::{Action, ActionContent, ReturnAction};
-use crate::shared::ast::blocks::expression::SimpleExpression;
-use crate::shared::ast::decorated_token::DecoratedToken;
-use crate::shared::error::general_issue::{GeneralIssue, IssueBase, IssueLevel, IssuePosition};
-use crate::shared::token::keyword::KeywordType;
-use crate::shared::utils::identifier::Identifier;
-
-pub fn return_action_builder(
-    tokens: &Vec<DecoratedToken>,
-) -> Result<(Action, usize), GeneralIssue<String>> {
-    // Minimum: return ; (2 tokens in total)
-    if tokens.len() >= 2 {
-        if tokens[0].content.get_decorated_keyword().is_some() {
-            if *tokens[0].content.get_decorated_keyword().unwrap() == KeywordType::KwReturn {
-                let next_semicolon_pos = find_next_semicolon(tokens.clone());
-
-                if next_semicolon_pos.unwrap_or(0) > 0 {
-                    #[allow(unused_assignments)]
-                    let mut result: Option<ReturnAction> = None;
-
-                    if next_semicolon_pos.unwrap() == 1 {
-                        // No return value
-                        result = Option::from(ReturnAction {
-                            value: Some(SimpleExpression {
-                                postfix_expr: vec![],
-                                output_type: Identifier::empty(),
-                            }),
-                        });
-                    } else {
-                        // With return value
-                        let expression_zone = tokens[1..next_semicolon_pos.unwrap()].to_vec();
-
-                        result = Option::from(ReturnAction {
-                            value: Some(SimpleExpression {
-                                postfix_expr: expression_infix_to_postfix(expression_term_decorator(&expression_zone)),
-                                output_type: Identifier::empty(),
-                            }),
-                        });
-                    }
-
-                    return Ok((
-                        Action::new(ActionContent::ReturnStatement(result.unwrap()), vec![]),
-                        next_semicolon_pos.unwrap() + 1,
-                    ));
-                }
-            }
-        }
-    }
-    return Err(GeneralIssue {
-        issues: vec![IssueBase {
-            level: IssueLevel::Info,
-            position: IssuePosition::Parsing,
-            code: "".to_string(),
-            detail: "".to_string(),
-        }]
-    });
-}
@@ -1,2 +0,0 @@
-pub mod declaration;
-pub mod implementation;
@@ -1,5 +0,0 @@
-pub mod blocks;
-pub mod expression_builder;
-pub mod function_builder;
-pub mod templates;
-pub mod group;
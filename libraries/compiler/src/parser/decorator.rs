@@ -8,9 +8,18 @@ use crate::shared::token::token::{Token, TokenContent};
 use crate::shared::utils::identifier::Identifier;
 
 pub fn decorate_token(tokens: Vec<Token>) -> (Vec<DecoratedToken>, Vec<StringConstant>) {
+    return decorate_token_with_string_pool(tokens, vec![]);
+}
+
+/// Decorate tokens while continuing an existing string pool, so that several source files
+/// could share one pool (and one set of slots) in the same package.
+pub fn decorate_token_with_string_pool(
+    tokens: Vec<Token>,
+    string_pool: Vec<StringConstant>,
+) -> (Vec<DecoratedToken>, Vec<StringConstant>) {
     let mut result: Vec<DecoratedToken> = Vec::new();
 
-    let mut string_pool: Vec<StringConstant> = vec![];
+    let mut string_pool = string_pool;
     for token in tokens {
         match token.clone().content {
             TokenContent::Identifier(x) => {
@@ -56,6 +65,10 @@ pub fn decorate_token(tokens: Vec<Token>) -> (Vec<DecoratedToken>, Vec<StringCon
                     content: DecoratedTokenContent::Data(DataToken::Identifier(Identifier::single("str"))),
                     original_token: token.clone(),
                 }),
+                KeywordType::KwBool => result.push(DecoratedToken {
+                    content: DecoratedTokenContent::Data(DataToken::Identifier(Identifier::single("bool"))),
+                    original_token: token.clone(),
+                }),
                 _ => result.push(DecoratedToken {
                     content: DecoratedTokenContent::DecoratedKeyword(x),
                     original_token: token.clone(),
@@ -83,7 +96,8 @@ fn post_combine_identifier(tokens: &Vec<DecoratedToken>) -> Vec<DecoratedToken>
     let mut result: Vec<DecoratedToken> = vec![];
     let mut index = 0;
     while index < tokens.len() {
-        if index <= tokens.len() - 2 {
+        // A scope operator joins the identifiers around it, short or leading ones are kept as they are
+        if index + 2 <= tokens.len() && !result.is_empty() {
             if tokens[index].original_token.content == TokenContent::Operator(Operator::Scope) &&
                 tokens[index + 1].content.is_valid_identifier() &&
                 result[result.len() - 1].content.is_valid_identifier() {
@@ -0,0 +1,199 @@
+use crate::shared::ast::decorated_token::{DataToken, DecoratedToken, DecoratedTokenContent};
+use crate::shared::error::general_issue::{FileMatch, GeneralIssue, IssueBase, IssueLevel, IssuePosition, UNKNOWN_FILE_PATH};
+use crate::shared::error::parsing_issue::ParsingIssue;
+use crate::shared::token::container::ContainerType;
+use crate::shared::token::keyword::KeywordType;
+use crate::shared::token::operator::Operator;
+use crate::shared::token::token::Token;
+use crate::shared::utils::identifier::Identifier;
+use crate::shared::utils::position::Position;
+
+/// A recursive-descent parser walking decorated tokens once.
+/// Every rule starts at the current token and moves past the tokens it accepts.
+/// A rule which fails reports an issue and returns `None`, then its caller skips to where parsing could continue,
+/// so every syntax error of the tokens is reported.
+pub struct Parser<'a> {
+    pub(super) tokens: &'a [DecoratedToken],
+    pub(super) index: usize,
+    pub(super) issues: Vec<IssueBase<ParsingIssue>>,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(tokens: &'a [DecoratedToken]) -> Parser<'a> {
+        return Parser { tokens, index: 0, issues: vec![] };
+    }
+
+    /// `result` if no issue is reported, rules returning `None` always report one
+    pub fn finish<T>(self, result: T) -> Result<T, GeneralIssue<ParsingIssue>> {
+        if self.issues.is_empty() {
+            return Ok(result);
+        }
+
+        return Err(GeneralIssue { issues: self.issues });
+    }
+
+    pub fn is_at_end(&self) -> bool {
+        return self.index >= self.tokens.len();
+    }
+
+    /// Report the tokens left after what is parsed, if there is any
+    pub fn expect_end(&mut self, expected: &str) {
+        if !self.is_at_end() {
+            self.report_expected(expected);
+        }
+    }
+
+    pub(super) fn peek(&self) -> Option<&'a DecoratedToken> {
+        return self.tokens.get(self.index);
+    }
+
+    pub(super) fn keyword(&self) -> Option<KeywordType> {
+        return self.peek().and_then(|t| t.content.get_decorated_keyword().copied());
+    }
+
+    pub(super) fn at_keyword(&self, keyword: KeywordType) -> bool {
+        return self.keyword() == Some(keyword);
+    }
+
+    pub(super) fn at_container(&self, container: ContainerType) -> bool {
+        return self.nth_is_container(0, container);
+    }
+
+    /// Whether the token `offset` tokens after the current one is `container`
+    pub(super) fn nth_is_container(&self, offset: usize, container: ContainerType) -> bool {
+        return self.tokens.get(self.index + offset).and_then(|t| t.content.get_container()) == Some(&container);
+    }
+
+    pub(super) fn at_operator(&self, operator: Operator) -> bool {
+        return self.peek().and_then(|t| t.content.get_operator()) == Some(&operator);
+    }
+
+    pub(super) fn at_semicolon(&self) -> bool {
+        return self.peek().is_some_and(|t| t.content.get_statement_end_sign());
+    }
+
+    pub(super) fn advance(&mut self) {
+        if !self.is_at_end() {
+            self.index += 1;
+        }
+    }
+
+    pub(super) fn expect_keyword(&mut self, keyword: KeywordType, expected: &str) -> Option<()> {
+        if !self.at_keyword(keyword) {
+            self.report_expected(expected);
+            return None;
+        }
+
+        self.advance();
+        return Some(());
+    }
+
+    pub(super) fn expect_container(&mut self, container: ContainerType, expected: &str) -> Option<()> {
+        if !self.at_container(container) {
+            self.report_expected(expected);
+            return None;
+        }
+
+        self.advance();
+        return Some(());
+    }
+
+    pub(super) fn expect_semicolon(&mut self) -> Option<()> {
+        if !self.at_semicolon() {
+            self.report_expected("\";\"");
+            return None;
+        }
+
+        self.advance();
+        return Some(());
+    }
+
+    /// `expected` describes the identifier in the issue if there isn't one
+    pub(super) fn expect_identifier(&mut self, expected: &str) -> Option<Identifier> {
+        let identifier = match self.peek().map(|t| &t.content) {
+            Some(DecoratedTokenContent::Data(DataToken::Identifier(x))) => x.clone(),
+            _ => {
+                self.report_expected(expected);
+                return None;
+            }
+        };
+
+        self.advance();
+        return Some(identifier);
+    }
+
+    /// Report that the current token is not `expected`
+    pub(super) fn report_expected(&mut self, expected: &str) {
+        let found = if self.is_at_end() { "the end of the code" } else { "this token" };
+        self.report("0001", format!("Expected {}, found {}", expected, found), self.index, self.index + 1);
+    }
+
+    /// Report an issue at tokens from `start` to `end` (exclusive), the end of the code if they are not there
+    pub(super) fn report(&mut self, code: &str, content: String, start: usize, end: usize) {
+        let (start_pos, end_pos) = self.source_range(start, end);
+
+        self.issues.push(IssueBase {
+            level: IssueLevel::Error,
+            position: IssuePosition::Parsing,
+            code: code.to_string(),
+            detail: ParsingIssue {
+                content,
+                location: FileMatch { file_path: UNKNOWN_FILE_PATH.to_string(), start_pos, end_pos },
+            },
+        });
+    }
+
+    /// Original tokens from `start` to the current one, kept by actions so that later issues could point to them
+    pub(super) fn tokens_from(&self, start: usize) -> Vec<Token> {
+        return self.tokens[start..self.index].iter().map(|t| t.original_token.clone()).collect();
+    }
+
+    /// Where the tokens from `start` to the current one are in the source, kept by expressions instead of the tokens
+    pub(super) fn position_from(&self, start: usize) -> Position {
+        let (start_pos, end_pos) = self.source_range(start, self.index);
+
+        return Position::new(start_pos, end_pos - start_pos);
+    }
+
+    /// Source positions from the token at `start` to the one before `end`, the end of the code if they are not there
+    fn source_range(&self, start: usize, end: usize) -> (usize, usize) {
+        let end = end.min(self.tokens.len());
+        return match (self.tokens.get(start), end.checked_sub(1).and_then(|i| self.tokens.get(i))) {
+            (Some(first), Some(last)) if start < end => {
+                (first.original_token.position.start, last.original_token.position.start + last.original_token.position.length)
+            }
+            _ => {
+                let position = self.tokens.last().map_or(0, |t| t.original_token.position.start + t.original_token.position.length);
+                (position, position)
+            }
+        };
+    }
+
+    /// Skip the rest of a statement with an issue.
+    /// Stops after its `;` or a block it opens, or before the `}` closing the block it is in.
+    pub(super) fn synchronize(&mut self) {
+        let mut depth = 0;
+        while let Some(token) = self.peek() {
+            match token.content {
+                DecoratedTokenContent::StatementEndSign if depth == 0 => {
+                    self.advance();
+                    return;
+                }
+                DecoratedTokenContent::Container(ContainerType::Brace) => depth += 1,
+                DecoratedTokenContent::Container(ContainerType::AntiBrace) => {
+                    if depth == 0 {
+                        return;
+                    }
+
+                    depth -= 1;
+                    if depth == 0 {
+                        self.advance();
+                        return;
+                    }
+                }
+                _ => {}
+            }
+            self.advance();
+        }
+    }
+}
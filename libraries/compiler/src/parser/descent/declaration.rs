@@ -0,0 +1,344 @@
+use std::path::PathBuf;
+
+use crate::parser::descent::cursor::Parser;
+use crate::shared::ast::blocks::function::{Function, FunctionDeclarator, FunctionLinkage};
+use crate::shared::ast::decorated_token::{DataToken, DecoratedTokenContent};
+use crate::shared::ast::group::declaration::{Field, GroupDeclarationBlock};
+use crate::shared::ast::group::implementation::{FieldGS, GroupImplementationBlock};
+use crate::shared::ast::link::SourceFileLink;
+use crate::shared::ast::package::ParserPackageStructure;
+use crate::shared::ast::parameter::Parameter;
+use crate::shared::error::general_issue::UNKNOWN_FILE_PATH;
+use crate::shared::token::container::ContainerType;
+use crate::shared::token::keyword::KeywordType;
+use crate::shared::token::operator::Operator;
+use crate::shared::utils::identifier::Identifier;
+
+impl<'a> Parser<'a> {
+    /// Every link, function, group and implementation of a source file
+    pub fn source_file(&mut self, entry_point: Identifier) -> ParserPackageStructure {
+        let mut result = ParserPackageStructure {
+            functions: vec![],
+            entry_point,
+            linked_code_files: vec![],
+            declared_groups: vec![],
+            declared_implementations: vec![],
+        };
+
+        while !self.is_at_end() {
+            let start = self.index;
+            let is_parsed = match self.keyword() {
+                Some(KeywordType::KwLink) => self.link().map(|x| result.linked_code_files.push(x)).is_some(),
+                Some(KeywordType::KwDeclare) => self.function().map(|x| result.functions.push(x)).is_some(),
+                // Only functions with a body could be exported
+                Some(KeywordType::KwExport) => {
+                    self.advance();
+                    let function = self.function();
+                    let is_parsed = function.is_some();
+                    match function {
+                        Some(x) if x.linkage == FunctionLinkage::External => {
+                            self.report("0003", "External functions couldn't be exported".to_string(), start, self.index);
+                        }
+                        Some(mut x) => {
+                            x.linkage = FunctionLinkage::Export;
+                            result.functions.push(x);
+                        }
+                        None => {}
+                    }
+                    is_parsed
+                }
+                Some(KeywordType::KwGroup) => self.group_declaration().map(|x| result.declared_groups.push(x)).is_some(),
+                Some(KeywordType::KwImplement) => {
+                    let implementation = self.group_implementation(&result.declared_groups);
+                    implementation.map(|x| result.declared_implementations.push(x)).is_some()
+                }
+                _ => {
+                    self.report_expected("\"link\", \"decl\", \"export\", \"group\" or \"impl\"");
+                    false
+                }
+            };
+
+            if !is_parsed {
+                self.synchronize_declaration(start);
+            }
+        }
+
+        return result;
+    }
+
+    /// Skip to the next declaration after an issue, which starts with a keyword outside of any block
+    fn synchronize_declaration(&mut self, start: usize) {
+        if self.index == start {
+            self.advance();
+        }
+
+        let mut depth = 0;
+        while let Some(token) = self.peek() {
+            match token.content {
+                DecoratedTokenContent::Container(ContainerType::Brace) => depth += 1,
+                DecoratedTokenContent::Container(ContainerType::AntiBrace) if depth > 0 => depth -= 1,
+                DecoratedTokenContent::DecoratedKeyword(
+                    KeywordType::KwLink | KeywordType::KwDeclare | KeywordType::KwExport | KeywordType::KwGroup | KeywordType::KwImplement,
+                ) if depth == 0 => return,
+                _ => {}
+            }
+            self.advance();
+        }
+    }
+
+    /// `link "<path>";` or `link <identifier>;`
+    fn link(&mut self) -> Option<SourceFileLink> {
+        self.advance();
+        let result = match self.peek().map(|t| &t.content) {
+            Some(DecoratedTokenContent::Data(DataToken::String(x))) => SourceFileLink::SourceFile(PathBuf::from(x.value.clone())),
+            Some(DecoratedTokenContent::Data(DataToken::Identifier(x))) => SourceFileLink::Identifier(x.clone()),
+            _ => {
+                self.report_expected("a file path or an identifier");
+                return None;
+            }
+        };
+        self.advance();
+        self.expect_semicolon()?;
+
+        return Some(result);
+    }
+
+    /// `decl func <declarator> { <actions> }`, or `decl func <declarator>;` for an external function
+    fn function(&mut self) -> Option<Function> {
+        self.expect_keyword(KeywordType::KwDeclare, "\"decl\"")?;
+        return self.function_base(KeywordType::KwFunc, "\"func\"");
+    }
+
+    /// `<leading_keyword> <declarator>` followed by a body, or by `;` if the function is external
+    fn function_base(&mut self, leading_keyword: KeywordType, expected: &str) -> Option<Function> {
+        self.expect_keyword(leading_keyword, expected)?;
+        let mut result = Function {
+            declarator: self.function_declarator()?,
+            body: vec![],
+            file_path: UNKNOWN_FILE_PATH.to_string(),
+            linkage: FunctionLinkage::Internal,
+        };
+
+        if self.at_semicolon() {
+            self.advance();
+            result.linkage = FunctionLinkage::External;
+            return Some(result);
+        }
+        result.body = self.block()?;
+
+        return Some(result);
+    }
+
+    /// `<identifier>(<type> <name>, ...)[<type>]`, the return type is `none` if nothing is returned
+    fn function_declarator(&mut self) -> Option<FunctionDeclarator> {
+        let identifier = self.expect_identifier("a function name")?;
+
+        self.expect_container(ContainerType::Bracket, "\"(\"")?;
+        let mut parameters = vec![];
+        if !self.at_container(ContainerType::AntiBracket) {
+            loop {
+                let type_name = self.expect_identifier("a parameter type")?;
+                let identifier = self.expect_identifier("a parameter name")?;
+                parameters.push(Parameter { type_name, identifier });
+
+                if !self.at_operator(Operator::Comma) {
+                    break;
+                }
+                self.advance();
+            }
+        }
+        self.expect_container(ContainerType::AntiBracket, "\")\"")?;
+
+        self.expect_container(ContainerType::Index, "\"[\"")?;
+        let return_type = if self.at_keyword(KeywordType::KwNone) {
+            self.advance();
+            Identifier::empty()
+        } else {
+            self.expect_identifier("a return type or \"none\"")?
+        };
+        self.expect_container(ContainerType::AntiIndex, "\"]\"")?;
+
+        return Some(FunctionDeclarator { identifier, parameters, return_type });
+    }
+
+    /// `group <identifier> { <field | method | func>... }`
+    fn group_declaration(&mut self) -> Option<GroupDeclarationBlock> {
+        self.advance();
+        let mut result = GroupDeclarationBlock {
+            identifier: self.expect_identifier("a group name")?,
+            fields: vec![],
+            methods: vec![],
+            functions: vec![],
+        };
+
+        self.expect_container(ContainerType::Brace, "\"{\"")?;
+        while !self.is_at_end() && !self.at_container(ContainerType::AntiBrace) {
+            let member = match self.keyword() {
+                Some(KeywordType::KwField) => self.field_declaration().map(|x| result.fields.push(x)),
+                Some(KeywordType::KwMethod) => self.member_declaration().map(|x| result.methods.push(x)),
+                Some(KeywordType::KwFunc) => self.member_declaration().map(|x| result.functions.push(x)),
+                _ => {
+                    self.report_expected("\"field\", \"method\" or \"func\"");
+                    None
+                }
+            };
+
+            if member.is_none() {
+                self.synchronize();
+            }
+        }
+        self.expect_container(ContainerType::AntiBrace, "\"}\"")?;
+
+        return Some(result);
+    }
+
+    /// `field <type> <name>(get, set);`, at least one of `get` and `set` is required
+    fn field_declaration(&mut self) -> Option<Field> {
+        self.advance();
+        let data_type = self.expect_identifier("a field type")?;
+        let mut result = Field {
+            identifier: self.expect_identifier("a field name")?,
+            data_type,
+            has_get: false,
+            has_set: false,
+        };
+
+        self.expect_container(ContainerType::Bracket, "\"(\"")?;
+        loop {
+            let feature = match self.keyword() {
+                Some(KeywordType::KwGet) => &mut result.has_get,
+                Some(KeywordType::KwSet) => &mut result.has_set,
+                _ => {
+                    self.report_expected("\"get\" or \"set\"");
+                    return None;
+                }
+            };
+            if *feature {
+                self.report("0003", "Duplicated feature declaration".to_string(), self.index, self.index + 1);
+            }
+            *feature = true;
+            self.advance();
+
+            if !self.at_operator(Operator::Comma) {
+                break;
+            }
+            self.advance();
+        }
+        self.expect_container(ContainerType::AntiBracket, "\")\"")?;
+        self.expect_semicolon()?;
+
+        return Some(result);
+    }
+
+    /// `method <declarator>;` or `func <declarator>;`
+    fn member_declaration(&mut self) -> Option<FunctionDeclarator> {
+        self.advance();
+        let result = self.function_declarator()?;
+        self.expect_semicolon()?;
+
+        return Some(result);
+    }
+
+    /// `impl <group> { <default | field | method | func>... }`, every member should be declared by the group
+    fn group_implementation(&mut self, declared_groups: &[GroupDeclarationBlock]) -> Option<GroupImplementationBlock> {
+        self.advance();
+        let start = self.index;
+        let identifier = self.expect_identifier("a group name")?;
+        let declaration = declared_groups.iter().find(|g| g.identifier == identifier);
+        if declaration.is_none() {
+            self.report("0003", format!("Group \"{}\" is not declared before being implemented", identifier), start, self.index);
+            return None;
+        }
+        let declaration = declaration.unwrap();
+        let mut result = GroupImplementationBlock::from_declaration(declaration);
+
+        self.expect_container(ContainerType::Brace, "\"{\"")?;
+        while !self.is_at_end() && !self.at_container(ContainerType::AntiBrace) {
+            let member = match self.keyword() {
+                Some(KeywordType::KwDefault) => self.default_implementation(&mut result),
+                Some(KeywordType::KwField) => self.field_implementation(declaration, &mut result),
+                Some(KeywordType::KwMethod) => self.function_implementation(KeywordType::KwMethod, &mut result),
+                Some(KeywordType::KwFunc) => self.function_implementation(KeywordType::KwFunc, &mut result),
+                _ => {
+                    self.report_expected("\"default\", \"field\", \"method\" or \"func\"");
+                    None
+                }
+            };
+
+            if member.is_none() {
+                self.synchronize();
+            }
+        }
+        self.expect_container(ContainerType::AntiBrace, "\"}\"")?;
+
+        return Some(result);
+    }
+
+    /// `default <field> = <expression>;`
+    fn default_implementation(&mut self, implementation: &mut GroupImplementationBlock) -> Option<()> {
+        self.advance();
+        let start = self.index;
+        let identifier = self.expect_identifier("a field name")?;
+        let end = self.index;
+        if !self.at_operator(Operator::Assignment) {
+            self.report_expected("\"=\"");
+            return None;
+        }
+        self.advance();
+        let value = self.expression()?;
+        self.expect_semicolon()?;
+
+        match implementation.fields.iter_mut().find(|f| f.identifier == identifier) {
+            Some(field) => field.default_value = value,
+            None => self.report("0003", format!("Field \"{}\" is not declared by the group", identifier), start, end),
+        }
+
+        return Some(());
+    }
+
+    /// `field <name> get { <actions> }` or `field <name> set { <actions> }`
+    fn field_implementation(&mut self, declaration: &GroupDeclarationBlock, implementation: &mut GroupImplementationBlock) -> Option<()> {
+        self.advance();
+        let start = self.index;
+        let identifier = self.expect_identifier("a field name")?;
+        let feature = self.keyword();
+        if !matches!(feature, Some(KeywordType::KwGet | KeywordType::KwSet)) {
+            self.report_expected("\"get\" or \"set\"");
+            return None;
+        }
+        self.advance();
+        let end = self.index;
+        let actions = self.block()?;
+
+        let field = declaration.fields.iter().find(|f| f.identifier == identifier);
+        let target = implementation.fields.iter_mut().find(|f| f.identifier == identifier);
+        match (field, target, feature) {
+            (Some(field), Some(target), Some(KeywordType::KwGet)) if field.has_get => target.get_block = Some(FieldGS { actions }),
+            (Some(field), Some(target), Some(KeywordType::KwSet)) if field.has_set => target.set_block = Some(FieldGS { actions }),
+            (Some(_), Some(_), _) => self.report("0003", format!("Field \"{}\" doesn't declare this feature", identifier), start, end),
+            _ => self.report("0003", format!("Field \"{}\" is not declared by the group", identifier), start, end),
+        }
+
+        return Some(());
+    }
+
+    /// A method or a function with a body, matched to its declaration by the name
+    fn function_implementation(&mut self, keyword: KeywordType, implementation: &mut GroupImplementationBlock) -> Option<()> {
+        let start = self.index;
+        let function = self.function_base(keyword, "\"method\" or \"func\"")?;
+
+        let members = match keyword {
+            KeywordType::KwMethod => &mut implementation.methods,
+            _ => &mut implementation.functions,
+        };
+        match members.iter_mut().find(|m| m.declarator.identifier == function.declarator.identifier) {
+            Some(target) => target.body = function.body,
+            None => {
+                let content = format!("\"{}\" is not declared by the group", function.declarator.identifier);
+                self.report("0003", content, start, start + 2);
+            }
+        }
+
+        return Some(());
+    }
+}
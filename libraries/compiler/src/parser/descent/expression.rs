@@ -0,0 +1,138 @@
+use crate::parser::descent::cursor::Parser;
+use crate::shared::ast::action::CallAction;
+use crate::shared::ast::blocks::expression::{ExprDataTerm, ExprNode, NodeContent, SimpleExpression};
+use crate::shared::ast::decorated_token::{DataToken, DecoratedTokenContent};
+use crate::shared::token::container::ContainerType;
+use crate::shared::token::operator::{CalculationOperator, LogicalOperator, Operator};
+use crate::shared::utils::identifier::Identifier;
+
+/**
+ * Binding power of binary operators, all of them are left associative:
+ * `||` < `&&` < relation < `+ -` < `* / %`
+ * Unary `!` and `-` bind tighter than any of them, and member access is the tightest.
+ */
+fn binary_precedence(operator: &Operator) -> Option<u8> {
+    return match operator {
+        Operator::Logical(LogicalOperator::Or) => Some(1),
+        Operator::Logical(LogicalOperator::And) => Some(2),
+        Operator::Relation(_) => Some(3),
+        Operator::Calculation(CalculationOperator::Addition | CalculationOperator::Subtraction) => Some(4),
+        Operator::Calculation(CalculationOperator::Multiply | CalculationOperator::Division | CalculationOperator::Modulo) => Some(5),
+        _ => None,
+    };
+}
+
+impl<'a> Parser<'a> {
+    /// An expression in postfix order
+    pub fn expression(&mut self) -> Option<SimpleExpression> {
+        let tree = self.expression_tree()?;
+
+        return Some(SimpleExpression { postfix_expr: tree.to_postfix(), output_type: Identifier::empty() });
+    }
+
+    /// An expression tree, which ends before any token that couldn't continue it
+    pub fn expression_tree(&mut self) -> Option<ExprNode> {
+        return self.binary_expression(1);
+    }
+
+    /// `<identifier>(<expression>, ...)`
+    pub(super) fn function_call(&mut self) -> Option<CallAction> {
+        let function_name = self.expect_identifier("a function name")?;
+        self.expect_container(ContainerType::Bracket, "\"(\"")?;
+
+        let mut arguments = vec![];
+        if !self.at_container(ContainerType::AntiBracket) {
+            loop {
+                arguments.push(self.expression()?);
+                if !self.at_operator(Operator::Comma) {
+                    break;
+                }
+                self.advance();
+            }
+        }
+        self.expect_container(ContainerType::AntiBracket, "\")\"")?;
+
+        return Some(CallAction { function_name, arguments });
+    }
+
+    /// Operands joined by binary operators binding at least as tight as `min_precedence`
+    fn binary_expression(&mut self, min_precedence: u8) -> Option<ExprNode> {
+        let start = self.index;
+        let mut left = self.unary_expression()?;
+
+        while let Some(operator) = self.peek().and_then(|t| t.content.get_operator()).copied() {
+            let precedence = match binary_precedence(&operator) {
+                Some(x) if x >= min_precedence => x,
+                _ => break,
+            };
+            self.advance();
+
+            // Operators of the same precedence on the right are left to this loop, so they associate to the left
+            let right = self.binary_expression(precedence + 1)?;
+            left = ExprNode {
+                content: NodeContent::Binary(operator, Box::new(left), Box::new(right)),
+                position: self.position_from(start),
+            };
+        }
+
+        return Some(left);
+    }
+
+    /// `!<operand>`, `-<operand>` or an operand with its members
+    fn unary_expression(&mut self) -> Option<ExprNode> {
+        let start = self.index;
+        let operator = match self.peek().and_then(|t| t.content.get_operator()) {
+            Some(x @ (Operator::Logical(LogicalOperator::Not) | Operator::Calculation(CalculationOperator::Subtraction))) => *x,
+            _ => return self.member_expression(),
+        };
+        self.advance();
+
+        let operand = self.unary_expression()?;
+        return Some(ExprNode { content: NodeContent::Unary(operator, Box::new(operand)), position: self.position_from(start) });
+    }
+
+    /// `<primary>.<member>...`, only functions named by an identifier could be called
+    fn member_expression(&mut self) -> Option<ExprNode> {
+        let start = self.index;
+        let mut result = self.primary_expression()?;
+
+        loop {
+            if self.at_operator(Operator::Dot) {
+                self.advance();
+                let member = self.expect_identifier("a member name")?;
+                result = ExprNode { content: NodeContent::Member(Box::new(result), member), position: self.position_from(start) };
+            } else if self.at_container(ContainerType::Bracket) {
+                self.report("0003", "Only functions named by an identifier could be called".to_string(), start, self.index + 1);
+                return None;
+            } else {
+                return Some(result);
+            }
+        }
+    }
+
+    /// Data, a function call or an expression in brackets
+    fn primary_expression(&mut self) -> Option<ExprNode> {
+        let start = self.index;
+        let content = match self.peek().map(|t| &t.content) {
+            Some(DecoratedTokenContent::Data(DataToken::Identifier(_))) if self.nth_is_container(1, ContainerType::Bracket) => {
+                NodeContent::Data(ExprDataTerm::FunctionCall(self.function_call()?))
+            }
+            Some(DecoratedTokenContent::Data(x)) => {
+                self.advance();
+                NodeContent::Data(ExprDataTerm::from_data_token(x))
+            }
+            Some(DecoratedTokenContent::Container(ContainerType::Bracket)) => {
+                self.advance();
+                let inner = self.expression_tree()?;
+                self.expect_container(ContainerType::AntiBracket, "\")\"")?;
+                inner.content
+            }
+            _ => {
+                self.report_expected("an expression");
+                return None;
+            }
+        };
+
+        return Some(ExprNode { content, position: self.position_from(start) });
+    }
+}
@@ -0,0 +1,4 @@
+pub mod cursor;
+pub mod declaration;
+pub mod expression;
+pub mod statement;
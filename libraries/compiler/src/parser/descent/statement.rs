@@ -0,0 +1,176 @@
+use crate::parser::descent::cursor::Parser;
+use crate::shared::ast::action::{Action, ActionBlock, ActionContent, AssignmentAction, ConditionBlock, DeclarationAction, IfAction, ReturnAction};
+use crate::shared::ast::blocks::expression::SimpleExpression;
+use crate::shared::ast::decorated_token::{DataToken, DecoratedTokenContent};
+use crate::shared::token::container::ContainerType;
+use crate::shared::token::keyword::KeywordType;
+use crate::shared::token::operator::Operator;
+use crate::shared::utils::identifier::Identifier;
+
+impl<'a> Parser<'a> {
+    /// Actions of a block body written without braces, a `}` closing no block is reported and skipped
+    pub fn action_block(&mut self) -> Vec<Action> {
+        let mut result = self.actions();
+        while !self.is_at_end() {
+            self.report_expected("a statement");
+            self.advance();
+            result.extend(self.actions());
+        }
+
+        return result;
+    }
+
+    /// `{ <actions> }`
+    pub(super) fn block(&mut self) -> Option<Vec<Action>> {
+        self.expect_container(ContainerType::Brace, "\"{\"")?;
+        let result = self.actions();
+        self.expect_container(ContainerType::AntiBrace, "\"}\"")?;
+
+        return Some(result);
+    }
+
+    /// Actions until the end of tokens or the `}` closing the block, statements with issues are skipped
+    fn actions(&mut self) -> Vec<Action> {
+        let mut result = vec![];
+        while !self.is_at_end() && !self.at_container(ContainerType::AntiBrace) {
+            let start = self.index;
+            if !self.at_statement() {
+                self.synchronize();
+                self.report("0002", "Unrecognizable token sequence".to_string(), start, self.index);
+                continue;
+            }
+
+            match self.statement() {
+                Some(content) => result.push(Action::new(content, self.tokens_from(start))),
+                None => self.synchronize(),
+            }
+        }
+
+        return result;
+    }
+
+    /// Whether a statement starts at the current token, an identifier starts an assignment
+    fn at_statement(&self) -> bool {
+        return match self.keyword() {
+            Some(keyword) => matches!(
+                keyword,
+                KeywordType::KwDeclare | KeywordType::KwCall | KeywordType::KwReturn | KeywordType::KwIf | KeywordType::KwWhile
+                    | KeywordType::KwLoop | KeywordType::KwBreak | KeywordType::KwContinue
+            ),
+            None => {
+                let is_identifier = matches!(self.peek().map(|t| &t.content), Some(DecoratedTokenContent::Data(DataToken::Identifier(_))));
+                let is_assigned = self.tokens.get(self.index + 1).and_then(|t| t.content.get_operator()) == Some(&Operator::Assignment);
+                is_identifier && is_assigned
+            }
+        };
+    }
+
+    fn statement(&mut self) -> Option<ActionContent> {
+        return match self.keyword() {
+            Some(KeywordType::KwDeclare) => self.declaration(),
+            Some(KeywordType::KwCall) => {
+                self.advance();
+                let call = self.function_call()?;
+                self.expect_semicolon()?;
+                Some(ActionContent::CallStatement(call))
+            }
+            Some(KeywordType::KwReturn) => self.return_statement(),
+            Some(KeywordType::KwIf) => self.if_statement(),
+            Some(KeywordType::KwWhile) => self.condition_block().map(ActionContent::WhileStatement),
+            Some(KeywordType::KwLoop) => {
+                self.advance();
+                Some(ActionContent::LoopBlock(ActionBlock { actions: self.block()? }))
+            }
+            Some(KeywordType::KwBreak) => {
+                self.advance();
+                self.expect_semicolon()?;
+                Some(ActionContent::BreakStatement)
+            }
+            Some(KeywordType::KwContinue) => {
+                self.advance();
+                self.expect_semicolon()?;
+                Some(ActionContent::ContinueStatement)
+            }
+            _ => self.assignment(),
+        };
+    }
+
+    /// `decl var <type> <name>;` or `decl const <type> <name>;`
+    fn declaration(&mut self) -> Option<ActionContent> {
+        self.advance();
+        let is_variable = match self.keyword() {
+            Some(KeywordType::KwVar) => true,
+            Some(KeywordType::KwConst) => false,
+            _ => {
+                self.report_expected("\"var\" or \"const\"");
+                return None;
+            }
+        };
+        self.advance();
+
+        let data_type = self.expect_identifier("a type name")?;
+        let identifier = self.expect_identifier("a variable name")?;
+        self.expect_semicolon()?;
+
+        return Some(ActionContent::DeclarationStatement(DeclarationAction { is_variable, identifier, data_type }));
+    }
+
+    /// `<identifier> = <expression>;`
+    fn assignment(&mut self) -> Option<ActionContent> {
+        let identifier = self.expect_identifier("a variable name")?;
+        if !self.at_operator(Operator::Assignment) {
+            self.report_expected("\"=\"");
+            return None;
+        }
+        self.advance();
+
+        let eval_expression = self.expression()?;
+        self.expect_semicolon()?;
+
+        return Some(ActionContent::AssignmentStatement(AssignmentAction { identifier, eval_expression }));
+    }
+
+    /// `return;` or `return <expression>;`, the value is an empty expression if nothing is returned
+    fn return_statement(&mut self) -> Option<ActionContent> {
+        self.advance();
+        let value = if self.at_semicolon() {
+            SimpleExpression { postfix_expr: vec![], output_type: Identifier::empty() }
+        } else {
+            self.expression()?
+        };
+        self.expect_semicolon()?;
+
+        return Some(ActionContent::ReturnStatement(ReturnAction { value: Some(value) }));
+    }
+
+    /// `if (<condition>) { <actions> }`, followed by any `elif` block and an optional `else` block
+    fn if_statement(&mut self) -> Option<ActionContent> {
+        let mut result = IfAction {
+            if_block: self.condition_block()?,
+            elif_collection: vec![],
+            else_action: None,
+        };
+
+        while self.at_keyword(KeywordType::KwElseIf) {
+            result.elif_collection.push(self.condition_block()?);
+        }
+        if self.at_keyword(KeywordType::KwElse) {
+            self.advance();
+            result.else_action = Some(ActionBlock { actions: self.block()? });
+        }
+
+        return Some(ActionContent::IfBlock(result));
+    }
+
+    /// `<keyword> (<condition>) { <actions> }`, shared by `if`, `elif` and `while` whose keyword is checked by the caller
+    fn condition_block(&mut self) -> Option<ConditionBlock> {
+        self.advance();
+        self.expect_container(ContainerType::Bracket, "\"(\"")?;
+        let condition = self.expression_tree()?;
+        self.expect_container(ContainerType::AntiBracket, "\")\"")?;
+
+        let body = ActionBlock { actions: self.block()? };
+
+        return Some(ConditionBlock { condition, body });
+    }
+}
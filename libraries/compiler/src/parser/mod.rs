@@ -1,4 +1,3 @@
-pub mod builder;
 pub mod decorator;
+pub mod descent;
 pub mod pipeline;
-pub mod utils;
@@ -1,84 +1,50 @@
-use crate::parser::builder::blocks::link::link_statement_builder;
-use crate::parser::builder::function_builder::function_builder;
-use crate::parser::builder::group::declaration::group_declaration_builder;
-use crate::parser::builder::group::implementation::group_implementation_builder;
+use crate::parser::descent::cursor::Parser;
+use crate::shared::ast::action::Action;
+use crate::shared::ast::blocks::expression::{ExprNode, SimpleExpression};
 use crate::shared::ast::decorated_token::DecoratedToken;
 use crate::shared::ast::package::ParserPackageStructure;
-use crate::shared::error::general_issue::{GeneralIssue, IssueBase, IssueLevel, IssuePosition};
-use crate::shared::token::keyword::KeywordType;
+use crate::shared::error::general_issue::GeneralIssue;
+use crate::shared::error::parsing_issue::ParsingIssue;
 use crate::shared::utils::identifier::Identifier;
 
+/// Parse a whole source file, every syntax error in it is reported
 pub fn build_whole_file(
     tokens: Vec<DecoratedToken>,
     entry_point: Identifier,
-) -> Result<ParserPackageStructure, GeneralIssue<String>> {
-    let mut result = ParserPackageStructure {
-        functions: vec![],
-        entry_point,
-        linked_code_files: vec![],
-        declared_groups: vec![],
-        declared_implementations: vec![],
-    };
+) -> Result<ParserPackageStructure, GeneralIssue<ParsingIssue>> {
+    let mut parser = Parser::new(&tokens);
+    let result = parser.source_file(entry_point);
 
-    // Build Link part
-    let mut current_index = 0;
-    loop {
-        let current_link = link_statement_builder(&tokens[current_index..].to_vec());
-        if current_link.is_err() {
-            break;
-        }
+    return parser.finish(result);
+}
 
-        result
-            .linked_code_files
-            .push(current_link.clone().ok().unwrap().0);
-        current_index += current_link.ok().unwrap().1 + 1;
-    }
+/// Parse statements written without the braces of a block
+pub fn build_action_block(tokens: &[DecoratedToken]) -> Result<Vec<Action>, GeneralIssue<ParsingIssue>> {
+    let mut parser = Parser::new(tokens);
+    let result = parser.action_block();
 
-    // Build Function or group part
-    while current_index < tokens.len() {
-        match tokens[current_index].content.get_decorated_keyword().unwrap() {
-            KeywordType::KwDeclare => {
-                let current_function = function_builder(&tokens[current_index..].to_vec());
-                if current_function.is_err() {
-                    break;
-                }
+    return parser.finish(result);
+}
 
-                result.functions
-                      .push(current_function.clone().ok().unwrap().0);
-                current_index += current_function.ok().unwrap().1;
-            }
-            KeywordType::KwGroup => {
-                let current_group = group_declaration_builder(&tokens[current_index..].to_vec());
-                if current_group.is_err() {
-                    break;
-                }
+/// Parse an expression taking every token
+pub fn build_expression(tokens: &[DecoratedToken]) -> Result<SimpleExpression, GeneralIssue<ParsingIssue>> {
+    let mut parser = Parser::new(tokens);
+    let result = parser.expression();
+    if result.is_some() {
+        parser.expect_end("an operator or the end of the expression");
+    }
 
-                result.declared_groups
-                      .push(current_group.clone().ok().unwrap().0);
-                current_index += current_group.ok().unwrap().1;
-            }
-            KeywordType::KwImplement => {
-                let current_group = group_implementation_builder(&tokens[current_index..].to_vec(), &result.declared_groups);
-                if current_group.is_err() {
-                    break;
-                }
+    // An issue is always reported if there is no expression
+    return parser.finish(result).map(|x| x.unwrap());
+}
 
-                result.declared_implementations
-                      .push(current_group.clone().ok().unwrap().0);
-                current_index += current_group.ok().unwrap().1;
-            }
-            _ => {
-                return Err(GeneralIssue {
-                    issues: vec![IssueBase {
-                        level: IssueLevel::Info,
-                        position: IssuePosition::Parsing,
-                        code: "-1".to_string(),
-                        detail: "Invalid token stream encountered!".to_string(),
-                    }]
-                });
-            }
-        }
+/// Parse an expression taking every token into its tree
+pub fn build_expression_tree(tokens: &[DecoratedToken]) -> Result<ExprNode, GeneralIssue<ParsingIssue>> {
+    let mut parser = Parser::new(tokens);
+    let result = parser.expression_tree();
+    if result.is_some() {
+        parser.expect_end("an operator or the end of the expression");
     }
 
-    return Ok(result);
+    return parser.finish(result).map(|x| x.unwrap());
 }
@@ -0,0 +1,262 @@
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::path::{Component, Path, PathBuf};
+
+use crate::lexer::tokenize::tokenize;
+use crate::package_generator::availability_check::package_check::check_package;
+use crate::package_generator::package_builder::{build_package, build_package_with_debug_info};
+use crate::parser::decorator::decorate_token_with_string_pool;
+use crate::parser::pipeline::build_whole_file;
+use crate::shared::ast::blocks::function::FunctionLinkage;
+use crate::shared::ast::link::SourceFileLink;
+use crate::shared::ast::package::ParserPackageStructure;
+use crate::shared::error::compilation_issue::CompilationIssue;
+use crate::shared::error::general_issue::{FileMatch, GeneralIssue, IssueBase, IssueLevel, IssueLocation, IssuePosition};
+use crate::shared::package_generation::data_descriptor::StringConstant;
+use crate::shared::package_generation::implementations::package_descriptor::LIBRARY_PACKAGE_TYPE;
+use crate::shared::package_generation::package_descriptor::PackageMetadata;
+use crate::shared::session::compile_session::{CompileOptions, Session, SourceFile};
+use crate::shared::utils::identifier::Identifier;
+use crate::shared::utils::source_map::SourceMap;
+
+/// Extension of source files, `link foo::bar;` refers to `foo/bar.cbs`
+pub const SOURCE_FILE_EXTENSION: &str = "cbs";
+
+/// An executable entered by `main`
+impl Default for CompileOptions {
+    fn default() -> CompileOptions {
+        return CompileOptions {
+            entry_function: "main".to_string(),
+            metadata: PackageMetadata::default(),
+            debug: false,
+            project_root: String::new(),
+        };
+    }
+}
+
+impl Session {
+    pub fn new(options: CompileOptions) -> Session {
+        return Session { options, sources: vec![] };
+    }
+
+    /// Add a source, which replaces the source added with the same path before.
+    /// Sources are compiled in the order they are added, the string pool follows the same order.
+    pub fn add_source(&mut self, path: &str, content: &str) {
+        let source = SourceFile { path: path.to_string(), content: content.to_string() };
+        match self.sources.iter_mut().find(|s| s.path == path) {
+            Some(x) => *x = source,
+            None => self.sources.push(source),
+        }
+    }
+
+    /// Lex, parse and check every source without generating a package
+    pub fn check(&self) -> Result<(), GeneralIssue<CompilationIssue>> {
+        return self.analyze().map(|_| ());
+    }
+
+    /// Compile every source into a package.
+    /// Issues of all sources are collected, code which couldn't be generated is reported as well.
+    pub fn compile(&self) -> Result<Vec<u8>, GeneralIssue<CompilationIssue>> {
+        let (tree, string_pool) = self.analyze()?;
+
+        return self.compile_package(&tree, string_pool);
+    }
+
+    /// Generate a package from a structure which passed `analyze_package`, such as one built by a tool instead of parsed from sources.
+    /// Sources of the session are only used by debug information.
+    pub fn compile_package(&self, package: &ParserPackageStructure, string_pool: Vec<StringConstant>) -> Result<Vec<u8>, GeneralIssue<CompilationIssue>> {
+        let metadata = &self.options.metadata;
+
+        let result = if self.options.debug {
+            let sources: Vec<SourceMap> = self.sources.iter().map(|s| SourceMap::new(s.path.as_str(), s.content.as_str())).collect();
+            build_package_with_debug_info(package, string_pool, metadata, &sources)
+        } else {
+            build_package(package, string_pool, metadata)
+        };
+
+        return result.map_err(|e| GeneralIssue { issues: convert_issues(e.issues) });
+    }
+
+    /// The file a `link` statement in `linking_file` points to, looked up next to the linking file first, then in the project root
+    pub fn resolve_link(&self, link: &SourceFileLink, linking_file: &str) -> Option<&SourceFile> {
+        let relative_path = match link {
+            SourceFileLink::SourceFile(path) => path.clone(),
+            SourceFileLink::Identifier(identifier) => identifier_to_path(identifier),
+        };
+
+        let mut candidates = vec![];
+        if let Some(parent) = Path::new(linking_file).parent() {
+            candidates.push(normalize_path(&parent.join(&relative_path)));
+        }
+        candidates.push(normalize_path(&Path::new(&self.options.project_root).join(&relative_path)));
+
+        return candidates.iter().find_map(|c| self.sources.iter().find(|s| normalize_path(Path::new(&s.path)) == *c));
+    }
+
+    /// Merged structure of every source and the string pool shared by them, if no issue is found
+    pub fn analyze(&self) -> Result<(ParserPackageStructure, Vec<StringConstant>), GeneralIssue<CompilationIssue>> {
+        if self.sources.is_empty() {
+            return Err(GeneralIssue { issues: vec![package_issue(IssuePosition::Parsing, "No source file to compile".to_string())] });
+        }
+
+        let mut issues = vec![];
+        let mut string_pool = vec![];
+        let mut package: Option<ParserPackageStructure> = None;
+        for source in &self.sources {
+            let tree = self.parse_source(source, &mut string_pool);
+            if tree.is_err() {
+                issues.extend(tree.unwrap_err().issues);
+                continue;
+            }
+            let tree = tree.unwrap();
+
+            for link in &tree.linked_code_files {
+                if self.resolve_link(link, source.path.as_str()).is_none() {
+                    let mut issue = package_issue(IssuePosition::Parsing, format!("Couldn't resolve link {} in \"{}\"", link_to_string(link), source.path));
+                    issue.detail.location = Some(FileMatch { file_path: source.path.clone(), start_pos: 0, end_pos: 0 });
+                    issues.push(issue);
+                }
+            }
+
+            match &mut package {
+                Some(x) => x.merge(tree),
+                None => package = Some(tree),
+            }
+        }
+        if !issues.is_empty() {
+            return Err(GeneralIssue { issues });
+        }
+
+        let package = package.unwrap();
+        self.analyze_package(&package)?;
+
+        return Ok((package, string_pool));
+    }
+
+    /// Check a merged structure against the options, then run every availability check
+    pub fn analyze_package(&self, package: &ParserPackageStructure) -> Result<(), GeneralIssue<CompilationIssue>> {
+        let issues = self.check_package_structure(package);
+        if !issues.is_empty() {
+            return Err(GeneralIssue { issues });
+        }
+
+        let checked = check_package(package);
+        if checked.is_err() {
+            return Err(GeneralIssue { issues: convert_issues(checked.unwrap_err().issues) });
+        }
+
+        return Ok(());
+    }
+
+    /// Lex and parse a source, constants are added to `string_pool`
+    fn parse_source(&self, source: &SourceFile, string_pool: &mut Vec<StringConstant>) -> Result<ParserPackageStructure, GeneralIssue<CompilationIssue>> {
+        let tokens = tokenize(source.content.as_str(), true);
+        if tokens.is_err() {
+            return Err(GeneralIssue { issues: convert_issues(tokens.unwrap_err().with_file_path(source.path.as_str()).issues) });
+        }
+
+        let (tokens, extended_pool) = decorate_token_with_string_pool(tokens.unwrap(), string_pool.clone());
+        let entry_point = Identifier::single(self.options.entry_function.as_str());
+        let tree = build_whole_file(tokens, entry_point);
+        if tree.is_err() {
+            return Err(GeneralIssue { issues: convert_issues(tree.unwrap_err().with_file_path(source.path.as_str()).issues) });
+        }
+
+        let mut tree = tree.unwrap();
+        tree.set_file_path(source.path.as_str());
+        *string_pool = extended_pool;
+        return Ok(tree);
+    }
+
+    /// Issues of the merged package which are not found by availability checks
+    fn check_package_structure(&self, package: &ParserPackageStructure) -> Vec<IssueBase<CompilationIssue>> {
+        let mut result = vec![];
+        let is_library = self.options.metadata.package_type == LIBRARY_PACKAGE_TYPE;
+
+        result.extend(check_unique_functions(package));
+
+        if !is_library {
+            if let Some(func) = package.functions.iter().find(|f| f.linkage == FunctionLinkage::External) {
+                let content = format!("External function \"{}\" is only allowed in libraries, which are linked into executables", func.declarator.identifier);
+                result.push(package_issue(IssuePosition::CodeGeneration, content));
+            }
+            if !package.functions.iter().any(|f| f.declarator.identifier == package.entry_point) {
+                result.push(package_issue(IssuePosition::CodeGeneration, format!("Entry function \"{}\" is not found", package.entry_point)));
+            }
+        }
+
+        return result;
+    }
+}
+
+/// Compile a single in-memory source with `options`
+pub fn compile_source(path: &str, content: &str, options: CompileOptions) -> Result<Vec<u8>, GeneralIssue<CompilationIssue>> {
+    let mut session = Session::new(options);
+    session.add_source(path, content);
+
+    return session.compile();
+}
+
+/// Functions from different files share one function table, so their names must be unique
+pub fn check_unique_functions(package: &ParserPackageStructure) -> Vec<IssueBase<CompilationIssue>> {
+    let mut result = vec![];
+    let mut declared_names = HashSet::new();
+    for func in &package.functions {
+        if !declared_names.insert(func.declarator.identifier.to_string()) {
+            result.push(package_issue(IssuePosition::CodeGeneration, format!("Function \"{}\" is declared more than once", func.declarator.identifier)));
+        }
+    }
+
+    return result;
+}
+
+fn convert_issues<T: Display + IssueLocation>(issues: Vec<IssueBase<T>>) -> Vec<IssueBase<CompilationIssue>> {
+    return issues.into_iter().map(|issue| IssueBase {
+        level: issue.level,
+        position: issue.position,
+        code: issue.code,
+        detail: CompilationIssue { content: issue.detail.to_string(), location: issue.detail.location() },
+    }).collect();
+}
+
+fn package_issue(position: IssuePosition, content: String) -> IssueBase<CompilationIssue> {
+    return IssueBase {
+        level: IssueLevel::Error,
+        position,
+        code: "-1".to_string(),
+        detail: CompilationIssue { content, location: None },
+    };
+}
+
+fn identifier_to_path(identifier: &Identifier) -> PathBuf {
+    let mut result = PathBuf::new();
+    for scope in &identifier.scope {
+        result.push(scope);
+    }
+    result.push(format!("{}.{}", identifier.name, SOURCE_FILE_EXTENSION));
+
+    return result;
+}
+
+/// Remove `.` and resolve `..` without touching the file system, since sources may not exist in it
+fn normalize_path(path: &Path) -> PathBuf {
+    let mut result = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir if result.file_name().is_some() => {
+                result.pop();
+            }
+            x => result.push(x),
+        }
+    }
+
+    return result;
+}
+
+fn link_to_string(link: &SourceFileLink) -> String {
+    return match link {
+        SourceFileLink::SourceFile(path) => format!("\"{}\"", path.display()),
+        SourceFileLink::Identifier(identifier) => identifier.to_string(),
+    };
+}
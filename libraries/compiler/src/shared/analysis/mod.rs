@@ -0,0 +1,2 @@
+pub mod source_analysis;
+pub mod symbol;
@@ -0,0 +1,25 @@
+use crate::shared::ast::package::ParserPackageStructure;
+use crate::shared::error::general_issue::{FileMatch, IssueLevel, IssuePosition};
+use crate::shared::token::token::Token;
+use crate::shared::utils::source_map::SourceMap;
+
+/// Everything known about a single source file, even if it doesn't compile
+#[derive(Clone, Debug)]
+pub struct SourceAnalysis {
+    pub source_map: SourceMap,
+    // Empty if lexical analysis failed
+    pub tokens: Vec<Token>,
+    // Only available if parsing succeeded
+    pub tree: Option<ParserPackageStructure>,
+    pub issues: Vec<SourceIssue>,
+}
+
+/// An issue of any stage, located in the analyzed file
+#[derive(Clone, Debug)]
+pub struct SourceIssue {
+    pub level: IssueLevel,
+    pub position: IssuePosition,
+    pub code: String,
+    pub message: String,
+    pub location: FileMatch,
+}
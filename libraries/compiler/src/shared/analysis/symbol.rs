@@ -0,0 +1,21 @@
+use crate::shared::error::general_issue::FileMatch;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum SymbolKind {
+    Function,
+    Group,
+    Variable,
+    Keyword,
+}
+
+#[derive(Clone, Debug)]
+pub struct Symbol {
+    pub kind: SymbolKind,
+    pub name: String,
+    // Declaration of the symbol, like `decl var number x`
+    pub detail: String,
+    // Where the name is declared, empty for keywords
+    pub location: FileMatch,
+    // Byte range where the symbol is visible, `None` for the whole file
+    pub scope: Option<(usize, usize)>,
+}
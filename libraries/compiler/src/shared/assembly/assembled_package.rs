@@ -0,0 +1,11 @@
+use crate::shared::package_generation::package_descriptor::PackageMetadata;
+use crate::shared::package_generation::relocation_reference::RelocatableCommandList;
+use crate::shared::utils::identifier::Identifier;
+
+#[derive(Debug, Clone)]
+pub struct AssembledPackage {
+    // Function commands with string pool and function table, whose relocation targets are not calculated yet
+    pub commands: RelocatableCommandList,
+    pub metadata: PackageMetadata,
+    pub entry_point: Option<Identifier>,
+}
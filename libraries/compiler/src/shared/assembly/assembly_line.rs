@@ -0,0 +1,62 @@
+use crate::shared::command_map::{MathCalcCommand, MathLogicalCommand};
+
+/// Operand of stack commands, written as `#<value>`, `$<slot>`, `@<slot>` or `"<string>"`
+#[derive(Debug, Clone, PartialEq)]
+pub enum AssemblyDataAccess {
+    InstantValue(i64),
+    LocalSlot(usize),
+    StringSlot(usize),
+    // Placed into the string pool automatically
+    StringLiteral(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum JumpDestination {
+    Label(String),
+    // Relative to the head of the jump command, written as `+<offset>` or `-<offset>`
+    Relative(i64),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AssemblyInstruction {
+    CreateObject { is_global: bool },
+    DestroyObject { slot: usize },
+
+    Push(AssemblyDataAccess),
+    PushFromObject(AssemblyDataAccess),
+    Pop,
+    PopToObject(AssemblyDataAccess),
+
+    JumpToRelative(JumpDestination),
+    JumpByStackTop { positive: JumpDestination, negative: JumpDestination, zero: JumpDestination },
+
+    EnterFunction { function: String, argument_count: u8 },
+    LeaveWithoutValue,
+    LeaveWithValue,
+    FunctionEndFlag,
+
+    Calculation(MathCalcCommand),
+    Logical(MathLogicalCommand),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AssemblyStatement {
+    // `.metadata type=0 data=8 domain_layer=2 data_slot=2 address=8`
+    Metadata(Vec<(String, u8)>),
+    // `.entry <function>`
+    Entry(String),
+    // `.string "<value>"`, slots are assigned in order
+    StringConstant(String),
+    // `.func <name>`, slots are assigned in order
+    Function(String),
+    // `<name>:`
+    Label(String),
+    Instruction(AssemblyInstruction),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AssemblyLine {
+    // Starts from 1
+    pub line: usize,
+    pub statement: AssemblyStatement,
+}
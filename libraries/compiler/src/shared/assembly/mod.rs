@@ -0,0 +1,2 @@
+pub mod assembled_package;
+pub mod assembly_line;
@@ -1,4 +1,4 @@
-use crate::shared::ast::blocks::expression::{SimpleExpression, RelationExpression};
+use crate::shared::ast::blocks::expression::{ExprNode, SimpleExpression};
 use crate::shared::ast::parameter::Parameter;
 use crate::shared::token::token::Token;
 use crate::shared::utils::identifier::Identifier;
@@ -6,6 +6,7 @@ use crate::shared::utils::identifier::Identifier;
 pub type VariableDefinition = Parameter;
 
 #[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ActionContent {
     DeclarationStatement(DeclarationAction),
     AssignmentStatement(AssignmentAction),
@@ -22,6 +23,7 @@ pub enum ActionContent {
 }
 
 #[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Action {
     pub content: ActionContent,
 
@@ -32,6 +34,7 @@ pub struct Action {
 pub type LoopBlock = ActionBlock;
 
 #[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ActionBlock {
     pub actions: Vec<Action>,
 }
@@ -42,12 +45,15 @@ pub type WhileBlock = ConditionBlock;
 
 // Used in while, if, elif
 #[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConditionBlock {
-    pub condition: RelationExpression,
+    // A bool expression, kept as a tree so that `&&` and `||` could skip their right operands
+    pub condition: ExprNode,
     pub body: ActionBlock,
 }
 
 #[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DeclarationAction {
     // A variable or a constant
     pub is_variable: bool,
@@ -57,12 +63,14 @@ pub struct DeclarationAction {
 }
 
 #[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AssignmentAction {
     pub identifier: Identifier,
     pub eval_expression: SimpleExpression,
 }
 
 #[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CallAction {
     pub function_name: Identifier,
     // Arguments are Expressions
@@ -72,11 +80,13 @@ pub struct CallAction {
 /// ### Field:
 /// - `value`: No return value when the field is `None`
 #[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReturnAction {
     pub value: Option<SimpleExpression>,
 }
 
 #[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IfAction {
     pub if_block: ConditionBlock,
     pub elif_collection: Vec<ElifBlock>,
@@ -85,6 +95,7 @@ pub struct IfAction {
 
 // TODO: Builder required
 #[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SwitchAction {
     pub condition: SimpleExpression,
     pub cases: Vec<SwitchCase>,
@@ -92,6 +103,7 @@ pub struct SwitchAction {
 
 // Builder with SwitchAction
 #[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SwitchCase {
     pub is_default: bool,
     pub value: String,
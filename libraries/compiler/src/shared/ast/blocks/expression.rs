@@ -1,25 +1,39 @@
 use crate::shared::ast::action::CallAction;
 use crate::shared::package_generation::data_descriptor::StringConstant;
-use crate::shared::token::operator::{Operator, RelationOperator};
-use crate::shared::token::token::Token;
+use crate::shared::token::operator::Operator;
 use crate::shared::utils::identifier::Identifier;
+use crate::shared::utils::position::Position;
 
 #[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SimpleExpression {
     pub postfix_expr: Vec<ExprTerm>,
 
     pub output_type: Identifier,
 }
 
+/// An expression parsed with operator precedence, the parser flattens it into a `SimpleExpression`
 #[derive(Clone, PartialEq, Debug)]
-pub struct RelationExpression {
-    pub left: SimpleExpression,
-    pub right: SimpleExpression,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExprNode {
+    pub content: NodeContent,
+    // Where the node is in the source, tokens are only looked up by diagnostics
+    pub position: Position,
+}
 
-    pub expected_relation: RelationOperator
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NodeContent {
+    Data(ExprDataTerm),
+    // `!` or `-` and its operand
+    Unary(Operator, Box<ExprNode>),
+    Binary(Operator, Box<ExprNode>, Box<ExprNode>),
+    // `<target>.<member>`
+    Member(Box<ExprNode>, Identifier),
 }
 
 #[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TermContent {
     Data(ExprDataTerm),
     Operator(Operator),
@@ -30,12 +44,14 @@ pub enum TermContent {
 }
 
 #[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExprTerm {
     pub content: TermContent,
-    pub original_token: Vec<Token>
+    pub position: Position,
 }
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ExprDataTerm {
     Number(String),
     String(StringConstant),
@@ -3,13 +3,30 @@ use crate::shared::ast::parameter::Parameter;
 use crate::shared::utils::identifier::Identifier;
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Function {
     pub declarator: FunctionDeclarator,
     pub body: Vec<Action>,
+    // The source file the function is declared in
+    pub file_path: String,
+    pub linkage: FunctionLinkage,
+}
+
+/// How a function is seen by other packages when they are linked together
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FunctionLinkage {
+    // Only visible inside the package
+    Internal,
+    // Declared with `export`, other packages could call it
+    Export,
+    // Declared without a body, which is provided by another package
+    External,
 }
 
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FunctionDeclarator {
     pub identifier: Identifier,
     pub parameters: Vec<Parameter>,
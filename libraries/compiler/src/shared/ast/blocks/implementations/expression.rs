@@ -1,10 +1,46 @@
 use crate::shared::ast::action::CallAction;
-use crate::shared::ast::blocks::expression::{ExprDataTerm, TermContent};
+use crate::shared::ast::blocks::expression::{ExprDataTerm, ExprNode, ExprTerm, NodeContent, TermContent};
 use crate::shared::ast::decorated_token::DataToken;
 use crate::shared::package_generation::data_descriptor::StringConstant;
-use crate::shared::token::operator::Operator;
+use crate::shared::token::operator::{CalculationOperator, Operator};
 use crate::shared::utils::identifier::Identifier;
 
+impl ExprNode {
+    /// Terms of the expression in postfix order.
+    /// Operator terms keep the position of the whole operation, `-x` becomes `x` followed by a `Negation`.
+    pub fn to_postfix(&self) -> Vec<ExprTerm> {
+        let mut result = vec![];
+        self.push_postfix(&mut result);
+
+        return result;
+    }
+
+    fn push_postfix(&self, result: &mut Vec<ExprTerm>) {
+        let term = |content: TermContent| ExprTerm { content, position: self.position.clone() };
+        match &self.content {
+            NodeContent::Data(x) => result.push(term(TermContent::Data(x.clone()))),
+            NodeContent::Unary(operator, operand) => {
+                operand.push_postfix(result);
+                let operator = match operator {
+                    Operator::Calculation(CalculationOperator::Subtraction) => Operator::Calculation(CalculationOperator::Negation),
+                    x => *x,
+                };
+                result.push(term(TermContent::Operator(operator)));
+            }
+            NodeContent::Binary(operator, left, right) => {
+                left.push_postfix(result);
+                right.push_postfix(result);
+                result.push(term(TermContent::Operator(*operator)));
+            }
+            NodeContent::Member(target, member) => {
+                target.push_postfix(result);
+                result.push(term(TermContent::Data(ExprDataTerm::Identifier(member.clone()))));
+                result.push(term(TermContent::Operator(Operator::Dot)));
+            }
+        }
+    }
+}
+
 impl TermContent {
     pub fn get_data_term(&self) -> Option<&ExprDataTerm> {
         return match self {
@@ -6,6 +6,7 @@ use crate::shared::token::token::Token;
 use crate::shared::utils::identifier::Identifier;
 
 #[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DecoratedTokenContent {
     DecoratedKeyword(KeywordType),
     Container(ContainerType),
@@ -15,12 +16,14 @@ pub enum DecoratedTokenContent {
 }
 
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DecoratedToken {
     pub content: DecoratedTokenContent,
     pub original_token: Token
 }
 
 #[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DataToken {
     Number(String),
     String(StringConstant),
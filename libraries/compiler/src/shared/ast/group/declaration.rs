@@ -4,6 +4,7 @@ use crate::shared::utils::identifier::Identifier;
 pub type MethodDeclarator = FunctionDeclarator;
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Field {
     pub identifier: Identifier,
     pub data_type: Identifier,
@@ -13,6 +14,7 @@ pub struct Field {
 }
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GroupDeclarationBlock {
     pub identifier: Identifier,
     pub fields: Vec<Field>,
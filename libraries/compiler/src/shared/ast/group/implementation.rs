@@ -8,6 +8,7 @@ pub type FunctionImplementation = Function;
 pub type FieldGS = ActionBlock;
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GroupImplementationBlock {
     pub source_group: Identifier,
 
@@ -17,6 +18,7 @@ pub struct GroupImplementationBlock {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FieldImplementation {
     pub identifier: Identifier,
     pub slot: usize,
@@ -1,6 +1,8 @@
 use crate::shared::ast::blocks::expression::SimpleExpression;
+use crate::shared::ast::blocks::function::FunctionLinkage;
 use crate::shared::ast::group::declaration::GroupDeclarationBlock;
 use crate::shared::ast::group::implementation::{FieldImplementation, FunctionImplementation, GroupImplementationBlock, MethodImplementation};
+use crate::shared::error::general_issue::UNKNOWN_FILE_PATH;
 use crate::shared::utils::identifier::Identifier;
 
 impl GroupImplementationBlock {
@@ -23,11 +25,11 @@ impl GroupImplementationBlock {
         }
 
         for method in &decl.methods {
-            result.methods.push(MethodImplementation{ declarator: method.clone(), body: vec![] });
+            result.methods.push(MethodImplementation{ declarator: method.clone(), body: vec![], file_path: UNKNOWN_FILE_PATH.to_string(), linkage: FunctionLinkage::Internal });
         }
 
         for function in &decl.functions {
-            result.functions.push(FunctionImplementation{declarator: function.clone(), body: vec![] });
+            result.functions.push(FunctionImplementation{declarator: function.clone(), body: vec![], file_path: UNKNOWN_FILE_PATH.to_string(), linkage: FunctionLinkage::Internal });
         }
 
         return result;
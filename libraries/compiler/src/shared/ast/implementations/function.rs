@@ -0,0 +1,15 @@
+use std::fmt::{Display, Formatter};
+
+use itertools::Itertools;
+
+use crate::shared::ast::blocks::function::FunctionDeclarator;
+
+/// Printed like it is declared: `name(number x, str y)[number]`
+impl Display for FunctionDeclarator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let parameters = self.parameters.iter().map(|p| format!("{} {}", p.type_name, p.identifier)).join(", ");
+        let return_type = if self.return_type.name.is_empty() { "none".to_string() } else { self.return_type.to_string() };
+
+        write!(f, "{}({})[{}]", self.identifier, parameters, return_type)
+    }
+}
@@ -1,3 +1,4 @@
 pub mod action;
 pub mod decorated_token;
+pub mod function;
 pub mod package;
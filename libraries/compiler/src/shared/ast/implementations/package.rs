@@ -8,10 +8,27 @@ impl ParserPackageStructure {
             result.push(FunctionTableEntry{
                 slot: result.len(),
                 name: x.declarator.identifier.clone(),
-                relocated_entry_address: 0
+                relocated_entry_address: 0,
+                linkage: x.linkage,
             });
         }
 
         return result;
     }
+
+    /// Merge a structure parsed from another source file into this one.
+    /// The entry point of `self` is kept, links of `other` are appended so they could be resolved later.
+    pub fn merge(&mut self, other: ParserPackageStructure) {
+        self.functions.extend(other.functions);
+        self.linked_code_files.extend(other.linked_code_files);
+        self.declared_groups.extend(other.declared_groups);
+        self.declared_implementations.extend(other.declared_implementations);
+    }
+
+    /// Record the source file every function comes from, issues found later could then point to it
+    pub fn set_file_path(&mut self, file_path: &str) {
+        for function in &mut self.functions {
+            function.file_path = file_path.to_string();
+        }
+    }
 }
@@ -2,6 +2,7 @@ use std::path::PathBuf;
 use crate::shared::utils::identifier::Identifier;
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SourceFileLink {
     SourceFile(PathBuf),
     Identifier(Identifier),
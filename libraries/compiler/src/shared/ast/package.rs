@@ -5,6 +5,7 @@ use crate::shared::ast::link::SourceFileLink;
 use crate::shared::utils::identifier::Identifier;
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ParserPackageStructure {
     pub functions: Vec<Function>,
     // The entry of the package (executable)
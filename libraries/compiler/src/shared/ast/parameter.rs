@@ -1,6 +1,7 @@
 use crate::shared::utils::identifier::Identifier;
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Parameter {
     pub type_name: Identifier,
     pub identifier: Identifier,
@@ -1,6 +1,6 @@
 pub const PLACE_HOLDER: u8 = 0x0;
 
-#[derive(Clone, Hash, Eq, PartialEq)]
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
 pub enum RootCommand {
     Object,
     Stack,
@@ -24,13 +24,13 @@ pub enum RootCommand {
  * If data is a global data, then `GDF` is set to `0x01`.
  * If data is a private data, `GDF` is set to `0x00`
  */
-#[derive(Clone, Hash, Eq, PartialEq)]
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
 pub enum ObjectCommand {
     Create,
     Destroy,
 }
 
-#[derive(Clone, Hash, Eq, PartialEq)]
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
 pub enum StackCommand {
     Push,
     PushFromObject,
@@ -38,13 +38,13 @@ pub enum StackCommand {
     PopToObject,
 }
 
-#[derive(Clone, Hash, Eq, PartialEq)]
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
 pub enum JumpCommand {
     ToRelative,
     ByStackTop,
 }
 
-#[derive(Clone, Hash, Eq, PartialEq)]
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
 pub enum FunctionCommand {
     Enter,
     LeaveWithoutValue,
@@ -52,13 +52,13 @@ pub enum FunctionCommand {
     FunctionEndFlag,
 }
 
-#[derive(Clone, Hash, Eq, PartialEq)]
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
 pub enum MathCommand {
     Calculation,
     Logical,
 }
 
-#[derive(Clone, Hash, Eq, PartialEq)]
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
 pub enum MathCalcCommand {
     Plus,
     Minus,
@@ -68,7 +68,7 @@ pub enum MathCalcCommand {
     Inverse
 }
 
-#[derive(Clone, Hash, Eq, PartialEq)]
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
 pub enum MathLogicalCommand {
     And,
     Or,
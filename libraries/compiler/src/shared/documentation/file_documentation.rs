@@ -0,0 +1,53 @@
+use crate::shared::ast::blocks::function::{FunctionDeclarator, FunctionLinkage};
+use crate::shared::ast::group::declaration::Field;
+use crate::shared::ast::link::SourceFileLink;
+use crate::shared::utils::identifier::Identifier;
+
+/// Declarations of a source file, with the comments written right above them.
+/// Comment lines keep their text after `//`, a leading `/` of `///` and one space are removed.
+#[derive(Clone, Debug)]
+pub struct FileDocumentation {
+    pub file_path: String,
+    // Comments at the top of the file which are separated from the first declaration by a blank line
+    pub summary: Vec<String>,
+    pub links: Vec<LinkDocumentation>,
+    pub functions: Vec<FunctionDocumentation>,
+    pub groups: Vec<GroupDocumentation>,
+}
+
+#[derive(Clone, Debug)]
+pub struct LinkDocumentation {
+    pub link: SourceFileLink,
+    // Path of the linked file if it is documented as well, resolving links is left to callers
+    pub target: Option<String>,
+}
+
+/// A function, a method or a function of a group
+#[derive(Clone, Debug)]
+pub struct FunctionDocumentation {
+    pub declarator: FunctionDeclarator,
+    // Members of groups are always `Internal`
+    pub linkage: FunctionLinkage,
+    pub comment: Vec<String>,
+}
+
+#[derive(Clone, Debug)]
+pub struct FieldDocumentation {
+    pub field: Field,
+    pub comment: Vec<String>,
+}
+
+#[derive(Clone, Debug)]
+pub struct GroupDocumentation {
+    pub identifier: Identifier,
+    pub comment: Vec<String>,
+    pub fields: Vec<FieldDocumentation>,
+    pub methods: Vec<FunctionDocumentation>,
+    pub functions: Vec<FunctionDocumentation>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DocumentationFormat {
+    Markdown,
+    Html,
+}
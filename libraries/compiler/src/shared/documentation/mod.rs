@@ -0,0 +1 @@
+pub mod file_documentation;
@@ -0,0 +1,6 @@
+#[derive(Debug, Clone)]
+pub struct AssemblyIssue {
+    pub content: String,
+    // Starts from 1
+    pub line: usize,
+}
@@ -0,0 +1,9 @@
+use crate::shared::error::general_issue::FileMatch;
+
+/// An issue of any stage of a compilation session, the stage is kept by `IssueBase::position`
+#[derive(Debug, Clone)]
+pub struct CompilationIssue {
+    pub content: String,
+    // `None` for issues of the whole package, like a missing entry function
+    pub location: Option<FileMatch>,
+}
@@ -15,6 +15,8 @@ pub enum IssuePosition {
     LexicalAnalysis,
     Parsing,
     CodeGeneration,
+    PackageReading,
+    Linking,
 }
 
 #[derive(Clone, Debug)]
@@ -25,9 +27,21 @@ pub struct IssueBase<T> {
     pub detail: T,
 }
 
+/// Used until the issue is attached to a file by the caller
+pub const UNKNOWN_FILE_PATH: &str = "N/A";
+
+/// A byte range in a source file, `end_pos` is exclusive
 #[derive(Clone, Debug)]
 pub struct FileMatch {
     pub file_path: String,
     pub start_pos: usize,
     pub end_pos: usize,
 }
+
+/// Tells where an issue is found in the source, issues without a source location return `None`
+pub trait IssueLocation {
+    fn location(&self) -> Option<FileMatch>;
+
+    /// Attach the issue to a file, issues without a source location ignore it
+    fn set_file_path(&mut self, _file_path: &str) {}
+}
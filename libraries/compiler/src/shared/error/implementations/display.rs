@@ -1,7 +1,12 @@
 use std::fmt::{Display, Formatter};
-use crate::shared::error::general_issue::FileMatch;
+use crate::shared::error::assembly_issue::AssemblyIssue;
+use crate::shared::error::compilation_issue::CompilationIssue;
+use crate::shared::error::general_issue::{FileMatch, IssueLevel, IssuePosition};
 use crate::shared::error::lexical_analysis_issue::LexicalAnalysisIssue;
+use crate::shared::error::linking_issue::LinkingIssue;
+use crate::shared::error::package_reading_issue::PackageReadingIssue;
 use crate::shared::error::parsing_issue::ParsingIssue;
+use crate::shared::error::pkg_gen_issue::PackageGenerationIssue;
 
 impl Display for LexicalAnalysisIssue {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -15,8 +20,63 @@ impl Display for ParsingIssue {
     }
 }
 
+impl Display for PackageGenerationIssue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.content)
+    }
+}
+
 impl Display for FileMatch {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "in file {} from position {} to {}", self.file_path, self.start_pos, self.end_pos)
     }
 }
+
+impl Display for PackageReadingIssue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} (at byte 0x{:X})", self.content, self.position)
+    }
+}
+
+impl Display for AssemblyIssue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} (at line {})", self.content, self.line)
+    }
+}
+
+impl Display for LinkingIssue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.package {
+            Some(package) => write!(f, "{} (in package {})", self.content, package),
+            None => write!(f, "{}", self.content),
+        }
+    }
+}
+
+impl Display for CompilationIssue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.content)
+    }
+}
+
+impl Display for IssueLevel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IssueLevel::Info => write!(f, "info"),
+            IssueLevel::Warning => write!(f, "warning"),
+            IssueLevel::Error => write!(f, "error"),
+        }
+    }
+}
+
+impl Display for IssuePosition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IssuePosition::LexicalAnalysis => write!(f, "lexical_analysis"),
+            IssuePosition::Parsing => write!(f, "parsing"),
+            IssuePosition::CodeGeneration => write!(f, "code_generation"),
+            IssuePosition::PackageReading => write!(f, "package_reading"),
+            IssuePosition::Linking => write!(f, "linking"),
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use crate::shared::error::assembly_issue::AssemblyIssue;
+use crate::shared::error::compilation_issue::CompilationIssue;
+use crate::shared::error::general_issue::{FileMatch, GeneralIssue, IssueLocation, UNKNOWN_FILE_PATH};
+use crate::shared::error::lexical_analysis_issue::LexicalAnalysisIssue;
+use crate::shared::error::linking_issue::LinkingIssue;
+use crate::shared::error::package_reading_issue::PackageReadingIssue;
+use crate::shared::error::parsing_issue::ParsingIssue;
+use crate::shared::error::pkg_gen_issue::PackageGenerationIssue;
+
+impl<T: IssueLocation> GeneralIssue<T> {
+    /// Lexer and parser work on source strings only, the caller knows which file the issues belong to
+    pub fn with_file_path(mut self, file_path: &str) -> GeneralIssue<T> {
+        for issue in &mut self.issues {
+            issue.detail.set_file_path(file_path);
+        }
+
+        return self;
+    }
+}
+
+impl IssueLocation for LexicalAnalysisIssue {
+    fn location(&self) -> Option<FileMatch> {
+        return Some(self.location.clone());
+    }
+
+    fn set_file_path(&mut self, file_path: &str) {
+        self.location.file_path = file_path.to_string();
+    }
+}
+
+impl IssueLocation for ParsingIssue {
+    fn location(&self) -> Option<FileMatch> {
+        return Some(self.location.clone());
+    }
+
+    fn set_file_path(&mut self, file_path: &str) {
+        self.location.file_path = file_path.to_string();
+    }
+}
+
+impl IssueLocation for PackageGenerationIssue {
+    fn location(&self) -> Option<FileMatch> {
+        return Some(self.location.clone());
+    }
+
+    fn set_file_path(&mut self, file_path: &str) {
+        self.location.file_path = file_path.to_string();
+    }
+}
+
+/// The position is the offset of the broken byte in the package
+impl IssueLocation for PackageReadingIssue {
+    fn location(&self) -> Option<FileMatch> {
+        return Some(FileMatch {
+            file_path: UNKNOWN_FILE_PATH.to_string(),
+            start_pos: self.position,
+            end_pos: self.position + 1,
+        });
+    }
+}
+
+/// Only the line number is known
+impl IssueLocation for AssemblyIssue {
+    fn location(&self) -> Option<FileMatch> {
+        return None;
+    }
+}
+
+/// Packages are binary, there is nothing to point at
+impl IssueLocation for LinkingIssue {
+    fn location(&self) -> Option<FileMatch> {
+        return None;
+    }
+}
+
+impl IssueLocation for CompilationIssue {
+    fn location(&self) -> Option<FileMatch> {
+        return self.location.clone();
+    }
+
+    fn set_file_path(&mut self, file_path: &str) {
+        if let Some(location) = &mut self.location {
+            location.file_path = file_path.to_string();
+        }
+    }
+}
+
+/// Plain messages, which are reported by the top level parser
+impl IssueLocation for String {
+    fn location(&self) -> Option<FileMatch> {
+        return None;
+    }
+}
@@ -1 +1,2 @@
-pub mod display;
\ No newline at end of file
+pub mod display;
+pub mod location;
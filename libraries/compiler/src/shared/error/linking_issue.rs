@@ -0,0 +1,6 @@
+#[derive(Debug, Clone)]
+pub struct LinkingIssue {
+    pub content: String,
+    // Name of the package where the problem is found, usually its file path
+    pub package: Option<String>,
+}
@@ -1,5 +1,10 @@
 pub mod implementations;
+pub mod assembly_issue;
+pub mod compilation_issue;
 pub mod general_issue;
 pub mod lexical_analysis_issue;
+pub mod linking_issue;
+pub mod package_reading_issue;
 pub mod parsing_issue;
 pub mod pkg_gen_issue;
+pub mod snippet;
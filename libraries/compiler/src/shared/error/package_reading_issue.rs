@@ -0,0 +1,6 @@
+#[derive(Debug, Clone)]
+pub struct PackageReadingIssue {
+    pub content: String,
+    // Offset in the package where the problem is found
+    pub position: usize,
+}
@@ -1,4 +1,7 @@
+use crate::shared::error::general_issue::FileMatch;
+
 #[derive(Debug, Clone)]
 pub struct PackageGenerationIssue {
-    // TODO: Code later
+    pub content: String,
+    pub location: FileMatch,
 }
@@ -0,0 +1,105 @@
+use crate::shared::utils::source_map::SourceMap;
+
+/// A byte range to be underlined, `end_pos` is exclusive
+#[derive(Clone, Debug)]
+pub struct LabelledSpan {
+    pub start_pos: usize,
+    pub end_pos: usize,
+    pub label: Option<String>,
+}
+
+/// Print the source lines covered by the spans, and underline every span.
+/// The first span is the primary one (`^`), the others are marked with `-`.
+/// Labels are placed after the underline on the last line of their spans.
+///
+/// ```text
+///  --> main.cbs:2:5
+///   |
+/// 2 |     let x: str = 1;
+///   |     ^^^^^^^^^^^^^^^ type mismatch
+/// ```
+pub fn render_snippet(source_map: &SourceMap, spans: &[LabelledSpan]) -> String {
+    if spans.is_empty() {
+        return String::new();
+    }
+
+    let ranges: Vec<(usize, usize)> = spans.iter().map(|s| span_lines(source_map, s)).collect();
+    let mut lines: Vec<usize> = ranges.iter().flat_map(|(first, last)| *first..=*last).collect();
+    lines.sort_unstable();
+    lines.dedup();
+
+    let gutter_width = lines.last().unwrap().to_string().len();
+    let blank_gutter = " ".repeat(gutter_width);
+    let (line, column) = source_map.line_column(spans[0].start_pos);
+
+    let mut result = vec![
+        format!("{}--> {}:{}:{}", blank_gutter, source_map.file_path, line, column),
+        format!("{} |", blank_gutter),
+    ];
+
+    let mut previous_line: Option<usize> = None;
+    for line in lines {
+        if previous_line.is_some_and(|x| line > x + 1) {
+            result.push("...".to_string());
+        }
+        previous_line = Some(line);
+
+        let text = source_map.line_text(line);
+        result.push(format!("{:>width$} | {}", line, text, width = gutter_width).trim_end().to_string());
+
+        for (index, span) in spans.iter().enumerate() {
+            let (first, last) = ranges[index];
+            if line < first || line > last {
+                continue;
+            }
+
+            let (start_column, end_column) = underline_columns(source_map, span, line, first, last);
+            let marker = if index == 0 { "^" } else { "-" };
+            let indent: String = text.chars()
+                                     .take(start_column - 1)
+                                     .map(|c| if c == '\t' { '\t' } else { ' ' })
+                                     .collect();
+
+            let mut underline = format!("{} | {}{}", blank_gutter, indent, marker.repeat(end_column - start_column));
+            if line == last {
+                if let Some(label) = &span.label {
+                    underline.push(' ');
+                    underline.push_str(label);
+                }
+            }
+            result.push(underline);
+        }
+    }
+
+    return result.join("\n");
+}
+
+/// First and last line covered by a span
+fn span_lines(source_map: &SourceMap, span: &LabelledSpan) -> (usize, usize) {
+    let first = source_map.line_column(span.start_pos).0;
+    if span.end_pos <= span.start_pos {
+        return (first, first);
+    }
+
+    return (first, source_map.line_column(span.end_pos - 1).0);
+}
+
+/// 1-based columns of the underline on `line`, the end is exclusive.
+/// Empty spans are underlined with a single marker.
+fn underline_columns(source_map: &SourceMap, span: &LabelledSpan, line: usize, first: usize, last: usize) -> (usize, usize) {
+    let text = source_map.line_text(line);
+    let line_end = text.chars().count() + 1;
+
+    let start = if line == first {
+        source_map.line_column(span.start_pos).1
+    } else {
+        text.chars().take_while(|c| c.is_whitespace()).count() + 1
+    };
+
+    let end = match source_map.line_column(span.end_pos) {
+        (end_line, end_column) if line == last && end_line == line => end_column,
+        _ => line_end,
+    };
+
+    return (start, end.max(start + 1));
+}
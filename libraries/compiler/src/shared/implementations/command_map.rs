@@ -78,6 +78,10 @@ impl RootCommand {
     pub fn to_opcode(&self) -> u8 {
         return ROOT_COMMAND_OPCODE[self];
     }
+
+    pub fn from_opcode(opcode: u8) -> Option<RootCommand> {
+        return ROOT_COMMAND_OPCODE.iter().find(|(_, &v)| v == opcode).map(|(k, _)| k.clone());
+    }
 }
 
 impl ObjectCommand {
@@ -85,6 +89,10 @@ impl ObjectCommand {
         return OBJECT_COMMAND_OPCODE[self];
     }
 
+    pub fn from_opcode(opcode: u8) -> Option<ObjectCommand> {
+        return OBJECT_COMMAND_OPCODE.iter().find(|(_, &v)| v == opcode).map(|(k, _)| k.clone());
+    }
+
     pub fn get_len(&self, slot_algn: u8) -> usize {
         return match self {
             ObjectCommand::Create => 2,
@@ -98,6 +106,10 @@ impl StackCommand {
         return STACK_COMMAND_OPCODE[self];
     }
 
+    pub fn from_opcode(opcode: u8) -> Option<StackCommand> {
+        return STACK_COMMAND_OPCODE.iter().find(|(_, &v)| v == opcode).map(|(k, _)| k.clone());
+    }
+
     pub fn get_len(&self, data_slot_algn: u8, data_algn: u8) -> usize {
         return match self {
             StackCommand::Push => 1 + data_algn as usize,
@@ -113,6 +125,10 @@ impl JumpCommand {
         return JUMP_COMMAND_OPCODE[self];
     }
 
+    pub fn from_opcode(opcode: u8) -> Option<JumpCommand> {
+        return JUMP_COMMAND_OPCODE.iter().find(|(_, &v)| v == opcode).map(|(k, _)| k.clone());
+    }
+
     pub fn get_len(&self, addr_algn: u8) -> usize {
         return match self {
             JumpCommand::ToRelative => 1 + jump_command_address_placeholder_len(addr_algn),
@@ -126,6 +142,10 @@ impl FunctionCommand {
         return FUNCTION_COMMAND_OPCODE[self];
     }
 
+    pub fn from_opcode(opcode: u8) -> Option<FunctionCommand> {
+        return FUNCTION_COMMAND_OPCODE.iter().find(|(_, &v)| v == opcode).map(|(k, _)| k.clone());
+    }
+
     pub fn get_len(&self, addr_algn: u8) -> usize {
         return match self {
             FunctionCommand::Enter => 1 + addr_algn as usize + 1,
@@ -140,6 +160,10 @@ impl MathCommand {
     pub fn to_opcode(&self) -> u8 {
         return MATH_COMMAND_OPCODE[self];
     }
+
+    pub fn from_opcode(opcode: u8) -> Option<MathCommand> {
+        return MATH_COMMAND_OPCODE.iter().find(|(_, &v)| v == opcode).map(|(k, _)| k.clone());
+    }
 }
 
 impl MathCalcCommand {
@@ -147,6 +171,10 @@ impl MathCalcCommand {
         return MATH_CALC_COMMAND_OPCODE[self];
     }
 
+    pub fn from_opcode(opcode: u8) -> Option<MathCalcCommand> {
+        return MATH_CALC_COMMAND_OPCODE.iter().find(|(_, &v)| v == opcode).map(|(k, _)| k.clone());
+    }
+
     pub fn get_len(&self) -> usize { 2 }
 }
 
@@ -155,5 +183,9 @@ impl MathLogicalCommand {
         return MATH_LOGICAL_OPCODE[self];
     }
 
+    pub fn from_opcode(opcode: u8) -> Option<MathLogicalCommand> {
+        return MATH_LOGICAL_OPCODE.iter().find(|(_, &v)| v == opcode).map(|(k, _)| k.clone());
+    }
+
     pub fn get_len(&self) -> usize { 2 }
 }
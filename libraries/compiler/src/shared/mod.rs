@@ -1,7 +1,12 @@
+pub mod analysis;
+pub mod assembly;
 pub mod ast;
 pub mod command_map;
+pub mod documentation;
 pub mod error;
 pub mod implementations;
 pub mod package_generation;
+pub mod package_reading;
+pub mod session;
 pub mod token;
 pub mod utils;
@@ -16,6 +16,7 @@ pub struct DataDeclarator {
 }
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StringConstant {
     pub value: String,
     pub slot: usize,
@@ -0,0 +1,69 @@
+/// The action which generates the command at `command_array_position`
+#[derive(Clone, Debug, PartialEq)]
+pub struct CommandSource {
+    pub command_array_position: usize,
+    // Filled by the function builder, actions don't know which file they are written in
+    pub file_path: String,
+    // Byte offset of the first token of the action
+    pub offset: usize,
+}
+
+/// A named local variable, which lives in `slot` of the current frame while commands in `start..end` are executed
+#[derive(Clone, Debug, PartialEq)]
+pub struct LocalVariableScope {
+    pub name: String,
+    pub slot: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Where a command of a package is written, lines and columns are 1-based
+#[derive(Clone, Debug, PartialEq)]
+pub struct LineTableEntry {
+    // Absolute offset of the command head
+    pub address: usize,
+    // Index of the file in `DebugInfo::files`
+    pub file_index: usize,
+    pub line: usize,
+    pub column: usize,
+}
+
+/// Optional section placed after function commands, pointing commands back to sources
+#[derive(Clone, Debug, PartialEq)]
+pub struct DebugInfo {
+    pub files: Vec<String>,
+    // Sorted by addresses, an entry covers commands until the next one
+    pub line_table: Vec<LineTableEntry>,
+    // Scope addresses are absolute offsets
+    pub locals: Vec<LocalVariableScope>,
+    pub functions: Vec<DebugFunction>,
+    // Sorted by start addresses, outer domains come before inner ones starting at the same address
+    pub domains: Vec<DebugDomain>,
+}
+
+/// Name of the function in `slot` of the function table
+#[derive(Clone, Debug, PartialEq)]
+pub struct DebugFunction {
+    pub slot: usize,
+    pub name: String,
+}
+
+/// The statement which creates a domain, `Block` is the domain of an action block itself
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DomainKind {
+    Function,
+    If,
+    Elif,
+    Else,
+    While,
+    Loop,
+    Block,
+}
+
+/// A domain between an entrance reference and its end reference, covering commands in `start..end`
+#[derive(Clone, Debug, PartialEq)]
+pub struct DebugDomain {
+    pub kind: DomainKind,
+    pub start: usize,
+    pub end: usize,
+}
@@ -1,3 +1,4 @@
+use crate::shared::ast::blocks::function::FunctionLinkage;
 use crate::shared::utils::identifier::Identifier;
 
 pub type FunctionTable = Vec<FunctionTableEntry>;
@@ -6,5 +7,7 @@ pub type FunctionTable = Vec<FunctionTableEntry>;
 pub struct FunctionTableEntry {
     pub slot: usize,
     pub name: Identifier,
-    pub relocated_entry_address: usize
+    pub relocated_entry_address: usize,
+    // External functions have a slot but no entry, the linker replaces them with the exported ones
+    pub linkage: FunctionLinkage,
 }
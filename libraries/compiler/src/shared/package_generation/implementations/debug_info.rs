@@ -0,0 +1,256 @@
+use std::fmt::{Display, Formatter};
+
+use crate::package_generator::utils::align_generated_width;
+use crate::shared::ast::blocks::function::FunctionLinkage;
+use crate::shared::error::general_issue::GeneralIssue;
+use crate::shared::error::pkg_gen_issue::PackageGenerationIssue;
+use crate::shared::package_generation::debug_info::{DebugDomain, DebugFunction, DebugInfo, DomainKind, LineTableEntry, LocalVariableScope};
+use crate::shared::package_generation::package_descriptor::PackageMetadata;
+use crate::shared::package_generation::relocation_reference::{RelocatableCommandList, RelocationReferenceType};
+use crate::shared::utils::source_map::SourceMap;
+
+/// The debug section starts with these bytes and ends with them after its offset,
+/// so readers could find it from the end of a package
+pub const DEBUG_SECTION_SIGNATURE: &[u8] = b"CBDG";
+
+impl DebugInfo {
+    /// Collect positions recorded in relocated `commands`, whose positions are absolute offsets in the package.
+    /// Commands of files missing in `sources` are left out of the line table.
+    pub fn from_commands(commands: &RelocatableCommandList, sources: &[SourceMap]) -> DebugInfo {
+        let mut result = DebugInfo {
+            files: vec![],
+            line_table: vec![],
+            locals: commands.local_scopes.clone(),
+            functions: commands.function_table
+                               .iter()
+                               .filter(|f| f.linkage != FunctionLinkage::External)
+                               .map(|f| DebugFunction { slot: f.slot, name: f.name.to_string() })
+                               .collect(),
+            domains: vec![],
+        };
+
+        let mut command_sources = commands.command_sources.clone();
+        // Nested actions are recorded after their containers, so the inner one wins at the same position
+        command_sources.sort_by_key(|s| s.command_array_position);
+        for source in command_sources {
+            let source_map = sources.iter().find(|m| m.file_path == source.file_path);
+            if source_map.is_none() {
+                continue;
+            }
+            let (line, column) = source_map.unwrap().line_column(source.offset);
+
+            let file_index = match result.files.iter().position(|f| *f == source.file_path) {
+                Some(x) => x,
+                None => {
+                    result.files.push(source.file_path.clone());
+                    result.files.len() - 1
+                }
+            };
+
+            let entry = LineTableEntry { address: source.command_array_position, file_index, line, column };
+            match result.line_table.last_mut() {
+                Some(last) if last.address == entry.address => *last = entry,
+                _ => result.line_table.push(entry),
+            }
+        }
+
+        result.locals.sort_by_key(|l| (l.start, l.slot));
+        result.domains = pair_domains(commands);
+        return result;
+    }
+
+    /// Layout:
+    /// ```text
+    /// <signature> <file count> (<length> <path>)*
+    /// <line count> (<address> <file index> <line> <column>)*
+    /// <local count> (<slot> <start> <end> <length> <name>)*
+    /// <function count> (<slot> <length> <name>)*
+    /// <domain count> (<kind> <start> <end>)*
+    /// <section offset> <signature>
+    /// ```
+    ///
+    /// `section_offset` is where the section is placed in the package.
+    /// Counts, addresses, lines and columns are aligned by the address alignment, while lengths, file indexes and slots by the data slot alignment.
+    /// Domain kinds take a single byte, see `DomainKind::to_flag`.
+    pub fn serialize(&self, section_offset: usize, metadata: &PackageMetadata) -> Result<Vec<u8>, GeneralIssue<PackageGenerationIssue>> {
+        let address = |value: usize| align_generated_width(&value.to_be_bytes().to_vec(), metadata.address_alignment, "Number in the debug section");
+        let slot = |value: usize| align_generated_width(&value.to_be_bytes().to_vec(), metadata.data_slot_alignment, "Length or slot in the debug section");
+
+        let mut result = DEBUG_SECTION_SIGNATURE.to_vec();
+
+        result.extend(address(self.files.len())?);
+        for file in &self.files {
+            result.extend(slot(file.len())?);
+            result.extend(file.as_bytes());
+        }
+
+        result.extend(address(self.line_table.len())?);
+        for entry in &self.line_table {
+            result.extend(address(entry.address)?);
+            result.extend(slot(entry.file_index)?);
+            result.extend(address(entry.line)?);
+            result.extend(address(entry.column)?);
+        }
+
+        result.extend(address(self.locals.len())?);
+        for local in &self.locals {
+            result.extend(slot(local.slot)?);
+            result.extend(address(local.start)?);
+            result.extend(address(local.end)?);
+            result.extend(slot(local.name.len())?);
+            result.extend(local.name.as_bytes());
+        }
+
+        result.extend(address(self.functions.len())?);
+        for function in &self.functions {
+            result.extend(slot(function.slot)?);
+            result.extend(slot(function.name.len())?);
+            result.extend(function.name.as_bytes());
+        }
+
+        result.extend(address(self.domains.len())?);
+        for domain in &self.domains {
+            result.push(domain.kind.to_flag());
+            result.extend(address(domain.start)?);
+            result.extend(address(domain.end)?);
+        }
+
+        result.extend(address(section_offset)?);
+        result.extend(DEBUG_SECTION_SIGNATURE);
+        return Ok(result);
+    }
+
+    /// The line table entry covering the command at `address`
+    pub fn line_at(&self, address: usize) -> Option<&LineTableEntry> {
+        return match self.line_table.binary_search_by_key(&address, |e| e.address) {
+            Ok(x) => Some(&self.line_table[x]),
+            Err(0) => None,
+            Err(x) => Some(&self.line_table[x - 1]),
+        };
+    }
+
+    /// `file:line:column` of the command at `address`
+    pub fn location_at(&self, address: usize) -> Option<String> {
+        return self.line_at(address).map(|e| format!("{}:{}:{}", self.files[e.file_index], e.line, e.column));
+    }
+
+    /// Local variables alive at `address`, a slot declared in nested blocks takes the innermost name
+    pub fn locals_at(&self, address: usize) -> Vec<&LocalVariableScope> {
+        let mut result: Vec<&LocalVariableScope> = vec![];
+        for local in self.locals.iter().filter(|l| l.start <= address && address < l.end) {
+            match result.iter().position(|r| r.slot == local.slot) {
+                Some(x) if result[x].start <= local.start => result[x] = local,
+                Some(_) => {}
+                None => result.push(local),
+            }
+        }
+
+        result.sort_by_key(|l| l.slot);
+        return result;
+    }
+
+    /// Name of the function in `slot`
+    pub fn function_name(&self, slot: usize) -> Option<&str> {
+        return self.functions.iter().find(|f| f.slot == slot).map(|f| f.name.as_str());
+    }
+
+    /// Domains containing the command at `address`, from the outermost to the innermost
+    pub fn domains_at(&self, address: usize) -> Vec<&DebugDomain> {
+        return self.domains.iter().filter(|d| d.start <= address && address < d.end).collect();
+    }
+}
+
+impl DomainKind {
+    pub fn to_flag(&self) -> u8 {
+        return match self {
+            DomainKind::Function => 0x01,
+            DomainKind::If => 0x02,
+            DomainKind::Elif => 0x03,
+            DomainKind::Else => 0x04,
+            DomainKind::While => 0x05,
+            DomainKind::Loop => 0x06,
+            DomainKind::Block => 0x07,
+        };
+    }
+
+    pub fn from_flag(flag: u8) -> Option<DomainKind> {
+        return match flag {
+            0x01 => Some(DomainKind::Function),
+            0x02 => Some(DomainKind::If),
+            0x03 => Some(DomainKind::Elif),
+            0x04 => Some(DomainKind::Else),
+            0x05 => Some(DomainKind::While),
+            0x06 => Some(DomainKind::Loop),
+            0x07 => Some(DomainKind::Block),
+            _ => None,
+        };
+    }
+
+    /// The kind created by an entrance reference
+    fn from_entrance(ref_type: &RelocationReferenceType) -> Option<DomainKind> {
+        return match ref_type {
+            RelocationReferenceType::FunctionEntrance(_) => Some(DomainKind::Function),
+            RelocationReferenceType::IfEntrance => Some(DomainKind::If),
+            RelocationReferenceType::ElifEntrance => Some(DomainKind::Elif),
+            RelocationReferenceType::ElseEntrance => Some(DomainKind::Else),
+            RelocationReferenceType::WhileEntrance => Some(DomainKind::While),
+            RelocationReferenceType::LoopEntrance => Some(DomainKind::Loop),
+            RelocationReferenceType::DomainEntrance => Some(DomainKind::Block),
+            _ => None,
+        };
+    }
+
+    fn from_end(ref_type: &RelocationReferenceType) -> Option<DomainKind> {
+        return match ref_type {
+            RelocationReferenceType::EndFunction(_) => Some(DomainKind::Function),
+            RelocationReferenceType::EndIf => Some(DomainKind::If),
+            RelocationReferenceType::EndElif => Some(DomainKind::Elif),
+            RelocationReferenceType::EndElse => Some(DomainKind::Else),
+            RelocationReferenceType::EndWhile => Some(DomainKind::While),
+            RelocationReferenceType::EndLoop => Some(DomainKind::Loop),
+            RelocationReferenceType::EndDomain => Some(DomainKind::Block),
+            _ => None,
+        };
+    }
+}
+
+impl Display for DomainKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            DomainKind::Function => "function",
+            DomainKind::If => "if",
+            DomainKind::Elif => "elif",
+            DomainKind::Else => "else",
+            DomainKind::While => "while",
+            DomainKind::Loop => "loop",
+            DomainKind::Block => "block",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Pair every end reference with the latest open entrance of the same kind.
+/// Kinds are paired separately, since a function and its body block start and end at the same positions.
+fn pair_domains(commands: &RelocatableCommandList) -> Vec<DebugDomain> {
+    let mut references = commands.descriptors.references.clone();
+    references.sort_by_key(|r| r.command_array_position);
+
+    let mut result = vec![];
+    let mut open: Vec<(DomainKind, usize)> = vec![];
+    for reference in &references {
+        if let Some(kind) = DomainKind::from_entrance(&reference.ref_type) {
+            open.push((kind, result.len()));
+            result.push(DebugDomain { kind, start: reference.command_array_position, end: reference.command_array_position });
+        } else if let Some(kind) = DomainKind::from_end(&reference.ref_type) {
+            if let Some(index) = open.iter().rposition(|(k, _)| *k == kind) {
+                let (_, domain_index) = open.remove(index);
+                // The end flag of a function belongs to it
+                let end_len = if kind == DomainKind::Function { 1 } else { 0 };
+                result[domain_index].end = reference.command_array_position + end_len;
+            }
+        }
+    }
+
+    result.sort_by(|a, b| a.start.cmp(&b.start).then(b.end.cmp(&a.end)));
+    return result;
+}
@@ -1,3 +1,5 @@
 pub mod relocation_reference;
 pub mod package_descriptor;
 pub mod data_descriptor;
+pub mod debug_info;
+pub mod object_file;
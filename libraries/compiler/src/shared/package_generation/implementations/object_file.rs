@@ -0,0 +1,135 @@
+use crate::package_generator::utils::align_generated_width;
+use crate::shared::error::general_issue::GeneralIssue;
+use crate::shared::error::pkg_gen_issue::PackageGenerationIssue;
+use crate::shared::package_generation::object_file::ObjectFile;
+use crate::shared::package_generation::implementations::relocation_reference::symbol_linkage_flag;
+use crate::shared::package_generation::package_descriptor::PackageMetadata;
+use crate::shared::package_generation::relocation_reference::{RelocationReferenceType, RelocationTargetElement};
+use crate::shared::utils::identifier::Identifier;
+
+/// Every object file starts with these bytes, which tell objects and packages apart
+pub const OBJECT_FILE_SIGNATURE: &[u8] = b"CBO";
+/// Increase it when the layout below changes, so stale objects are compiled again
+pub const OBJECT_FILE_VERSION: u8 = 1;
+
+impl ObjectFile {
+    /// Layout:
+    /// ```text
+    /// <signature> <version> <metadata> <checksum length> <checksum>
+    /// <string count> (<length> <value>)*
+    /// <function count> (<linkage> <slot> <entry> <name>)*
+    /// <command length> <commands> <command entry count> <command entry>*
+    /// <target count> (<position> <offset> <relocated address> <element count> <element>*)*
+    /// <reference count> (<position> <reference type>)*
+    /// ```
+    ///
+    /// Counts, positions and slots are aligned by the address alignment, string lengths by the data slot alignment.
+    /// Signed numbers are `<sign> <absolute value>` like relative jump addresses, names are strings written as `foo::bar`.
+    /// Numbers which don't fit in their alignments are reported.
+    pub fn serialize(&self) -> Result<Vec<u8>, GeneralIssue<PackageGenerationIssue>> {
+        let metadata = &self.metadata;
+        let commands = &self.commands;
+
+        let mut result = OBJECT_FILE_SIGNATURE.to_vec();
+        result.push(OBJECT_FILE_VERSION);
+        result.extend(metadata.serialize());
+
+        result.extend(unsigned(self.source_checksum.len(), metadata)?);
+        result.extend(&self.source_checksum);
+
+        result.extend(align_generated_width(&commands.string_pool.len().to_be_bytes().to_vec(), metadata.data_slot_alignment, "Count of string constants")?);
+        for string in &commands.string_pool {
+            result.extend(string_bytes(string.value.as_str(), metadata)?);
+        }
+
+        result.extend(unsigned(commands.function_table.len(), metadata)?);
+        for function in &commands.function_table {
+            result.push(symbol_linkage_flag(function.linkage));
+            result.extend(unsigned(function.slot, metadata)?);
+            result.extend(unsigned(function.relocated_entry_address, metadata)?);
+            result.extend(identifier_bytes(&function.name, metadata)?);
+        }
+
+        result.extend(unsigned(commands.commands.len(), metadata)?);
+        result.extend(&commands.commands);
+        result.extend(unsigned(commands.command_entries.len(), metadata)?);
+        for entry in &commands.command_entries {
+            result.extend(unsigned(*entry, metadata)?);
+        }
+
+        result.extend(unsigned(commands.descriptors.targets.len(), metadata)?);
+        for target in &commands.descriptors.targets {
+            result.extend(unsigned(target.command_array_position, metadata)?);
+            result.extend(signed(target.offset as i64, metadata)?);
+            result.extend(signed(target.relocated_address as i64, metadata)?);
+            result.extend(unsigned(target.relocation_elements.len(), metadata)?);
+            for element in &target.relocation_elements {
+                result.extend(target_element_bytes(element, metadata)?);
+            }
+        }
+
+        result.extend(unsigned(commands.descriptors.references.len(), metadata)?);
+        for reference in &commands.descriptors.references {
+            result.extend(unsigned(reference.command_array_position, metadata)?);
+            result.extend(reference_type_bytes(&reference.ref_type, metadata)?);
+        }
+
+        return Ok(result);
+    }
+}
+
+/// `Relative`, `BreakDomain` and `IgnoreDomain` carry a number, `EnterFunction` carries the function name
+fn target_element_bytes(element: &RelocationTargetElement, metadata: &PackageMetadata) -> Result<Vec<u8>, GeneralIssue<PackageGenerationIssue>> {
+    return Ok(match element {
+        RelocationTargetElement::Relative(x) => [vec![0x00], signed(*x as i64, metadata)?].concat(),
+        RelocationTargetElement::IterationHead => vec![0x01],
+        RelocationTargetElement::BreakIteration => vec![0x02],
+        RelocationTargetElement::DomainHead => vec![0x03],
+        RelocationTargetElement::BreakDomain(x) => [vec![0x04], unsigned(*x, metadata)?].concat(),
+        RelocationTargetElement::IgnoreDomain(x) => [vec![0x05], unsigned(*x, metadata)?].concat(),
+        RelocationTargetElement::EnterFunction(id) => [vec![0x06], identifier_bytes(id, metadata)?].concat(),
+        RelocationTargetElement::Undefined => vec![0x07],
+    });
+}
+
+fn reference_type_bytes(ref_type: &RelocationReferenceType, metadata: &PackageMetadata) -> Result<Vec<u8>, GeneralIssue<PackageGenerationIssue>> {
+    return Ok(match ref_type {
+        RelocationReferenceType::FunctionEntrance(id) => [vec![0x00], identifier_bytes(id, metadata)?].concat(),
+        RelocationReferenceType::EndFunction(id) => [vec![0x01], identifier_bytes(id, metadata)?].concat(),
+        RelocationReferenceType::IfEntrance => vec![0x02],
+        RelocationReferenceType::ElifEntrance => vec![0x03],
+        RelocationReferenceType::ElseEntrance => vec![0x04],
+        RelocationReferenceType::EndIf => vec![0x05],
+        RelocationReferenceType::EndElif => vec![0x06],
+        RelocationReferenceType::EndElse => vec![0x07],
+        RelocationReferenceType::WhileEntrance => vec![0x08],
+        RelocationReferenceType::EndWhile => vec![0x09],
+        RelocationReferenceType::LoopEntrance => vec![0x0A],
+        RelocationReferenceType::EndLoop => vec![0x0B],
+        RelocationReferenceType::DomainEntrance => vec![0x0C],
+        RelocationReferenceType::EndDomain => vec![0x0D],
+    });
+}
+
+fn unsigned(value: usize, metadata: &PackageMetadata) -> Result<Vec<u8>, GeneralIssue<PackageGenerationIssue>> {
+    return align_generated_width(&value.to_be_bytes().to_vec(), metadata.address_alignment, format!("Number {}", value).as_str());
+}
+
+fn signed(value: i64, metadata: &PackageMetadata) -> Result<Vec<u8>, GeneralIssue<PackageGenerationIssue>> {
+    let mut result = align_generated_width(&value.unsigned_abs().to_be_bytes().to_vec(), metadata.address_alignment, format!("Number {}", value).as_str())?;
+    result.insert(0, if value < 0 { 0x0B } else { 0x0F });
+
+    return Ok(result);
+}
+
+fn string_bytes(value: &str, metadata: &PackageMetadata) -> Result<Vec<u8>, GeneralIssue<PackageGenerationIssue>> {
+    let name = format!("Length {} of a string", value.len());
+    let mut result = align_generated_width(&value.len().to_be_bytes().to_vec(), metadata.data_slot_alignment, name.as_str())?;
+    result.extend(value.as_bytes());
+
+    return Ok(result);
+}
+
+fn identifier_bytes(identifier: &Identifier, metadata: &PackageMetadata) -> Result<Vec<u8>, GeneralIssue<PackageGenerationIssue>> {
+    return string_bytes(identifier.to_string().as_str(), metadata);
+}
@@ -1,4 +1,15 @@
-use crate::shared::package_generation::package_descriptor::PackageMetadata;
+use crate::shared::package_generation::package_descriptor::{PackageDescriptor, PackageMetadata};
+
+/// The length of serialized metadata
+pub const PACKAGE_METADATA_LEN: usize = 5;
+
+/// Version of this compiler, recorded in package descriptors
+pub const COMPILER_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// `package_type` of packages with an entry point, which could be executed
+pub const EXECUTABLE_PACKAGE_TYPE: u8 = 0;
+/// `package_type` of packages with a symbol table, which could be linked into executables
+pub const LIBRARY_PACKAGE_TYPE: u8 = 1;
 
 impl PackageMetadata {
     pub fn serialize(&self) -> Vec<u8> {
@@ -8,4 +19,47 @@ impl PackageMetadata {
                     self.data_slot_alignment,
                     self.address_alignment];
     }
+
+    /// Read the metadata placed at the beginning of a package by `serialize`
+    pub fn deserialize(bytes: &[u8]) -> Option<PackageMetadata> {
+        if bytes.len() < PACKAGE_METADATA_LEN {
+            return None;
+        }
+
+        return Some(PackageMetadata {
+            package_type: bytes[0],
+            data_alignment: bytes[1],
+            domain_layer_count_alignment: bytes[2],
+            data_slot_alignment: bytes[3],
+            address_alignment: bytes[4],
+            global_command_offset: PACKAGE_METADATA_LEN as u8,
+        });
+    }
+}
+
+/// An executable with 8-byte numbers and addresses, which is what `arc compile` produces
+impl Default for PackageMetadata {
+    fn default() -> PackageMetadata {
+        return PackageMetadata {
+            package_type: EXECUTABLE_PACKAGE_TYPE,
+            data_alignment: 8,
+            domain_layer_count_alignment: 2,
+            data_slot_alignment: 2,
+            address_alignment: 8,
+            global_command_offset: PACKAGE_METADATA_LEN as u8,
+        };
+    }
+}
+
+impl PackageDescriptor {
+    /// The entry offset is known after the package is built
+    pub fn new(name: &str, author: &str, metadata: PackageMetadata) -> PackageDescriptor {
+        return PackageDescriptor {
+            name: name.to_string(),
+            entry_offset: None,
+            compiler_version: COMPILER_VERSION.to_string(),
+            author: author.to_string(),
+            metadata,
+        };
+    }
 }
@@ -1,11 +1,18 @@
-use crate::package_generator::utils::{align_array_width,
+use crate::package_generator::utils::{align_generated_width,
+                                      generation_issue,
                                       is_domain_create_command,
                                       is_domain_destroy_command,
+                                      is_function_begin_command,
                                       is_function_end_command,
                                       is_iteration_end_command,
                                       is_iteration_head_command,
                                       jump_command_address_placeholder_len,
                                       pair_container_action};
+use crate::shared::ast::blocks::function::FunctionLinkage;
+use crate::shared::error::general_issue::GeneralIssue;
+use crate::shared::error::pkg_gen_issue::PackageGenerationIssue;
+use crate::shared::package_generation::func_table::FunctionTableEntry;
+use crate::shared::package_generation::package_descriptor::PackageMetadata;
 use crate::shared::package_generation::relocation_reference::{RelocatableCommandList,
                                                               RelocationCredential,
                                                               RelocationReference,
@@ -34,6 +41,15 @@ impl RelocatableCommandList {
         for item in model.command_entries {
             self.command_entries.push(item + original_len);
         }
+        for mut source in model.command_sources {
+            source.command_array_position += original_len;
+            self.command_sources.push(source);
+        }
+        for mut scope in model.local_scopes {
+            scope.start += original_len;
+            scope.end += original_len;
+            self.local_scopes.push(scope);
+        }
 
         self.commands.extend(model.commands);
     }
@@ -50,6 +66,8 @@ impl RelocatableCommandList {
             string_pool: vec![],
             function_table: vec![],
             group_table: vec![],
+            command_sources: vec![],
+            local_scopes: vec![],
         }
     }
 
@@ -61,10 +79,14 @@ impl RelocatableCommandList {
             string_pool: vec![],
             function_table: vec![],
             group_table: vec![],
+            command_sources: vec![],
+            local_scopes: vec![],
         };
     }
 
-    pub fn calculate_ref_to_target(&mut self) {
+    /// Resolve relocation targets into addresses relative to their commands.
+    /// Targets which couldn't be resolved, like `break` outside of any iteration, are reported.
+    pub fn calculate_ref_to_target(&mut self) -> Result<(), GeneralIssue<PackageGenerationIssue>> {
         self.descriptors.references.sort_by(|a, b| a.command_array_position.cmp(&b.command_array_position));
         self.descriptors.targets.sort_by(|a, b| a.command_array_position.cmp(&b.command_array_position));
 
@@ -102,6 +124,9 @@ impl RelocatableCommandList {
                     RelocationTargetElement::BreakDomain(_x) => {
                         let mut refs = self.descriptors.references.clone();
                         refs.retain(|p| is_domain_destroy_command(p) && p.command_array_position > iter_reloc_target.command_array_position);
+                        if refs.is_empty() {
+                            return Err(generation_issue("Couldn't find the domain to break"));
+                        }
 
                         iter_reloc_target.relocated_address = refs[0].command_array_position as i32 - iter_reloc_target.command_array_position as i32;
                     }
@@ -113,12 +138,18 @@ impl RelocatableCommandList {
 
                         let mut current_index = 0;
                         for _ in 0..*x {
-                            let pair_result = pair_container_action(&domain_refs[current_index..]);
+                            let pair_result = domain_refs.get(current_index..).and_then(pair_container_action);
+                            if pair_result.is_none() {
+                                return Err(generation_issue("Couldn't find the domains to ignore"));
+                            }
                             // Locate the next container entrance
-                            current_index = pair_result.1 + 1;
+                            current_index = pair_result.unwrap().1 + 1;
                         }
 
                         // Restore to end container
+                        if current_index == 0 {
+                            return Err(generation_issue("Couldn't find the domains to ignore"));
+                        }
                         current_index -= 1;
 
                         let target_ref = &domain_refs[current_index];
@@ -131,9 +162,11 @@ impl RelocatableCommandList {
                         // Find the first valid reference
                         let end_ref = self.descriptors.references.iter()
                                           .find(|r| r.command_array_position > iter_reloc_target.command_array_position
-                                              && is_iteration_end_command(r))
-                                          .unwrap()
-                                          .clone();
+                                              && is_iteration_end_command(r));
+                        if end_ref.is_none() {
+                            return Err(generation_issue("\"break\" is only allowed in an iteration"));
+                        }
+                        let end_ref = end_ref.unwrap().clone();
 
                         let default = RelocationReference { ref_type: RelocationReferenceType::FunctionEntrance(Identifier::empty()), command_array_position: usize::MAX };
                         let nearest_function_end = self.descriptors
@@ -147,56 +180,64 @@ impl RelocatableCommandList {
                         if end_ref.command_array_position <= nearest_function_end.command_array_position {
                             iter_reloc_target.relocated_address += end_ref.command_array_position as i32 - iter_reloc_target.command_array_position as i32;
                         } else {
-                            panic!("Invalid instruction");
+                            return Err(generation_issue("\"break\" is only allowed in an iteration"));
                         }
                     }
                     RelocationTargetElement::IterationHead => {
                         // Find the first valid reference
                         let head_ref = self.descriptors.references.iter().find(|r| r.command_array_position < iter_reloc_target.command_array_position
-                            && is_iteration_head_command(r))
-                                           .unwrap()
-                                           .clone();
+                            && is_iteration_head_command(r));
+                        if head_ref.is_none() {
+                            return Err(generation_issue("\"continue\" is only allowed in an iteration"));
+                        }
+                        let head_ref = head_ref.unwrap().clone();
 
                         let default = RelocationReference { ref_type: RelocationReferenceType::FunctionEntrance(Identifier::empty()), command_array_position: usize::MIN };
                         let nearest_function_begin = self.descriptors
                                                          .references
                                                          .iter()
-                                                         .find(|r| is_function_end_command(r))
+                                                         .rev()
+                                                         .find(|r| is_function_begin_command(r) && r.command_array_position <= iter_reloc_target.command_array_position)
                                                          .unwrap_or(&default)
                                                          .clone();
 
                         if head_ref.command_array_position >= nearest_function_begin.command_array_position {
                             iter_reloc_target.relocated_address += head_ref.command_array_position as i32 - iter_reloc_target.command_array_position as i32;
                         } else {
-                            panic!("Invalid instruction");
+                            return Err(generation_issue("\"continue\" is only allowed in an iteration"));
                         }
                     }
                     RelocationTargetElement::Undefined => {
-                        panic!("Encountered undefined relocation target!");
+                        return Err(generation_issue("Encountered undefined relocation target"));
                     }
                 }
             }
         }
+
+        return Ok(());
     }
 
-    pub fn apply_relocation(&mut self, addr_len: u8) {
+    /// Write resolved addresses into the placeholders of their commands
+    pub fn apply_relocation(&mut self, addr_len: u8) -> Result<(), GeneralIssue<PackageGenerationIssue>> {
         let placeholder_len = jump_command_address_placeholder_len(addr_len);
         for desc in &self.descriptors.targets {
             let mut addr_bytes;
 
-            match &desc.relocation_elements[0] {
-                RelocationTargetElement::EnterFunction(id) => {
+            match desc.relocation_elements.first() {
+                Some(RelocationTargetElement::EnterFunction(id)) => {
                     let target_function = self.function_table
                                               .iter()
-                                              .find(|f| f.name == *id)
-                                              .unwrap();
+                                              .find(|f| f.name == *id);
+                    if target_function.is_none() {
+                        return Err(generation_issue(format!("Function \"{}\" is not found in the function table", id).as_str()));
+                    }
 
-                    addr_bytes = align_array_width(&target_function.slot.to_be_bytes().to_vec(), addr_len);
+                    addr_bytes = align_generated_width(&target_function.unwrap().slot.to_be_bytes().to_vec(), addr_len, "Function slot")?;
                     addr_bytes.insert(0, 0x00);
                 }
                 _ => {
                     let addr = desc.relocated_address;
-                    addr_bytes = align_array_width(&addr.abs().to_be_bytes().to_vec(), addr_len);
+                    addr_bytes = align_generated_width(&addr.abs().to_be_bytes().to_vec(), addr_len, "Jump address")?;
 
                     if addr < 0 {
                         addr_bytes.insert(0, 0x0B);
@@ -209,38 +250,67 @@ impl RelocatableCommandList {
             let begin_pos = desc.command_array_position + desc.offset as usize;
             self.commands.splice(begin_pos..(begin_pos + placeholder_len), addr_bytes);
         }
+
+        return Ok(());
     }
 
-    pub fn generate_string_pool(&mut self, addr_len: u8) -> Vec<u8> {
+    pub fn generate_string_pool(&mut self, addr_len: u8) -> Result<Vec<u8>, GeneralIssue<PackageGenerationIssue>> {
         let mut result = vec![];
 
         // Push pool size
-        result.extend(align_array_width(&self.string_pool.len().to_be_bytes().to_vec(), addr_len));
+        result.extend(align_generated_width(&self.string_pool.len().to_be_bytes().to_vec(), addr_len, "Size of the string pool")?);
 
         for string in self.string_pool.iter() {
-            let len = align_array_width(&string.value.len().to_be_bytes().to_vec(), addr_len);
+            let len = align_generated_width(&string.value.len().to_be_bytes().to_vec(), addr_len, "Length of a string constant")?;
             result.extend(len);
             result.extend(string.value.as_bytes().to_vec());
         }
 
-        return result;
+        return Ok(result);
     }
 
-    pub fn generate_function_table(&self, addr_len: u8) -> Vec<u8> {
+    pub fn generate_function_table(&self, addr_len: u8) -> Result<Vec<u8>, GeneralIssue<PackageGenerationIssue>> {
         let mut result = vec![];
+        // External functions don't have any command in this package
+        let defined_functions: Vec<&FunctionTableEntry> = self.function_table.iter().filter(|f| f.linkage != FunctionLinkage::External).collect();
 
         // Push table size
-        result.extend(align_array_width(&self.function_table.len().to_be_bytes().to_vec(), addr_len));
+        result.extend(align_generated_width(&defined_functions.len().to_be_bytes().to_vec(), addr_len, "Size of the function table")?);
+
+        for func in defined_functions {
+            result.extend(align_generated_width(&func.slot.to_be_bytes().to_vec(), addr_len, "Function slot")?);
+            result.extend(align_generated_width(&func.relocated_entry_address.to_be_bytes().to_vec(), addr_len, "Function entry address")?);
+        }
 
-        for func in self.function_table.iter() {
-            result.extend(align_array_width(&func.slot.to_be_bytes().to_vec(), addr_len));
-            result.extend(align_array_width(&func.relocated_entry_address.to_be_bytes().to_vec(), addr_len));
+        return Ok(result);
+    }
+
+    /// Layout: `<count> (<linkage> <slot> <name length> <name>)*`, linkage is `0x01` for exported and `0x02` for external functions.
+    /// The count and slots are aligned by the address alignment, lengths by the data slot alignment.
+    pub fn generate_symbol_table(&self, metadata: &PackageMetadata) -> Result<Vec<u8>, GeneralIssue<PackageGenerationIssue>> {
+        let symbols: Vec<&FunctionTableEntry> = self.function_table.iter().filter(|f| f.linkage != FunctionLinkage::Internal).collect();
+        let mut result = align_generated_width(&symbols.len().to_be_bytes().to_vec(), metadata.address_alignment, "Size of the symbol table")?;
+
+        for symbol in symbols {
+            let name = symbol.name.to_string();
+            result.push(symbol_linkage_flag(symbol.linkage));
+            result.extend(align_generated_width(&symbol.slot.to_be_bytes().to_vec(), metadata.address_alignment, "Function slot")?);
+            result.extend(align_generated_width(&name.len().to_be_bytes().to_vec(), metadata.data_slot_alignment, "Length of a symbol name")?);
+            result.extend(name.as_bytes());
         }
 
-        return result;
+        return Ok(result);
     }
 }
 
+pub fn symbol_linkage_flag(linkage: FunctionLinkage) -> u8 {
+    return match linkage {
+        FunctionLinkage::Internal => 0x00,
+        FunctionLinkage::Export => 0x01,
+        FunctionLinkage::External => 0x02,
+    };
+}
+
 impl RelocationCredential {
     pub fn new() -> RelocationCredential {
         return RelocationCredential {
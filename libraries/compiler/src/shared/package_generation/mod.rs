@@ -1,4 +1,5 @@
 pub mod data_descriptor;
+pub mod debug_info;
 pub mod package_descriptor;
 pub mod implementations;
 pub mod linear_action_tree;
@@ -6,3 +7,4 @@ pub mod relocation_reference;
 pub mod gen_context;
 pub mod func_table;
 pub mod group_context;
+pub mod object_file;
@@ -0,0 +1,15 @@
+use crate::shared::package_generation::package_descriptor::PackageMetadata;
+use crate::shared::package_generation::relocation_reference::RelocatableCommandList;
+
+/// Function commands generated from source files but not relocated yet,
+/// objects are merged into a package by `linker::object_linker`
+#[derive(Clone, Debug)]
+pub struct ObjectFile {
+    pub metadata: PackageMetadata,
+
+    // Opaque bytes identifying the sources, build systems use them to find out whether the object is out of date
+    pub source_checksum: Vec<u8>,
+
+    // The string pool, the function table and relocation descriptors are kept with the commands
+    pub commands: RelocatableCommandList,
+}
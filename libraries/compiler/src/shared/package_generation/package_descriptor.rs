@@ -1,3 +1,4 @@
+#[derive(Clone, Debug)]
 pub struct PackageDescriptor {
     pub name: String,
 
@@ -13,6 +14,7 @@ pub struct PackageDescriptor {
     pub metadata: PackageMetadata,
 }
 
+#[derive(Clone, Debug, PartialEq)]
 pub struct PackageMetadata {
     pub package_type: u8,
     pub data_alignment: u8,
@@ -1,4 +1,5 @@
 use crate::shared::package_generation::data_descriptor::StringConstant;
+use crate::shared::package_generation::debug_info::{CommandSource, LocalVariableScope};
 use crate::shared::package_generation::func_table::FunctionTable;
 use crate::shared::package_generation::group_context::GroupTable;
 use crate::shared::utils::identifier::Identifier;
@@ -63,6 +64,9 @@ pub struct RelocatableCommandList {
     pub string_pool: StringPool,
     pub function_table: FunctionTable,
     pub group_table: GroupTable,
+    // Only used to generate the debug section, positions are rebased like `command_entries`
+    pub command_sources: Vec<CommandSource>,
+    pub local_scopes: Vec<LocalVariableScope>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -0,0 +1,57 @@
+use std::fmt::{Display, Formatter};
+
+use crate::shared::command_map::{MathCalcCommand, MathLogicalCommand};
+use crate::shared::package_reading::instruction::{DataAccess, Instruction};
+
+impl Instruction {
+    /// The name of the command in Carbon assembly
+    pub fn mnemonic(&self) -> &'static str {
+        return match self {
+            Instruction::CreateObject { .. } => "object.create",
+            Instruction::DestroyObject { .. } => "object.destroy",
+            Instruction::Push(_) => "stack.push",
+            Instruction::PushFromObject(_) => "stack.push_object",
+            Instruction::Pop => "stack.pop",
+            Instruction::PopToObject(_) => "stack.pop_object",
+            Instruction::JumpToRelative(_) => "jump.relative",
+            Instruction::JumpByStackTop { .. } => "jump.stack_top",
+            Instruction::EnterFunction { .. } => "function.enter",
+            Instruction::LeaveWithoutValue => "function.leave",
+            Instruction::LeaveWithValue => "function.leave_value",
+            Instruction::FunctionEndFlag => "function.end",
+            Instruction::Calculation(x) => match x {
+                MathCalcCommand::Plus => "calc.plus",
+                MathCalcCommand::Minus => "calc.minus",
+                MathCalcCommand::Times => "calc.times",
+                MathCalcCommand::Divide => "calc.divide",
+                MathCalcCommand::Mod => "calc.mod",
+                MathCalcCommand::Inverse => "calc.inverse",
+            },
+            Instruction::Logical(x) => match x {
+                MathLogicalCommand::And => "logic.and",
+                MathLogicalCommand::Or => "logic.or",
+                MathLogicalCommand::Not => "logic.not",
+            },
+        };
+    }
+
+    /// Relative offsets of every jump target of this command
+    pub fn jump_offsets(&self) -> Vec<i64> {
+        return match self {
+            Instruction::JumpToRelative(x) => vec![*x],
+            Instruction::JumpByStackTop { positive, negative, zero } => vec![*positive, *negative, *zero],
+            _ => vec![],
+        };
+    }
+}
+
+/// `#` for instant values, `$` for local slots and `@` for string slots
+impl Display for DataAccess {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DataAccess::InstantValue(x) => write!(f, "#{}", x),
+            DataAccess::LocalSlot(x) => write!(f, "${}", x),
+            DataAccess::StringSlot(x) => write!(f, "@{}", x),
+        }
+    }
+}
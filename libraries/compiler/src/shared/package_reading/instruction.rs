@@ -0,0 +1,40 @@
+use crate::shared::command_map::{MathCalcCommand, MathLogicalCommand};
+
+/// Operand of stack commands, see `dac_builder`
+#[derive(Debug, Clone, PartialEq)]
+pub enum DataAccess {
+    InstantValue(i64),
+    LocalSlot(usize),
+    StringSlot(usize),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Instruction {
+    CreateObject { is_global: bool },
+    DestroyObject { slot: usize },
+
+    Push(DataAccess),
+    PushFromObject(DataAccess),
+    Pop,
+    PopToObject(DataAccess),
+
+    // Offsets are relative to the head of the jump command
+    JumpToRelative(i64),
+    JumpByStackTop { positive: i64, negative: i64, zero: i64 },
+
+    EnterFunction { slot: usize, argument_count: u8 },
+    LeaveWithoutValue,
+    LeaveWithValue,
+    FunctionEndFlag,
+
+    Calculation(MathCalcCommand),
+    Logical(MathLogicalCommand),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodedInstruction {
+    // Absolute offset of the command head
+    pub position: usize,
+    pub length: usize,
+    pub instruction: Instruction,
+}
@@ -0,0 +1,3 @@
+pub mod implementations;
+pub mod instruction;
+pub mod package_layout;
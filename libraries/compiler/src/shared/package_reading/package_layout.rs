@@ -0,0 +1,41 @@
+use crate::shared::ast::blocks::function::FunctionLinkage;
+use crate::shared::package_generation::data_descriptor::StringConstant;
+use crate::shared::package_generation::debug_info::DebugInfo;
+use crate::shared::package_generation::package_descriptor::PackageMetadata;
+
+/// A package split into the sections written by the package generator
+#[derive(Debug, Clone)]
+pub struct PackageLayout {
+    pub metadata: PackageMetadata,
+
+    // Absolute offset of the entry function, only exists in executables
+    pub entry_point: Option<usize>,
+
+    pub string_pool: Vec<StringConstant>,
+    pub function_table: Vec<PackageFunctionEntry>,
+    // Exported and external functions, only exist in libraries
+    pub symbols: Vec<PackageSymbol>,
+
+    // Absolute offset of the first function command
+    pub code_offset: usize,
+    // The whole package without the debug section, addresses above are indexes of it
+    pub commands: Vec<u8>,
+
+    // Only exists in packages built with debug information
+    pub debug_info: Option<DebugInfo>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PackageFunctionEntry {
+    pub slot: usize,
+    // Absolute offset of the first command of the function
+    pub entry_address: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PackageSymbol {
+    pub name: String,
+    // Slot in the function table, external functions have no entry in it
+    pub slot: usize,
+    pub linkage: FunctionLinkage,
+}
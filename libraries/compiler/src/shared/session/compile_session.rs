@@ -0,0 +1,26 @@
+use crate::shared::package_generation::package_descriptor::PackageMetadata;
+
+/// A source file kept in memory, `path` is only used to resolve links and locate issues
+#[derive(Clone, Debug)]
+pub struct SourceFile {
+    pub path: String,
+    pub content: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct CompileOptions {
+    pub entry_function: String,
+    // Libraries don't need an entry function, while executables couldn't have external functions
+    pub metadata: PackageMetadata,
+    // Place a debug section after function commands
+    pub debug: bool,
+    // Links which are not found next to the linking file are looked up relative to it, empty for the current directory
+    pub project_root: String,
+}
+
+/// Sources compiled together into a single package, links between them are resolved without touching the file system
+#[derive(Clone, Debug)]
+pub struct Session {
+    pub options: CompileOptions,
+    pub sources: Vec<SourceFile>,
+}
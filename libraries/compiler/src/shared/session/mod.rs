@@ -0,0 +1 @@
+pub mod compile_session;
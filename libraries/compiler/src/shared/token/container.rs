@@ -1,4 +1,5 @@
 #[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ContainerType {
     Brace,       // {
     AntiBrace,   // }
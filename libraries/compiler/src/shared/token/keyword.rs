@@ -1,4 +1,5 @@
 #[derive(Copy, Clone, PartialEq, Debug, Hash, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum KeywordType {
     KwNumber,       // number
     KwChar,         // char
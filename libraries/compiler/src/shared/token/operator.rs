@@ -1,4 +1,5 @@
 #[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Operator {
     // Root type
     Calculation(CalculationOperator),
@@ -13,16 +14,19 @@ pub enum Operator {
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CalculationOperator {
     Addition,       // +
     Subtraction,    // -
     Multiply,       // *
     Division,       // /
     Modulo,         // %,
+    Negation,       // - with a single operand, only found in postfix expressions
     Invalid
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RelationOperator {
     Greater,            // >
     GreaterOrEqual,     // >=
@@ -34,6 +38,7 @@ pub enum RelationOperator {
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LogicalOperator {
     Not, // !
     And, // &&
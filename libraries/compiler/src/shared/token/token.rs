@@ -6,12 +6,14 @@ use crate::shared::token::data::Number;
 use crate::shared::utils::position::Position;
 
 #[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Token {
     pub content: TokenContent,
     pub position: Position,
 }
 
 #[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TokenContent {
     Identifier(String),
     Number(Number),
@@ -1,6 +1,7 @@
 pub type Scope = Vec<String>;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Identifier {
 	pub name: String,
 	pub scope: Scope,
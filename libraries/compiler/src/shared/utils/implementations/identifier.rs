@@ -7,6 +7,14 @@ impl Identifier {
         Identifier { name: name.to_string(), scope: vec![] }
     }
 
+    /// Build an identifier from its textual form like `foo::bar`
+    pub fn from_string(value: &str) -> Identifier {
+        let mut scope: Vec<String> = value.split("::").map(|s| s.to_string()).collect();
+        let name = scope.pop().unwrap();
+
+        Identifier { name, scope }
+    }
+
     pub fn empty() -> Identifier {
         Identifier { name: "".to_string(), scope: vec![] }
     }
@@ -1 +1,2 @@
 pub mod identifier;
+pub mod source_map;
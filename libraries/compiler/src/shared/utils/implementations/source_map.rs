@@ -0,0 +1,52 @@
+use crate::shared::utils::position::Position;
+use crate::shared::utils::source_map::SourceMap;
+
+impl SourceMap {
+    pub fn new(file_path: &str, source: &str) -> SourceMap {
+        let mut line_starts = vec![0];
+        line_starts.extend(source.match_indices('\n').map(|(index, _)| index + 1));
+
+        SourceMap { file_path: file_path.to_string(), source: source.to_string(), line_starts }
+    }
+
+    pub fn line_count(&self) -> usize {
+        return self.line_starts.len();
+    }
+
+    /// 1-based line and column of a byte offset, columns are counted in characters.
+    /// Offsets beyond the source are placed at its end.
+    pub fn line_column(&self, offset: usize) -> (usize, usize) {
+        let offset = self.floor_char_boundary(offset);
+        let line_index = match self.line_starts.binary_search(&offset) {
+            Ok(x) => x,
+            Err(x) => x - 1,
+        };
+        let column = self.source[self.line_starts[line_index]..offset].chars().count();
+
+        return (line_index + 1, column + 1);
+    }
+
+    pub fn position_line_column(&self, position: &Position) -> (usize, usize) {
+        return self.line_column(position.start);
+    }
+
+    /// Content of a 1-based line without the line break
+    pub fn line_text(&self, line: usize) -> &str {
+        if line == 0 || line > self.line_count() {
+            return "";
+        }
+
+        let start = self.line_starts[line - 1];
+        let end = self.line_starts.get(line).map_or(self.source.len(), |x| x - 1);
+        return self.source[start..end].trim_end_matches('\r');
+    }
+
+    fn floor_char_boundary(&self, offset: usize) -> usize {
+        let mut offset = offset.min(self.source.len());
+        while !self.source.is_char_boundary(offset) {
+            offset -= 1;
+        }
+
+        return offset;
+    }
+}
@@ -1,3 +1,4 @@
 pub mod implementations;
 pub mod identifier;
 pub mod position;
+pub mod source_map;
@@ -0,0 +1,8 @@
+/// Source code of a file, used to turn byte offsets into lines and columns
+#[derive(Clone, Debug)]
+pub struct SourceMap {
+    pub file_path: String,
+    pub source: String,
+    // Byte offset of the first character of every line
+    pub line_starts: Vec<usize>,
+}
@@ -0,0 +1,2 @@
+mod source;
+mod symbols;
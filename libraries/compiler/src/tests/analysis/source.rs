@@ -0,0 +1,34 @@
+use crate::analysis::source::analyze_source;
+
+#[test]
+fn valid_source() {
+    let analysis = analyze_source("main.cbs", "decl func main()[number] {\n    return 0;\n}\n");
+
+    assert!(analysis.issues.is_empty());
+    assert_eq!(analysis.tree.unwrap().functions[0].file_path, "main.cbs");
+}
+
+#[test]
+fn collect_issues_of_every_stage() {
+    let lexical = analyze_source("main.cbs", "decl func main()[number] {\n    return $;\n}\n");
+    assert_eq!(lexical.issues.len(), 1);
+    assert!(lexical.tokens.is_empty());
+
+    let parsing = analyze_source("main.cbs", "decl func main()[number] {\n    return 0;\n}\nreturn 1;");
+    assert_eq!(parsing.issues[0].location.start_pos, 43);
+    assert!(parsing.tree.is_none());
+
+    let check = analyze_source("main.cbs", "decl func main()[number] {\n    x = 1;\n    return 0;\n}\n");
+    assert_eq!(check.issues[0].code, "0103");
+    assert_eq!(check.issues[0].location.file_path, "main.cbs");
+    assert!(check.tree.is_some());
+}
+
+#[test]
+fn incomplete_source() {
+    // Such code made the parser panic
+    let analysis = analyze_source("main.cbs", "decl");
+
+    assert_eq!(analysis.issues.len(), 1);
+    assert_eq!(analysis.tokens.len(), 1);
+}
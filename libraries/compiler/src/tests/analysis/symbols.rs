@@ -0,0 +1,55 @@
+use crate::analysis::source::analyze_source;
+use crate::analysis::symbols::{completion_candidates, find_declaration, keyword_symbols, symbols_in_scope};
+use crate::shared::analysis::symbol::SymbolKind;
+
+const SOURCE: &str = r#"decl func twice(number x)[number] {
+    return x * 2;
+}
+
+decl func main()[number] {
+    decl var number x;
+    x = twice(4);
+    while (x > 0) {
+        decl var str s;
+        x = x - 1;
+    }
+    return x;
+}
+
+group point {
+    field number x(get,set);
+}
+"#;
+
+#[test]
+fn find_declarations() {
+    let analysis = analyze_source("main.cbs", SOURCE);
+
+    let call = find_declaration(&analysis, SOURCE.find("twice(4)").unwrap()).unwrap();
+    assert_eq!(call.kind, SymbolKind::Function);
+    assert_eq!(call.detail, "decl func twice(number x)[number]");
+    assert_eq!(call.location.start_pos, 10);
+
+    // `x` in `main` refers to the variable, `x` in `twice` to the parameter
+    let variable = find_declaration(&analysis, SOURCE.find("return x;").unwrap() + 7).unwrap();
+    assert_eq!(variable.detail, "decl var number x");
+    assert_eq!(variable.location.start_pos, SOURCE.find("x;").unwrap());
+    let parameter = find_declaration(&analysis, SOURCE.find("x * 2").unwrap()).unwrap();
+    assert_eq!(parameter.detail, "number x");
+
+    assert!(find_declaration(&analysis, SOURCE.find("return").unwrap()).is_none());
+}
+
+#[test]
+fn scopes() {
+    let analysis = analyze_source("main.cbs", SOURCE);
+    let names = |offset: usize| -> Vec<String> { symbols_in_scope(&analysis, offset).into_iter().map(|s| s.name).collect() };
+
+    assert_eq!(names(0), vec!["main", "point", "twice"]);
+    assert_eq!(names(SOURCE.find("x = x - 1").unwrap()), vec!["main", "point", "s", "twice", "x"]);
+    assert_eq!(names(SOURCE.find("return x;").unwrap()), vec!["main", "point", "twice", "x"]);
+
+    let candidates = completion_candidates(&analysis, 0);
+    assert_eq!(candidates.len(), keyword_symbols().len() + 3);
+    assert!(candidates.iter().any(|s| s.name == "decl" && s.kind == SymbolKind::Keyword));
+}
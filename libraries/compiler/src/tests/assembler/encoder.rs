@@ -0,0 +1,90 @@
+use crate::assembler::encoder::assemble_package;
+use crate::lexer::tokenize::tokenize;
+use crate::package_generator::package_builder::build_package;
+use crate::package_reader::disassembler::disassemble_package;
+use crate::package_reader::layout_reader::read_package_layout;
+use crate::parser::decorator::decorate_token;
+use crate::parser::pipeline::build_whole_file;
+use crate::shared::package_generation::package_descriptor::PackageMetadata;
+use crate::shared::utils::identifier::Identifier;
+
+#[test]
+fn reassemble_disassembled_package() {
+    let source = r#"
+        decl func main(number x)[number] {
+            while (x > 0) {
+                x = x - 1;
+            }
+            return twice(x);
+        }
+
+        decl func twice(number x)[number] {
+            decl var str s;
+            s = "twice";
+            return x * 2;
+        }
+    "#;
+
+    let (tokens, string_pool) = decorate_token(tokenize(source, true).unwrap());
+    let tree = build_whole_file(tokens, Identifier::single("main")).ok().unwrap();
+    let metadata = PackageMetadata {
+        data_slot_alignment: 2,
+        data_alignment: 8,
+        package_type: 0,
+        domain_layer_count_alignment: 2,
+        address_alignment: 8,
+        global_command_offset: 5,
+    };
+    let package = build_package(&tree, string_pool, &metadata).unwrap();
+
+    let listing = disassemble_package(&package).ok().unwrap();
+    let reassembled = assemble_package(listing.as_str()).ok().unwrap();
+
+    assert_eq!(reassembled, package);
+}
+
+#[test]
+fn assemble_labels_and_literals() {
+    let source = r#"
+        .metadata type=0 address=4
+        .entry main
+        .func main
+            stack.push "text"
+            stack.push "text"
+        loop:
+            jump.relative loop
+            function.enter helper 0
+            function.end
+        .func helper
+            function.leave
+    "#;
+
+    let package = assemble_package(source).ok().unwrap();
+    let layout = read_package_layout(&package).ok().unwrap();
+
+    assert_eq!(layout.metadata.address_alignment, 4);
+    // Equal literals share one slot
+    assert_eq!(layout.string_pool.len(), 1);
+    assert_eq!(layout.function_table.len(), 2);
+    assert_eq!(layout.entry_point, Some(layout.function_table[0].entry_address));
+
+    let listing = disassemble_package(&package).ok().unwrap();
+    assert!(listing.contains("jump.relative L"));
+    assert!(listing.contains("function.enter fn_1 0"));
+}
+
+#[test]
+fn report_undefined_names() {
+    let source = r#"
+        .entry start
+        .func main
+            jump.relative nowhere
+            function.enter missing 0
+            function.end
+    "#;
+
+    let issues = assemble_package(source).err().unwrap().issues;
+    let lines: Vec<usize> = issues.iter().map(|i| i.detail.line).collect();
+
+    assert_eq!(lines, vec![4, 5, 2]);
+}
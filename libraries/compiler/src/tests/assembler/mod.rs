@@ -0,0 +1,2 @@
+mod encoder;
+mod parser;
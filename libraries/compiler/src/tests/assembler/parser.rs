@@ -0,0 +1,46 @@
+use crate::assembler::parser::parse_assembly;
+use crate::shared::assembly::assembly_line::{AssemblyDataAccess, AssemblyInstruction, AssemblyStatement, JumpDestination};
+
+#[test]
+fn parse_lines() {
+    let source = r#"
+        ; Comment
+        .entry main
+        .string "a \"quoted\"; string"
+        .func main
+        head:                       ; Label
+            stack.push #-5
+            stack.push_object "hi"
+            stack.pop_object $1
+            jump.stack_top head +4 -2
+    "#;
+
+    let lines = parse_assembly(source).ok().unwrap();
+    let statements: Vec<AssemblyStatement> = lines.iter().map(|l| l.statement.clone()).collect();
+
+    assert_eq!(statements, vec![
+        AssemblyStatement::Entry("main".to_string()),
+        AssemblyStatement::StringConstant("a \"quoted\"; string".to_string()),
+        AssemblyStatement::Function("main".to_string()),
+        AssemblyStatement::Label("head".to_string()),
+        AssemblyStatement::Instruction(AssemblyInstruction::Push(AssemblyDataAccess::InstantValue(-5))),
+        AssemblyStatement::Instruction(AssemblyInstruction::PushFromObject(AssemblyDataAccess::StringLiteral("hi".to_string()))),
+        AssemblyStatement::Instruction(AssemblyInstruction::PopToObject(AssemblyDataAccess::LocalSlot(1))),
+        AssemblyStatement::Instruction(AssemblyInstruction::JumpByStackTop {
+            positive: JumpDestination::Label("head".to_string()),
+            negative: JumpDestination::Relative(4),
+            zero: JumpDestination::Relative(-2),
+        }),
+    ]);
+    assert_eq!(lines[0].line, 3);
+}
+
+#[test]
+fn report_every_invalid_line() {
+    let source = ".func main\n    stack.push 5\n    calc.plus #1\n    unknown.command\n    stack.pop\n";
+
+    let issues = parse_assembly(source).err().unwrap().issues;
+    let lines: Vec<usize> = issues.iter().map(|i| i.detail.line).collect();
+
+    assert_eq!(lines, vec![2, 3, 4]);
+}
@@ -0,0 +1,2 @@
+mod snippet;
+mod source_map;
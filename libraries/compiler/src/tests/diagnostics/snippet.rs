@@ -0,0 +1,84 @@
+use crate::lexer::tokenize::tokenize;
+use crate::package_generator::availability_check::package_check::check_package;
+use crate::parser::decorator::decorate_token;
+use crate::parser::pipeline::build_whole_file;
+use crate::shared::error::general_issue::IssueLocation;
+use crate::shared::error::snippet::{render_snippet, LabelledSpan};
+use crate::shared::utils::identifier::Identifier;
+use crate::shared::utils::source_map::SourceMap;
+
+#[test]
+fn single_line_span() {
+    let map = SourceMap::new("main.cbs", "decl func main()[none] {\n    x = 1;\n}\n");
+    let span = LabelledSpan { start_pos: 29, end_pos: 35, label: Some("not defined".to_string()) };
+
+    let expected = [
+        " --> main.cbs:2:5",
+        "  |",
+        "2 |     x = 1;",
+        "  |     ^^^^^^ not defined",
+    ];
+    assert_eq!(render_snippet(&map, &[span]), expected.join("\n"));
+}
+
+#[test]
+fn multiple_spans() {
+    let source = "decl var number x;\nx = \"a\";\n\n\n\n\n\n\n\n\ncall x();\n";
+    let map = SourceMap::new("main.cbs", source);
+    let spans = [
+        LabelledSpan { start_pos: 23, end_pos: 26, label: Some("str".to_string()) },
+        LabelledSpan { start_pos: 16, end_pos: 17, label: Some("number".to_string()) },
+        LabelledSpan { start_pos: 36, end_pos: 36, label: None },
+    ];
+
+    let expected = [
+        "  --> main.cbs:2:5",
+        "   |",
+        " 1 | decl var number x;",
+        "   |                 - number",
+        " 2 | x = \"a\";",
+        "   |     ^^^ str",
+        "...",
+        "11 | call x();",
+        "   | -",
+    ];
+    assert_eq!(render_snippet(&map, &spans), expected.join("\n"));
+}
+
+#[test]
+fn multiple_line_span() {
+    let map = SourceMap::new("main.cbs", "if (x > 0) {\n    y = 1;\n}");
+    let span = LabelledSpan { start_pos: 0, end_pos: 25, label: Some("here".to_string()) };
+
+    let expected = [
+        " --> main.cbs:1:1",
+        "  |",
+        "1 | if (x > 0) {",
+        "  | ^^^^^^^^^^^^",
+        "2 |     y = 1;",
+        "  |     ^^^^^^",
+        "3 | }",
+        "  | ^ here",
+    ];
+    assert_eq!(render_snippet(&map, &[span]), expected.join("\n"));
+}
+
+#[test]
+fn issues_point_to_source() {
+    let source = "decl func main()[none] {\n    decl var number x;\n    x = \"a\";\n}\n";
+    let (tokens, _) = decorate_token(tokenize(source, true).unwrap());
+    let mut tree = build_whole_file(tokens, Identifier::single("main")).ok().unwrap();
+    tree.set_file_path("main.cbs");
+
+    let issue = check_package(&tree).err().unwrap().issues[0].detail.location().unwrap();
+    assert_eq!(issue.file_path, "main.cbs");
+    assert_eq!(&source[issue.start_pos..issue.end_pos], "x = \"a\";");
+
+    let lexical_issue = tokenize("x = $;", true).err().unwrap().with_file_path("lex.cbs");
+    assert_eq!(lexical_issue.issues[0].detail.location.file_path, "lex.cbs");
+
+    let (tokens, _) = decorate_token(tokenize("decl func main()[none] {\n}\nreturn 0;", true).unwrap());
+    let parsing_issue = build_whole_file(tokens, Identifier::single("main")).err().unwrap();
+    let location = parsing_issue.issues[0].detail.location.clone();
+    assert_eq!((location.start_pos, location.end_pos), (27, 33));
+}
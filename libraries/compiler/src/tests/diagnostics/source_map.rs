@@ -0,0 +1,19 @@
+use crate::shared::utils::source_map::SourceMap;
+
+#[test]
+fn line_column() {
+    let map = SourceMap::new("main.cbs", "decl var number x;\r\n\tx = 1;\n\"é\" x\n");
+
+    assert_eq!(map.line_count(), 4);
+    assert_eq!(map.line_column(0), (1, 1));
+    assert_eq!(map.line_column(16), (1, 17));
+    assert_eq!(map.line_column(21), (2, 2));
+    // Columns are counted in characters, `é` takes 2 bytes
+    assert_eq!(map.line_column(33), (3, 5));
+    assert_eq!(map.line_column(1000), (4, 1));
+
+    assert_eq!(map.line_text(1), "decl var number x;");
+    assert_eq!(map.line_text(2), "\tx = 1;");
+    assert_eq!(map.line_text(4), "");
+    assert_eq!(map.line_text(5), "");
+}
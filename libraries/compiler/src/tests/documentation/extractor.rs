@@ -0,0 +1,77 @@
+use crate::documentation::extractor::document_source;
+use crate::lexer::tokenize::tokenize;
+use crate::parser::decorator::decorate_token;
+use crate::parser::pipeline::build_whole_file;
+use crate::shared::ast::blocks::function::FunctionLinkage;
+use crate::shared::ast::package::ParserPackageStructure;
+use crate::shared::utils::identifier::Identifier;
+
+pub const SOURCE: &str = r#"// Geometry helpers
+
+link std;
+
+/// Double a number.
+///
+/// Works for negative numbers as well.
+export decl func twice(number x)[number] {
+    // Not documentation
+    return x * 2;
+}
+
+// Detached by the blank line
+
+decl func noop()[none] {
+    return;
+}
+
+// A point on a plane
+group point {
+    // Horizontal position
+    field number x(get, set);
+    field number y(get);
+
+    // Move the point
+    method move(number dx, number dy)[none];
+
+    /// Create a point at the origin
+    func origin()[point];
+}
+"#;
+
+pub fn parse(source: &str) -> ParserPackageStructure {
+    let tokens = decorate_token(tokenize(source, true).unwrap()).0;
+    return build_whole_file(tokens, Identifier::single("main")).unwrap();
+}
+
+#[test]
+fn attach_comments() {
+    let documentation = document_source("geometry.cbs", SOURCE, &parse(SOURCE));
+
+    assert_eq!(documentation.summary, vec!["Geometry helpers"]);
+    assert_eq!(documentation.links.len(), 1);
+
+    let twice = &documentation.functions[0];
+    assert_eq!(twice.declarator.to_string(), "twice(number x)[number]");
+    assert_eq!(twice.linkage, FunctionLinkage::Export);
+    assert_eq!(twice.comment, vec!["Double a number.", "", "Works for negative numbers as well."]);
+    assert!(documentation.functions[1].comment.is_empty());
+
+    let point = &documentation.groups[0];
+    assert_eq!(point.comment, vec!["A point on a plane"]);
+    assert_eq!(point.fields[0].comment, vec!["Horizontal position"]);
+    assert!(point.fields[1].comment.is_empty());
+    assert!(point.fields[1].field.has_get && !point.fields[1].field.has_set);
+    assert_eq!(point.methods[0].comment, vec!["Move the point"]);
+    assert_eq!(point.functions[0].comment, vec!["Create a point at the origin"]);
+}
+
+#[test]
+fn without_comments() {
+    let source = "decl func main()[number] {\n    return 0;\n}\n";
+    let documentation = document_source("main.cbs", source, &parse(source));
+
+    assert!(documentation.summary.is_empty());
+    assert!(documentation.links.is_empty());
+    assert!(documentation.functions[0].comment.is_empty());
+    assert!(documentation.groups.is_empty());
+}
@@ -0,0 +1,2 @@
+mod extractor;
+mod renderer;
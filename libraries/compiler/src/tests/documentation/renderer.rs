@@ -0,0 +1,39 @@
+use crate::documentation::extractor::document_source;
+use crate::documentation::renderer::{page_name, render_file, render_index};
+use crate::shared::documentation::file_documentation::DocumentationFormat;
+use crate::tests::documentation::extractor::{parse, SOURCE};
+
+#[test]
+fn markdown() {
+    let mut documentation = document_source("lib/geometry.cbs", SOURCE, &parse(SOURCE));
+    documentation.links[0].target = Some("std.cbs".to_string());
+    let files = vec![documentation];
+
+    let page = render_file(&files[0], &files, DocumentationFormat::Markdown);
+    assert!(page.starts_with("# `lib/geometry.cbs`\n\nGeometry helpers\n\n"));
+    assert!(page.contains("- [`link std;`](std.cbs.md)"));
+    assert!(page.contains("```\nexport decl func twice(number x)[number]\n```\n\nDouble a number.\n\nWorks for negative numbers as well.\n\n"));
+    assert!(page.contains("| `x` | `number` |"));
+    assert!(page.contains("| `x` | `number` | get, set | Horizontal position |"));
+    assert!(page.contains("| `y` | `number` | get |  |"));
+    assert!(page.contains("Returns [`point`](lib.geometry.cbs.md#group-point)."));
+    assert!(page.contains("<a id=\"point-method-move\"></a>"));
+    assert!(page.contains("Returns nothing."));
+
+    let index = render_index("Geometry", &files, DocumentationFormat::Markdown);
+    assert_eq!(index, "# Geometry\n\n- [`lib/geometry.cbs`](lib.geometry.cbs.md): Geometry helpers\n\n");
+}
+
+#[test]
+fn html() {
+    let source = "// Compare <a> & <b>\ndecl func less(number a, number b)[number] {\n    return a < b;\n}\n";
+    let files = vec![document_source("less.cbs", source, &parse(source))];
+
+    let page = render_file(&files[0], &files, DocumentationFormat::Html);
+    assert!(page.starts_with("<!DOCTYPE html>"));
+    assert!(page.contains("<title>less.cbs</title>"));
+    assert!(page.contains("<h3 id=\"func-less\"><code>less</code></h3>"));
+    assert!(page.contains("<p>Compare &lt;a&gt; &amp; &lt;b&gt;</p>"));
+    assert!(page.contains("<tr><td><code>a</code></td><td><code>number</code></td></tr>"));
+    assert_eq!(page_name("a/b.cbs", DocumentationFormat::Html), "a.b.cbs.html");
+}
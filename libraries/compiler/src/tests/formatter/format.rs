@@ -0,0 +1,80 @@
+use crate::formatter::format::format_source;
+use crate::lexer::tokenize::tokenize;
+use crate::shared::token::token::TokenContent;
+
+#[test]
+fn layout() {
+    let source = "decl func main(number foo)[number]{decl var number x;x=-foo+2*(3-1);\nif(x>0){call f(x,1);}elif(x<0){x=!x;}else{\n\n\n  return x;}\n\n\n\nreturn a.b::c[1];}";
+    let expected = r#"decl func main(number foo)[number] {
+    decl var number x;
+    x = -foo + 2 * (3 - 1);
+    if (x > 0) {
+        call f(x, 1);
+    } elif (x < 0) {
+        x = !x;
+    } else {
+        return x;
+    }
+
+    return a.b::c[1];
+}
+"#;
+
+    assert_eq!(format_source(source).unwrap(), expected);
+}
+
+#[test]
+fn group_members() {
+    let source = "group arc { field number foo(get,set); method run()[none]; }\n\
+                  impl arc { default foo = 42; method run()[none] { return; } }";
+    let expected = r#"group arc {
+    field number foo(get, set);
+    method run()[none];
+}
+
+impl arc {
+    default foo = 42;
+    method run()[none] {
+        return;
+    }
+}
+"#;
+
+    assert_eq!(format_source(source).unwrap(), expected);
+}
+
+#[test]
+fn keep_comments() {
+    let source = "// Entry\ndecl func main()[number] { // Trailing\n\n    // Own line   \n    return 0; }";
+    let expected = r#"// Entry
+decl func main()[number] { // Trailing
+    // Own line
+    return 0;
+}
+"#;
+
+    assert_eq!(format_source(source).unwrap(), expected);
+}
+
+#[test]
+fn keep_tokens() {
+    let source = include_str!("../sample.cbs");
+    let formatted = format_source(source).unwrap();
+
+    let significant_tokens = |code: &str| -> Vec<TokenContent> {
+        return tokenize(code, false).unwrap()
+                                    .into_iter()
+                                    .map(|t| t.content)
+                                    .filter(|c| !matches!(c, TokenContent::Whitespace(_)))
+                                    .collect();
+    };
+    assert_eq!(significant_tokens(source), significant_tokens(formatted.as_str()));
+
+    // Formatting is stable
+    assert_eq!(format_source(formatted.as_str()).unwrap(), formatted);
+}
+
+#[test]
+fn unrecognized_characters() {
+    assert!(format_source("decl func main()[number] { return $; }").is_err());
+}
@@ -1,7 +1,7 @@
 use crate::lexer::tokenize::tokenize;
 use crate::shared::token::container::ContainerType;
 use crate::shared::token::keyword::KeywordType;
-use crate::shared::token::operator::Operator;
+use crate::shared::token::operator::{Operator, RelationOperator};
 
 #[test]
 fn simple() {
@@ -93,3 +93,36 @@ fn group_related_keywords_test() {
 
     assert_eq!(result.len(), 65);
 }
+
+#[test]
+fn unrecognized_characters() {
+    let issues = tokenize("a = 1 $$ 2; b ?", true).err().unwrap().issues;
+    let locations: Vec<(usize, usize)> = issues.iter().map(|i| (i.detail.location.start_pos, i.detail.location.end_pos)).collect();
+
+    assert_eq!(locations, vec![(6, 8), (14, 15)]);
+}
+
+#[test]
+fn comments() {
+    let result = tokenize("// first\nx = 1; // last", false).unwrap();
+
+    assert_eq!(result[0].get_comment().unwrap(), " first");
+    assert_eq!(result[2].get_identifier().unwrap(), "x");
+    assert_eq!(result.last().unwrap().get_comment().unwrap(), " last");
+    assert_eq!(tokenize("// first\nx = 1; // last", true).unwrap().len(), 4);
+}
+
+#[test]
+fn relation_operators() {
+    let result = tokenize("a >= b <= c <> d < e > f == g", true).unwrap();
+    let operators: Vec<Operator> = result.iter().filter_map(|t| t.get_operator()).collect();
+
+    assert_eq!(operators, vec![
+        Operator::Relation(RelationOperator::GreaterOrEqual),
+        Operator::Relation(RelationOperator::LessOrEqual),
+        Operator::Relation(RelationOperator::NotEqual),
+        Operator::Relation(RelationOperator::Less),
+        Operator::Relation(RelationOperator::Greater),
+        Operator::Relation(RelationOperator::Equal),
+    ]);
+}
@@ -0,0 +1,2 @@
+mod object_linker;
+mod package_linker;
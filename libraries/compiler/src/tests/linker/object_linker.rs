@@ -0,0 +1,171 @@
+use crate::lexer::tokenize::tokenize;
+use crate::linker::object_linker::link_objects;
+use crate::package_generator::package_builder::{build_package, build_relocatable_commands};
+use crate::package_reader::layout_reader::read_package_layout;
+use crate::package_reader::object_reader::{is_object_file, read_object_file};
+use crate::parser::decorator::decorate_token;
+use crate::parser::pipeline::build_whole_file;
+use crate::shared::ast::blocks::function::FunctionLinkage;
+use crate::shared::package_generation::object_file::ObjectFile;
+use crate::shared::package_generation::package_descriptor::PackageMetadata;
+use crate::shared::utils::identifier::Identifier;
+
+fn metadata(package_type: u8) -> PackageMetadata {
+    return PackageMetadata {
+        data_slot_alignment: 2,
+        data_alignment: 8,
+        package_type,
+        domain_layer_count_alignment: 2,
+        address_alignment: 8,
+        global_command_offset: 5,
+    };
+}
+
+fn compile_object(source: &str) -> ObjectFile {
+    return compile_object_with(source, &metadata(0));
+}
+
+fn compile_object_with(source: &str, metadata: &PackageMetadata) -> ObjectFile {
+    let (tokens, string_pool) = decorate_token(tokenize(source, true).unwrap());
+    let tree = build_whole_file(tokens, Identifier::single("main")).ok().unwrap();
+
+    return ObjectFile {
+        metadata: metadata.clone(),
+        source_checksum: vec![0xAB, 0xCD],
+        commands: build_relocatable_commands(&tree, string_pool, metadata).unwrap(),
+    };
+}
+
+const MAIN_SOURCE: &str = r#"
+    decl func main()[number] {
+        decl var str s;
+        s = "main";
+        return count(3);
+    }
+"#;
+
+const COUNT_SOURCE: &str = r#"
+    decl func count(number n)[number] {
+        decl var str s;
+        s = "count";
+        decl var number i;
+        i = 0;
+        while (i < n) {
+            i = i + 1;
+            s = "loop";
+        }
+        return i;
+    }
+"#;
+
+#[test]
+fn object_round_trip() {
+    let object = compile_object(COUNT_SOURCE);
+    let bytes = object.serialize().unwrap();
+    assert!(is_object_file(&bytes));
+
+    let result = read_object_file(&bytes).ok().unwrap();
+    assert_eq!(result.metadata, object.metadata);
+    assert_eq!(result.source_checksum, vec![0xAB, 0xCD]);
+    assert_eq!(result.commands.commands, object.commands.commands);
+    assert_eq!(result.commands.command_entries, object.commands.command_entries);
+    assert_eq!(result.commands.string_pool, object.commands.string_pool);
+    assert_eq!(result.commands.descriptors.targets.len(), object.commands.descriptors.targets.len());
+    assert_eq!(result.commands.descriptors.references.len(), object.commands.descriptors.references.len());
+    assert_eq!(format!("{:?}", result.commands.function_table), format!("{:?}", object.commands.function_table));
+
+    // Objects are written the same way every time
+    assert_eq!(result.serialize().unwrap(), bytes);
+}
+
+#[test]
+fn read_broken_object() {
+    let bytes = compile_object(COUNT_SOURCE).serialize().unwrap();
+
+    assert!(read_object_file(&bytes[..bytes.len() - 1]).is_err());
+    assert!(read_object_file(&[bytes.as_slice(), &[0x00]].concat()).is_err());
+    assert_eq!(read_object_file(&bytes[3..]).unwrap_err().issues[0].detail.content, "Object file signature is not found");
+}
+
+#[test]
+fn link_objects_like_whole_package() {
+    let objects = vec![
+        ("main".to_string(), compile_object(MAIN_SOURCE)),
+        ("count".to_string(), compile_object(COUNT_SOURCE)),
+    ];
+    let package = link_objects(&objects, "main", &metadata(0)).ok().unwrap();
+
+    // Compiling both files as a whole gives the same package
+    let (tokens, string_pool) = decorate_token(tokenize(format!("{}{}", MAIN_SOURCE, COUNT_SOURCE).as_str(), true).unwrap());
+    let tree = build_whole_file(tokens, Identifier::single("main")).ok().unwrap();
+    assert_eq!(package, build_package(&tree, string_pool, &metadata(0)).unwrap());
+
+    let layout = read_package_layout(&package).ok().unwrap();
+    let strings: Vec<(&str, usize)> = layout.string_pool.iter().map(|s| (s.value.as_str(), s.slot)).collect();
+    assert_eq!(strings, vec![("main", 0), ("count", 1), ("loop", 2)]);
+}
+
+#[test]
+fn link_objects_into_library() {
+    let source = format!("decl func count(number n)[number];\nexport {}", MAIN_SOURCE.trim());
+    let objects = vec![("main".to_string(), compile_object(source.as_str()))];
+    let layout = read_package_layout(&link_objects(&objects, "main", &metadata(1)).ok().unwrap()).ok().unwrap();
+
+    assert_eq!(layout.entry_point, None);
+    let symbols: Vec<(&str, usize, FunctionLinkage)> = layout.symbols.iter().map(|s| (s.name.as_str(), s.slot, s.linkage)).collect();
+    assert_eq!(symbols, vec![("main", 0, FunctionLinkage::Export), ("count", 1, FunctionLinkage::External)]);
+}
+
+#[test]
+fn link_objects_undefined_function() {
+    let objects = vec![("main".to_string(), compile_object(MAIN_SOURCE))];
+    let result = link_objects(&objects, "main", &metadata(0));
+
+    assert!(result.is_err());
+    let issues = result.unwrap_err().issues;
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].detail.content, "Function \"count\" is not defined in any object");
+    assert_eq!(issues[0].detail.package, Some("main".to_string()));
+}
+
+#[test]
+fn link_objects_duplicate_function() {
+    let objects = vec![
+        ("main".to_string(), compile_object(MAIN_SOURCE)),
+        ("count".to_string(), compile_object(COUNT_SOURCE)),
+        ("count_copy".to_string(), compile_object(COUNT_SOURCE)),
+    ];
+    let result = link_objects(&objects, "main", &metadata(0));
+
+    assert!(result.is_err());
+    let issues = result.unwrap_err().issues;
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].detail.content, "Function \"count\" is defined by \"count\" as well");
+}
+
+#[test]
+fn serialize_long_string() {
+    // String lengths are aligned by the data slot alignment
+    let metadata = PackageMetadata { data_slot_alignment: 1, ..metadata(0) };
+    let source = format!("decl func main()[number] {{ decl var str s; s = \"{}\"; return 0; }}", "a".repeat(300));
+    let result = compile_object_with(source.as_str(), &metadata).serialize();
+
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().issues[0].detail.content, "Length 300 of a string doesn't fit in 1 bytes, consider changing the alignment into a longer width");
+}
+
+#[test]
+fn link_objects_too_many_strings() {
+    // Every object fits in one byte wide slots, but not all of them together
+    let metadata = PackageMetadata { data_slot_alignment: 1, ..metadata(0) };
+    let object = |name: &str| {
+        let assignments: String = (0..200).map(|i| format!("s = \"{}_{}\";", name, i)).collect();
+        let source = format!("decl func {}()[number] {{ decl var str s; {} return 0; }}", name, assignments);
+
+        return (name.to_string(), compile_object_with(source.as_str(), &metadata));
+    };
+    let result = link_objects(&[object("main"), object("other")], "main", &metadata);
+
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().issues[0].detail.content, "Too many string constants for the data slot alignment");
+}
@@ -0,0 +1,136 @@
+use crate::lexer::tokenize::tokenize;
+use crate::linker::package_linker::link_packages;
+use crate::package_generator::package_builder::build_package;
+use crate::package_reader::layout_reader::read_package_layout;
+use crate::parser::decorator::decorate_token;
+use crate::parser::pipeline::build_whole_file;
+use crate::shared::ast::blocks::function::FunctionLinkage;
+use crate::shared::package_generation::package_descriptor::PackageMetadata;
+use crate::shared::utils::identifier::Identifier;
+
+fn compile_library(source: &str) -> Vec<u8> {
+    return compile(source, 1);
+}
+
+fn compile(source: &str, package_type: u8) -> Vec<u8> {
+    return compile_with_slot_width(source, package_type, 2);
+}
+
+fn compile_with_slot_width(source: &str, package_type: u8, data_slot_alignment: u8) -> Vec<u8> {
+    let (tokens, string_pool) = decorate_token(tokenize(source, true).unwrap());
+    let tree = build_whole_file(tokens, Identifier::single("main")).ok().unwrap();
+    let metadata = PackageMetadata {
+        data_slot_alignment,
+        data_alignment: 8,
+        package_type,
+        domain_layer_count_alignment: 2,
+        address_alignment: 8,
+        global_command_offset: 5,
+    };
+
+    return build_package(&tree, string_pool, &metadata).unwrap();
+}
+
+const MAIN_SOURCE: &str = r#"
+    decl func twice(number x)[number];
+
+    export decl func main()[number] {
+        decl var str s;
+        s = "main";
+        return twice(21);
+    }
+"#;
+
+const MATH_SOURCE: &str = r#"
+    export decl func twice(number x)[number] {
+        return helper(x) + x;
+    }
+
+    decl func helper(number x)[number] {
+        decl var str s;
+        s = "helper";
+        return x;
+    }
+"#;
+
+#[test]
+fn library_symbols() {
+    let layout = read_package_layout(&compile_library(MAIN_SOURCE)).ok().unwrap();
+
+    assert_eq!(layout.entry_point, None);
+    // External functions take a slot but have no entry
+    assert_eq!(layout.function_table.len(), 1);
+    assert_eq!(layout.function_table[0].slot, 1);
+    assert_eq!(layout.symbols.len(), 2);
+    assert_eq!((layout.symbols[0].name.as_str(), layout.symbols[0].slot, layout.symbols[0].linkage), ("twice", 0, FunctionLinkage::External));
+    assert_eq!((layout.symbols[1].name.as_str(), layout.symbols[1].slot, layout.symbols[1].linkage), ("main", 1, FunctionLinkage::Export));
+}
+
+#[test]
+fn link_libraries() {
+    let packages = vec![
+        ("main".to_string(), compile_library(MAIN_SOURCE)),
+        ("math".to_string(), compile_library(MATH_SOURCE)),
+    ];
+    let layout = read_package_layout(&link_packages(&packages, "main").ok().unwrap()).ok().unwrap();
+
+    assert_eq!(layout.metadata.package_type, 0);
+    assert!(layout.symbols.is_empty());
+    assert_eq!(layout.function_table.len(), 3);
+    assert_eq!(layout.entry_point, Some(layout.function_table[0].entry_address));
+
+    // String slots of the second package are moved behind the first one
+    let strings: Vec<(&str, usize)> = layout.string_pool.iter().map(|s| (s.value.as_str(), s.slot)).collect();
+    assert_eq!(strings, vec![("main", 0), ("helper", 1)]);
+}
+
+#[test]
+fn link_missing_symbol() {
+    let packages = vec![("main".to_string(), compile_library(MAIN_SOURCE))];
+    let result = link_packages(&packages, "main");
+
+    assert!(result.is_err());
+    let issues = result.unwrap_err().issues;
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].detail.content, "Symbol \"twice\" is not exported by any package");
+    assert_eq!(issues[0].detail.package, Some("main".to_string()));
+}
+
+#[test]
+fn link_duplicate_symbol() {
+    let packages = vec![
+        ("main".to_string(), compile_library(MAIN_SOURCE)),
+        ("math".to_string(), compile_library(MATH_SOURCE)),
+        ("math_copy".to_string(), compile_library(MATH_SOURCE)),
+    ];
+    let result = link_packages(&packages, "main");
+
+    assert!(result.is_err());
+    let issues = result.unwrap_err().issues;
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].detail.content, "Symbol \"twice\" is exported by \"math\" as well");
+}
+
+#[test]
+fn link_executable() {
+    let packages = [("main".to_string(), compile("export decl func main()[number] { return 0; }", 0))];
+    let result = link_packages(&packages, "main");
+
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().issues[0].detail.content, "Only library packages could be linked");
+}
+
+#[test]
+fn link_too_many_strings() {
+    // Every package fits in one byte wide slots, but not all of them together
+    let library = |name: &str| {
+        let assignments: String = (0..200).map(|i| format!("s = \"{}_{}\";", name, i)).collect();
+        let source = format!("export decl func {}()[number] {{ decl var str s; {} return 0; }}", name, assignments);
+
+        return (name.to_string(), compile_with_slot_width(source.as_str(), 1, 1));
+    };
+    let result = link_packages(&[library("main"), library("other")], "main");
+
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().issues[0].detail.content, "Too many string constants for the data slot alignment");
+}
@@ -1,3 +1,11 @@
+mod analysis;
+mod assembler;
+mod diagnostics;
+mod documentation;
+mod formatter;
 mod lexer;
+mod linker;
+mod package_reader;
 mod parser;
 mod pkg_gen;
+mod session;
@@ -0,0 +1,138 @@
+use crate::lexer::tokenize::tokenize;
+use crate::package_generator::package_builder::{build_package, build_package_with_debug_info};
+use crate::package_reader::disassembler::disassemble_package;
+use crate::package_reader::layout_reader::read_package_layout;
+use crate::parser::decorator::decorate_token;
+use crate::parser::pipeline::build_whole_file;
+use crate::shared::ast::package::ParserPackageStructure;
+use crate::shared::package_generation::data_descriptor::StringConstant;
+use crate::shared::package_generation::debug_info::DomainKind;
+use crate::shared::package_generation::package_descriptor::PackageMetadata;
+use crate::shared::utils::identifier::Identifier;
+use crate::shared::utils::source_map::SourceMap;
+
+const SOURCE: &str = "decl func main(number x)[number] {
+    decl var number y;
+    y = x * 2;
+    if (y > 4) {
+        decl var number z;
+        z = y - 4;
+        return z;
+    }
+    return y;
+}
+";
+
+fn parse(source: &str) -> (ParserPackageStructure, Vec<StringConstant>) {
+    let (tokens, string_pool) = decorate_token(tokenize(source, true).unwrap());
+    let mut tree = build_whole_file(tokens, Identifier::single("main")).ok().unwrap();
+    for function in tree.functions.iter_mut() {
+        function.file_path = "main.cbs".to_string();
+    }
+
+    return (tree, string_pool);
+}
+
+#[test]
+fn line_table_and_locals() {
+    let (tree, string_pool) = parse(SOURCE);
+    let metadata = PackageMetadata::default();
+    let package = build_package_with_debug_info(&tree, string_pool.clone(), &metadata, &[SourceMap::new("main.cbs", SOURCE)]).unwrap();
+
+    // Commands are the same as the ones without debug information
+    let plain_package = build_package(&tree, string_pool, &metadata).unwrap();
+    assert!(package.starts_with(&plain_package));
+
+    let layout = read_package_layout(&package).ok().unwrap();
+    assert_eq!(layout.commands, plain_package);
+    let debug_info = layout.debug_info.clone().unwrap();
+    assert_eq!(debug_info.files, vec!["main.cbs".to_string()]);
+
+    let lines: Vec<(usize, usize)> = debug_info.line_table.iter().map(|e| (e.line, e.column)).collect();
+    assert_eq!(lines, vec![(2, 5), (3, 5), (4, 5), (5, 9), (6, 9), (7, 9), (9, 5)]);
+    assert!(debug_info.line_table.iter().all(|e| e.address >= layout.code_offset && e.address < package.len()));
+
+    let names: Vec<(usize, &str)> = debug_info.locals.iter().map(|l| (l.slot, l.name.as_str())).collect();
+    assert_eq!(names, vec![(0, "x"), (1, "y"), (2, "z")]);
+
+    // `z` is only alive inside the `if` block
+    let inner_address = debug_info.line_table[4].address;
+    let outer_address = debug_info.line_table[6].address;
+    let alive = |address: usize| debug_info.locals_at(address).iter().map(|l| l.name.clone()).collect::<Vec<String>>();
+    assert_eq!(alive(inner_address), vec!["x", "y", "z"]);
+    assert_eq!(alive(outer_address), vec!["x", "y"]);
+    assert_eq!(debug_info.location_at(inner_address + 1), Some("main.cbs:6:9".to_string()));
+}
+
+#[test]
+fn disassemble_with_debug_info() {
+    let (tree, string_pool) = parse(SOURCE);
+    let package = build_package_with_debug_info(&tree, string_pool, &PackageMetadata::default(), &[SourceMap::new("main.cbs", SOURCE)]).unwrap();
+
+    let listing = disassemble_package(&package).ok().unwrap();
+    assert!(listing.contains("    ; main.cbs:3:5\n"));
+    assert!(listing.contains("    ; local $2 z "));
+    assert!(!listing.contains(".byte"));
+}
+
+#[test]
+fn package_without_debug_info() {
+    let (tree, string_pool) = parse(SOURCE);
+    let package = build_package(&tree, string_pool.clone(), &PackageMetadata::default()).unwrap();
+    assert!(read_package_layout(&package).ok().unwrap().debug_info.is_none());
+
+    // Files without sources only keep names of local variables
+    let package = build_package_with_debug_info(&tree, string_pool, &PackageMetadata::default(), &[]).unwrap();
+    let debug_info = read_package_layout(&package).ok().unwrap().debug_info.unwrap();
+    assert!(debug_info.line_table.is_empty());
+    assert_eq!(debug_info.locals.len(), 3);
+}
+
+#[test]
+fn broken_debug_section() {
+    let (tree, string_pool) = parse(SOURCE);
+    let mut package = build_package_with_debug_info(&tree, string_pool, &PackageMetadata::default(), &[SourceMap::new("main.cbs", SOURCE)]).unwrap();
+
+    // Point the first line table entry to a missing file
+    let layout = read_package_layout(&package).ok().unwrap();
+    let file_index_position = layout.commands.len() + 4 + 8 + 2 + "main.cbs".len() + 8 + 8;
+    package[file_index_position + 1] = 0x07;
+
+    assert!(read_package_layout(&package).is_err());
+}
+
+#[test]
+fn function_names_and_domains() {
+    let source = "decl func main(number x)[number] {
+    while (x > 0) {
+        x = twice(x) - 7;
+    }
+    return x;
+}
+
+decl func twice(number x)[number] {
+    return x * 2;
+}
+";
+    let (tree, string_pool) = parse(source);
+    let package = build_package_with_debug_info(&tree, string_pool, &PackageMetadata::default(), &[SourceMap::new("main.cbs", source)]).unwrap();
+    let layout = read_package_layout(&package).ok().unwrap();
+    let debug_info = layout.debug_info.clone().unwrap();
+
+    assert_eq!(debug_info.function_name(0), Some("main"));
+    assert_eq!(debug_info.function_name(1), Some("twice"));
+
+    // The assignment in the loop body
+    let address = debug_info.line_table.iter().find(|e| e.line == 3).unwrap().address;
+    let kinds: Vec<DomainKind> = debug_info.domains_at(address).iter().map(|d| d.kind).collect();
+    assert_eq!(kinds, vec![DomainKind::Function, DomainKind::Block, DomainKind::While, DomainKind::Block]);
+
+    // Function domains cover every command of their functions, including the end flag
+    let functions: Vec<(usize, usize)> = debug_info.domains.iter().filter(|d| d.kind == DomainKind::Function).map(|d| (d.start, d.end)).collect();
+    let mut entries: Vec<usize> = layout.function_table.iter().map(|f| f.entry_address).collect();
+    entries.sort();
+    assert_eq!(functions, vec![(entries[0], entries[1]), (entries[1], layout.commands.len())]);
+
+    let listing = disassemble_package(&package).ok().unwrap();
+    assert!(listing.contains(", twice\n"));
+}
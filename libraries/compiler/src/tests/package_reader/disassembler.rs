@@ -0,0 +1,56 @@
+use crate::lexer::tokenize::tokenize;
+use crate::package_generator::package_builder::build_package;
+use crate::package_reader::disassembler::disassemble_package;
+use crate::parser::decorator::decorate_token;
+use crate::parser::pipeline::build_whole_file;
+use crate::shared::package_generation::package_descriptor::PackageMetadata;
+use crate::shared::utils::identifier::Identifier;
+
+#[test]
+fn disassemble_compiled_package() {
+    let source = r#"
+        decl func main(number x)[number] {
+            while (x > 0) {
+                x = x - 1;
+            }
+            return twice(x);
+        }
+
+        decl func twice(number x)[number] {
+            decl var str s;
+            s = "twice";
+            return x * 2;
+        }
+    "#;
+
+    let (tokens, string_pool) = decorate_token(tokenize(source, true).unwrap());
+    let tree = build_whole_file(tokens, Identifier::single("main")).ok().unwrap();
+    let metadata = PackageMetadata {
+        data_slot_alignment: 2,
+        data_alignment: 8,
+        package_type: 0,
+        domain_layer_count_alignment: 2,
+        address_alignment: 8,
+        global_command_offset: 5,
+    };
+    let package = build_package(&tree, string_pool, &metadata).unwrap();
+
+    let listing = disassemble_package(&package).ok().unwrap();
+    let lines: Vec<&str> = listing.lines().map(|l| l.split(';').next().unwrap().trim_end()).collect();
+
+    assert!(lines.contains(&".entry fn_0"));
+    assert!(lines.contains(&".string \"twice\""));
+    assert!(lines.contains(&".func fn_1"));
+    assert!(lines.contains(&"    function.enter fn_1 1"));
+    assert!(lines.contains(&"    stack.push_object @0"));
+    assert!(lines.iter().any(|l| l.starts_with("    jump.stack_top L")));
+    // The loop starts right at the head of `main`
+    assert!(lines.contains(&"    jump.relative fn_0"));
+    // No byte is left undecoded
+    assert!(!listing.contains(".byte"));
+}
+
+#[test]
+fn disassemble_broken_package() {
+    assert!(disassemble_package(&vec![0x00, 0x08]).is_err());
+}
@@ -0,0 +1,52 @@
+use crate::package_reader::instruction_decoder::{decode_instruction, decode_instructions};
+use crate::shared::command_map::MathCalcCommand;
+use crate::shared::package_generation::package_descriptor::PackageMetadata;
+use crate::shared::package_reading::instruction::{DataAccess, Instruction};
+
+fn metadata() -> PackageMetadata {
+    return PackageMetadata {
+        data_slot_alignment: 2,
+        data_alignment: 4,
+        package_type: 0,
+        domain_layer_count_alignment: 2,
+        address_alignment: 2,
+        global_command_offset: 5,
+    };
+}
+
+#[test]
+fn decode_command_sequence() {
+    let commands = vec![
+        0xA1, 0x00,
+        0xB1, 0x00, 0xFF, 0xFF, 0xFF, 0xFE,
+        0xB4, 0x01, 0x00, 0x03,
+        0xB2, 0x02, 0x00, 0x01,
+        0xF1, 0x02,
+        0xE1, 0x00, 0x00, 0x04, 0x02,
+        0xD1, 0x0B, 0x00, 0x10,
+        0xEF,
+    ];
+
+    let result = decode_instructions(&commands, 0, commands.len(), &metadata()).ok().unwrap();
+    let instructions: Vec<Instruction> = result.iter().map(|i| i.instruction.clone()).collect();
+
+    assert_eq!(instructions, vec![
+        Instruction::CreateObject { is_global: false },
+        Instruction::Push(DataAccess::InstantValue(-2)),
+        Instruction::PopToObject(DataAccess::LocalSlot(3)),
+        Instruction::PushFromObject(DataAccess::StringSlot(1)),
+        Instruction::Calculation(MathCalcCommand::Minus),
+        Instruction::EnterFunction { slot: 4, argument_count: 2 },
+        Instruction::JumpToRelative(-16),
+        Instruction::FunctionEndFlag,
+    ]);
+    assert_eq!(result[5].position, 18);
+    assert_eq!(result[5].length, 5);
+}
+
+#[test]
+fn decode_invalid_command() {
+    assert!(decode_instruction(&vec![0x13], 0, &metadata()).is_err());
+    // Truncated operand
+    assert!(decode_instruction(&vec![0xB1, 0x00, 0x00], 0, &metadata()).is_err());
+}
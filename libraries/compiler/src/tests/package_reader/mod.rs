@@ -0,0 +1,3 @@
+mod debug_info;
+mod disassembler;
+mod instruction_decoder;
@@ -1,25 +1,19 @@
 use crate::lexer::tokenize::tokenize;
-use crate::parser::builder::blocks::action_block::action_block_builder;
-use crate::parser::builder::blocks::assignment::assignment_block_builder;
-use crate::parser::builder::blocks::call::call_action_builder;
-use crate::parser::builder::blocks::condition::if_block_builder;
-use crate::parser::builder::blocks::declaration::declaration_action_builder;
-use crate::parser::builder::blocks::loops::while_action_builder;
-use crate::parser::builder::blocks::return_expression::return_action_builder;
-use crate::parser::builder::blocks::short_actions::short_statements_builder;
-use crate::parser::builder::function_builder::function_builder;
 use crate::parser::decorator::decorate_token;
-use crate::shared::ast::action::ActionContent;
-use crate::shared::token::operator::CalculationOperator;
+use crate::parser::pipeline::{build_action_block, build_whole_file};
+use crate::shared::ast::action::{Action, ActionContent};
+use crate::shared::ast::blocks::expression::NodeContent;
+use crate::shared::ast::decorated_token::DecoratedToken;
+use crate::shared::token::operator::{CalculationOperator, Operator, RelationOperator};
 use crate::shared::utils::identifier::Identifier;
 
 #[test]
 fn assignment() {
     let tokens = tokenize("a = 1 + 2;", true).unwrap();
-    let raw = assignment_block_builder(&decorate_token(tokens.clone()).0);
+    let raw = single_action(&decorate_token(tokens.clone()).0);
 
-    let result = raw.clone().ok().unwrap().0.get_assignment_action().unwrap().clone();
-    assert_eq!(raw.ok().unwrap().1, tokens.len());
+    let result = raw.get_assignment_action().unwrap().clone();
+    assert_eq!(raw.tokens.len(), tokens.len());
 
     assert_eq!(result.identifier, Identifier::single("a"));
 
@@ -42,10 +36,10 @@ fn assignment() {
 #[test]
 fn variable_declaration() {
     let tokens = tokenize("decl var number foo;", true).unwrap();
-    let raw = declaration_action_builder(&decorate_token(tokens.clone()).0);
+    let raw = single_action(&decorate_token(tokens.clone()).0);
 
-    let result = raw.clone().ok().unwrap().0.get_declaration_action().unwrap().clone();
-    assert_eq!(raw.ok().unwrap().1, tokens.len());
+    let result = raw.get_declaration_action().unwrap().clone();
+    assert_eq!(raw.tokens.len(), tokens.len());
 
     assert_eq!(result.identifier, Identifier::single("foo"));
     assert_eq!(result.data_type, Identifier::single("number"));
@@ -55,10 +49,10 @@ fn variable_declaration() {
 #[test]
 fn function_call() {
     let tokens = tokenize("call func_1(5, 2.66, var1, 3 - 2);", true).unwrap();
-    let raw = call_action_builder(&decorate_token(tokens.clone()).0);
+    let raw = single_action(&decorate_token(tokens.clone()).0);
 
-    let result = raw.clone().ok().unwrap().0.get_call_action().unwrap().clone();
-    assert_eq!(raw.ok().unwrap().1, tokens.len());
+    let result = raw.get_call_action().unwrap().clone();
+    assert_eq!(raw.tokens.len(), tokens.len());
 
     assert_eq!(result.function_name, Identifier::single("func_1"));
     assert_eq!(result.arguments.len(), 4);
@@ -81,10 +75,10 @@ fn function_call() {
 #[test]
 fn return_from_function_no_value() {
     let tokens = tokenize("return;", true).unwrap();
-    let raw = return_action_builder(&decorate_token(tokens.clone()).0);
+    let raw = single_action(&decorate_token(tokens.clone()).0);
 
-    let result = raw.clone().ok().unwrap().0.get_return_action().unwrap().clone();
-    assert_eq!(raw.ok().unwrap().1, tokens.len());
+    let result = raw.get_return_action().unwrap().clone();
+    assert_eq!(raw.tokens.len(), tokens.len());
 
     assert_eq!(result.value.unwrap().postfix_expr.len(), 0);
 }
@@ -92,10 +86,10 @@ fn return_from_function_no_value() {
 #[test]
 fn return_from_function_with_value() {
     let tokens = tokenize("return 1 + 2 * tb_234;", true).unwrap();
-    let raw = return_action_builder(&decorate_token(tokens.clone()).0);
+    let raw = single_action(&decorate_token(tokens.clone()).0);
 
-    let result = raw.clone().ok().unwrap().0.get_return_action().unwrap().clone();
-    assert_eq!(raw.ok().unwrap().1, tokens.len());
+    let result = raw.get_return_action().unwrap().clone();
+    assert_eq!(raw.tokens.len(), tokens.len());
 
     let val = result.value.unwrap();
     assert_eq!(val.postfix_expr.len(), 5);
@@ -114,10 +108,8 @@ fn return_from_function_with_value() {
 #[test]
 fn single_token_statement_break() {
     let tokens = tokenize("break;", true).unwrap();
-    let raw = short_statements_builder(&decorate_token(tokens.clone()).0);
-
-    let result = raw.clone().ok().unwrap().0;
-    assert_eq!(raw.ok().unwrap().1, tokens.len());
+    let result = single_action(&decorate_token(tokens.clone()).0);
+    assert_eq!(result.tokens.len(), tokens.len());
 
     assert_eq!(result.content, ActionContent::BreakStatement);
 }
@@ -125,10 +117,8 @@ fn single_token_statement_break() {
 #[test]
 fn single_token_statement_continue() {
     let tokens = tokenize("continue;", true).unwrap();
-    let raw = short_statements_builder(&decorate_token(tokens.clone()).0);
-
-    let result = raw.clone().ok().unwrap().0;
-    assert_eq!(raw.ok().unwrap().1, tokens.len());
+    let result = single_action(&decorate_token(tokens.clone()).0);
+    assert_eq!(result.tokens.len(), tokens.len());
 
     assert_eq!(result.content, ActionContent::ContinueStatement);
 }
@@ -144,7 +134,7 @@ fn action_block() {
                                                 while (1 == 1) { call func_1(0); }",
                           true).unwrap();
 
-    let result = action_block_builder(decorate_token(tokens.clone()).0).unwrap();
+    let result = build_action_block(&decorate_token(tokens.clone()).0).unwrap();
 
     assert_eq!(result.len(), 7);
 
@@ -155,16 +145,33 @@ fn action_block() {
     assert!(result[5].get_return_action().is_some());
 }
 
+#[test]
+fn action_block_trailing_tokens() {
+    let tokens = tokenize("decl var number a; a ;", true).unwrap();
+    let result = build_action_block(&decorate_token(tokens).0);
+
+    assert!(result.is_err());
+    let issues = result.unwrap_err().issues;
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].detail.location.start_pos, 19);
+    assert_eq!(issues[0].detail.location.end_pos, 22);
+}
+
 #[test]
 fn while_block() {
     let tokens = tokenize("while (1 + 1 == 2) { a = a + 1; return; }", true).unwrap();
-    let raw = while_action_builder(&decorate_token(tokens.clone()).0);
-
-    let result = raw.clone().ok().unwrap().0.get_while_block().unwrap().clone();
-    assert_eq!(raw.ok().unwrap().1, tokens.len());
-
-    assert_eq!(result.condition.left.postfix_expr.len(), 3);
-    assert_eq!(result.condition.right.postfix_expr.len(), 1);
+    let raw = single_action(&decorate_token(tokens.clone()).0);
+
+    let result = raw.get_while_block().unwrap().clone();
+    assert_eq!(raw.tokens.len(), tokens.len());
+
+    match &result.condition.content {
+        NodeContent::Binary(Operator::Relation(RelationOperator::Equal), left, right) => {
+            assert_eq!(left.to_postfix().len(), 3);
+            assert_eq!(right.to_postfix().len(), 1);
+        }
+        _ => panic!("A comparison is expected"),
+    }
     assert_eq!(result.body.actions.len(), 2);
 }
 
@@ -179,13 +186,12 @@ fn if_block() {
                                                  else \
                                                     { decl var number foo; }", 
                           true).unwrap();
-    let raw = if_block_builder(&decorate_token(tokens.clone()).0);
+    let raw = single_action(&decorate_token(tokens.clone()).0);
 
-    let result = raw.clone().ok().unwrap().0.get_if_action().unwrap().clone();
-    assert_eq!(raw.ok().unwrap().1, tokens.len());
+    let result = raw.get_if_action().unwrap().clone();
+    assert_eq!(raw.tokens.len(), tokens.len());
 
-    assert_eq!(result.if_block.condition.left.postfix_expr.len(), 3);
-    assert_eq!(result.if_block.condition.right.postfix_expr.len(), 1);
+    assert!(matches!(result.if_block.condition.content, NodeContent::Binary(Operator::Relation(RelationOperator::Equal), ..)));
     assert_eq!(result.elif_collection.len(), 2);
     assert_eq!(result.else_action.unwrap().actions.len(), 1);
 }
@@ -193,10 +199,10 @@ fn if_block() {
 #[test]
 fn function_block() {
     let tokens = tokenize("decl func main(number a, number b)[number] { return a + b; }", true).unwrap();
-    let raw = function_builder(&decorate_token(tokens.clone()).0);
+    let mut structure = build_whole_file(decorate_token(tokens).0, Identifier::single("main")).unwrap();
 
-    let result = raw.clone().ok().unwrap().0;
-    assert_eq!(raw.ok().unwrap().1, tokens.len());
+    assert_eq!(structure.functions.len(), 1);
+    let result = structure.functions.remove(0);
 
     assert_eq!(result.declarator.identifier, Identifier::single("main"));
     assert_eq!(result.declarator.return_type, Identifier::single("number"));
@@ -211,3 +217,17 @@ fn identifier_section() {
 
     assert_eq!(raw.len(), 1);
 }
+
+#[test]
+fn single_token_section() {
+    assert_eq!(decorate_token(tokenize("a", true).unwrap()).0.len(), 1);
+    assert_eq!(decorate_token(tokenize("::a", true).unwrap()).0.len(), 2);
+}
+
+/// The only action built from `tokens`
+fn single_action(tokens: &[DecoratedToken]) -> Action {
+    let mut result = build_action_block(tokens).unwrap();
+    assert_eq!(result.len(), 1);
+
+    return result.remove(0);
+}
@@ -1,8 +1,9 @@
 use lazy_static::lazy_static;
 
 use crate::lexer::tokenize::tokenize;
-use crate::parser::decorator::decorate_token;
+use crate::parser::decorator::{decorate_token, decorate_token_with_string_pool};
 use crate::parser::pipeline::build_whole_file;
+use crate::shared::ast::blocks::function::FunctionLinkage;
 use crate::shared::utils::identifier::Identifier;
 
 lazy_static! {
@@ -75,3 +76,50 @@ fn file_example() {
     assert_eq!(structure.linked_code_files.len(), 2);
     assert_eq!(structure.functions.len(), 3);
 }
+
+#[test]
+fn merge_linked_files() {
+    let main_file = r#"
+        link util;
+        decl func main()[number] { decl var str s; s = "shared"; return twice(2); }
+    "#;
+    let util_file = r#"
+        decl func twice(number x)[number] { decl var str s; s = "util"; s = "shared"; return x * 2; }
+    "#;
+
+    let (main_tokens, string_pool) = decorate_token(tokenize(main_file, true).unwrap());
+    let (util_tokens, string_pool) = decorate_token_with_string_pool(tokenize(util_file, true).unwrap(), string_pool);
+
+    // Equal strings share one slot, new strings continue after the existing ones
+    assert_eq!(string_pool.len(), 2);
+    assert_eq!(string_pool[1].value, "util");
+    assert_eq!(string_pool[1].slot, 1);
+
+    let mut structure = build_whole_file(main_tokens, Identifier::single("main")).ok().unwrap();
+    structure.merge(build_whole_file(util_tokens, Identifier::single("main")).ok().unwrap());
+
+    assert_eq!(structure.linked_code_files.len(), 1);
+    assert_eq!(structure.functions.len(), 2);
+    assert_eq!(structure.export_function_table()[1].name, Identifier::single("twice"));
+}
+
+#[test]
+fn function_linkage() {
+    let file = r#"
+        decl func twice(number x)[number];
+        export decl func main()[number] { return twice(2); }
+        decl func helper()[none] { }
+    "#;
+
+    let tokens = decorate_token(tokenize(file, true).unwrap()).0;
+    let structure = build_whole_file(tokens, Identifier::single("main")).ok().unwrap();
+
+    let linkages: Vec<FunctionLinkage> = structure.functions.iter().map(|f| f.linkage).collect();
+    assert_eq!(linkages, vec![FunctionLinkage::External, FunctionLinkage::Export, FunctionLinkage::Internal]);
+    assert!(structure.functions[0].body.is_empty());
+    assert_eq!(structure.functions[0].declarator.parameters.len(), 1);
+
+    // External functions are provided by other packages, they couldn't be exported again
+    let tokens = decorate_token(tokenize("export decl func twice(number x)[number];", true).unwrap()).0;
+    assert!(build_whole_file(tokens, Identifier::single("main")).is_err());
+}
@@ -1,14 +1,20 @@
 use crate::lexer::tokenize::tokenize;
-use crate::parser::builder::expression_builder::expression_infix_to_postfix;
-use crate::parser::builder::expression_builder::expression_term_decorator;
-use crate::parser::builder::expression_builder::relation_expression_builder;
 use crate::parser::decorator::decorate_token;
-use crate::shared::token::operator::CalculationOperator;
+use crate::parser::pipeline::{build_action_block, build_expression, build_expression_tree};
+use crate::shared::ast::blocks::expression::{ExprDataTerm, NodeContent};
+use crate::shared::token::operator::{CalculationOperator, LogicalOperator, Operator, RelationOperator};
+use crate::shared::utils::identifier::Identifier;
+
+fn postfix_operators(source: &str) -> Vec<Operator> {
+    let result = build_expression(&decorate_token(tokenize(source, true).unwrap()).0).unwrap().postfix_expr;
+
+    return result.iter().filter_map(|t| t.content.get_operator().copied()).collect();
+}
 
 #[test]
 fn simple_expression() {
     let tokens = tokenize("1+2*3", true).unwrap();
-    let result = expression_infix_to_postfix(expression_term_decorator(&decorate_token(tokens).0));
+    let result = build_expression(&decorate_token(tokens).0).unwrap().postfix_expr;
 
     assert_eq!(result.len(), 5);
 
@@ -37,7 +43,7 @@ fn simple_expression() {
 #[test]
 fn expression_with_bracket() {
     let tokens = tokenize("2*(3+5)-7", true).unwrap();
-    let result = expression_infix_to_postfix(expression_term_decorator(&decorate_token(tokens).0));
+    let result = build_expression(&decorate_token(tokens).0).unwrap().postfix_expr;
 
     assert_eq!(result.len(), 7);
 
@@ -74,16 +80,114 @@ fn expression_with_bracket() {
 #[test]
 fn expression_with_function_call() {
     let tokens = tokenize("11+22.6*demo1(22.5)", true).unwrap();
-    let result = expression_infix_to_postfix(expression_term_decorator(&decorate_token(tokens).0));
+    let result = build_expression(&decorate_token(tokens).0).unwrap().postfix_expr;
 
     assert_eq!(result.len(), 5);
 }
 
 #[test]
 fn relation_expression() {
-    let tokens = tokenize("1 + a > 3 + foo(144)", true).unwrap();
-    let result = relation_expression_builder(expression_term_decorator(&decorate_token(tokens).0));
+    let tokens = tokenize("while (1 + a > 3 + foo(144)) { }", true).unwrap();
+    let actions = build_action_block(&decorate_token(tokens).0).unwrap();
+    let result = &actions[0].get_while_block().unwrap().condition;
+
+    match &result.content {
+        NodeContent::Binary(Operator::Relation(RelationOperator::Greater), left, right) => {
+            assert_eq!(left.to_postfix().len(), 3);
+            assert_eq!(right.to_postfix().len(), 3);
+        }
+        _ => panic!("A comparison is expected"),
+    }
+}
+
+#[test]
+fn compound_condition() {
+    let tokens = tokenize("if (!(a > 1) && b || c) { }", true).unwrap();
+    let actions = build_action_block(&decorate_token(tokens).0).unwrap();
+    let result = &actions[0].get_if_action().unwrap().if_block.condition;
+
+    match &result.content {
+        NodeContent::Binary(Operator::Logical(LogicalOperator::Or), left, _) => {
+            assert!(matches!(left.content, NodeContent::Binary(Operator::Logical(LogicalOperator::And), ..)));
+        }
+        _ => panic!("A logical operation is expected"),
+    }
+}
+
+#[test]
+fn logical_precedence() {
+    assert_eq!(postfix_operators("a || b && c == 1 + 2 * 3"), vec![
+        Operator::Calculation(CalculationOperator::Multiply),
+        Operator::Calculation(CalculationOperator::Addition),
+        Operator::Relation(RelationOperator::Equal),
+        Operator::Logical(LogicalOperator::And),
+        Operator::Logical(LogicalOperator::Or),
+    ]);
+}
 
-    assert_eq!(result.left.postfix_expr.len(), 3);
-    assert_eq!(result.right.postfix_expr.len(), 3);
+#[test]
+fn left_associativity() {
+    let tokens = tokenize("10 - 4 - 3", true).unwrap();
+    let result = build_expression_tree(&decorate_token(tokens).0).unwrap();
+
+    match result.content {
+        NodeContent::Binary(Operator::Calculation(CalculationOperator::Subtraction), left, right) => {
+            assert!(matches!(left.content, NodeContent::Binary(_, _, _)));
+            assert_eq!(right.content, NodeContent::Data(ExprDataTerm::Number("3".to_string())));
+        }
+        _ => panic!("A subtraction is expected"),
+    }
+
+    // Relations are chained in the same way
+    assert_eq!(postfix_operators("a < b == c"), vec![
+        Operator::Relation(RelationOperator::Less),
+        Operator::Relation(RelationOperator::Equal),
+    ]);
+}
+
+#[test]
+fn unary_operators() {
+    let tokens = tokenize("!a && -b * 2", true).unwrap();
+    let result = build_expression(&decorate_token(tokens).0).unwrap().postfix_expr;
+
+    assert_eq!(result.len(), 7);
+    assert_eq!(result[1].content.get_operator(), Some(&Operator::Logical(LogicalOperator::Not)));
+    assert_eq!(result[3].content.get_operator(), Some(&Operator::Calculation(CalculationOperator::Negation)));
+    assert_eq!(result[5].content.get_operator(), Some(&Operator::Calculation(CalculationOperator::Multiply)));
+    assert_eq!(result[6].content.get_operator(), Some(&Operator::Logical(LogicalOperator::And)));
+}
+
+#[test]
+fn term_positions() {
+    let tokens = tokenize("a * (b + -c)", true).unwrap();
+    let result = build_expression(&decorate_token(tokens).0).unwrap().postfix_expr;
+
+    // Operator terms cover their operands, and an operation in brackets covers the brackets as well
+    let positions: Vec<(usize, usize)> = result.iter().map(|t| (t.position.start, t.position.length)).collect();
+    assert_eq!(positions, vec![(0, 1), (5, 1), (10, 1), (9, 2), (4, 8), (0, 12)]);
+}
+
+#[test]
+fn member_access() {
+    let tokens = tokenize("a.b.c + 1", true).unwrap();
+    let result = build_expression_tree(&decorate_token(tokens).0).unwrap();
+
+    match result.content {
+        NodeContent::Binary(_, left, _) => match left.content {
+            NodeContent::Member(target, member) => {
+                assert_eq!(member, Identifier::single("c"));
+                assert!(matches!(target.content, NodeContent::Member(_, _)));
+            }
+            _ => panic!("A member access is expected"),
+        },
+        _ => panic!("An addition is expected"),
+    }
+}
+
+#[test]
+fn invalid_expression() {
+    for source in ["1 +", "(1 + 2", "1 2", "* 3", "a.", "a.b(1)", "(a)(1)"] {
+        let result = build_expression(&decorate_token(tokenize(source, true).unwrap()).0);
+        assert!(result.is_err(), "{} should be reported", source);
+    }
 }
@@ -1,7 +1,7 @@
 use crate::lexer::tokenize::tokenize;
-use crate::parser::builder::group::declaration::group_declaration_builder;
-use crate::parser::builder::group::implementation::group_implementation_builder;
 use crate::parser::decorator::decorate_token;
+use crate::parser::pipeline::build_whole_file;
+use crate::shared::utils::identifier::Identifier;
 use lazy_static::lazy_static;
 
 lazy_static! {
@@ -33,7 +33,7 @@ lazy_static! {
 fn declarator() {
     let tokens = decorate_token(tokenize(&DECLARATOR, true).unwrap()).0;
 
-    let group_result = group_declaration_builder(&tokens).unwrap().0;
+    let group_result = build_whole_file(tokens, Identifier::empty()).unwrap().declared_groups.remove(0);
 
     assert_eq!(group_result.identifier.name, "Arc".to_string());
     assert_eq!(group_result.fields.len(), 2);
@@ -43,13 +43,11 @@ fn declarator() {
 
 #[test]
 fn implementer() {
-    let tokens = decorate_token(tokenize(&IMPLEMENTER, true).unwrap()).0;
-    let declarator_token = decorate_token(tokenize(&DECLARATOR, true).unwrap()).0;
-    let declarator = group_declaration_builder(&declarator_token).unwrap().0;
+    let source = format!("{}\n{}", *DECLARATOR, *IMPLEMENTER);
+    let mut structure = build_whole_file(decorate_token(tokenize(&source, true).unwrap()).0, Identifier::empty()).unwrap();
+    let declarator = structure.declared_groups.remove(0);
 
-    let group_result = group_implementation_builder(&tokens, &vec![declarator.clone()])
-        .unwrap()
-        .0;
+    let group_result = structure.declared_implementations.remove(0);
 
     assert_eq!(group_result.source_group, declarator.identifier);
 
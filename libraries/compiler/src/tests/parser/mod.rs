@@ -2,3 +2,4 @@ mod blocks;
 mod code_file;
 mod expression;
 mod group;
+mod recovery;
@@ -0,0 +1,79 @@
+use crate::lexer::tokenize::tokenize;
+use crate::parser::decorator::decorate_token;
+use crate::parser::pipeline::{build_action_block, build_whole_file};
+use crate::shared::ast::blocks::expression::ExprDataTerm;
+use crate::shared::utils::identifier::Identifier;
+
+#[test]
+fn every_statement_issue() {
+    let source = "decl var number a; a = ; a = 1; decl number b; call f(1;";
+    let result = build_action_block(&decorate_token(tokenize(source, true).unwrap()).0);
+
+    assert!(result.is_err());
+    let issues = result.unwrap_err().issues;
+    assert_eq!(issues.len(), 3);
+    // `;` where the expression is expected
+    assert_eq!(issues[0].detail.location.start_pos, 23);
+    // `number` where `var` or `const` is expected
+    assert_eq!(issues[1].detail.location.start_pos, 37);
+    // `;` where `)` is expected
+    assert_eq!(issues[2].detail.location.start_pos, 55);
+}
+
+#[test]
+fn every_function_issue() {
+    let source = "decl func f()[number] { return 1 }\n\
+                  decl func g(number)[none] { return; }\n\
+                  decl func main()[number] { while (1 <) { } return 0; }\n\
+                  decl func h()[none] { }";
+    let result = build_whole_file(decorate_token(tokenize(source, true).unwrap()).0, Identifier::single("main"));
+
+    assert!(result.is_err());
+    let issues = result.unwrap_err().issues;
+    assert_eq!(issues.len(), 3);
+    assert_eq!(issues.iter().map(|i| i.code.as_str()).collect::<Vec<_>>(), vec!["0001", "0001", "0001"]);
+    // The `}` after `return 1`, `)` after the parameter type and `)` after the relation operator
+    assert_eq!(issues[0].detail.location.start_pos, 33);
+    assert_eq!(issues[1].detail.location.start_pos, 53);
+    assert_eq!(issues[2].detail.location.start_pos, 110);
+}
+
+#[test]
+fn unclosed_block() {
+    let source = "decl func main()[number] { return 0;";
+    let result = build_whole_file(decorate_token(tokenize(source, true).unwrap()).0, Identifier::single("main"));
+
+    assert!(result.is_err());
+    let issues = result.unwrap_err().issues;
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].detail.location.start_pos, source.len());
+}
+
+#[test]
+fn nested_call() {
+    let source = "return f(g(1, 2), 3);";
+    let actions = build_action_block(&decorate_token(tokenize(source, true).unwrap()).0).unwrap();
+
+    let value = actions[0].get_return_action().unwrap().value.clone().unwrap();
+    assert_eq!(value.postfix_expr.len(), 1);
+    match value.postfix_expr[0].content.get_data_term().unwrap() {
+        ExprDataTerm::FunctionCall(call) => {
+            assert_eq!(call.function_name, Identifier::single("f"));
+            assert_eq!(call.arguments.len(), 2);
+            assert_eq!(call.arguments[0].postfix_expr.len(), 1);
+        }
+        _ => panic!("A function call is expected"),
+    }
+}
+
+#[test]
+fn undeclared_group_member() {
+    let source = "group Arc { field number foo(get); }\n\
+                  impl Arc { field foo set { return; } method run()[none] { } }";
+    let result = build_whole_file(decorate_token(tokenize(source, true).unwrap()).0, Identifier::empty());
+
+    assert!(result.is_err());
+    let issues = result.unwrap_err().issues;
+    assert_eq!(issues.len(), 2);
+    assert!(issues.iter().all(|i| i.code == "0003"));
+}
@@ -1,8 +1,7 @@
 use crate::lexer::tokenize::tokenize;
 use crate::package_generator::availability_check::expression::expr_sequence::check_expression_sequence;
-use crate::parser::builder::expression_builder::expression_infix_to_postfix;
-use crate::parser::builder::expression_builder::expression_term_decorator;
 use crate::parser::decorator::decorate_token;
+use crate::parser::pipeline::build_expression;
 use crate::shared::ast::blocks::expression::SimpleExpression;
 use crate::shared::utils::identifier::Identifier;
 
@@ -10,18 +9,27 @@ use crate::shared::utils::identifier::Identifier;
 fn sequence() {
     // A legal expression
     let mut tokens = tokenize("1 * (2 + 3)", true).unwrap();
-    let mut expr = expression_infix_to_postfix(expression_term_decorator(&decorate_token(tokens).0));
+    let mut expr = build_expression(&decorate_token(tokens).0).unwrap().postfix_expr;
 
     assert!(check_expression_sequence(SimpleExpression {
         postfix_expr: expr.clone(),
         output_type: Identifier::empty()
     }));
 
-    // An illegal expression
-    tokens = tokenize("8 * (2 + 3) -", true).unwrap();
-    expr = expression_infix_to_postfix(expression_term_decorator(&decorate_token(tokens).0));
+    // An illegal expression, whose last operator is missing
+    tokens = tokenize("8 * (2 + 3)", true).unwrap();
+    expr = build_expression(&decorate_token(tokens).0).unwrap().postfix_expr;
+    expr.pop();
     assert!(!check_expression_sequence(SimpleExpression {
         postfix_expr: expr.clone(),
         output_type: Identifier::empty()
     }));
+
+    // A single term
+    tokens = tokenize("(8)", true).unwrap();
+    expr = build_expression(&decorate_token(tokens).0).unwrap().postfix_expr;
+    assert!(check_expression_sequence(SimpleExpression {
+        postfix_expr: expr.clone(),
+        output_type: Identifier::empty()
+    }));
 }
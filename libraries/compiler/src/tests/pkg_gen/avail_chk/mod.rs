@@ -1,2 +1,3 @@
 mod expression;
+mod package_check;
 mod variable;
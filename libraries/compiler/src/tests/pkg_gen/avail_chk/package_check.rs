@@ -0,0 +1,140 @@
+use crate::lexer::tokenize::tokenize;
+use crate::package_generator::availability_check::package_check::check_package;
+use crate::parser::decorator::decorate_token;
+use crate::parser::pipeline::build_whole_file;
+use crate::shared::utils::identifier::Identifier;
+
+fn check_issue_codes(source: &str) -> Vec<String> {
+    let (tokens, _) = decorate_token(tokenize(source, true).unwrap());
+    let tree = build_whole_file(tokens, Identifier::single("main")).ok().unwrap();
+
+    return match check_package(&tree) {
+        Ok(_) => vec![],
+        Err(e) => e.issues.iter().map(|i| i.code.clone()).collect(),
+    };
+}
+
+#[test]
+fn valid_package() {
+    let source = r#"
+        decl func main(number x)[number] {
+            decl var number result;
+            result = 0;
+            while (x > 0) {
+                result = result + twice(x);
+                x = x - 1;
+            }
+            decl var str s;
+            s = "Hello";
+            call log();
+            return result;
+        }
+
+        decl func twice(number x)[number] {
+            return x * 2;
+        }
+
+        decl func log()[none] {
+            return;
+        }
+    "#;
+
+    assert!(check_issue_codes(source).is_empty());
+}
+
+#[test]
+fn report_all_issues() {
+    let source = r#"
+        decl func main(number x)[number] {
+            decl var number r;
+            decl var number r;
+            y = 3;
+            r = "text";
+            r = twice(x, 2);
+            r = twice("a");
+            r = missing(1);
+            r = log();
+            if (x > 0) {
+                r = q;
+            }
+            return;
+        }
+
+        decl func twice(number x)[number] {
+            return x * 2;
+        }
+
+        decl func log()[none] {
+            return 1;
+        }
+    "#;
+
+    assert_eq!(check_issue_codes(source), vec![
+        "0102", "0103", "0105", "0107", "0107", "0106", "0105", "0103", "0108", "0108",
+    ]);
+}
+
+#[test]
+fn member_access() {
+    let source = r#"
+        decl func main(number x)[number] {
+            return -x + x.y;
+        }
+    "#;
+
+    assert_eq!(check_issue_codes(source), vec!["0109"]);
+}
+
+#[test]
+fn bool_values() {
+    let source = r#"
+        decl func main(number x, number y)[bool] {
+            decl var bool ok;
+            ok = x == y || !(x < y);
+            if (ok && x > 0) {
+                ok = y >= 1;
+            }
+            while (ok) {
+                ok = !ok;
+            }
+            return ok;
+        }
+    "#;
+    assert!(check_issue_codes(source).is_empty());
+
+    let source = r#"
+        decl func main(number x)[number] {
+            decl var bool ok;
+            ok = x + 1;
+            ok = !x;
+            ok = ok > ok;
+            if (x) {
+                x = 0;
+            }
+            return x;
+        }
+    "#;
+    assert_eq!(check_issue_codes(source), vec!["0105", "0105", "0105", "0105"]);
+}
+
+#[test]
+fn break_outside_iteration() {
+    let source = r#"
+        decl func main(number x)[number] {
+            while (x > 0) {
+                if (x == 3) {
+                    break;
+                }
+                x = x - 1;
+                continue;
+            }
+            if (x < 0) {
+                continue;
+            }
+            break;
+            return x;
+        }
+    "#;
+
+    assert_eq!(check_issue_codes(source), vec!["0110", "0110"]);
+}
@@ -1,7 +1,7 @@
 use crate::lexer::tokenize::tokenize;
 use crate::package_generator::command_builder::action_block::action_block_command_builder;
-use crate::parser::builder::blocks::action_block::action_block_builder;
 use crate::parser::decorator::decorate_token;
+use crate::parser::pipeline::build_action_block;
 use crate::shared::ast::action::ActionBlock;
 use crate::shared::package_generation::package_descriptor::PackageMetadata;
 
@@ -14,8 +14,8 @@ fn action_block_no_condition() {
                    foo = 1;\
                    bar = 2;\
                    res = foo + bar;", true).unwrap();
-    let actions = action_block_builder(
-        decorate_token(tokens).0,
+    let actions = build_action_block(
+        &decorate_token(tokens).0,
     ).unwrap();
 
     let metadata = PackageMetadata {
@@ -27,7 +27,7 @@ fn action_block_no_condition() {
         address_alignment: 4
     };
 
-    let _commands = action_block_command_builder(&ActionBlock { actions }, false, &vec![], &metadata);
+    let _commands = action_block_command_builder(&ActionBlock { actions }, false, &vec![], &metadata).unwrap();
 
     // for element in commands {
     //     print!("{},", format!("{:X}", element));
@@ -50,8 +50,8 @@ fn action_block_complete() {
                    }"
     , true).unwrap();
 
-    let actions = action_block_builder(
-        decorate_token(tokens).0,
+    let actions = build_action_block(
+        &decorate_token(tokens).0,
     ).unwrap();
 
     let metadata = PackageMetadata {
@@ -63,5 +63,5 @@ fn action_block_complete() {
         address_alignment: 8
     };
 
-    let _commands = action_block_command_builder(&ActionBlock { actions }, false, &vec![], &metadata);
+    let _commands = action_block_command_builder(&ActionBlock { actions }, false, &vec![], &metadata).unwrap();
 }
@@ -1,7 +1,7 @@
 use crate::lexer::tokenize::tokenize;
 use crate::package_generator::command_builder::assignment_action::build_assignment_command;
-use crate::parser::builder::blocks::assignment::assignment_block_builder;
 use crate::parser::decorator::decorate_token;
+use crate::parser::pipeline::build_action_block;
 use crate::shared::package_generation::data_descriptor::{DataDeclarator, DataLocation};
 use crate::shared::package_generation::package_descriptor::PackageMetadata;
 use crate::shared::utils::identifier::Identifier;
@@ -9,10 +9,9 @@ use crate::shared::utils::identifier::Identifier;
 #[test]
 fn simple_test() {
     let tokens = tokenize("t = 1 + 2 * 3;", true).unwrap();
-    let action = assignment_block_builder(&decorate_token(tokens).0)
+    let action = build_action_block(&decorate_token(tokens).0)
         .ok()
-        .unwrap()
-        .0
+        .unwrap()[0]
         .get_assignment_action()
         .unwrap()
         .clone();
@@ -33,7 +32,7 @@ fn simple_test() {
         is_string: false
     }];
 
-    let result = build_assignment_command(&action, &defined_data, &metadata);
+    let result = build_assignment_command(&action, &defined_data, &metadata).unwrap();
     assert_eq!(
         result.commands,
         vec![177, 0, 0, 0, 0, 0, 0, 0, 0, 1, 177, 0, 0, 0, 0, 0, 0, 0, 0, 2, 177, 0, 0, 0, 0, 0, 0, 0, 0, 3, 241, 3, 241, 1, 180, 1, 0, 0]
@@ -1,8 +1,7 @@
 use crate::lexer::tokenize::tokenize;
 use crate::package_generator::command_builder::expression_evaluation::build_expression_evaluation_command;
-use crate::parser::builder::expression_builder::expression_infix_to_postfix;
-use crate::parser::builder::expression_builder::expression_term_decorator;
 use crate::parser::decorator::decorate_token;
+use crate::parser::pipeline::build_expression;
 use crate::shared::ast::blocks::expression::SimpleExpression;
 use crate::shared::package_generation::data_descriptor::{DataDeclarator, DataLocation};
 use crate::shared::package_generation::package_descriptor::PackageMetadata;
@@ -13,7 +12,7 @@ use crate::shared::utils::identifier::Identifier;
 fn expression_with_number_only() {
     let tokens = tokenize("1 + 2 * 3", true).unwrap();
     let expression = SimpleExpression {
-        postfix_expr: expression_infix_to_postfix(expression_term_decorator(&decorate_token(tokens).0)),
+        postfix_expr: build_expression(&decorate_token(tokens).0).unwrap().postfix_expr,
         output_type: Identifier::single("number"),
     };
 
@@ -27,7 +26,7 @@ fn expression_with_number_only() {
     };
 
     // This is very abstract, needs to be validated
-    let result = build_expression_evaluation_command(&expression, &vec![], &metadata);
+    let result = build_expression_evaluation_command(&expression, &vec![], &metadata).unwrap();
     assert_eq!(
         result.commands,
         vec![177, 0, 0, 0, 0, 0, 0, 0, 0, 1, 177, 0, 0, 0, 0, 0, 0, 0, 0, 2, 177, 0, 0, 0, 0, 0, 0, 0, 0, 3, 241, 3, 241, 1]
@@ -40,7 +39,7 @@ fn expression_with_number_only() {
 fn expression_with_defined_data() {
     let tokens = tokenize("a + b * 2", true).unwrap();
     let expression = SimpleExpression {
-        postfix_expr: expression_infix_to_postfix(expression_term_decorator(&decorate_token(tokens).0)),
+        postfix_expr: build_expression(&decorate_token(tokens).0).unwrap().postfix_expr,
         output_type: Identifier::single("number"),
     };
 
@@ -68,7 +67,7 @@ fn expression_with_defined_data() {
         },
     ];
 
-    let result = build_expression_evaluation_command(&expression, &defined_data, &metadata);
+    let result = build_expression_evaluation_command(&expression, &defined_data, &metadata).unwrap();
     assert_eq!(
         result.commands,
         vec![178, 1, 0, 0, 178, 1, 0, 1, 177, 0, 0, 0, 0, 0, 0, 0, 0, 2, 241, 3, 241, 1]
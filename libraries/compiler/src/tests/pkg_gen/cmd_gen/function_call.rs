@@ -1,7 +1,7 @@
 use crate::lexer::tokenize::tokenize;
 use crate::package_generator::command_builder::function_call::build_function_call_command;
-use crate::parser::builder::blocks::call::call_action_builder;
 use crate::parser::decorator::decorate_token;
+use crate::parser::pipeline::build_action_block;
 use crate::shared::package_generation::data_descriptor::{DataDeclarator, DataLocation};
 use crate::shared::package_generation::package_descriptor::PackageMetadata;
 use crate::shared::utils::identifier::Identifier;
@@ -9,9 +9,8 @@ use crate::shared::utils::identifier::Identifier;
 #[test]
 fn function_call() {
     let tokens = tokenize("call foo(23, bar);", true).unwrap();
-    let action = call_action_builder(&decorate_token(tokens).0)
-        .unwrap()
-        .0
+    let action = build_action_block(&decorate_token(tokens).0)
+        .unwrap()[0]
         .get_call_action()
         .unwrap()
         .clone();
@@ -32,7 +31,7 @@ fn function_call() {
         is_string: false
     }];
 
-    let _commands = build_function_call_command(&action, &defined_data, &metadata);
+    let _commands = build_function_call_command(&action, &defined_data, &metadata).unwrap();
 
     // for element in commands {
     //     print!("{},", convert_number_to_hex(element.to_string()));
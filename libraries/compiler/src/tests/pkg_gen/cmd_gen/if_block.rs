@@ -1,7 +1,7 @@
 use crate::lexer::tokenize::tokenize;
 use crate::package_generator::command_builder::condition_command::if_command_builder;
-use crate::parser::builder::blocks::condition::if_block_builder;
 use crate::parser::decorator::decorate_token;
+use crate::parser::pipeline::build_action_block;
 use crate::shared::package_generation::package_descriptor::PackageMetadata;
 
 #[test]
@@ -17,7 +17,7 @@ fn bare_if_test() {
         address_alignment: 4
     };
 
-    let result = if_command_builder(&if_block_builder(&decorate_token(tokens).0).unwrap().0.get_if_action().unwrap(),  &vec![], &metadata);
+    let result = if_command_builder(&build_action_block(&decorate_token(tokens).0).unwrap()[0].get_if_action().unwrap(),  &vec![], &metadata).unwrap();
 
     assert_eq!(result.descriptors.targets.len(), 3);
 
@@ -37,9 +37,10 @@ fn complex_if_test() {
         address_alignment: 4
     };
 
-    let result = if_command_builder(&if_block_builder(&decorate_token(tokens).0).unwrap().0.get_if_action().unwrap(), &vec![], &metadata);
+    let result = if_command_builder(&build_action_block(&decorate_token(tokens).0).unwrap()[0].get_if_action().unwrap(), &vec![], &metadata).unwrap();
 
-    assert_eq!(result.descriptors.targets.len(), 8);
+    // Two comparisons and the jump out of the `if` body, the `elif` body has no empty `else` to jump over
+    assert_eq!(result.descriptors.targets.len(), 7);
 
     // println!("{}", itertools::Itertools::join(&mut result.commands.iter(), ", "));
 }
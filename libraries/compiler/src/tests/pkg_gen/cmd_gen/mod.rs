@@ -3,3 +3,4 @@ mod assignment;
 mod expression;
 mod function_call;
 mod if_block;
+mod relation;
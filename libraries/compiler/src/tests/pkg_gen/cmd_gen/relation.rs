@@ -0,0 +1,35 @@
+use crate::package_generator::command_builder::templates::jump_command::relation_jump_directions;
+use crate::shared::token::operator::RelationOperator;
+
+/// Relations are checked by the sign of `left - right`, which is left on the stack by evaluating `left` first.
+/// Jumping on the negative difference for `>` was the wrong polarity, `3 > 1` was false and `1 > 3` was true.
+#[test]
+fn relation_polarity() {
+    let relations = [
+        RelationOperator::Greater,
+        RelationOperator::GreaterOrEqual,
+        RelationOperator::Less,
+        RelationOperator::LessOrEqual,
+        RelationOperator::NotEqual,
+        RelationOperator::Equal,
+    ];
+
+    for relation in relations {
+        let (positive, negative, zero) = relation_jump_directions(&relation).unwrap();
+        for (left, right) in [(3, 1), (1, 3), (2, 2)] {
+            let difference: i64 = left - right;
+            let is_true = if difference > 0 { positive } else if difference < 0 { negative } else { zero };
+            let expected = match relation {
+                RelationOperator::Greater => left > right,
+                RelationOperator::GreaterOrEqual => left >= right,
+                RelationOperator::Less => left < right,
+                RelationOperator::LessOrEqual => left <= right,
+                RelationOperator::NotEqual => left != right,
+                _ => left == right,
+            };
+            assert_eq!(is_true, expected, "{} {:?} {}", left, relation, right);
+        }
+    }
+
+    assert!(relation_jump_directions(&RelationOperator::Invalid).is_err());
+}
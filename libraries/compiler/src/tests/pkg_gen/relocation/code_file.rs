@@ -102,11 +102,11 @@ fn code_file() {
 
     for func in &structure.functions {
         result.function_table.iter_mut().find(|f| f.name == func.declarator.identifier).unwrap().relocated_entry_address = result.commands.len();
-        result.combine(build_function_command(func, &metadata));
+        result.combine(build_function_command(func, &metadata).unwrap());
     }
 
-    result.calculate_ref_to_target();
-    result.apply_relocation(metadata.address_alignment);
+    result.calculate_ref_to_target().unwrap();
+    result.apply_relocation(metadata.address_alignment).unwrap();
 
     // let mut file = std::fs::File::create("F:\\test.cbp").unwrap();
     // file.write_all(metadata.serialize().as_slice()).unwrap();
@@ -1,8 +1,8 @@
 // use std::io::Write;
 use crate::lexer::tokenize::tokenize;
 use crate::package_generator::command_builder::condition_command::while_command_builder;
-use crate::parser::builder::blocks::loops::while_action_builder;
 use crate::parser::decorator::decorate_token;
+use crate::parser::pipeline::build_action_block;
 use crate::shared::package_generation::package_descriptor::PackageMetadata;
 
 #[test]
@@ -18,10 +18,10 @@ fn simple_while_test() {
         address_alignment: 4,
     };
 
-    let mut result = while_command_builder(&while_action_builder(&decorate_token(tokens).0).unwrap().0.get_while_block().unwrap(), &vec![], &metadata);
+    let mut result = while_command_builder(&build_action_block(&decorate_token(tokens).0).unwrap()[0].get_while_block().unwrap(), &vec![], &metadata).unwrap();
 
-    result.calculate_ref_to_target();
-    result.apply_relocation(metadata.address_alignment);
+    result.calculate_ref_to_target().unwrap();
+    result.apply_relocation(metadata.address_alignment).unwrap();
 
     // let mut file = std::fs::File::create("F:\\test.cbp").unwrap();
     // file.write_all(result.commands.as_slice()).unwrap();
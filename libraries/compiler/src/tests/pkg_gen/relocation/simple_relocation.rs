@@ -2,8 +2,8 @@
 
 use crate::lexer::tokenize::tokenize;
 use crate::package_generator::command_builder::action_block::action_block_command_builder;
-use crate::parser::builder::blocks::action_block::action_block_builder;
 use crate::parser::decorator::decorate_token;
+use crate::parser::pipeline::build_action_block;
 use crate::shared::ast::action::ActionBlock;
 use crate::shared::package_generation::package_descriptor::PackageMetadata;
 
@@ -30,7 +30,7 @@ fn no_function_relocation() {
     )
     .unwrap();
 
-    let actions = action_block_builder(decorate_token(tokens).0).unwrap();
+    let actions = build_action_block(&decorate_token(tokens).0).unwrap();
 
     let metadata = PackageMetadata {
         data_slot_alignment: 2,
@@ -42,13 +42,13 @@ fn no_function_relocation() {
     };
 
     let mut target =
-        action_block_command_builder(&ActionBlock { actions }, false, &vec![], &metadata);
+        action_block_command_builder(&ActionBlock { actions }, false, &vec![], &metadata).unwrap();
 
     // Write file
     // let mut file = std::fs::File::create("F:\\test.cbp").unwrap();
 
     // let mut bytes = metadata.serialize();
-    target.calculate_ref_to_target();
+    target.calculate_ref_to_target().unwrap();
     // target.apply_relocation(metadata.address_alignment);
 
     // bytes.extend(target.commands.clone());
@@ -27,14 +27,14 @@ fn single_function() {
         address_alignment: 4,
     };
 
-    let mut target = build_function_command(&tree.functions[0], &metadata);
+    let mut target = build_function_command(&tree.functions[0], &metadata).unwrap();
 
     // Write file
     // let mut file = std::fs::File::create("F:\\test.cbp").unwrap();
 
     // let bytes = metadata.serialize();
-    target.calculate_ref_to_target();
-    target.apply_relocation(metadata.address_alignment);
+    target.calculate_ref_to_target().unwrap();
+    target.apply_relocation(metadata.address_alignment).unwrap();
 
     // bytes.extend(target.commands.clone());
     // file.write_all(bytes.as_slice()).unwrap();
@@ -79,15 +79,15 @@ fn multiple_functions() {
 
     target.function_table = tree.export_function_table();
     for func in &tree.functions {
-        target.combine(build_function_command(func, &metadata));
+        target.combine(build_function_command(func, &metadata).unwrap());
     }
 
     // Write file
     // let mut file = std::fs::File::create("F:\\test.cbp").unwrap();
 
     // let bytes = metadata.serialize();
-    target.calculate_ref_to_target();
-    target.apply_relocation(metadata.address_alignment);
+    target.calculate_ref_to_target().unwrap();
+    target.apply_relocation(metadata.address_alignment).unwrap();
 
     // bytes.extend(target.commands.clone());
     // file.write_all(bytes.as_slice()).unwrap();
@@ -1,9 +1,8 @@
 use crate::lexer::tokenize::tokenize;
 use crate::package_generator::type_inference::expression::infer_expression_output_type;
 use crate::package_generator::utils::infer_every_expression_data_term_type;
-use crate::parser::builder::expression_builder::expression_infix_to_postfix;
-use crate::parser::builder::expression_builder::expression_term_decorator;
 use crate::parser::decorator::decorate_token;
+use crate::parser::pipeline::build_expression;
 use crate::shared::ast::action::VariableDefinition;
 use crate::shared::ast::blocks::expression::SimpleExpression;
 use crate::shared::utils::identifier::Identifier;
@@ -12,7 +11,7 @@ use crate::shared::utils::identifier::Identifier;
 fn expression_data_type() {
     let tokens = tokenize("1 + 2 - 3.55", true).unwrap();
     let mut expr = SimpleExpression {
-        postfix_expr: expression_infix_to_postfix(expression_term_decorator(&decorate_token(tokens).0)),
+        postfix_expr: build_expression(&decorate_token(tokens).0).unwrap().postfix_expr,
         output_type: Identifier::empty(),
     };
 
@@ -37,3 +36,19 @@ fn expression_data_type() {
         Identifier::single("number")
     );
 }
+
+#[test]
+fn negation_type() {
+    let defined_types = vec![Identifier::single("number"), Identifier::single("str")];
+    let infer = |source: &str| {
+        let expr = SimpleExpression {
+            postfix_expr: build_expression(&decorate_token(tokenize(source, true).unwrap()).0).unwrap().postfix_expr,
+            output_type: Identifier::empty(),
+        };
+
+        return infer_expression_output_type(&infer_every_expression_data_term_type(&expr, &vec![], &vec![]), &defined_types);
+    };
+
+    assert_eq!(infer("-(1 - 2) * -3"), Some(Identifier::single("number")));
+    assert_eq!(infer("-\"a\""), None);
+}
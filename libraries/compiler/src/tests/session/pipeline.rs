@@ -0,0 +1,128 @@
+use crate::package_reader::layout_reader::read_package_layout;
+use crate::shared::error::general_issue::IssuePosition;
+use crate::shared::package_generation::implementations::package_descriptor::LIBRARY_PACKAGE_TYPE;
+use crate::shared::session::compile_session::{CompileOptions, Session};
+use crate::session::pipeline::compile_source;
+
+const MAIN: &str = r#"link "lib/math.cbs";
+
+decl func main()[number] {
+    return twice(4);
+}
+"#;
+
+const MATH: &str = r#"link util;
+
+decl func twice(number x)[number] {
+    return x * 2;
+}
+"#;
+
+const UTIL: &str = "decl func nothing()[number] {\n    return 0;\n}\n";
+
+#[test]
+fn linked_sources() {
+    let mut session = Session::new(CompileOptions::default());
+    session.add_source("app/main.cbs", MAIN);
+    session.add_source("app/lib/math.cbs", MATH);
+    // Not found next to `math.cbs`, but in the project root
+    session.add_source("app/util.cbs", UTIL);
+    session.options.project_root = "app".to_string();
+
+    let layout = read_package_layout(&session.compile().ok().unwrap()).ok().unwrap();
+    assert_eq!(layout.function_table.len(), 3);
+    assert!(layout.debug_info.is_none());
+
+    session.options.project_root = String::new();
+    let result = session.compile();
+    assert!(result.is_err());
+    let issues = result.unwrap_err().issues;
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].detail.content, "Couldn't resolve link util in \"app/lib/math.cbs\"");
+    assert_eq!(issues[0].detail.location.as_ref().unwrap().file_path, "app/lib/math.cbs");
+}
+
+#[test]
+fn replace_source() {
+    let mut session = Session::new(CompileOptions::default());
+    session.add_source("main.cbs", "decl func main()[number] {\n    return $;\n}\n");
+    let result = session.check();
+    assert!(result.is_err());
+    let issue = &result.unwrap_err().issues[0];
+    assert!(matches!(issue.position, IssuePosition::LexicalAnalysis));
+    assert_eq!(issue.detail.location.as_ref().unwrap().file_path, "main.cbs");
+
+    session.add_source("main.cbs", UTIL.replace("nothing", "main").as_str());
+    assert_eq!(session.sources.len(), 1);
+    assert!(session.check().is_ok());
+}
+
+#[test]
+fn package_issues() {
+    let result = compile_source("util.cbs", UTIL, CompileOptions::default());
+    assert!(result.is_err());
+    let issue = &result.unwrap_err().issues[0];
+    assert_eq!(issue.detail.content, "Entry function \"main\" is not found");
+    assert_eq!(issue.code, "-1");
+
+    let mut options = CompileOptions::default();
+    options.metadata.package_type = LIBRARY_PACKAGE_TYPE;
+    assert!(compile_source("util.cbs", UTIL, options.clone()).is_ok());
+
+    // Functions of every source share one function table
+    let mut session = Session::new(options);
+    session.add_source("util.cbs", UTIL);
+    session.add_source("copy.cbs", UTIL);
+    let result = session.check();
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().issues[0].detail.content, "Function \"nothing\" is declared more than once");
+
+    let result = compile_source("main.cbs", "decl func main()[number] {\n    return x;\n}\n", CompileOptions::default());
+    assert!(result.is_err());
+    let issue = &result.unwrap_err().issues[0];
+    assert!(matches!(issue.position, IssuePosition::CodeGeneration));
+    assert_eq!(issue.detail.location.as_ref().unwrap().file_path, "main.cbs");
+}
+
+#[test]
+fn debug_section() {
+    let options = CompileOptions { debug: true, ..CompileOptions::default() };
+
+    let layout = read_package_layout(&compile_source("main.cbs", UTIL.replace("nothing", "main").as_str(), options).ok().unwrap()).ok().unwrap();
+    let debug_info = layout.debug_info.unwrap();
+    assert_eq!(debug_info.files, vec!["main.cbs"]);
+    assert_eq!(debug_info.location_at(debug_info.line_table[0].address).unwrap(), "main.cbs:2:5");
+}
+
+#[test]
+fn code_generation_issues() {
+    let source = "decl func main()[number] {\n    return 99999999999999999999999;\n}\n";
+    let result = compile_source("main.cbs", source, CompileOptions::default());
+    assert!(result.is_err());
+    let issue = &result.unwrap_err().issues[0];
+    assert_eq!(issue.detail.content, "Number 99999999999999999999999 is out of range");
+    let location = issue.detail.location.as_ref().unwrap();
+    assert_eq!((location.file_path.as_str(), location.start_pos, location.end_pos), ("main.cbs", 31, 62));
+
+    // Numbers are stored in `data_alignment` bytes
+    let mut options = CompileOptions::default();
+    options.metadata.data_alignment = 1;
+    let result = compile_source("main.cbs", source.replace("99999999999999999999999", "300").as_str(), options);
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().issues[0].detail.content, "Number 300 doesn't fit in 1 bytes, consider changing the alignment into a longer width");
+}
+
+#[test]
+fn prebuilt_package() {
+    let mut session = Session::new(CompileOptions::default());
+    session.add_source("main.cbs", UTIL.replace("nothing", "main").as_str());
+    let (mut tree, string_pool) = session.analyze().ok().unwrap();
+    assert!(session.analyze_package(&tree).is_ok());
+    assert!(session.compile_package(&tree, string_pool.clone()).is_ok());
+
+    // Structures changed by tools are checked like parsed ones
+    tree.functions[0].declarator.identifier.name = "other".to_string();
+    let result = session.analyze_package(&tree);
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().issues[0].detail.content, "Entry function \"main\" is not found");
+}
@@ -0,0 +1,5 @@
+pub mod machine;
+pub mod models;
+
+#[cfg(test)]
+mod tests;
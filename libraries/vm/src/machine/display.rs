@@ -0,0 +1,19 @@
+use std::fmt::{Display, Formatter};
+
+use crate::models::runtime_issue::RuntimeIssue;
+use crate::models::value::Value;
+
+impl Display for Value {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Number(x) => write!(f, "{}", x),
+            Value::String(x) => write!(f, "\"{}\"", x),
+        }
+    }
+}
+
+impl Display for RuntimeIssue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} (at byte 0x{:X})", self.content, self.position)
+    }
+}
@@ -0,0 +1,276 @@
+use carbon_lang_compiler::package_reader::instruction_decoder::decode_instruction;
+use carbon_lang_compiler::package_reader::layout_reader::read_package_layout;
+use carbon_lang_compiler::shared::command_map::{MathCalcCommand, MathLogicalCommand};
+use carbon_lang_compiler::shared::error::general_issue::GeneralIssue;
+use carbon_lang_compiler::shared::error::package_reading_issue::PackageReadingIssue;
+use carbon_lang_compiler::shared::package_reading::instruction::{DataAccess, DecodedInstruction, Instruction};
+use carbon_lang_compiler::shared::package_reading::package_layout::PackageLayout;
+
+use crate::models::call_frame::CallFrame;
+use crate::models::runtime_issue::RuntimeIssue;
+use crate::models::value::Value;
+use crate::models::virtual_machine::{MachineState, VirtualMachine, DEFAULT_MAX_CALL_DEPTH};
+
+impl RuntimeIssue {
+    pub fn new(content: &str, position: usize) -> RuntimeIssue {
+        return RuntimeIssue { content: content.to_string(), position };
+    }
+
+    pub fn from_reading_issue(issue: GeneralIssue<PackageReadingIssue>) -> RuntimeIssue {
+        let detail = issue.issues[0].detail.clone();
+        return RuntimeIssue { content: detail.content, position: detail.position };
+    }
+}
+
+impl VirtualMachine {
+    pub fn new(layout: PackageLayout) -> VirtualMachine {
+        return VirtualMachine {
+            layout,
+            program_counter: 0,
+            stack: vec![],
+            frames: vec![],
+            state: MachineState::Ready,
+            max_call_depth: DEFAULT_MAX_CALL_DEPTH,
+        };
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<VirtualMachine, RuntimeIssue> {
+        let layout = read_package_layout(bytes).map_err(RuntimeIssue::from_reading_issue)?;
+        return Ok(VirtualMachine::new(layout));
+    }
+
+    /// Prepare to execute the entry function of an executable package
+    pub fn start(&mut self, arguments: Vec<Value>) -> Result<(), RuntimeIssue> {
+        let entry_point = self.layout.entry_point;
+        if entry_point.is_none() {
+            return Err(RuntimeIssue::new("Package is not an executable", 0));
+        }
+
+        return self.start_at(entry_point.unwrap(), arguments);
+    }
+
+    /// Prepare to execute the function whose first command is at `entry_address`
+    pub fn start_at(&mut self, entry_address: usize, arguments: Vec<Value>) -> Result<(), RuntimeIssue> {
+        if entry_address >= self.layout.commands.len() {
+            return Err(RuntimeIssue::new("Entry address is out of package", entry_address));
+        }
+
+        self.stack = vec![];
+        self.frames = vec![CallFrame {
+            entry_address,
+            return_address: None,
+            locals: arguments.into_iter().map(Some).collect(),
+            stack_base: 0,
+        }];
+        self.program_counter = entry_address;
+        self.state = MachineState::Running;
+
+        return Ok(());
+    }
+
+    /// Execute the entry function until it returns
+    pub fn run(&mut self, arguments: Vec<Value>) -> Result<Option<Value>, RuntimeIssue> {
+        self.start(arguments)?;
+
+        return self.run_to_end();
+    }
+
+    pub fn run_to_end(&mut self) -> Result<Option<Value>, RuntimeIssue> {
+        loop {
+            if let MachineState::Finished(value) = &self.state {
+                return Ok(value.clone());
+            }
+
+            self.step()?;
+        }
+    }
+
+    pub fn current_instruction(&self) -> Result<DecodedInstruction, RuntimeIssue> {
+        return decode_instruction(&self.layout.commands, self.program_counter, &self.layout.metadata)
+            .map_err(RuntimeIssue::from_reading_issue);
+    }
+
+    /// Execute a single command
+    pub fn step(&mut self) -> Result<(), RuntimeIssue> {
+        if self.state != MachineState::Running {
+            return Err(RuntimeIssue::new("Virtual machine is not running", self.program_counter));
+        }
+
+        let decoded = self.current_instruction()?;
+        let position = decoded.position;
+        let next = position + decoded.length;
+
+        match decoded.instruction {
+            // Local slots are allocated when they are written for the first time
+            Instruction::CreateObject { .. } => {}
+            Instruction::DestroyObject { slot } => {
+                let frame = self.current_frame_mut();
+                if slot < frame.locals.len() {
+                    frame.locals[slot] = None;
+                }
+            }
+            Instruction::Push(dac) | Instruction::PushFromObject(dac) => {
+                let value = self.read_data(&dac, position)?;
+                self.stack.push(value);
+            }
+            Instruction::Pop => {
+                self.pop_value(position)?;
+            }
+            Instruction::PopToObject(dac) => {
+                let value = self.pop_value(position)?;
+                match dac {
+                    DataAccess::LocalSlot(slot) => {
+                        let frame = self.current_frame_mut();
+                        if slot >= frame.locals.len() {
+                            frame.locals.resize(slot + 1, None);
+                        }
+                        frame.locals[slot] = Some(value);
+                    }
+                    _ => return Err(RuntimeIssue::new("Only local slots could be assigned", position)),
+                }
+            }
+            Instruction::JumpToRelative(offset) => {
+                self.program_counter = self.jump_target(position, offset)?;
+                return Ok(());
+            }
+            Instruction::JumpByStackTop { positive, negative, zero } => {
+                let value = self.pop_value(position)?;
+                let number = value.as_number();
+                if number.is_none() {
+                    return Err(RuntimeIssue::new(format!("Unable to compare {} with zero", value).as_str(), position));
+                }
+
+                let offset = match number.unwrap() {
+                    x if x > 0 => positive,
+                    x if x < 0 => negative,
+                    _ => zero,
+                };
+                self.program_counter = self.jump_target(position, offset)?;
+                return Ok(());
+            }
+            Instruction::EnterFunction { slot, argument_count } => {
+                let function = self.layout.function_table.iter().find(|f| f.slot == slot);
+                if function.is_none() {
+                    return Err(RuntimeIssue::new(format!("Function slot {} is not found", slot).as_str(), position));
+                }
+                let entry_address = function.unwrap().entry_address;
+
+                if self.frames.len() >= self.max_call_depth {
+                    return Err(RuntimeIssue::new("Call stack overflow", position));
+                }
+
+                // Arguments are pushed in reverse order, so the first one is on the top
+                let mut locals = vec![];
+                for _ in 0..argument_count {
+                    locals.push(Some(self.pop_value(position)?));
+                }
+
+                self.frames.push(CallFrame {
+                    entry_address,
+                    return_address: Some(next),
+                    locals,
+                    stack_base: self.stack.len(),
+                });
+                self.program_counter = entry_address;
+                return Ok(());
+            }
+            Instruction::LeaveWithValue => {
+                let value = self.pop_value(position)?;
+                self.leave_function(Some(value));
+                return Ok(());
+            }
+            Instruction::LeaveWithoutValue | Instruction::FunctionEndFlag => {
+                self.leave_function(None);
+                return Ok(());
+            }
+            Instruction::Calculation(MathCalcCommand::Inverse) => {
+                let value = self.pop_value(position)?;
+                let result = value.inverse().map_err(|e| RuntimeIssue::new(e.as_str(), position))?;
+                self.stack.push(result);
+            }
+            Instruction::Calculation(command) => {
+                let right = self.pop_value(position)?;
+                let left = self.pop_value(position)?;
+                let result = Value::calculate(&left, &right, &command).map_err(|e| RuntimeIssue::new(e.as_str(), position))?;
+                self.stack.push(result);
+            }
+            Instruction::Logical(MathLogicalCommand::Not) => {
+                let value = self.pop_value(position)?;
+                let result = value.not().map_err(|e| RuntimeIssue::new(e.as_str(), position))?;
+                self.stack.push(result);
+            }
+            Instruction::Logical(command) => {
+                let right = self.pop_value(position)?;
+                let left = self.pop_value(position)?;
+                let result = Value::logical(&left, &right, &command).map_err(|e| RuntimeIssue::new(e.as_str(), position))?;
+                self.stack.push(result);
+            }
+        }
+
+        self.program_counter = next;
+        return Ok(());
+    }
+
+    pub fn current_frame(&self) -> &CallFrame {
+        return self.frames.last().unwrap();
+    }
+
+    /// `file:line:column` of the command at `position`, only known by packages with a debug section
+    pub fn source_location(&self, position: usize) -> Option<String> {
+        return self.layout.debug_info.as_ref().and_then(|d| d.location_at(position));
+    }
+
+    fn current_frame_mut(&mut self) -> &mut CallFrame {
+        return self.frames.last_mut().unwrap();
+    }
+
+    fn leave_function(&mut self, value: Option<Value>) {
+        let frame = self.frames.pop().unwrap();
+        // Drop values the callee left on the stack
+        self.stack.truncate(frame.stack_base);
+
+        match frame.return_address {
+            Some(address) => {
+                if let Some(v) = value {
+                    self.stack.push(v);
+                }
+                self.program_counter = address;
+            }
+            None => {
+                self.state = MachineState::Finished(value);
+            }
+        }
+    }
+
+    fn pop_value(&mut self, position: usize) -> Result<Value, RuntimeIssue> {
+        if self.stack.len() <= self.current_frame().stack_base {
+            return Err(RuntimeIssue::new("Stack underflow", position));
+        }
+
+        return Ok(self.stack.pop().unwrap());
+    }
+
+    fn read_data(&self, dac: &DataAccess, position: usize) -> Result<Value, RuntimeIssue> {
+        return match dac {
+            DataAccess::InstantValue(x) => Ok(Value::Number(*x)),
+            DataAccess::LocalSlot(slot) => {
+                let value = self.current_frame().locals.get(*slot).cloned().flatten();
+                value.ok_or_else(|| RuntimeIssue::new(format!("Local slot {} is not assigned", slot).as_str(), position))
+            }
+            DataAccess::StringSlot(slot) => {
+                let constant = self.layout.string_pool.iter().find(|s| s.slot == *slot);
+                constant.map(|s| Value::String(s.value.clone()))
+                        .ok_or_else(|| RuntimeIssue::new(format!("String slot {} is not found", slot).as_str(), position))
+            }
+        };
+    }
+
+    fn jump_target(&self, position: usize, offset: i64) -> Result<usize, RuntimeIssue> {
+        let target = position as i64 + offset;
+        if target < 0 || target as usize >= self.layout.commands.len() {
+            return Err(RuntimeIssue::new("Jump target is out of package", position));
+        }
+
+        return Ok(target as usize);
+    }
+}
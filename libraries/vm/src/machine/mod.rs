@@ -0,0 +1,3 @@
+pub mod display;
+pub mod execution;
+pub mod value;
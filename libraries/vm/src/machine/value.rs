@@ -0,0 +1,86 @@
+use carbon_lang_compiler::shared::command_map::{MathCalcCommand, MathLogicalCommand};
+
+use crate::models::value::Value;
+
+impl Value {
+    pub fn as_number(&self) -> Option<i64> {
+        return match self {
+            Value::Number(x) => Some(*x),
+            Value::String(_) => None,
+        };
+    }
+
+    /// Strings without quotes
+    pub fn to_plain_string(&self) -> String {
+        return match self {
+            Value::Number(x) => x.to_string(),
+            Value::String(x) => x.clone(),
+        };
+    }
+
+    pub fn from_bool(value: bool) -> Value {
+        return Value::Number(value as i64);
+    }
+
+    /// `left` is the 2nd top value on the stack and `right` is the top one
+    pub fn calculate(left: &Value, right: &Value, command: &MathCalcCommand) -> Result<Value, String> {
+        // Strings could only be concatenated
+        if *command == MathCalcCommand::Plus && (left.as_number().is_none() || right.as_number().is_none()) {
+            return Ok(Value::String(format!("{}{}", left.to_plain_string(), right.to_plain_string())));
+        }
+
+        let (l, r) = match (left.as_number(), right.as_number()) {
+            (Some(l), Some(r)) => (l, r),
+            _ => return Err(format!("Unable to calculate {:?} between {} and {}", command, left, right)),
+        };
+
+        let result = match command {
+            MathCalcCommand::Plus => l.checked_add(r),
+            MathCalcCommand::Minus => l.checked_sub(r),
+            MathCalcCommand::Times => l.checked_mul(r),
+            MathCalcCommand::Divide => {
+                if r == 0 {
+                    return Err("Division by zero".to_string());
+                }
+                l.checked_div(r)
+            }
+            MathCalcCommand::Mod => {
+                if r == 0 {
+                    return Err("Division by zero".to_string());
+                }
+                l.checked_rem(r)
+            }
+            MathCalcCommand::Inverse => return Err("`Inverse` only takes one operand".to_string()),
+        };
+
+        return result.map(Value::Number).ok_or_else(|| "Arithmetic overflow".to_string());
+    }
+
+    pub fn inverse(&self) -> Result<Value, String> {
+        return match self.as_number() {
+            Some(x) => x.checked_neg().map(Value::Number).ok_or_else(|| "Arithmetic overflow".to_string()),
+            None => Err(format!("Unable to inverse {}", self)),
+        };
+    }
+
+    /// Non-zero numbers are true
+    pub fn logical(left: &Value, right: &Value, command: &MathLogicalCommand) -> Result<Value, String> {
+        let (l, r) = match (left.as_number(), right.as_number()) {
+            (Some(l), Some(r)) => (l != 0, r != 0),
+            _ => return Err(format!("Unable to apply {:?} between {} and {}", command, left, right)),
+        };
+
+        return match command {
+            MathLogicalCommand::And => Ok(Value::from_bool(l && r)),
+            MathLogicalCommand::Or => Ok(Value::from_bool(l || r)),
+            MathLogicalCommand::Not => Err("`Not` only takes one operand".to_string()),
+        };
+    }
+
+    pub fn not(&self) -> Result<Value, String> {
+        return match self.as_number() {
+            Some(x) => Ok(Value::from_bool(x == 0)),
+            None => Err(format!("Unable to apply Not to {}", self)),
+        };
+    }
+}
@@ -0,0 +1,13 @@
+use crate::models::value::Value;
+
+#[derive(Debug, Clone)]
+pub struct CallFrame {
+    // Absolute offset of the first command of the function
+    pub entry_address: usize,
+    // Where to continue after leaving the function, `None` for the entry function
+    pub return_address: Option<usize>,
+    // Local slots grow on demand, `None` means the slot is not assigned yet
+    pub locals: Vec<Option<Value>>,
+    // Values below this index belong to callers
+    pub stack_base: usize,
+}
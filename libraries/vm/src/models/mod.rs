@@ -0,0 +1,4 @@
+pub mod call_frame;
+pub mod runtime_issue;
+pub mod value;
+pub mod virtual_machine;
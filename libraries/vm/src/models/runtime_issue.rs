@@ -0,0 +1,6 @@
+#[derive(Debug, Clone)]
+pub struct RuntimeIssue {
+    pub content: String,
+    // Offset of the command being executed
+    pub position: usize,
+}
@@ -0,0 +1,5 @@
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Number(i64),
+    String(String),
+}
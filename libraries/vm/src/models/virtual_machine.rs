@@ -0,0 +1,24 @@
+use carbon_lang_compiler::shared::package_reading::package_layout::PackageLayout;
+
+use crate::models::call_frame::CallFrame;
+use crate::models::value::Value;
+
+pub const DEFAULT_MAX_CALL_DEPTH: usize = 1024;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MachineState {
+    Ready,
+    Running,
+    // Carries the return value of the outermost function
+    Finished(Option<Value>),
+}
+
+#[derive(Debug, Clone)]
+pub struct VirtualMachine {
+    pub layout: PackageLayout,
+    pub program_counter: usize,
+    pub stack: Vec<Value>,
+    pub frames: Vec<CallFrame>,
+    pub state: MachineState,
+    pub max_call_depth: usize,
+}
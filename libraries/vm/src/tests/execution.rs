@@ -0,0 +1,321 @@
+use carbon_lang_compiler::assembler::encoder::assemble_package;
+use carbon_lang_compiler::lexer::tokenize::tokenize;
+use carbon_lang_compiler::linker::package_linker::link_packages;
+use carbon_lang_compiler::package_generator::package_builder::{build_package, build_package_with_debug_info};
+use carbon_lang_compiler::parser::decorator::decorate_token;
+use carbon_lang_compiler::parser::pipeline::build_whole_file;
+use carbon_lang_compiler::shared::package_generation::package_descriptor::PackageMetadata;
+use carbon_lang_compiler::shared::utils::identifier::Identifier;
+use carbon_lang_compiler::shared::utils::source_map::SourceMap;
+
+use crate::models::value::Value;
+use crate::models::virtual_machine::VirtualMachine;
+
+fn compile(source: &str) -> Vec<u8> {
+    return compile_with_type(source, 0);
+}
+
+fn compile_with_type(source: &str, package_type: u8) -> Vec<u8> {
+    let (tokens, string_pool) = decorate_token(tokenize(source, true).unwrap());
+    let tree = build_whole_file(tokens, Identifier::single("main")).ok().unwrap();
+
+    let metadata = PackageMetadata {
+        data_slot_alignment: 2,
+        data_alignment: 8,
+        package_type,
+        domain_layer_count_alignment: 2,
+        address_alignment: 8,
+        global_command_offset: 5,
+    };
+
+    return build_package(&tree, string_pool, &metadata).unwrap();
+}
+
+fn run(source: &str, arguments: Vec<Value>) -> Option<Value> {
+    let mut vm = VirtualMachine::from_bytes(&compile(source)).unwrap();
+    return vm.run(arguments).unwrap();
+}
+
+#[test]
+fn arithmetic() {
+    let source = r#"
+        decl func main()[number] {
+            decl var number a;
+            a = 7;
+            decl var number b;
+            b = (a - 2) * 3 - 10 / 5 + 9 % 4;
+            return b;
+        }
+    "#;
+
+    assert_eq!(run(source, vec![]), Some(Value::Number(14)));
+}
+
+#[test]
+fn function_call_with_arguments() {
+    let source = r#"
+        decl func main(number x)[number] {
+            decl var number result;
+            result = sub(x, 3) * 2;
+            return result;
+        }
+
+        decl func sub(number a, number b)[number] {
+            return a - b;
+        }
+    "#;
+
+    assert_eq!(run(source, vec![Value::Number(10)]), Some(Value::Number(14)));
+}
+
+#[test]
+fn string_constant() {
+    let source = r#"
+        decl func main()[str] {
+            decl var str s;
+            s = "Hello";
+            return s;
+        }
+    "#;
+
+    assert_eq!(run(source, vec![]), Some(Value::String("Hello".to_string())));
+}
+
+#[test]
+fn negation() {
+    let source = r#"
+        decl func main(number x)[number] {
+            decl var number y;
+            y = -x * 2 - -(x - 8);
+            if (-y > 0) {
+                return -y;
+            }
+            return y;
+        }
+    "#;
+
+    assert_eq!(run(source, vec![Value::Number(5)]), Some(Value::Number(13)));
+    assert_eq!(run(source, vec![Value::Number(-10)]), Some(Value::Number(2)));
+}
+
+#[test]
+fn relation_condition() {
+    let source = r#"
+        decl func main(number x)[number] {
+            decl var number result;
+            result = 0;
+            while (x > 0) {
+                result = result + x;
+                x = x - 1;
+            }
+            return result;
+        }
+    "#;
+
+    assert_eq!(run(source, vec![Value::Number(4)]), Some(Value::Number(10)));
+}
+
+#[test]
+fn comparison_value() {
+    let source = r#"
+        decl func main(number x, number y)[bool] {
+            decl var bool ok;
+            ok = x == y;
+            if (x > 0) {
+                ok = ok || x - y >= 3;
+            }
+            return !ok;
+        }
+    "#;
+
+    assert_eq!(run(source, vec![Value::Number(2), Value::Number(2)]), Some(Value::Number(0)));
+    assert_eq!(run(source, vec![Value::Number(5), Value::Number(1)]), Some(Value::Number(0)));
+    assert_eq!(run(source, vec![Value::Number(3), Value::Number(1)]), Some(Value::Number(1)));
+    assert_eq!(run(source, vec![Value::Number(-1), Value::Number(-5)]), Some(Value::Number(1)));
+}
+
+#[test]
+fn logical_condition() {
+    let source = r#"
+        decl func main(number x)[number] {
+            decl var number result;
+            result = 0;
+            if (x > 1 && x < 5 || x == -1) {
+                result = 1;
+            }
+            return result;
+        }
+    "#;
+
+    let results: Vec<Option<Value>> = [-1, 0, 1, 3, 5].iter().map(|x| run(source, vec![Value::Number(*x)])).collect();
+    assert_eq!(results, [1, 0, 0, 1, 0].iter().map(|x| Some(Value::Number(*x))).collect::<Vec<_>>());
+}
+
+#[test]
+fn short_circuit_condition() {
+    // Dividing by `x` is skipped once the left operands decide the conditions
+    let source = r#"
+        decl func main(number x)[number] {
+            decl var number result;
+            result = 0;
+            if (x <> 0 && 10 / x > 1) {
+                result = result + 1;
+            }
+            if (x == 0 || ten_times(x) > 1) {
+                result = result + 10;
+            }
+            return result;
+        }
+
+        decl func ten_times(number x)[number] {
+            return 10 / x;
+        }
+    "#;
+
+    assert_eq!(run(source, vec![Value::Number(0)]), Some(Value::Number(10)));
+    assert_eq!(run(source, vec![Value::Number(2)]), Some(Value::Number(11)));
+    assert_eq!(run(source, vec![Value::Number(20)]), Some(Value::Number(0)));
+}
+
+#[test]
+fn elif_chain() {
+    let source = r#"
+        decl func main(number x)[number] {
+            decl var number result;
+            result = 0;
+            if (x < 0) {
+                result = 1;
+            } elif (!(x > 10) && x <> 5) {
+                result = 2;
+            } elif (x == 5) {
+                result = 3;
+            }
+            return result;
+        }
+    "#;
+
+    let results: Vec<Option<Value>> = [-3, 5, 7, 12].iter().map(|x| run(source, vec![Value::Number(*x)])).collect();
+    assert_eq!(results, [1, 3, 2, 0].iter().map(|x| Some(Value::Number(*x))).collect::<Vec<_>>());
+}
+
+#[test]
+fn compound_while_condition() {
+    // Sum of the odd numbers below 10, stopped once it is over `x`
+    let source = r#"
+        decl func main(number x)[number] {
+            decl var number i;
+            i = 0;
+            decl var number sum;
+            sum = 0;
+            while (i < 10 && !(sum > x)) {
+                i = i + 1;
+                if (i % 2 == 0) {
+                    continue;
+                }
+                sum = sum + i;
+                if (i == 7 || sum == 0 - 1) {
+                    break;
+                }
+            }
+            return sum;
+        }
+    "#;
+
+    assert_eq!(run(source, vec![Value::Number(100)]), Some(Value::Number(16)));
+    assert_eq!(run(source, vec![Value::Number(3)]), Some(Value::Number(4)));
+}
+
+#[test]
+fn runtime_issue() {
+    let source = r#"
+        decl func main(number x)[number] {
+            return 1 / x;
+        }
+    "#;
+
+    let mut vm = VirtualMachine::from_bytes(&compile(source)).unwrap();
+    assert!(vm.run(vec![Value::Number(0)]).is_err());
+}
+
+#[test]
+fn hand_written_assembly() {
+    // Recursive factorial, the first argument is in local slot 0
+    let source = r#"
+        .entry main
+        .func main
+            stack.push_object $0
+            function.enter factorial 1
+            function.leave_value
+        .func factorial
+            stack.push_object $0
+            jump.stack_top recurse end end
+        recurse:
+            stack.push_object $0
+            stack.push #1
+            calc.minus
+            function.enter factorial 1
+            stack.push_object $0
+            calc.times
+            function.leave_value
+        end:
+            stack.push #1
+            function.leave_value
+    "#;
+
+    let mut vm = VirtualMachine::from_bytes(&assemble_package(source).ok().unwrap()).unwrap();
+    assert_eq!(vm.run(vec![Value::Number(5)]).unwrap(), Some(Value::Number(120)));
+}
+
+#[test]
+fn linked_libraries() {
+    let main = r#"
+        decl func describe(number x)[str];
+
+        export decl func main(number x)[str] {
+            decl var str s;
+            s = "unused";
+            return describe(x);
+        }
+    "#;
+    let util = r#"
+        export decl func describe(number x)[str] {
+            decl var str s;
+            s = positive();
+            if (x > 0) {
+                return s;
+            }
+            return "not positive";
+        }
+
+        decl func positive()[str] {
+            return "positive";
+        }
+    "#;
+
+    let packages = vec![
+        ("main".to_string(), compile_with_type(main, 1)),
+        ("util".to_string(), compile_with_type(util, 1)),
+    ];
+    let package = link_packages(&packages, "main").ok().unwrap();
+
+    let mut vm = VirtualMachine::from_bytes(&package).unwrap();
+    assert_eq!(vm.run(vec![Value::Number(3)]).unwrap(), Some(Value::String("positive".to_string())));
+    let mut vm = VirtualMachine::from_bytes(&package).unwrap();
+    assert_eq!(vm.run(vec![Value::Number(0)]).unwrap(), Some(Value::String("not positive".to_string())));
+}
+
+#[test]
+fn runtime_issue_source_location() {
+    let source = "decl func main(number x)[number] {\n    decl var number y;\n    y = 10 / x;\n    return y;\n}\n";
+    let (tokens, string_pool) = decorate_token(tokenize(source, true).unwrap());
+    let mut tree = build_whole_file(tokens, Identifier::single("main")).ok().unwrap();
+    tree.functions[0].file_path = "main.cbs".to_string();
+
+    let bytes = build_package_with_debug_info(&tree, string_pool, &PackageMetadata::default(), &[SourceMap::new("main.cbs", source)]).unwrap();
+    let mut vm = VirtualMachine::from_bytes(&bytes).unwrap();
+    assert_eq!(vm.run(vec![Value::Number(5)]).unwrap(), Some(Value::Number(2)));
+
+    let issue = vm.run(vec![Value::Number(0)]).unwrap_err();
+    assert_eq!(issue.content, "Division by zero");
+    assert_eq!(vm.source_location(issue.position), Some("main.cbs:3:5".to_string()));
+}